@@ -0,0 +1,140 @@
+//! Anthropic messages API client
+
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+use reqwest::Client;
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+
+use crate::ai::client::{api_error, http_client, request_error};
+use crate::ai::AiProvider;
+use crate::core::config::{AiBackend, Config};
+use crate::core::credentials::CredentialStore;
+use crate::error::Result;
+
+/// Anthropic messages endpoint
+const ANTHROPIC_MESSAGES_URL: &str = "https://api.anthropic.com/v1/messages";
+
+/// API version sent in the `anthropic-version` header
+const ANTHROPIC_API_VERSION: &str = "2023-06-01";
+
+/// Anthropic API client
+pub struct AnthropicClient {
+    client: Client,
+    api_key: String,
+    /// Request timeout, reported when it elapses
+    timeout: Duration,
+    model: String,
+}
+
+impl AnthropicClient {
+    /// Create an Anthropic client using the model from an already loaded config
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let api_key = CredentialStore::require_ai_key(AiBackend::Anthropic)?;
+
+        Ok(Self {
+            client: http_client(AiBackend::Anthropic, config.ai_timeout())?,
+            timeout: config.ai_timeout(),
+            api_key: api_key.expose_secret().to_string(),
+            model: config.anthropic_model.clone(),
+        })
+    }
+
+    /// Generate content using the messages API
+    async fn generate_content(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        let request_body = MessagesRequest {
+            model: &self.model,
+            max_tokens,
+            messages: vec![Message {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+        };
+
+        let response = self
+            .client
+            .post(ANTHROPIC_MESSAGES_URL)
+            .header("x-api-key", &self.api_key)
+            .header("anthropic-version", ANTHROPIC_API_VERSION)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| request_error(AiBackend::Anthropic, self.timeout, "Request failed", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(api_error(
+                AiBackend::Anthropic,
+                format!("API error ({}): {}", status, error_text),
+            ));
+        }
+
+        let messages_response: MessagesResponse = response.json().await.map_err(|e| {
+            request_error(
+                AiBackend::Anthropic,
+                self.timeout,
+                "Failed to parse response",
+                e,
+            )
+        })?;
+
+        // Join the text blocks of the response
+        let text: String = messages_response
+            .content
+            .into_iter()
+            .filter_map(|block| block.text)
+            .collect();
+
+        if text.is_empty() {
+            return Err(api_error(
+                AiBackend::Anthropic,
+                "Empty response from API".to_string(),
+            ));
+        }
+        Ok(text)
+    }
+}
+
+impl AiProvider for AnthropicClient {
+    fn backend(&self) -> AiBackend {
+        AiBackend::Anthropic
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    fn generate<'a>(&'a self, prompt: &'a str, max_tokens: u32) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.generate_content(prompt, max_tokens))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Anthropic API Request/Response types
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct MessagesRequest<'a> {
+    model: &'a str,
+    max_tokens: u32,
+    messages: Vec<Message>,
+}
+
+#[derive(Debug, Serialize)]
+struct Message {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct MessagesResponse {
+    content: Vec<ContentBlock>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ContentBlock {
+    #[serde(default)]
+    text: Option<String>,
+}
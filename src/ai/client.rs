@@ -0,0 +1,563 @@
+//! Provider-independent AI client
+//!
+//! Builds prompts and parses responses; the configured `AiProvider`
+//! only sends the prompt and returns the generated text.
+
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::Deserialize;
+
+use crate::ai::anthropic::AnthropicClient;
+use crate::ai::gemini::GeminiClient;
+use crate::ai::openai::OpenAiClient;
+use crate::ai::prompts;
+use crate::ai::AiProvider;
+use crate::core::branch_name;
+use crate::core::config::{AiBackend, Config};
+use crate::error::{GhrustError, Result};
+
+/// AI client for the provider selected in config (`ai_provider`)
+pub struct AiClient {
+    provider: Box<dyn AiProvider>,
+    /// Custom commit prompt from config, if enabled
+    commit_prompt_template: Option<String>,
+    /// Ask for conventional-commit subjects scoped by directory
+    conventional_commits: bool,
+}
+
+impl AiClient {
+    /// Create a client for the configured AI provider
+    pub fn new() -> Result<Self> {
+        let config = Config::load()?;
+        let provider: Box<dyn AiProvider> = match config.ai_provider {
+            AiBackend::Gemini => Box::new(GeminiClient::from_config(&config)?),
+            AiBackend::OpenAi => Box::new(OpenAiClient::from_config(&config)?),
+            AiBackend::Anthropic => Box::new(AnthropicClient::from_config(&config)?),
+        };
+
+        Ok(Self {
+            provider,
+            commit_prompt_template: config.active_commit_prompt_template().map(String::from),
+            conventional_commits: config.conventional_commits,
+        })
+    }
+
+    /// Get the current model name
+    pub fn model_name(&self) -> &str {
+        self.provider.model_name()
+    }
+
+    /// Model and prompt settings that shape the generated text
+    ///
+    /// Part of the key of cached AI results, so a result generated with
+    /// another model or commit prompt template is not reused.
+    pub fn prompt_settings(&self) -> String {
+        format!(
+            "{}\n{}\n{}",
+            self.model_name(),
+            self.conventional_commits,
+            self.commit_prompt_template.as_deref().unwrap_or_default()
+        )
+    }
+
+    /// Generate a commit message from a diff
+    ///
+    /// `directories` are the directories of the staged files, used as scope
+    /// candidates when conventional commits are enabled.
+    pub async fn generate_commit_message(
+        &self,
+        diff: &str,
+        directories: &[String],
+    ) -> Result<String> {
+        let prompt = self.commit_message_prompt(diff, directories);
+        let response = self.provider.generate(&prompt, 1024).await?;
+        Ok(clean_message(&response))
+    }
+
+    /// Generate a commit message, passing partial text to `on_chunk` as it streams in
+    ///
+    /// The returned message is cleaned up like `generate_commit_message`, so it
+    /// can differ slightly from the concatenated chunks.
+    pub async fn generate_commit_message_stream(
+        &self,
+        diff: &str,
+        directories: &[String],
+        on_chunk: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<String> {
+        let prompt = self.commit_message_prompt(diff, directories);
+        let response = self
+            .provider
+            .generate_stream(&prompt, 1024, on_chunk)
+            .await?;
+        Ok(clean_message(&response))
+    }
+
+    /// Build the commit message prompt for a diff
+    fn commit_message_prompt(&self, diff: &str, directories: &[String]) -> String {
+        // Smart truncate: keeps complete files, summarizes the rest
+        let truncated_diff = smart_truncate_diff(diff, 8000);
+        let prompt = match &self.commit_prompt_template {
+            Some(template) => prompts::custom_commit_message_prompt(template, &truncated_diff),
+            None => prompts::commit_message_prompt(&truncated_diff),
+        };
+        if self.conventional_commits {
+            format!(
+                "{}\n\n{}",
+                prompt,
+                prompts::conventional_commit_instructions(directories)
+            )
+        } else {
+            prompt
+        }
+    }
+
+    /// Generate a PR title and body from a diff
+    pub async fn generate_pr_content(&self, diff: &str, branch_name: &str) -> Result<PrContent> {
+        // Smart truncate: keeps complete files, summarizes the rest
+        let truncated_diff = smart_truncate_diff(diff, 8000);
+        let prompt = prompts::pr_content_prompt(&truncated_diff, branch_name);
+
+        let response = self.provider.generate(&prompt, 4096).await?;
+
+        // Parse JSON response
+        parse_pr_content(self.provider.backend(), &response)
+    }
+
+    /// Regenerate a PR title and body, steered by feedback on the previous result
+    ///
+    /// The diff is sent again so the model keeps the full context.
+    pub async fn regenerate_pr_content(
+        &self,
+        diff: &str,
+        branch_name: &str,
+        previous: &PrContent,
+        feedback: &str,
+    ) -> Result<PrContent> {
+        let truncated_diff = smart_truncate_diff(diff, 8000);
+        let prompt = format!(
+            "{}\n\n{}",
+            prompts::pr_content_prompt(&truncated_diff, branch_name),
+            prompts::pr_feedback_instructions(&previous.title, &previous.body, feedback)
+        );
+
+        let response = self.provider.generate(&prompt, 4096).await?;
+        parse_pr_content(self.provider.backend(), &response)
+    }
+
+    /// Generate a squash-merge commit body from a PR's commit messages
+    pub async fn generate_squash_message(
+        &self,
+        pr_title: &str,
+        commit_messages: &[String],
+    ) -> Result<String> {
+        let commits = truncate_diff(&commit_messages.join("\n---\n"), 8000);
+        let prompt = prompts::squash_message_prompt(pr_title, &commits);
+
+        let response = self.provider.generate(&prompt, 1024).await?;
+        Ok(clean_message(&response))
+    }
+
+    /// Suggest a branch name from a diff
+    ///
+    /// The response is normalized and checked against git ref naming rules.
+    pub async fn suggest_branch_name(&self, diff: &str) -> Result<String> {
+        let truncated_diff = smart_truncate_diff(diff, 8000);
+        let prompt = prompts::branch_name_prompt(&truncated_diff);
+
+        let response = self.provider.generate(&prompt, 256).await?;
+        let name = branch_name::sanitize(&response);
+
+        if !branch_name::is_valid(&name) {
+            return Err(api_error(
+                self.provider.backend(),
+                format!("Suggested branch name is not valid: '{}'", response.trim()),
+            ));
+        }
+
+        Ok(name)
+    }
+}
+
+/// Clean up a generated message - remove markdown code blocks if present
+fn clean_message(response: &str) -> String {
+    response
+        .trim()
+        .trim_start_matches("```")
+        .trim_end_matches("```")
+        .trim()
+        .to_string()
+}
+
+/// HTTP client for a provider with the configured request timeout
+pub(crate) fn http_client(backend: AiBackend, timeout: Duration) -> Result<Client> {
+    Client::builder()
+        .timeout(timeout)
+        .build()
+        .map_err(|e| api_error(backend, format!("Failed to create HTTP client: {}", e)))
+}
+
+/// Map a failed request, reporting elapsed timeouts as `AiTimeout`
+pub(crate) fn request_error(
+    backend: AiBackend,
+    timeout: Duration,
+    context: &str,
+    err: reqwest::Error,
+) -> GhrustError {
+    if err.is_timeout() {
+        GhrustError::AiTimeout {
+            provider: backend.display_name().to_string(),
+            seconds: timeout.as_secs(),
+        }
+    } else {
+        api_error(backend, format!("{}: {}", context, err))
+    }
+}
+
+/// Build the API error for a provider
+///
+/// Gemini keeps its own error variant so existing hints stay unchanged.
+pub(crate) fn api_error(backend: AiBackend, message: String) -> GhrustError {
+    match backend {
+        AiBackend::Gemini => GhrustError::GeminiApi(message),
+        other => GhrustError::AiApi {
+            provider: other.display_name().to_string(),
+            key: other.key_config_name().to_string(),
+            message,
+        },
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Diff parsing and smart truncation
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Represents a single file's diff section
+struct DiffSection {
+    /// File path from the diff header
+    file_path: String,
+    /// Full content of this file's diff
+    content: String,
+    /// Number of added lines (lines starting with '+', excluding header)
+    additions: usize,
+    /// Number of removed lines (lines starting with '-', excluding header)
+    deletions: usize,
+    /// Whether this is a binary file
+    is_binary: bool,
+}
+
+/// Parse a unified diff into per-file sections
+fn parse_diff_sections(diff: &str) -> Vec<DiffSection> {
+    let mut sections = Vec::new();
+    let mut current_content = String::new();
+    let mut current_path: Option<String> = None;
+    let mut additions = 0;
+    let mut deletions = 0;
+    let mut is_binary = false;
+
+    for line in diff.lines() {
+        // Check for new file section
+        if line.starts_with("diff --git ") {
+            // Save previous section if exists
+            if let Some(path) = current_path.take() {
+                sections.push(DiffSection {
+                    file_path: path,
+                    content: std::mem::take(&mut current_content),
+                    additions,
+                    deletions,
+                    is_binary,
+                });
+            }
+
+            // Extract file path: "diff --git a/path b/path" -> "path"
+            // Handle both regular and renamed files
+            if let Some(b_path) = line.split(" b/").last() {
+                current_path = Some(b_path.to_string());
+            }
+            additions = 0;
+            deletions = 0;
+            is_binary = false;
+        }
+
+        // Detect binary files
+        if line.starts_with("Binary files") || line.contains("GIT binary patch") {
+            is_binary = true;
+        }
+
+        // Count additions/deletions (but not header lines)
+        if line.starts_with('+') && !line.starts_with("+++") {
+            additions += 1;
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            deletions += 1;
+        }
+
+        // Append to current section
+        if current_path.is_some() {
+            current_content.push_str(line);
+            current_content.push('\n');
+        }
+    }
+
+    // Don't forget the last section
+    if let Some(path) = current_path {
+        sections.push(DiffSection {
+            file_path: path,
+            content: current_content,
+            additions,
+            deletions,
+            is_binary,
+        });
+    }
+
+    sections
+}
+
+/// Smart truncation that keeps complete files and summarizes the rest
+fn smart_truncate_diff(diff: &str, max_chars: usize) -> String {
+    // If diff fits, return as-is
+    if diff.len() <= max_chars {
+        return diff.to_string();
+    }
+
+    let sections = parse_diff_sections(diff);
+
+    // Fallback to simple truncation if parsing fails or no sections
+    if sections.is_empty() {
+        return truncate_diff(diff, max_chars);
+    }
+
+    let mut result = String::new();
+    let mut summarized_sections: Vec<&DiffSection> = Vec::new();
+
+    // Reserve space for summary section (~60 chars per file + header)
+    let summary_header = "\n--- FILES SUMMARIZED (diff too large) ---\n";
+    let chars_per_summary = 60;
+
+    for section in &sections {
+        let section_size = section.content.len();
+
+        // Estimate how much space we need for summaries of remaining files
+        let remaining_files = sections.len() - summarized_sections.len();
+        let estimated_summary_space = summary_header.len() + (remaining_files * chars_per_summary);
+
+        // Check if we can include this complete file
+        let available_space = max_chars.saturating_sub(result.len() + estimated_summary_space);
+
+        if section_size <= available_space {
+            result.push_str(&section.content);
+        } else {
+            // Can't fit this file, add to summary list
+            summarized_sections.push(section);
+        }
+    }
+
+    // Add summary for files that couldn't be included
+    if !summarized_sections.is_empty() {
+        result.push_str(summary_header);
+        for section in summarized_sections {
+            if section.is_binary {
+                result.push_str(&format!("{} (binary file)\n", section.file_path));
+            } else {
+                result.push_str(&format!(
+                    "{} (+{}/-{} lines)\n",
+                    section.file_path, section.additions, section.deletions
+                ));
+            }
+        }
+    }
+
+    result
+}
+
+/// Simple line-based truncation (fallback when diff parsing fails)
+fn truncate_diff(diff: &str, max_chars: usize) -> String {
+    if diff.len() <= max_chars {
+        return diff.to_string();
+    }
+
+    let mut result = String::with_capacity(max_chars);
+    let mut char_count = 0;
+
+    for line in diff.lines() {
+        if char_count + line.len() + 1 > max_chars {
+            result.push_str("\n... (diff truncated)");
+            break;
+        }
+        if !result.is_empty() {
+            result.push('\n');
+            char_count += 1;
+        }
+        result.push_str(line);
+        char_count += line.len();
+    }
+
+    result
+}
+
+/// Extract JSON content from a response (handles markdown code blocks)
+fn extract_json_from_markdown(response: &str) -> String {
+    let trimmed = response.trim();
+
+    // Strategy 1: Extract from ```json ... ``` blocks
+    if let Some(start) = trimmed.find("```json") {
+        let content_start = start + 7; // Skip "```json"
+        if content_start < trimmed.len() {
+            let rest = &trimmed[content_start..];
+            // Look for closing ``` or take everything after ```json if no closing
+            let content = if let Some(end) = rest.find("```") {
+                &rest[..end]
+            } else {
+                rest // No closing fence, take the rest
+            };
+            let json = content.trim();
+            if !json.is_empty() && json.starts_with('{') {
+                return json.to_string();
+            }
+        }
+    }
+
+    // Strategy 2: Extract from plain ``` ... ``` blocks (without json tag)
+    if !trimmed.contains("```json") {
+        if let Some(start) = trimmed.find("```") {
+            let content_start = start + 3;
+            if content_start < trimmed.len() {
+                let rest = &trimmed[content_start..];
+                let content = if let Some(end) = rest.find("```") {
+                    &rest[..end]
+                } else {
+                    rest
+                };
+                let json = content.trim();
+                if !json.is_empty() && json.starts_with('{') {
+                    return json.to_string();
+                }
+            }
+        }
+    }
+
+    // Strategy 3: Find raw JSON object { ... } anywhere in response
+    if let Some(start) = trimmed.find('{') {
+        if let Some(end) = trimmed.rfind('}') {
+            if end > start {
+                return trimmed[start..=end].to_string();
+            }
+        }
+    }
+
+    // Last resort: return as-is
+    trimmed.to_string()
+}
+
+/// Parse PR content from JSON response
+fn parse_pr_content(backend: AiBackend, response: &str) -> Result<PrContent> {
+    // Extract JSON from markdown code block (handles ```json ... ``` wrapping)
+    let json_str = extract_json_from_markdown(response);
+
+    // Check if we got valid-looking JSON
+    if json_str.is_empty() || !json_str.starts_with('{') {
+        return Err(api_error(
+            backend,
+            format!(
+                "AI response doesn't contain valid JSON. Got: {}",
+                response.chars().take(100).collect::<String>()
+            ),
+        ));
+    }
+
+    // Try direct parsing first
+    if let Ok(parsed) = serde_json::from_str::<PrContentJson>(&json_str) {
+        return Ok(PrContent {
+            title: parsed.title,
+            body: parsed.body,
+        });
+    }
+
+    // Fallback: Extract title and body using regex (handles malformed JSON)
+    let title = extract_json_field(&json_str, "title");
+    let body = extract_json_field(&json_str, "body");
+
+    if let Some(title) = title {
+        return Ok(PrContent {
+            title,
+            body: body.unwrap_or_default(),
+        });
+    }
+
+    // Last resort error
+    let preview = json_str.chars().take(200).collect::<String>();
+    Err(api_error(
+        backend,
+        format!("Failed to parse AI response. Preview: {}...", preview),
+    ))
+}
+
+/// Extract a string field from potentially malformed JSON
+fn extract_json_field(json: &str, field: &str) -> Option<String> {
+    // Look for "field": "value" or "field": "value...
+    let pattern = format!(r#""{}"\s*:\s*""#, field);
+    let re = regex::Regex::new(&pattern).ok()?;
+
+    if let Some(m) = re.find(json) {
+        let start = m.end();
+        let rest = &json[start..];
+
+        // Find the end of the string value (handling escaped quotes)
+        let mut chars = rest.chars().peekable();
+        let mut value = String::new();
+        let mut escaped = false;
+
+        for c in chars.by_ref() {
+            if escaped {
+                value.push(c);
+                escaped = false;
+            } else if c == '\\' {
+                escaped = true;
+                value.push(c);
+            } else if c == '"' {
+                break;
+            } else {
+                value.push(c);
+            }
+        }
+
+        // Unescape the value
+        let unescaped = value
+            .replace("\\n", "\n")
+            .replace("\\r", "\r")
+            .replace("\\t", "\t")
+            .replace("\\\"", "\"")
+            .replace("\\\\", "\\");
+
+        return Some(unescaped);
+    }
+
+    None
+}
+
+/// Generated PR content
+#[derive(Debug, Clone)]
+pub struct PrContent {
+    /// PR title
+    pub title: String,
+    /// PR body/description
+    pub body: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct PrContentJson {
+    title: String,
+    body: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_pr_content_previews_multibyte_text() {
+        // 99 ASCII bytes followed by a 4-byte emoji straddling byte 100
+        let response = format!("{}🚀 no json here", "a".repeat(99));
+        let err = parse_pr_content(AiBackend::Gemini, &response).unwrap_err();
+        assert!(err.to_string().contains("🚀"));
+    }
+}
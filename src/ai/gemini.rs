@@ -1,11 +1,15 @@
 //! Gemini API client
 
-use reqwest::Client;
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+use reqwest::{Client, StatusCode};
 use secrecy::ExposeSecret;
 use serde::{Deserialize, Serialize};
 
-use crate::ai::prompts;
-use crate::core::config::{Config, GeminiModel};
+use crate::ai::client::{http_client, request_error};
+use crate::ai::AiProvider;
+use crate::core::config::{AiBackend, Config, GeminiModel};
 use crate::core::credentials::CredentialStore;
 use crate::error::{GhrustError, Result};
 
@@ -16,55 +20,45 @@ const GEMINI_API_BASE: &str = "https://generativelanguage.googleapis.com/v1beta/
 pub struct GeminiClient {
     client: Client,
     api_key: String,
-    model: GeminiModel,
+    /// Request timeout, reported when it elapses
+    timeout: Duration,
+    /// Model identifier used in the request URL
+    model: String,
+    /// Model name shown to the user
+    model_display: String,
 }
 
 impl GeminiClient {
-    /// Create a new Gemini client
-    pub fn new() -> Result<Self> {
+    /// Create a Gemini client using the model from an already loaded config
+    pub fn from_config(config: &Config) -> Result<Self> {
         let api_key = CredentialStore::require_gemini_key()?;
-        let config = Config::load()?;
 
         Ok(Self {
-            client: Client::new(),
+            client: http_client(AiBackend::Gemini, config.ai_timeout())?,
+            timeout: config.ai_timeout(),
             api_key: api_key.expose_secret().to_string(),
-            model: config.gemini_model,
+            model: config.gemini_model_name().to_string(),
+            model_display: match config.gemini_model {
+                GeminiModel::Custom => config.gemini_model_name().to_string(),
+                model => model.display_name().to_string(),
+            },
         })
     }
 
-    /// Get the current model name
-    pub fn model_name(&self) -> &str {
-        self.model.display_name()
-    }
-
     /// Generate content using the Gemini API
-    async fn generate(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+    async fn generate_content(&self, prompt: &str, max_tokens: u32) -> Result<String> {
         let url = format!(
             "{}/{}:generateContent?key={}",
-            GEMINI_API_BASE,
-            self.model.api_name(),
-            self.api_key
+            GEMINI_API_BASE, self.model, self.api_key
         );
 
-        let request_body = GeminiRequest {
-            contents: vec![Content {
-                parts: vec![Part {
-                    text: prompt.to_string(),
-                }],
-            }],
-            generation_config: Some(GenerationConfig {
-                temperature: 0.7,
-                max_output_tokens: max_tokens,
-            }),
-        };
-
         let response = self
             .client
             .post(&url)
-            .json(&request_body)
+            .json(&request_body(prompt, max_tokens))
             .send()
             .await
-            .map_err(|e| GhrustError::GeminiApi(format!("Request failed: {}", e)))?;
+            .map_err(|e| request_error(AiBackend::Gemini, self.timeout, "Request failed", e))?;
 
         if !response.status().is_success() {
             let status = response.status();
@@ -75,362 +69,167 @@ impl GeminiClient {
             )));
         }
 
-        let gemini_response: GeminiResponse = response
-            .json()
-            .await
-            .map_err(|e| GhrustError::GeminiApi(format!("Failed to parse response: {}", e)))?;
+        let gemini_response: GeminiResponse = response.json().await.map_err(|e| {
+            request_error(
+                AiBackend::Gemini,
+                self.timeout,
+                "Failed to parse response",
+                e,
+            )
+        })?;
 
         // Extract the text from the response
         gemini_response
             .candidates
             .into_iter()
             .next()
-            .and_then(|c| c.content.parts.into_iter().next())
+            .and_then(|c| c.content)
+            .and_then(|c| c.parts.into_iter().next())
             .map(|p| p.text)
             .ok_or_else(|| GhrustError::GeminiApi("Empty response from API".to_string()))
     }
 
-    /// Generate a commit message from a diff
-    pub async fn generate_commit_message(&self, diff: &str) -> Result<String> {
-        // Smart truncate: keeps complete files, summarizes the rest
-        let truncated_diff = smart_truncate_diff(diff, 8000);
-        let prompt = prompts::commit_message_prompt(&truncated_diff);
-
-        let response = self.generate(&prompt, 1024).await?;
-
-        // Clean up the response - remove markdown code blocks if present
-        let cleaned = response
-            .trim()
-            .trim_start_matches("```")
-            .trim_end_matches("```")
-            .trim();
-
-        Ok(cleaned.to_string())
-    }
-
-    /// Generate a PR title and body from a diff
-    pub async fn generate_pr_content(&self, diff: &str, branch_name: &str) -> Result<PrContent> {
-        // Smart truncate: keeps complete files, summarizes the rest
-        let truncated_diff = smart_truncate_diff(diff, 8000);
-        let prompt = prompts::pr_content_prompt(&truncated_diff, branch_name);
-
-        let response = self.generate(&prompt, 4096).await?;
-
-        // Parse JSON response
-        parse_pr_content(&response)
-    }
-}
-
-// ─────────────────────────────────────────────────────────────────────────────
-// Diff parsing and smart truncation
-// ─────────────────────────────────────────────────────────────────────────────
-
-/// Represents a single file's diff section
-struct DiffSection {
-    /// File path from the diff header
-    file_path: String,
-    /// Full content of this file's diff
-    content: String,
-    /// Number of added lines (lines starting with '+', excluding header)
-    additions: usize,
-    /// Number of removed lines (lines starting with '-', excluding header)
-    deletions: usize,
-    /// Whether this is a binary file
-    is_binary: bool,
-}
-
-/// Parse a unified diff into per-file sections
-fn parse_diff_sections(diff: &str) -> Vec<DiffSection> {
-    let mut sections = Vec::new();
-    let mut current_content = String::new();
-    let mut current_path: Option<String> = None;
-    let mut additions = 0;
-    let mut deletions = 0;
-    let mut is_binary = false;
-
-    for line in diff.lines() {
-        // Check for new file section
-        if line.starts_with("diff --git ") {
-            // Save previous section if exists
-            if let Some(path) = current_path.take() {
-                sections.push(DiffSection {
-                    file_path: path,
-                    content: std::mem::take(&mut current_content),
-                    additions,
-                    deletions,
-                    is_binary,
-                });
-            }
-
-            // Extract file path: "diff --git a/path b/path" -> "path"
-            // Handle both regular and renamed files
-            if let Some(b_path) = line.split(" b/").last() {
-                current_path = Some(b_path.to_string());
-            }
-            additions = 0;
-            deletions = 0;
-            is_binary = false;
-        }
-
-        // Detect binary files
-        if line.starts_with("Binary files") || line.contains("GIT binary patch") {
-            is_binary = true;
-        }
-
-        // Count additions/deletions (but not header lines)
-        if line.starts_with('+') && !line.starts_with("+++") {
-            additions += 1;
-        } else if line.starts_with('-') && !line.starts_with("---") {
-            deletions += 1;
-        }
+    /// Stream content using the SSE endpoint, reporting each text piece to `on_chunk`
+    ///
+    /// Falls back to `generate_content` only when the model doesn't support
+    /// streaming (400 or 404 from `:streamGenerateContent`); other errors such
+    /// as an invalid key (401) or a rate limit (429) are returned.
+    async fn stream_content(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_chunk: &mut (dyn FnMut(&str) + Send),
+    ) -> Result<String> {
+        let url = format!(
+            "{}/{}:streamGenerateContent?alt=sse&key={}",
+            GEMINI_API_BASE, self.model, self.api_key
+        );
 
-        // Append to current section
-        if current_path.is_some() {
-            current_content.push_str(line);
-            current_content.push('\n');
+        let mut response = self
+            .client
+            .post(&url)
+            .json(&request_body(prompt, max_tokens))
+            .send()
+            .await
+            .map_err(|e| request_error(AiBackend::Gemini, self.timeout, "Request failed", e))?;
+
+        let status = response.status();
+        if status == StatusCode::BAD_REQUEST || status == StatusCode::NOT_FOUND {
+            tracing::debug!(
+                "Gemini streaming unsupported ({}), retrying without streaming",
+                status
+            );
+            let text = self.generate_content(prompt, max_tokens).await?;
+            on_chunk(&text);
+            return Ok(text);
         }
-    }
-
-    // Don't forget the last section
-    if let Some(path) = current_path {
-        sections.push(DiffSection {
-            file_path: path,
-            content: current_content,
-            additions,
-            deletions,
-            is_binary,
-        });
-    }
-
-    sections
-}
-
-/// Smart truncation that keeps complete files and summarizes the rest
-fn smart_truncate_diff(diff: &str, max_chars: usize) -> String {
-    // If diff fits, return as-is
-    if diff.len() <= max_chars {
-        return diff.to_string();
-    }
-
-    let sections = parse_diff_sections(diff);
-
-    // Fallback to simple truncation if parsing fails or no sections
-    if sections.is_empty() {
-        return truncate_diff(diff, max_chars);
-    }
-
-    let mut result = String::new();
-    let mut summarized_sections: Vec<&DiffSection> = Vec::new();
-
-    // Reserve space for summary section (~60 chars per file + header)
-    let summary_header = "\n--- FILES SUMMARIZED (diff too large) ---\n";
-    let chars_per_summary = 60;
-
-    for section in &sections {
-        let section_size = section.content.len();
-
-        // Estimate how much space we need for summaries of remaining files
-        let remaining_files = sections.len() - summarized_sections.len();
-        let estimated_summary_space = summary_header.len() + (remaining_files * chars_per_summary);
-
-        // Check if we can include this complete file
-        let available_space = max_chars.saturating_sub(result.len() + estimated_summary_space);
-
-        if section_size <= available_space {
-            result.push_str(&section.content);
-        } else {
-            // Can't fit this file, add to summary list
-            summarized_sections.push(section);
+        if !status.is_success() {
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(GhrustError::GeminiApi(format!(
+                "API error ({}): {}",
+                status, error_text
+            )));
         }
-    }
 
-    // Add summary for files that couldn't be included
-    if !summarized_sections.is_empty() {
-        result.push_str(summary_header);
-        for section in summarized_sections {
-            if section.is_binary {
-                result.push_str(&format!("{} (binary file)\n", section.file_path));
+        // Events are `data: {json}` lines; bytes are buffered since a chunk
+        // can end in the middle of a line or a UTF-8 sequence
+        let mut text = String::new();
+        let mut buffer: Vec<u8> = Vec::new();
+        loop {
+            let chunk = response
+                .chunk()
+                .await
+                .map_err(|e| request_error(AiBackend::Gemini, self.timeout, "Stream failed", e))?;
+            let done = chunk.is_none();
+            if let Some(bytes) = chunk {
+                buffer.extend_from_slice(&bytes);
             } else {
-                result.push_str(&format!(
-                    "{} (+{}/-{} lines)\n",
-                    section.file_path, section.additions, section.deletions
-                ));
+                buffer.push(b'\n');
             }
-        }
-    }
-
-    result
-}
-
-/// Simple line-based truncation (fallback when diff parsing fails)
-fn truncate_diff(diff: &str, max_chars: usize) -> String {
-    if diff.len() <= max_chars {
-        return diff.to_string();
-    }
 
-    let mut result = String::with_capacity(max_chars);
-    let mut char_count = 0;
-
-    for line in diff.lines() {
-        if char_count + line.len() + 1 > max_chars {
-            result.push_str("\n... (diff truncated)");
-            break;
-        }
-        if !result.is_empty() {
-            result.push('\n');
-            char_count += 1;
-        }
-        result.push_str(line);
-        char_count += line.len();
-    }
-
-    result
-}
-
-/// Extract JSON content from a response (handles markdown code blocks)
-fn extract_json_from_markdown(response: &str) -> String {
-    let trimmed = response.trim();
-
-    // Strategy 1: Extract from ```json ... ``` blocks
-    if let Some(start) = trimmed.find("```json") {
-        let content_start = start + 7; // Skip "```json"
-        if content_start < trimmed.len() {
-            let rest = &trimmed[content_start..];
-            // Look for closing ``` or take everything after ```json if no closing
-            let content = if let Some(end) = rest.find("```") {
-                &rest[..end]
-            } else {
-                rest // No closing fence, take the rest
-            };
-            let json = content.trim();
-            if !json.is_empty() && json.starts_with('{') {
-                return json.to_string();
+            for piece in take_sse_pieces(&mut buffer) {
+                on_chunk(&piece);
+                text.push_str(&piece);
             }
-        }
-    }
 
-    // Strategy 2: Extract from plain ``` ... ``` blocks (without json tag)
-    if !trimmed.contains("```json") {
-        if let Some(start) = trimmed.find("```") {
-            let content_start = start + 3;
-            if content_start < trimmed.len() {
-                let rest = &trimmed[content_start..];
-                let content = if let Some(end) = rest.find("```") {
-                    &rest[..end]
-                } else {
-                    rest
-                };
-                let json = content.trim();
-                if !json.is_empty() && json.starts_with('{') {
-                    return json.to_string();
-                }
+            if done {
+                break;
             }
         }
-    }
 
-    // Strategy 3: Find raw JSON object { ... } anywhere in response
-    if let Some(start) = trimmed.find('{') {
-        if let Some(end) = trimmed.rfind('}') {
-            if end > start {
-                return trimmed[start..=end].to_string();
-            }
+        if text.is_empty() {
+            return Err(GhrustError::GeminiApi(
+                "Empty response from API".to_string(),
+            ));
         }
+        Ok(text)
     }
-
-    // Last resort: return as-is
-    trimmed.to_string()
 }
 
-/// Parse PR content from JSON response
-fn parse_pr_content(response: &str) -> Result<PrContent> {
-    // Extract JSON from markdown code block (handles ```json ... ``` wrapping)
-    let json_str = extract_json_from_markdown(response);
-
-    // Check if we got valid-looking JSON
-    if json_str.is_empty() || !json_str.starts_with('{') {
-        return Err(GhrustError::GeminiApi(format!(
-            "AI response doesn't contain valid JSON. Got: {}",
-            &response[..response.len().min(100)]
-        )));
+impl AiProvider for GeminiClient {
+    fn backend(&self) -> AiBackend {
+        AiBackend::Gemini
     }
 
-    // Try direct parsing first
-    if let Ok(parsed) = serde_json::from_str::<PrContentJson>(&json_str) {
-        return Ok(PrContent {
-            title: parsed.title,
-            body: parsed.body,
-        });
+    fn model_name(&self) -> &str {
+        &self.model_display
     }
 
-    // Fallback: Extract title and body using regex (handles malformed JSON)
-    let title = extract_json_field(&json_str, "title");
-    let body = extract_json_field(&json_str, "body");
-
-    if let Some(title) = title {
-        return Ok(PrContent {
-            title,
-            body: body.unwrap_or_default(),
-        });
+    fn generate<'a>(&'a self, prompt: &'a str, max_tokens: u32) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.generate_content(prompt, max_tokens))
     }
 
-    // Last resort error
-    let preview = &json_str[..json_str.len().min(200)];
-    Err(GhrustError::GeminiApi(format!(
-        "Failed to parse AI response. Preview: {}...",
-        preview
-    )))
+    fn generate_stream<'a>(
+        &'a self,
+        prompt: &'a str,
+        max_tokens: u32,
+        on_chunk: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.stream_content(prompt, max_tokens, on_chunk))
+    }
 }
 
-/// Extract a string field from potentially malformed JSON
-fn extract_json_field(json: &str, field: &str) -> Option<String> {
-    // Look for "field": "value" or "field": "value...
-    let pattern = format!(r#""{}"\s*:\s*""#, field);
-    let re = regex::Regex::new(&pattern).ok()?;
-
-    if let Some(m) = re.find(json) {
-        let start = m.end();
-        let rest = &json[start..];
-
-        // Find the end of the string value (handling escaped quotes)
-        let mut chars = rest.chars().peekable();
-        let mut value = String::new();
-        let mut escaped = false;
-
-        for c in chars.by_ref() {
-            if escaped {
-                value.push(c);
-                escaped = false;
-            } else if c == '\\' {
-                escaped = true;
-                value.push(c);
-            } else if c == '"' {
-                break;
-            } else {
-                value.push(c);
-            }
-        }
-
-        // Unescape the value
-        let unescaped = value
-            .replace("\\n", "\n")
-            .replace("\\r", "\r")
-            .replace("\\t", "\t")
-            .replace("\\\"", "\"")
-            .replace("\\\\", "\\");
-
-        return Some(unescaped);
+/// Build a request for a single-turn prompt
+fn request_body(prompt: &str, max_tokens: u32) -> GeminiRequest {
+    GeminiRequest {
+        contents: vec![Content {
+            parts: vec![Part {
+                text: prompt.to_string(),
+            }],
+        }],
+        generation_config: Some(GenerationConfig {
+            temperature: 0.7,
+            max_output_tokens: max_tokens,
+        }),
     }
+}
 
-    None
+/// Remove the complete lines from `buffer` and return the text they carry
+///
+/// A trailing partial line stays in the buffer until the rest arrives.
+fn take_sse_pieces(buffer: &mut Vec<u8>) -> Vec<String> {
+    let mut pieces = Vec::new();
+    while let Some(pos) = buffer.iter().position(|b| *b == b'\n') {
+        let line: Vec<u8> = buffer.drain(..=pos).collect();
+        pieces.extend(parse_sse_line(&String::from_utf8_lossy(&line)));
+    }
+    pieces
 }
 
-/// Generated PR content
-#[derive(Debug, Clone)]
-pub struct PrContent {
-    /// PR title
-    pub title: String,
-    /// PR body/description
-    pub body: String,
+/// Text of one server-sent event line, if it carries any
+fn parse_sse_line(line: &str) -> Option<String> {
+    let data = line.trim_end().strip_prefix("data:")?.trim_start();
+    let response: GeminiResponse = serde_json::from_str(data).ok()?;
+    let text: String = response
+        .candidates
+        .into_iter()
+        .next()?
+        .content?
+        .parts
+        .into_iter()
+        .map(|p| p.text)
+        .collect();
+    (!text.is_empty()).then_some(text)
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -446,11 +245,13 @@ struct GeminiRequest {
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Content {
+    #[serde(default)]
     parts: Vec<Part>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 struct Part {
+    #[serde(default)]
     text: String,
 }
 
@@ -462,16 +263,56 @@ struct GenerationConfig {
 
 #[derive(Debug, Deserialize)]
 struct GeminiResponse {
+    #[serde(default)]
     candidates: Vec<Candidate>,
 }
 
 #[derive(Debug, Deserialize)]
 struct Candidate {
-    content: Content,
+    /// Missing on the final streamed event, which only carries the finish reason
+    #[serde(default)]
+    content: Option<Content>,
 }
 
-#[derive(Debug, Deserialize)]
-struct PrContentJson {
-    title: String,
-    body: String,
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn event(text: &str) -> String {
+        format!(
+            "data: {{\"candidates\": [{{\"content\": {{\"parts\": [{{\"text\": \"{}\"}}]}}}}]}}\r\n",
+            text
+        )
+    }
+
+    #[test]
+    fn test_parse_sse_line() {
+        assert_eq!(parse_sse_line(&event("Fix")), Some("Fix".to_string()));
+        assert_eq!(parse_sse_line(": keep-alive"), None);
+        assert_eq!(parse_sse_line(""), None);
+        assert_eq!(parse_sse_line("data: not json"), None);
+        // A final event may carry only finish metadata
+        assert_eq!(
+            parse_sse_line(r#"data: {"candidates": [{"finishReason": "STOP"}]}"#),
+            None
+        );
+    }
+
+    #[test]
+    fn test_take_sse_pieces_across_chunks() {
+        let stream = format!("{}\r\n{}\r\n", event("Add ü"), event("parser"));
+        let bytes = stream.as_bytes();
+        // Split inside the multi-byte "ü" and inside the second line
+        let split = stream.find('ü').unwrap() + 1;
+
+        let mut buffer = bytes[..split].to_vec();
+        assert!(take_sse_pieces(&mut buffer).is_empty());
+
+        buffer.extend_from_slice(&bytes[split..split + 40]);
+        assert_eq!(take_sse_pieces(&mut buffer), vec!["Add ü".to_string()]);
+
+        buffer.extend_from_slice(&bytes[split + 40..]);
+        assert_eq!(take_sse_pieces(&mut buffer), vec!["parser".to_string()]);
+        assert!(buffer.is_empty());
+    }
 }
@@ -1,10 +1,55 @@
 //! AI integration module
 //!
-//! This module provides Gemini AI integration for generating:
+//! This module provides AI integration for generating:
 //! - Commit messages
 //! - PR titles and descriptions
+//!
+//! Gemini, OpenAI and Anthropic are supported; `ai_provider` in the config
+//! selects which one `AiClient` talks to.
 
+pub mod anthropic;
+pub mod client;
 pub mod gemini;
+pub mod openai;
 pub mod prompts;
 
-pub use gemini::{GeminiClient, PrContent};
+pub use anthropic::AnthropicClient;
+pub use client::{AiClient, PrContent};
+pub use gemini::GeminiClient;
+pub use openai::OpenAiClient;
+
+use futures::future::BoxFuture;
+
+use crate::core::config::AiBackend;
+use crate::error::Result;
+
+/// A text-generation backend
+///
+/// Implementations only send a prompt and return the generated text;
+/// prompt building and response parsing are shared in `AiClient`.
+pub trait AiProvider: Send + Sync {
+    /// Which provider this is
+    fn backend(&self) -> AiBackend;
+
+    /// Model name shown to the user
+    fn model_name(&self) -> &str;
+
+    /// Generate text for a prompt, limited to `max_tokens` output tokens
+    fn generate<'a>(&'a self, prompt: &'a str, max_tokens: u32) -> BoxFuture<'a, Result<String>>;
+
+    /// Generate text, passing partial output to `on_chunk` as it arrives
+    ///
+    /// Providers without streaming pass the whole response as a single chunk.
+    fn generate_stream<'a>(
+        &'a self,
+        prompt: &'a str,
+        max_tokens: u32,
+        on_chunk: &'a mut (dyn FnMut(&str) + Send),
+    ) -> BoxFuture<'a, Result<String>> {
+        Box::pin(async move {
+            let text = self.generate(prompt, max_tokens).await?;
+            on_chunk(&text);
+            Ok(text)
+        })
+    }
+}
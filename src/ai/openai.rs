@@ -0,0 +1,133 @@
+//! OpenAI chat-completions client
+
+use futures::future::BoxFuture;
+use std::time::Duration;
+
+use reqwest::Client;
+use secrecy::ExposeSecret;
+use serde::{Deserialize, Serialize};
+
+use crate::ai::client::{api_error, http_client, request_error};
+use crate::ai::AiProvider;
+use crate::core::config::{AiBackend, Config};
+use crate::core::credentials::CredentialStore;
+use crate::error::Result;
+
+/// OpenAI chat-completions endpoint
+const OPENAI_CHAT_URL: &str = "https://api.openai.com/v1/chat/completions";
+
+/// OpenAI API client
+pub struct OpenAiClient {
+    client: Client,
+    api_key: String,
+    /// Request timeout, reported when it elapses
+    timeout: Duration,
+    model: String,
+}
+
+impl OpenAiClient {
+    /// Create an OpenAI client using the model from an already loaded config
+    pub fn from_config(config: &Config) -> Result<Self> {
+        let api_key = CredentialStore::require_ai_key(AiBackend::OpenAi)?;
+
+        Ok(Self {
+            client: http_client(AiBackend::OpenAi, config.ai_timeout())?,
+            timeout: config.ai_timeout(),
+            api_key: api_key.expose_secret().to_string(),
+            model: config.openai_model.clone(),
+        })
+    }
+
+    /// Generate content using the chat-completions API
+    async fn generate_content(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        let request_body = ChatRequest {
+            model: &self.model,
+            messages: vec![ChatMessage {
+                role: "user".to_string(),
+                content: prompt.to_string(),
+            }],
+            max_completion_tokens: max_tokens,
+        };
+
+        let response = self
+            .client
+            .post(OPENAI_CHAT_URL)
+            .bearer_auth(&self.api_key)
+            .json(&request_body)
+            .send()
+            .await
+            .map_err(|e| request_error(AiBackend::OpenAi, self.timeout, "Request failed", e))?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().await.unwrap_or_default();
+            return Err(api_error(
+                AiBackend::OpenAi,
+                format!("API error ({}): {}", status, error_text),
+            ));
+        }
+
+        let chat_response: ChatResponse = response.json().await.map_err(|e| {
+            request_error(
+                AiBackend::OpenAi,
+                self.timeout,
+                "Failed to parse response",
+                e,
+            )
+        })?;
+
+        chat_response
+            .choices
+            .into_iter()
+            .next()
+            .and_then(|c| c.message.content)
+            .filter(|text| !text.is_empty())
+            .ok_or_else(|| api_error(AiBackend::OpenAi, "Empty response from API".to_string()))
+    }
+}
+
+impl AiProvider for OpenAiClient {
+    fn backend(&self) -> AiBackend {
+        AiBackend::OpenAi
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model
+    }
+
+    fn generate<'a>(&'a self, prompt: &'a str, max_tokens: u32) -> BoxFuture<'a, Result<String>> {
+        Box::pin(self.generate_content(prompt, max_tokens))
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// OpenAI API Request/Response types
+// ─────────────────────────────────────────────────────────────────────────────
+
+#[derive(Debug, Serialize)]
+struct ChatRequest<'a> {
+    model: &'a str,
+    messages: Vec<ChatMessage>,
+    max_completion_tokens: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct ChatMessage {
+    role: String,
+    content: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct ChatResponse {
+    choices: Vec<Choice>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Choice {
+    message: ResponseMessage,
+}
+
+#[derive(Debug, Deserialize)]
+struct ResponseMessage {
+    content: Option<String>,
+}
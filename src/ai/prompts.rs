@@ -24,6 +24,42 @@ Generate only the commit message, no explanations:"#
     )
 }
 
+/// Extra instructions asking for a conventional-commit subject
+///
+/// `directories` are the directories of the staged files; the scope is chosen
+/// from them so it matches the project's layout.
+pub fn conventional_commit_instructions(directories: &[String]) -> String {
+    let mut instructions = String::from(
+        "Format the first line as a conventional commit: type(scope): description\n\
+         Types: feat, fix, docs, style, refactor, perf, test, build, ci, chore\n",
+    );
+
+    if directories.is_empty() {
+        instructions.push_str("Omit the scope if no single area of the project fits.");
+    } else {
+        instructions.push_str(&format!(
+            "Pick the scope from the changed directories, using the last path segment \
+             of the most relevant one (e.g. src/tui -> tui). Omit the scope if the \
+             changes span unrelated areas.\nChanged directories: {}",
+            directories.join(", ")
+        ));
+    }
+
+    instructions
+}
+
+/// Fill a user-supplied commit prompt template with the diff
+///
+/// `{diff}` is replaced with the diff; templates without the placeholder get
+/// the diff appended at the end.
+pub fn custom_commit_message_prompt(template: &str, diff: &str) -> String {
+    if template.contains("{diff}") {
+        template.replace("{diff}", diff)
+    } else {
+        format!("{}\n\nDiff:\n```\n{}\n```", template.trim_end(), diff)
+    }
+}
+
 /// Generate the prompt for PR title/body generation
 pub fn pr_content_prompt(diff: &str, branch_name: &str) -> String {
     format!(
@@ -59,3 +95,68 @@ Respond in this exact JSON format:
 }}"#
     )
 }
+
+/// Instructions for revising a generated PR title/body with user feedback
+///
+/// Appended to `pr_content_prompt`, so the model still sees the full diff.
+pub fn pr_feedback_instructions(
+    previous_title: &str,
+    previous_body: &str,
+    feedback: &str,
+) -> String {
+    let mut instructions = String::from("Revise your previous answer.\n");
+    if !previous_title.is_empty() || !previous_body.is_empty() {
+        instructions.push_str(&format!(
+            "\nPrevious title: {}\nPrevious body:\n{}\n",
+            previous_title, previous_body
+        ));
+    }
+    instructions.push_str(&format!(
+        "\nFeedback from the author: {}\n\nApply the feedback and respond in the same JSON format.",
+        feedback.trim()
+    ));
+    instructions
+}
+
+/// Generate the prompt for a squash-merge commit body
+pub fn squash_message_prompt(pr_title: &str, commits: &str) -> String {
+    format!(
+        r#"Write the body of a squash-merge commit for this pull request.
+
+PR title: {pr_title}
+
+Requirements:
+1. Summarize what the pull request changes as a whole, not commit by commit
+2. Use a short paragraph followed by bullet points for the key changes
+3. Drop noise such as "fix typo", "address review", "wip" and merge commits
+4. Wrap lines at 72 characters
+5. Do NOT repeat the title and do NOT invent changes not described below
+
+Commits in this pull request:
+```
+{commits}
+```
+
+Generate only the commit body, no explanations:"#
+    )
+}
+
+/// Generate the prompt for branch name suggestion
+pub fn branch_name_prompt(diff: &str) -> String {
+    format!(
+        r#"Analyze this git diff and suggest a git branch name for the work it contains.
+
+Requirements:
+1. Format: type/short-description (e.g. feat/add-login, fix/null-token)
+2. Types: feat, fix, docs, style, refactor, test, chore
+3. Lowercase words separated by dashes, at most 5 words
+4. Must be a valid git branch name (no spaces, no "..", no trailing ".lock")
+
+Diff:
+```
+{diff}
+```
+
+Respond with only the branch name:"#
+    )
+}
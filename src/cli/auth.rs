@@ -6,10 +6,13 @@ use std::process::Command;
 use chrono::Utc;
 use octocrab::Octocrab;
 
-use secrecy::SecretString;
+use secrecy::{ExposeSecret, SecretString};
 
 use crate::cli::commands::AuthCommand;
+use crate::core::config::{AiBackend, Config, GeminiModel};
 use crate::core::credentials::CredentialStore;
+use crate::core::update::UpdatePersistentState;
+use crate::core::TokenManager;
 use crate::error::{GhrustError, Result};
 use crate::github::auth::{DeviceFlowAuth, OAuthTokenData};
 
@@ -23,15 +26,21 @@ pub async fn handle_auth(command: AuthCommand) -> Result<()> {
                 handle_login_oauth().await
             }
         }
-        AuthCommand::Logout => handle_logout(),
-        AuthCommand::Status => handle_status(),
+        AuthCommand::Logout { all } => {
+            if all {
+                handle_logout_all()
+            } else {
+                handle_logout()
+            }
+        }
+        AuthCommand::Status => handle_status().await,
     }
 }
 
 /// Handle the login command using OAuth Device Flow
 async fn handle_login_oauth() -> Result<()> {
-    // Check if already authenticated
-    if CredentialStore::has_github_token()? {
+    // Check if already authenticated (an environment token doesn't count)
+    if CredentialStore::get_stored_github_token()?.is_some() {
         println!("✓ Already authenticated with GitHub.");
         println!();
         println!("  To re-authenticate, first run: gr auth logout");
@@ -81,8 +90,8 @@ async fn handle_login_oauth() -> Result<()> {
 /// PATs work with all repositories (personal + all organizations)
 /// without requiring OAuth app approval from org admins.
 async fn handle_login_pat() -> Result<()> {
-    // Check if already authenticated
-    if CredentialStore::has_github_token()? {
+    // Check if already authenticated (an environment token doesn't count)
+    if CredentialStore::get_stored_github_token()?.is_some() {
         println!("✓ Already authenticated with GitHub.");
         println!();
         println!("  To re-authenticate, first run: gr auth logout");
@@ -185,7 +194,7 @@ fn open_browser(url: &str) -> bool {
 
 /// Handle the logout command
 fn handle_logout() -> Result<()> {
-    if !CredentialStore::has_github_token()? {
+    if CredentialStore::get_stored_github_token()?.is_none() {
         println!("Not currently authenticated.");
         return Ok(());
     }
@@ -196,10 +205,101 @@ fn handle_logout() -> Result<()> {
     Ok(())
 }
 
+/// Handle `logout --all`: remove every stored credential and related state
+///
+/// Safe to run repeatedly; it reports what was removed and what was already gone.
+fn handle_logout_all() -> Result<()> {
+    let mut removed = Vec::new();
+    let mut absent = Vec::new();
+
+    if CredentialStore::get_stored_github_token()?.is_some() {
+        CredentialStore::delete_github_token_data()?;
+        removed.push("GitHub token");
+    } else {
+        absent.push("GitHub token");
+    }
+
+    for (provider, label) in [
+        (AiBackend::Gemini, "Gemini API key"),
+        (AiBackend::OpenAi, "OpenAI API key"),
+        (AiBackend::Anthropic, "Anthropic API key"),
+    ] {
+        if CredentialStore::get_stored_ai_key(provider)?.is_some() {
+            CredentialStore::delete_ai_key(provider)?;
+            removed.push(label);
+        } else {
+            absent.push(label);
+        }
+    }
+
+    let mut config = Config::load()?;
+    if config.gemini_model != GeminiModel::default() || config.gemini_custom_model.is_some() {
+        config.set_gemini_model(GeminiModel::default());
+        config.gemini_custom_model = None;
+        config.save()?;
+        removed.push("Gemini model setting");
+    } else {
+        absent.push("Gemini model setting");
+    }
+
+    if UpdatePersistentState::delete()? {
+        removed.push("update state");
+    } else {
+        absent.push("update state");
+    }
+
+    if removed.is_empty() {
+        println!("Nothing to remove; no credentials were stored.");
+    } else {
+        println!("Removed: {}", removed.join(", "));
+        if !absent.is_empty() {
+            println!("Not stored: {}", absent.join(", "));
+        }
+    }
+
+    // Environment variables can't be cleared from here
+    if let Some(name) = CredentialStore::github_token_env_var() {
+        println!("Note: ${} is still set and will be used for GitHub.", name);
+    }
+    for (name, provider) in [
+        ("GEMINI_API_KEY", "Gemini"),
+        ("OPENAI_API_KEY", "OpenAI"),
+        ("ANTHROPIC_API_KEY", "Anthropic"),
+    ] {
+        if std::env::var(name).is_ok_and(|key| !key.is_empty()) {
+            println!(
+                "Note: ${} is still set and will be used for {}.",
+                name, provider
+            );
+        }
+    }
+
+    Ok(())
+}
+
+/// Where the GitHub token in use comes from
+fn github_token_source() -> Option<String> {
+    if let Ok(Some(token_data)) = CredentialStore::get_github_token_data() {
+        return Some(if token_data.refresh_token.expose_secret().is_empty() {
+            "Personal access token".to_string()
+        } else {
+            "OAuth (device flow)".to_string()
+        });
+    }
+    if let Ok(Some(_)) = CredentialStore::get_stored_github_token() {
+        return Some("Stored token".to_string());
+    }
+    CredentialStore::github_token_env_var().map(|name| format!("Environment (${})", name))
+}
+
 /// Handle the status command
-fn handle_status() -> Result<()> {
+///
+/// Fails with `NotAuthenticated` (or the token error) so scripts can gate on the exit code.
+async fn handle_status() -> Result<()> {
     let has_github = CredentialStore::has_github_token()?;
-    let has_gemini = CredentialStore::has_gemini_key()?;
+    // A missing keychain (e.g. on CI) just means no AI key is stored
+    let provider = Config::load().map(|c| c.ai_provider).unwrap_or_default();
+    let has_ai_key = CredentialStore::has_ai_key(provider).unwrap_or(false);
 
     println!("Authentication Status:");
     println!(
@@ -211,17 +311,36 @@ fn handle_status() -> Result<()> {
         }
     );
     println!(
-        "  Gemini: {}",
-        if has_gemini {
+        "  {}: {}",
+        provider.display_name(),
+        if has_ai_key {
             "Configured"
         } else {
             "Not configured"
         }
     );
 
+    // Ask GitHub who the token belongs to; a rejected token is reported after the details
+    let user = if has_github {
+        Some(fetch_authenticated_user().await)
+    } else {
+        None
+    };
+
     if has_github {
+        println!();
+        if let Some(source) = github_token_source() {
+            println!("  GitHub source: {}", source);
+        }
+        match &user {
+            Some(Ok(login)) => println!("  GitHub user: @{}", login),
+            Some(Err(e)) if !is_auth_failure(e) => {
+                println!("  GitHub user: unknown (could not reach GitHub)")
+            }
+            _ => {}
+        }
         if let Ok(Some(token)) = CredentialStore::get_github_token() {
-            println!("\n  GitHub token: {}", CredentialStore::mask_token(&token));
+            println!("  GitHub token: {}", CredentialStore::mask_token(&token));
         }
 
         // Show token expiration if available (new format)
@@ -229,7 +348,10 @@ fn handle_status() -> Result<()> {
             let now = Utc::now();
             let expires_in = token_data.expires_at.signed_duration_since(now);
 
-            if expires_in.num_seconds() > 0 {
+            // Within a few minutes of expiry the token is already refreshed
+            if TokenManager::is_expired().unwrap_or(false) {
+                println!("  Token expired (will auto-refresh on next API call)");
+            } else {
                 let hours = expires_in.num_hours();
                 let minutes = expires_in.num_minutes() % 60;
                 if hours > 0 {
@@ -237,8 +359,6 @@ fn handle_status() -> Result<()> {
                 } else {
                     println!("  Token expires in: {}m", minutes);
                 }
-            } else {
-                println!("  Token expired (will auto-refresh on next API call)");
             }
 
             // Show refresh token status
@@ -254,11 +374,36 @@ fn handle_status() -> Result<()> {
         }
     }
 
-    if has_gemini {
-        if let Ok(Some(key)) = CredentialStore::get_gemini_key() {
-            println!("  Gemini key: {}", CredentialStore::mask_token(&key));
+    if has_ai_key {
+        if let Ok(Some(key)) = CredentialStore::get_ai_key(provider) {
+            println!(
+                "  {} key: {}",
+                provider.display_name(),
+                CredentialStore::mask_token(&key)
+            );
         }
     }
 
-    Ok(())
+    match user {
+        None => Err(GhrustError::NotAuthenticated),
+        Some(Err(e)) if is_auth_failure(&e) => Err(e),
+        Some(_) => Ok(()),
+    }
+}
+
+/// Whether an error means the token itself is unusable (as opposed to GitHub being unreachable)
+fn is_auth_failure(err: &GhrustError) -> bool {
+    err.exit_code() == crate::error::exit_code::AUTH
+}
+
+/// Look up the login of the user the GitHub token belongs to
+async fn fetch_authenticated_user() -> Result<String> {
+    let token = TokenManager::get_valid_token().await?;
+    let config = Config::load().unwrap_or_default();
+    let octocrab = Octocrab::builder()
+        .base_uri(config.github_base_url())?
+        .personal_token(token.expose_secret().to_string())
+        .build()?;
+    let user = octocrab.current().user().await?;
+    Ok(user.login)
 }
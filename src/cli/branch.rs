@@ -2,16 +2,27 @@
 
 use std::io::{self, Write};
 
+use crate::ai::AiClient;
 use crate::cli::commands::BranchCommand;
+use crate::core::branch_name;
+use crate::core::config::Config;
+use crate::core::git::GitRepository;
 use crate::core::repository::RepositoryContext;
-use crate::error::{GhrustError, Result};
+use crate::error::{DryRunPlan, GhrustError, Result};
 use crate::github::{BranchHandler, GitHubClient};
 
 /// Handle branch commands
-pub async fn handle_branch(command: BranchCommand) -> Result<()> {
+pub async fn handle_branch(command: BranchCommand, dry_run: bool) -> Result<()> {
     match command {
         BranchCommand::List => handle_list().await,
-        BranchCommand::Delete { name, force } => handle_delete(name, force).await,
+        BranchCommand::Create { name, ai } => handle_create(name, ai).await,
+        BranchCommand::Compare { base, head } => handle_compare(&base, &head),
+        BranchCommand::Delete {
+            name,
+            remote,
+            force,
+            yes,
+        } => handle_delete(name, remote, force, yes, dry_run),
     }
 }
 
@@ -50,27 +61,179 @@ async fn handle_list() -> Result<()> {
     Ok(())
 }
 
-async fn handle_delete(name: String, force: bool) -> Result<()> {
-    let repo_ctx = RepositoryContext::detect()?;
-    let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
-    let handler = BranchHandler::new(&client);
+async fn handle_create(name: Option<String>, ai: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?;
 
-    // Check if branch exists
-    if !handler.exists(&name).await? {
-        return Err(GhrustError::BranchNotFound(name));
+    let name = match name {
+        Some(name) => name,
+        None => {
+            let suggestion = if ai {
+                suggest_ai_branch_name(&git).await?
+            } else {
+                git.head_commit_summary()?
+                    .and_then(|subject| branch_name::suggest_from_subject(&subject))
+            };
+            prompt_branch_name(suggestion)?
+        }
+    };
+
+    if !branch_name::is_valid(&name) {
+        return Err(GhrustError::InvalidInput(format!(
+            "'{}' is not a valid branch name",
+            name
+        )));
+    }
+
+    git.create_branch(&name)?;
+    println!("✓ Created and switched to branch '{}'", name);
+
+    Ok(())
+}
+
+/// Suggest a branch name from staged (or all) changes using AI
+async fn suggest_ai_branch_name(git: &GitRepository) -> Result<Option<String>> {
+    let mut diff = git.staged_diff()?;
+    if diff.is_empty() {
+        diff = git.all_changes_diff()?;
+    }
+    if diff.is_empty() {
+        return Err(GhrustError::InvalidInput(
+            "No changes to suggest a branch name from".to_string(),
+        ));
+    }
+
+    println!("Suggesting branch name with AI...");
+    let client = AiClient::new()?;
+    Ok(Some(client.suggest_branch_name(&diff.text).await?))
+}
+
+/// Ask for a branch name, offering the suggestion as the default
+fn prompt_branch_name(suggestion: Option<String>) -> Result<String> {
+    match &suggestion {
+        Some(s) => print!("Branch name [{}]: ", s),
+        None => print!("Branch name: "),
+    }
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+    let input = input.trim();
+
+    if !input.is_empty() {
+        return Ok(input.to_string());
+    }
+
+    suggestion.ok_or(GhrustError::Cancelled)
+}
+
+/// Warn before committing or pushing directly to a protected branch
+///
+/// Protection comes from the configured list (default: the repository's default
+/// branch) or GitHub branch protection when it can be queried. Returns
+/// `Cancelled` unless the user confirms or `allow` is set.
+pub(crate) async fn confirm_protected_branch(
+    git: &GitRepository,
+    branch: &str,
+    action: &str,
+    allow: bool,
+) -> Result<()> {
+    if allow {
+        return Ok(());
+    }
+
+    let config = Config::load().unwrap_or_default();
+    let default_branch = git.default_branch().unwrap_or_else(|| "main".to_string());
+    let protected =
+        config.is_protected_branch(branch, &default_branch) || github_protected(branch).await;
+
+    if !protected {
+        return Ok(());
+    }
+
+    println!("⚠ '{}' is a protected branch.", branch);
+    println!("  Create a feature branch first with: gr branch create");
+    print!("{} directly to '{}' anyway? [y/N] ", action, branch);
+    io::stdout().flush()?;
+
+    let mut input = String::new();
+    io::stdin().read_line(&mut input)?;
+
+    if input.trim().eq_ignore_ascii_case("y") {
+        Ok(())
+    } else {
+        Err(GhrustError::Cancelled)
     }
+}
+
+/// Check GitHub branch protection, treating any failure as unprotected
+async fn github_protected(branch: &str) -> bool {
+    let Ok(repo_ctx) = RepositoryContext::detect() else {
+        return false;
+    };
+    let Ok(client) = GitHubClient::new(repo_ctx.owner, repo_ctx.name).await else {
+        return false;
+    };
+    BranchHandler::new(&client)
+        .is_protected(branch)
+        .await
+        .unwrap_or(false)
+}
+
+fn handle_compare(base: &str, head: &str) -> Result<()> {
+    let git = GitRepository::open_current_dir()?;
+
+    let (ahead, behind) = git.commits_count_between(base, head)?;
+    let commits = git.get_commits_between(base, head)?;
+
+    println!("Comparing {}...{}\n", base, head);
+    println!(
+        "  {} {} ahead, {} behind",
+        ahead,
+        if ahead == 1 { "commit" } else { "commits" },
+        behind
+    );
+
+    if !commits.is_empty() {
+        println!("\nCommits in {} not in {}:\n", head, base);
+        for subject in commits {
+            println!("  • {}", subject);
+        }
+    }
+
+    Ok(())
+}
+
+fn handle_delete(name: String, remote: bool, force: bool, yes: bool, dry_run: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
     // Check if trying to delete current branch
-    if name == repo_ctx.current_branch {
+    if git.current_branch().is_ok_and(|current| current == name) {
         return Err(GhrustError::InvalidInput(format!(
-            "Cannot delete '{}': it is your current branch",
+            "Cannot delete '{}': it is your current branch\n\n  → Switch to another branch first.",
             name
         )));
     }
 
-    // Confirm deletion unless --force
-    if !force {
-        print!("Delete remote branch '{}'? [y/N] ", name);
+    let exists_locally = git.branch_exists(&name);
+    if !exists_locally && !remote {
+        // Before --remote existed, this command deleted the branch on origin
+        if git.remote_branch_exists(&name) {
+            return Err(GhrustError::InvalidInput(format!(
+                "Branch '{}' only exists on origin\n\n  → 'branch delete' no longer deletes remote branches by default; pass --remote to delete it from origin.",
+                name
+            )));
+        }
+        return Err(GhrustError::BranchNotFound(name));
+    }
+
+    // Confirm deletion unless --yes
+    if !yes {
+        let scope = match (exists_locally, remote) {
+            (true, true) => "locally and from origin",
+            (false, true) => "from origin",
+            _ => "locally",
+        };
+        print!("Delete branch '{}' {}? [y/N] ", name, scope);
         io::stdout().flush()?;
 
         let mut input = String::new();
@@ -82,8 +245,23 @@ async fn handle_delete(name: String, force: bool) -> Result<()> {
         }
     }
 
-    handler.delete(&name).await?;
-    println!("✓ Deleted remote branch '{}'", name);
+    // In dry-run each step is recorded and all of them are reported at the end
+    let mut plan = DryRunPlan::default();
 
-    Ok(())
+    if exists_locally && plan.step(git.delete_local_branch(&name, force))? {
+        println!("✓ Deleted local branch '{}'", name);
+    }
+
+    if remote && plan.step(git.delete_remote_branch(&name))? {
+        println!("✓ Deleted branch '{}' from origin", name);
+    }
+
+    if !remote && git.remote_branch_exists(&name) {
+        println!(
+            "  (origin still has '{}'; use --remote to delete it there too)",
+            name
+        );
+    }
+
+    plan.finish()
 }
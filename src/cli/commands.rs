@@ -14,6 +14,51 @@ pub struct Cli {
     /// Subcommand to execute
     #[command(subcommand)]
     pub command: Option<Commands>,
+
+    /// Show what commits, merges, pushes and deletions would do without doing them
+    #[arg(long, global = true, hide = true)]
+    pub dry_run: bool,
+
+    /// Only log errors (overrides RUST_LOG)
+    #[arg(short, long, global = true, conflicts_with = "verbose")]
+    pub quiet: bool,
+
+    /// Log more detail: -v info, -vv debug, -vvv trace (overrides RUST_LOG)
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    pub verbose: u8,
+
+    /// Render the TUI without color (also enabled by a non-empty NO_COLOR)
+    #[arg(long, global = true)]
+    pub no_color: bool,
+
+    /// Output format for errors (json emits a machine-readable object)
+    #[arg(long, global = true, value_enum, default_value_t = OutputFormat::Text)]
+    pub format: OutputFormat,
+}
+
+impl Cli {
+    /// Log level requested by `-q` / `-v`, or `None` to use `RUST_LOG`
+    pub fn log_level(&self) -> Option<&'static str> {
+        if self.quiet {
+            return Some("error");
+        }
+        match self.verbose {
+            0 => None,
+            1 => Some("info"),
+            2 => Some("debug"),
+            _ => Some("trace"),
+        }
+    }
+}
+
+/// Output format for CLI errors
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, ValueEnum)]
+pub enum OutputFormat {
+    /// Human-readable messages
+    #[default]
+    Text,
+    /// A JSON object with a code, message and context
+    Json,
 }
 
 /// Available commands
@@ -68,7 +113,11 @@ pub enum AuthCommand {
         pat: bool,
     },
     /// Logout and remove stored credentials
-    Logout,
+    Logout {
+        /// Also remove the Gemini API key, the saved Gemini model and update state
+        #[arg(long)]
+        all: bool,
+    },
     /// Show current authentication status
     Status,
 }
@@ -99,6 +148,10 @@ pub enum PrCommand {
         /// Maximum number of PRs to show
         #[arg(short = 'n', long, default_value = "30")]
         limit: usize,
+
+        /// Print the PRs as a JSON array
+        #[arg(long)]
+        json: bool,
     },
 
     /// Create a new pull request
@@ -123,6 +176,14 @@ pub enum PrCommand {
         #[arg(long)]
         draft: bool,
 
+        /// Request reviews from these users (comma-separated or repeated)
+        #[arg(long = "reviewer", value_delimiter = ',')]
+        reviewers: Vec<String>,
+
+        /// Assign these users (comma-separated or repeated)
+        #[arg(long = "assignee", value_delimiter = ',')]
+        assignees: Vec<String>,
+
         /// Auto-generate title and body using Gemini AI
         #[arg(long)]
         ai: bool,
@@ -134,12 +195,18 @@ pub enum PrCommand {
         number: u64,
     },
 
+    /// Fetch a pull request's branch and switch to it as `pr/<number>`
+    Checkout {
+        /// PR number
+        number: u64,
+    },
+
     /// Add a comment to a pull request
     Comment {
         /// PR number
         number: u64,
 
-        /// Comment text
+        /// Comment text (`-` reads it from stdin)
         text: String,
     },
 
@@ -201,14 +268,41 @@ pub enum BranchCommand {
     /// List remote branches
     List,
 
-    /// Delete a remote branch
+    /// Create a local branch from HEAD and switch to it
+    Create {
+        /// Branch name (suggested from the last commit or changes if omitted)
+        name: Option<String>,
+
+        /// Suggest the branch name from the current changes using AI
+        #[arg(long)]
+        ai: bool,
+    },
+
+    /// Show how far head is ahead of / behind base and list its unique commits
+    Compare {
+        /// Base branch to compare against
+        base: String,
+
+        /// Branch whose unique commits are listed
+        head: String,
+    },
+
+    /// Delete a local branch, and optionally the branch on origin
     Delete {
         /// Branch name to delete
         name: String,
 
-        /// Force delete without confirmation
-        #[arg(long, short)]
+        /// Also delete the branch from origin
+        #[arg(short, long)]
+        remote: bool,
+
+        /// Delete the local branch even if it has unmerged commits
+        #[arg(short, long)]
         force: bool,
+
+        /// Delete without confirmation
+        #[arg(short, long)]
+        yes: bool,
     },
 }
 
@@ -305,6 +399,10 @@ pub struct CommitArgs {
     /// Create a tag with this name
     #[arg(short = 't', long)]
     pub tag: Option<String>,
+
+    /// Commit even if the current branch is protected
+    #[arg(long)]
+    pub allow_protected: bool,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -325,6 +423,10 @@ pub struct PushArgs {
     /// Set upstream tracking for the branch
     #[arg(short = 'u', long)]
     pub set_upstream: bool,
+
+    /// Push even if the current branch is protected
+    #[arg(long)]
+    pub allow_protected: bool,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -360,6 +462,15 @@ pub enum ConfigCommand {
         /// Configuration key
         key: ConfigKey,
     },
+
+    /// Set the GitHub API base URL (GitHub Enterprise Server)
+    SetBaseUrl {
+        /// API URL including the scheme, e.g. https://github.example.com/api/v3
+        url: String,
+    },
+
+    /// Show the GitHub API base URL in effect
+    GetBaseUrl,
 }
 
 /// Available configuration keys
@@ -372,6 +483,86 @@ pub enum ConfigKey {
     /// Gemini model selection
     #[value(name = "gemini-model")]
     GeminiModel,
+
+    /// AI provider for generated content (gemini, openai, anthropic)
+    #[value(name = "ai-provider")]
+    AiProvider,
+
+    /// OpenAI API key
+    #[value(name = "openai-key")]
+    OpenaiKey,
+
+    /// OpenAI model name (e.g. gpt-4o-mini)
+    #[value(name = "openai-model")]
+    OpenaiModel,
+
+    /// Anthropic API key
+    #[value(name = "anthropic-key")]
+    AnthropicKey,
+
+    /// Anthropic model name (e.g. claude-sonnet-4-5)
+    #[value(name = "anthropic-model")]
+    AnthropicModel,
+
+    /// Seconds before an AI request is abandoned (5-600)
+    #[value(name = "ai-timeout-seconds")]
+    AiTimeoutSeconds,
+
+    /// Comma-separated branches protected from direct commits/pushes
+    #[value(name = "protected-branches")]
+    ProtectedBranches,
+
+    /// What `q` does on the TUI dashboard (back, quit, prompt)
+    #[value(name = "q-behavior")]
+    QBehavior,
+
+    /// Render HTML in PR descriptions instead of stripping it (true, false)
+    #[value(name = "render-html")]
+    RenderHtml,
+
+    /// Keep TUI list selections when navigating back (true, false)
+    #[value(name = "remember-selection")]
+    RememberSelection,
+
+    /// Click and scroll with the mouse in the TUI (true, false)
+    #[value(name = "mouse")]
+    Mouse,
+
+    /// TUI color theme preset (dark, light, high-contrast)
+    #[value(name = "theme")]
+    Theme,
+
+    /// Editor for long PR bodies and commit messages in the TUI
+    #[value(name = "editor")]
+    Editor,
+
+    /// Report merges, pushes and deletions instead of performing them (true, false)
+    #[value(name = "dry-run")]
+    DryRun,
+
+    /// Seconds between TUI workflow run refreshes while runs are active (2-60)
+    #[value(name = "workflow-poll-seconds")]
+    WorkflowPollSeconds,
+
+    /// Store credentials in a file when the system keychain is unavailable (true, false)
+    #[value(name = "credential-file-fallback")]
+    CredentialFileFallback,
+
+    /// Retries for GitHub API reads after server or connection errors (0-10)
+    #[value(name = "github-retries")]
+    GithubRetries,
+
+    /// Pull requests fetched per page in the TUI PR list (1-100)
+    #[value(name = "pr-list-page-size")]
+    PrListPageSize,
+
+    /// Custom AI commit prompt; `{diff}` is replaced with the diff
+    #[value(name = "commit-prompt-template")]
+    CommitPromptTemplate,
+
+    /// Scope AI commit messages by changed directory, e.g. `feat(ui): ...` (true, false)
+    #[value(name = "conventional-commits")]
+    ConventionalCommits,
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -393,6 +584,10 @@ pub enum WorkflowCommand {
         #[arg(long, short)]
         branch: Option<String>,
 
+        /// Filter by the user who triggered the run (use @me for yourself)
+        #[arg(long, short)]
+        actor: Option<String>,
+
         /// Filter by status (queued, in_progress, completed)
         #[arg(long)]
         status: Option<String>,
@@ -400,6 +595,10 @@ pub enum WorkflowCommand {
         /// Maximum number of runs to show
         #[arg(short = 'n', long, default_value = "20")]
         limit: u8,
+
+        /// Print the runs as a JSON array
+        #[arg(long)]
+        json: bool,
     },
 
     /// View details of a specific workflow run
@@ -407,6 +606,16 @@ pub enum WorkflowCommand {
         /// Workflow run ID
         run_id: u64,
     },
+
+    /// Watch the latest run on a branch until it completes
+    Watch {
+        /// Branch to watch (defaults to the current branch)
+        branch: Option<String>,
+
+        /// Seconds between status checks
+        #[arg(long, short, default_value = "10")]
+        interval: u64,
+    },
 }
 
 // ─────────────────────────────────────────────────────────────────────────────
@@ -2,14 +2,15 @@
 
 use std::io::{self, Write};
 
-use crate::ai::GeminiClient;
+use crate::ai::AiClient;
+use crate::cli::branch::confirm_protected_branch;
 use crate::cli::commands::CommitArgs;
-use crate::core::git::GitRepository;
+use crate::core::git::{staged_directories, GitRepository, MAX_DIFF_BYTES};
 use crate::error::{GhrustError, Result};
 
 /// Handle commit commands
-pub async fn handle_commit(args: CommitArgs) -> Result<()> {
-    let git = GitRepository::open_current_dir()?;
+pub async fn handle_commit(args: CommitArgs, dry_run: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
     // Show branch info
     let branch = git.current_branch()?;
@@ -18,25 +19,30 @@ pub async fn handle_commit(args: CommitArgs) -> Result<()> {
         .unwrap_or_else(|| format!("origin/{}", branch));
     println!("On branch {} → {}", branch, tracking);
 
-    // Stage specified paths if provided
+    // Stage specified paths if provided. A dry run stages into the in-memory
+    // index only, so the preview below shows the would-be staged set.
+    let staged_verb = if dry_run { "Would stage" } else { "Staged" };
     if !args.paths.is_empty() {
         for path in &args.paths {
             if path.is_dir() {
                 git.stage_directory(path)?;
-                println!("  Staged directory: {}/", path.display());
-            } else if path.exists() {
-                git.stage_file(&path.to_string_lossy())?;
-                println!("  Staged: {}", path.display());
+                println!("  {} directory: {}/", staged_verb, path.display());
             } else {
-                // Try as a pattern (file might be deleted or path is relative)
+                // Also stages deletions of paths that no longer exist
                 git.stage_file(&path.to_string_lossy())?;
-                println!("  Staged: {}", path.display());
+                println!("  {}: {}", staged_verb, path.display());
             }
         }
     } else if args.all {
         // Stage all if requested (only if no explicit paths provided)
         git.stage_all()?;
-        println!("Staged all modified files.");
+        println!("{} all modified files.", staged_verb);
+    }
+
+    // Refuse early so no AI request is spent on a tree that cannot be committed
+    let conflicts = git.conflicted_files()?;
+    if !conflicts.is_empty() {
+        return Err(GhrustError::UnresolvedConflicts(conflicts));
     }
 
     // Check for staged changes
@@ -65,9 +71,12 @@ pub async fn handle_commit(args: CommitArgs) -> Result<()> {
     }
     println!();
 
+    confirm_protected_branch(&git, &branch, "Commit", args.allow_protected).await?;
+
     // Get commit message
     let message = if args.ai {
-        generate_ai_commit_message(&git).await?
+        let directories = staged_directories(staged_files.iter().copied());
+        generate_ai_commit_message(&git, &directories, dry_run).await?
     } else if let Some(msg) = args.message {
         msg
     } else {
@@ -76,6 +85,10 @@ pub async fn handle_commit(args: CommitArgs) -> Result<()> {
         ));
     };
 
+    if dry_run {
+        return print_commit_preview(&git, &message, args.tag.as_deref(), args.push, &tracking);
+    }
+
     // Create commit
     let commit_hash = git.commit(&message)?;
     println!("✓ Created commit: {}", &commit_hash[..8]);
@@ -103,8 +116,58 @@ pub async fn handle_commit(args: CommitArgs) -> Result<()> {
     Ok(())
 }
 
+/// Print what a dry-run commit would do, without touching the index or HEAD
+fn print_commit_preview(
+    git: &GitRepository,
+    message: &str,
+    tag: Option<&str>,
+    push: bool,
+    tracking: &str,
+) -> Result<()> {
+    let diff = git.staged_diff()?;
+    let (insertions, deletions) = diff_line_counts(&diff.text);
+
+    println!(
+        "[dry-run] Would commit ({} +, {} -):",
+        insertions, deletions
+    );
+    println!("─────────────────────────────────────");
+    println!("{}", message);
+    println!("─────────────────────────────────────");
+
+    if let Some(tag_name) = tag {
+        println!("[dry-run] Would create tag: {}", tag_name);
+    }
+    if push {
+        println!("[dry-run] Would push to {}", tracking);
+    }
+    println!("Nothing was changed.");
+
+    Ok(())
+}
+
+/// Count added and removed lines in patch text, ignoring file headers
+fn diff_line_counts(patch: &str) -> (usize, usize) {
+    patch.lines().fold((0, 0), |(added, removed), line| {
+        if line.starts_with('+') && !line.starts_with("+++") {
+            (added + 1, removed)
+        } else if line.starts_with('-') && !line.starts_with("---") {
+            (added, removed + 1)
+        } else {
+            (added, removed)
+        }
+    })
+}
+
 /// Generate commit message using AI
-async fn generate_ai_commit_message(git: &GitRepository) -> Result<String> {
+///
+/// In a dry run the message is returned without asking for confirmation, since
+/// it is only previewed.
+async fn generate_ai_commit_message(
+    git: &GitRepository,
+    directories: &[String],
+    dry_run: bool,
+) -> Result<String> {
     // Get the diff for AI generation
     let diff = git.staged_diff()?;
     if diff.is_empty() {
@@ -113,14 +176,27 @@ async fn generate_ai_commit_message(git: &GitRepository) -> Result<String> {
         ));
     }
 
+    if diff.truncated {
+        println!(
+            "Note: the staged diff is larger than {} KB; only the first part is used.",
+            MAX_DIFF_BYTES / 1024
+        );
+    }
+
     println!("Generating commit message with AI...");
 
-    // Create Gemini client
-    let client = GeminiClient::new()?;
+    // Create AI client for the configured provider
+    let client = AiClient::new()?;
     println!("Using model: {}", client.model_name());
 
     // Generate message
-    let generated = client.generate_commit_message(&diff).await?;
+    let generated = client
+        .generate_commit_message(&diff.text, directories)
+        .await?;
+
+    if dry_run {
+        return Ok(generated);
+    }
 
     println!("\nGenerated message:");
     println!("─────────────────────────────────────");
@@ -154,3 +230,34 @@ async fn generate_ai_commit_message(git: &GitRepository) -> Result<String> {
         _ => Err(GhrustError::Cancelled),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_line_counts() {
+        let patch = "\
+diff --git a/src/lib.rs b/src/lib.rs
+--- a/src/lib.rs
++++ b/src/lib.rs
+@@ -1,3 +1,4 @@
+ fn main() {
+-    old();
++    new();
++    more();
+ }
+";
+        assert_eq!(diff_line_counts(patch), (2, 1));
+    }
+
+    #[test]
+    fn test_diff_line_counts_ignores_headers() {
+        assert_eq!(diff_line_counts(""), (0, 0));
+        // New and deleted files only have header lines besides content
+        let patch = "--- /dev/null\n+++ b/new.txt\n@@ -0,0 +1 @@\n+hello\n";
+        assert_eq!(diff_line_counts(patch), (1, 0));
+        let patch = "--- a/old.txt\n+++ /dev/null\n@@ -1,2 +0,0 @@\n-a\n-b\n";
+        assert_eq!(diff_line_counts(patch), (0, 2));
+    }
+}
@@ -1,7 +1,10 @@
 //! Configuration CLI command handlers
 
 use crate::cli::commands::{ConfigCommand, ConfigKey};
-use crate::core::config::{Config, GeminiModel};
+use crate::core::config::{
+    AiBackend, Config, GeminiModel, QKeyBehavior, AI_TIMEOUT_SECONDS_RANGE, GITHUB_RETRIES_RANGE,
+    PR_LIST_PAGE_SIZE_RANGE, THEME_PRESETS, WORKFLOW_POLL_SECONDS_RANGE,
+};
 use crate::core::credentials::CredentialStore;
 use crate::error::{GhrustError, Result};
 
@@ -11,9 +14,53 @@ pub fn handle_config(command: ConfigCommand) -> Result<()> {
         ConfigCommand::Set { key, value } => handle_set(key, value),
         ConfigCommand::Get { key } => handle_get(key),
         ConfigCommand::Remove { key } => handle_remove(key),
+        ConfigCommand::SetBaseUrl { url } => handle_set_base_url(&url),
+        ConfigCommand::GetBaseUrl => handle_get_base_url(),
     }
 }
 
+/// Validate and store the GitHub API base URL
+fn handle_set_base_url(url: &str) -> Result<()> {
+    let url = url.trim().trim_end_matches('/');
+    let parsed = url::Url::parse(url).map_err(|e| {
+        GhrustError::InvalidInput(format!(
+            "Invalid base URL '{}': {}. Expected e.g. https://github.example.com/api/v3",
+            url, e
+        ))
+    })?;
+
+    // `host:port` parses with the host as the scheme, so check it explicitly
+    if !matches!(parsed.scheme(), "http" | "https") || !parsed.has_host() {
+        return Err(GhrustError::InvalidInput(format!(
+            "Base URL '{}' must start with http:// or https://",
+            url
+        )));
+    }
+
+    let mut config = Config::load()?;
+    config.set_github_base_url(Some(url.to_string()));
+    config.save()?;
+
+    println!("GitHub API base URL set to: {}", config.github_base_url());
+    Ok(())
+}
+
+/// Print the GitHub API base URL in effect
+fn handle_get_base_url() -> Result<()> {
+    let config = Config::load()?;
+    let source = if config.github_base_url.is_some() {
+        "configured"
+    } else {
+        "default"
+    };
+    println!(
+        "GitHub API base URL: {} ({})",
+        config.github_base_url(),
+        source
+    );
+    Ok(())
+}
+
 /// Handle setting a configuration value
 fn handle_set(key: ConfigKey, value: String) -> Result<()> {
     match key {
@@ -22,23 +69,295 @@ fn handle_set(key: ConfigKey, value: String) -> Result<()> {
             println!("Gemini API key has been stored securely.");
         }
         ConfigKey::GeminiModel => {
-            let model = GeminiModel::parse(&value).ok_or_else(|| {
+            let mut config = Config::load()?;
+
+            // Built-in models by API name; anything else is a custom model name
+            if let Some(model) = GeminiModel::parse(&value) {
+                config.set_gemini_model(model);
+                config.save()?;
+                println!("Gemini model set to: {}", model.display_name());
+            } else {
+                let name = GeminiModel::normalize_custom_name(&value).ok_or_else(|| {
+                    GhrustError::InvalidInput(format!(
+                        "Invalid model '{}'. Use a built-in model ({}) or a Gemini model name",
+                        value,
+                        GeminiModel::all()
+                            .iter()
+                            .filter(|m| **m != GeminiModel::Custom)
+                            .map(|m| m.api_name())
+                            .collect::<Vec<_>>()
+                            .join(", ")
+                    ))
+                })?;
+                config.set_gemini_custom_model(name.clone());
+                config.save()?;
+                println!("Gemini model set to custom model: {}", name);
+            }
+        }
+        ConfigKey::AiProvider => {
+            let provider = AiBackend::parse(&value).ok_or_else(|| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid AI provider '{}'. Use: gemini, openai, anthropic",
+                    value
+                ))
+            })?;
+            let mut config = Config::load()?;
+            config.set_ai_provider(provider);
+            config.save()?;
+            println!("AI provider set to: {}", provider.display_name());
+
+            if !CredentialStore::has_ai_key(provider).unwrap_or(false) {
+                println!(
+                    "Note: no {} API key is configured yet. Run 'gr config set {} YOUR_KEY'.",
+                    provider.display_name(),
+                    provider.key_config_name()
+                );
+            }
+        }
+        ConfigKey::OpenaiKey => {
+            CredentialStore::store_ai_key(AiBackend::OpenAi, &value)?;
+            println!("OpenAI API key has been stored securely.");
+        }
+        ConfigKey::OpenaiModel => {
+            let mut config = Config::load()?;
+            config.set_openai_model(parse_model_name(&value)?);
+            config.save()?;
+            println!("OpenAI model set to: {}", config.openai_model);
+        }
+        ConfigKey::AnthropicKey => {
+            CredentialStore::store_ai_key(AiBackend::Anthropic, &value)?;
+            println!("Anthropic API key has been stored securely.");
+        }
+        ConfigKey::AnthropicModel => {
+            let mut config = Config::load()?;
+            config.set_anthropic_model(parse_model_name(&value)?);
+            config.save()?;
+            println!("Anthropic model set to: {}", config.anthropic_model);
+        }
+        ConfigKey::AiTimeoutSeconds => {
+            let seconds = value
+                .parse::<u16>()
+                .ok()
+                .filter(|seconds| AI_TIMEOUT_SECONDS_RANGE.contains(seconds))
+                .ok_or_else(|| {
+                    GhrustError::InvalidInput(format!(
+                        "Invalid AI timeout '{}'. Expected a number of seconds from 5 to 600",
+                        value
+                    ))
+                })?;
+
+            let mut config = Config::load()?;
+            config.set_ai_timeout_seconds(seconds);
+            config.save()?;
+
+            println!("AI request timeout set to: {}s", seconds);
+        }
+        ConfigKey::ProtectedBranches => {
+            let branches: Vec<String> = value
+                .split(',')
+                .map(|b| b.trim().to_string())
+                .filter(|b| !b.is_empty())
+                .collect();
+
+            let mut config = Config::load()?;
+            config.set_protected_branches(branches.clone());
+            config.save()?;
+
+            if branches.is_empty() {
+                println!("Protected branches reset to the default branch.");
+            } else {
+                println!("Protected branches set to: {}", branches.join(", "));
+            }
+        }
+        ConfigKey::QBehavior => {
+            let behavior = QKeyBehavior::parse(&value).ok_or_else(|| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid q behavior '{}'. Available values: back, quit, prompt",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_q_behavior(behavior);
+            config.save()?;
+
+            println!("q key behavior set to: {}", behavior);
+        }
+        ConfigKey::RenderHtml => {
+            let render = value.parse::<bool>().map_err(|_| {
                 GhrustError::InvalidInput(format!(
-                    "Invalid model '{}'. Available models: {}",
+                    "Invalid value '{}'. Expected true or false",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_render_html(render);
+            config.save()?;
+
+            println!("Render HTML set to: {}", render);
+        }
+        ConfigKey::RememberSelection => {
+            let remember = value.parse::<bool>().map_err(|_| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid value '{}'. Expected true or false",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_remember_selection(remember);
+            config.save()?;
+
+            println!("Remember selection set to: {}", remember);
+        }
+        ConfigKey::Mouse => {
+            let mouse = value.parse::<bool>().map_err(|_| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid value '{}'. Expected true or false",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_mouse(mouse);
+            config.save()?;
+
+            println!("Mouse support set to: {}", mouse);
+        }
+        ConfigKey::Theme => {
+            let preset = value.trim().to_lowercase();
+            if !THEME_PRESETS.contains(&preset.as_str()) {
+                return Err(GhrustError::InvalidInput(format!(
+                    "Invalid theme '{}'. Available values: {}",
                     value,
-                    GeminiModel::all()
-                        .iter()
-                        .map(|m| m.api_name())
-                        .collect::<Vec<_>>()
-                        .join(", ")
+                    THEME_PRESETS.join(", ")
+                )));
+            }
+
+            let mut config = Config::load()?;
+            config.set_theme_preset(Some(preset.clone()));
+            config.save()?;
+
+            println!("Theme set to: {}", preset);
+        }
+        ConfigKey::Editor => {
+            let mut config = Config::load()?;
+            config.set_editor(Some(value.clone()));
+            config.save()?;
+
+            println!("Editor set to: {}", value);
+        }
+        ConfigKey::DryRun => {
+            let dry_run = value.parse::<bool>().map_err(|_| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid value '{}'. Expected true or false",
+                    value
                 ))
             })?;
 
             let mut config = Config::load()?;
-            config.set_gemini_model(model);
+            config.set_dry_run(dry_run);
+            config.save()?;
+
+            println!("Dry run set to: {}", dry_run);
+        }
+        ConfigKey::WorkflowPollSeconds => {
+            let seconds = value
+                .parse::<u8>()
+                .ok()
+                .filter(|seconds| WORKFLOW_POLL_SECONDS_RANGE.contains(seconds))
+                .ok_or_else(|| {
+                    GhrustError::InvalidInput(format!(
+                        "Invalid poll interval '{}'. Expected a number of seconds from 2 to 60",
+                        value
+                    ))
+                })?;
+
+            let mut config = Config::load()?;
+            config.set_workflow_poll_seconds(seconds);
+            config.save()?;
+
+            println!("Workflow poll interval set to: {}s", seconds);
+        }
+        ConfigKey::CredentialFileFallback => {
+            let enabled = value.parse::<bool>().map_err(|_| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid value '{}'. Expected true or false",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_credential_file_fallback(enabled);
+            config.save()?;
+
+            println!("Credential file fallback set to: {}", enabled);
+        }
+        ConfigKey::GithubRetries => {
+            let retries = value
+                .parse::<u8>()
+                .ok()
+                .filter(|retries| GITHUB_RETRIES_RANGE.contains(retries))
+                .ok_or_else(|| {
+                    GhrustError::InvalidInput(format!(
+                        "Invalid retry count '{}'. Expected a number from 0 to 10",
+                        value
+                    ))
+                })?;
+
+            let mut config = Config::load()?;
+            config.set_github_retries(retries);
+            config.save()?;
+
+            println!("GitHub API retries set to: {}", retries);
+        }
+        ConfigKey::PrListPageSize => {
+            let size = value
+                .parse::<u8>()
+                .ok()
+                .filter(|size| PR_LIST_PAGE_SIZE_RANGE.contains(size))
+                .ok_or_else(|| {
+                    GhrustError::InvalidInput(format!(
+                        "Invalid page size '{}'. Expected a number from 1 to 100",
+                        value
+                    ))
+                })?;
+
+            let mut config = Config::load()?;
+            config.set_pr_list_page_size(size);
+            config.save()?;
+
+            println!("PR list page size set to: {}", size);
+        }
+        ConfigKey::ConventionalCommits => {
+            let enabled = value.parse::<bool>().map_err(|_| {
+                GhrustError::InvalidInput(format!(
+                    "Invalid value '{}'. Expected true or false",
+                    value
+                ))
+            })?;
+
+            let mut config = Config::load()?;
+            config.set_conventional_commits(enabled);
+            config.save()?;
+
+            println!("Conventional commits set to: {}", enabled);
+        }
+        ConfigKey::CommitPromptTemplate => {
+            let mut config = Config::load()?;
+            config.set_commit_prompt_template(Some(value.clone()));
+            config.set_use_commit_prompt_template(true);
             config.save()?;
 
-            println!("Gemini model set to: {}", model.display_name());
+            if value.trim().is_empty() {
+                println!("Commit prompt template is empty; the default prompt will be used.");
+            } else {
+                if !value.contains("{diff}") {
+                    println!("Note: no {{diff}} placeholder, the diff will be appended.");
+                }
+                println!("Custom commit prompt template enabled.");
+            }
         }
     }
     Ok(())
@@ -59,9 +378,110 @@ fn handle_get(key: ConfigKey) -> Result<()> {
             println!(
                 "Gemini model: {} ({})",
                 config.gemini_model.display_name(),
-                config.gemini_model.api_name()
+                config.gemini_model_name()
+            );
+        }
+        ConfigKey::AiProvider => {
+            let config = Config::load()?;
+            println!(
+                "AI provider: {} ({})",
+                config.ai_provider.display_name(),
+                config.ai_model_name()
+            );
+        }
+        ConfigKey::OpenaiKey => print_ai_key(AiBackend::OpenAi)?,
+        ConfigKey::OpenaiModel => {
+            let config = Config::load()?;
+            println!("OpenAI model: {}", config.openai_model);
+        }
+        ConfigKey::AnthropicKey => print_ai_key(AiBackend::Anthropic)?,
+        ConfigKey::AnthropicModel => {
+            let config = Config::load()?;
+            println!("Anthropic model: {}", config.anthropic_model);
+        }
+        ConfigKey::AiTimeoutSeconds => {
+            let config = Config::load()?;
+            println!("AI request timeout: {}s", config.ai_timeout().as_secs());
+        }
+        ConfigKey::ProtectedBranches => {
+            let config = Config::load()?;
+            if config.protected_branches.is_empty() {
+                println!("Protected branches: (default branch)");
+            } else {
+                println!(
+                    "Protected branches: {}",
+                    config.protected_branches.join(", ")
+                );
+            }
+        }
+        ConfigKey::QBehavior => {
+            let config = Config::load()?;
+            println!("q key behavior: {}", config.q_behavior);
+        }
+        ConfigKey::RenderHtml => {
+            let config = Config::load()?;
+            println!("Render HTML: {}", config.render_html);
+        }
+        ConfigKey::RememberSelection => {
+            let config = Config::load()?;
+            println!("Remember selection: {}", config.remember_selection);
+        }
+        ConfigKey::Mouse => {
+            let config = Config::load()?;
+            println!("Mouse support: {}", config.mouse);
+        }
+        ConfigKey::Theme => {
+            let config = Config::load()?;
+            println!(
+                "Theme: {}",
+                config.theme.preset.as_deref().unwrap_or("dark")
+            );
+        }
+        ConfigKey::Editor => {
+            println!("Editor: {}", crate::tui::editor::editor_command());
+        }
+        ConfigKey::DryRun => {
+            let config = Config::load()?;
+            println!("Dry run: {}", config.dry_run);
+        }
+        ConfigKey::WorkflowPollSeconds => {
+            let config = Config::load()?;
+            println!(
+                "Workflow poll interval: {}s",
+                config.workflow_poll_seconds()
             );
         }
+        ConfigKey::CredentialFileFallback => {
+            let config = Config::load()?;
+            println!(
+                "Credential file fallback: {}",
+                config.credential_file_fallback
+            );
+        }
+        ConfigKey::GithubRetries => {
+            let config = Config::load()?;
+            println!("GitHub API retries: {}", config.github_retries());
+        }
+        ConfigKey::PrListPageSize => {
+            let config = Config::load()?;
+            println!("PR list page size: {}", config.pr_list_page_size());
+        }
+        ConfigKey::ConventionalCommits => {
+            let config = Config::load()?;
+            println!("Conventional commits: {}", config.conventional_commits);
+        }
+        ConfigKey::CommitPromptTemplate => {
+            let config = Config::load()?;
+            let mode = if config.active_commit_prompt_template().is_some() {
+                "custom"
+            } else {
+                "default"
+            };
+            println!("Commit prompt: {}", mode);
+            if let Some(template) = &config.commit_prompt_template {
+                println!("Template:\n{}", template);
+            }
+        }
     }
     Ok(())
 }
@@ -76,12 +496,169 @@ fn handle_remove(key: ConfigKey) -> Result<()> {
         ConfigKey::GeminiModel => {
             let mut config = Config::load()?;
             config.set_gemini_model(GeminiModel::default());
+            config.gemini_custom_model = None;
             config.save()?;
             println!(
                 "Gemini model reset to default: {}",
                 GeminiModel::default().display_name()
             );
         }
+        ConfigKey::AiProvider => {
+            let mut config = Config::load()?;
+            config.set_ai_provider(AiBackend::default());
+            config.save()?;
+            println!(
+                "AI provider reset to default: {}",
+                AiBackend::default().display_name()
+            );
+        }
+        ConfigKey::OpenaiKey => {
+            CredentialStore::delete_ai_key(AiBackend::OpenAi)?;
+            println!("OpenAI API key has been removed.");
+        }
+        ConfigKey::OpenaiModel => {
+            let mut config = Config::load()?;
+            config.set_openai_model(Config::default().openai_model);
+            config.save()?;
+            println!("OpenAI model reset to default: {}", config.openai_model);
+        }
+        ConfigKey::AnthropicKey => {
+            CredentialStore::delete_ai_key(AiBackend::Anthropic)?;
+            println!("Anthropic API key has been removed.");
+        }
+        ConfigKey::AnthropicModel => {
+            let mut config = Config::load()?;
+            config.set_anthropic_model(Config::default().anthropic_model);
+            config.save()?;
+            println!(
+                "Anthropic model reset to default: {}",
+                config.anthropic_model
+            );
+        }
+        ConfigKey::AiTimeoutSeconds => {
+            let default = Config::default().ai_timeout_seconds;
+            let mut config = Config::load()?;
+            config.set_ai_timeout_seconds(default);
+            config.save()?;
+            println!("AI request timeout reset to default: {}s", default);
+        }
+        ConfigKey::ProtectedBranches => {
+            let mut config = Config::load()?;
+            config.set_protected_branches(Vec::new());
+            config.save()?;
+            println!("Protected branches reset to the default branch.");
+        }
+        ConfigKey::QBehavior => {
+            let mut config = Config::load()?;
+            config.set_q_behavior(QKeyBehavior::default());
+            config.save()?;
+            println!(
+                "q key behavior reset to default: {}",
+                QKeyBehavior::default()
+            );
+        }
+        ConfigKey::RenderHtml => {
+            let mut config = Config::load()?;
+            config.set_render_html(false);
+            config.save()?;
+            println!("Render HTML reset to default: false");
+        }
+        ConfigKey::RememberSelection => {
+            let mut config = Config::load()?;
+            config.set_remember_selection(true);
+            config.save()?;
+            println!("Remember selection reset to default: true");
+        }
+        ConfigKey::Mouse => {
+            let mut config = Config::load()?;
+            config.set_mouse(true);
+            config.save()?;
+            println!("Mouse support reset to default: true");
+        }
+        ConfigKey::Theme => {
+            let mut config = Config::load()?;
+            config.set_theme_preset(None);
+            config.save()?;
+            println!("Theme reset to default: dark");
+        }
+        ConfigKey::Editor => {
+            let mut config = Config::load()?;
+            config.set_editor(None);
+            config.save()?;
+            println!("Editor reset to default: $VISUAL / $EDITOR");
+        }
+        ConfigKey::DryRun => {
+            let mut config = Config::load()?;
+            config.set_dry_run(false);
+            config.save()?;
+            println!("Dry run reset to default: false");
+        }
+        ConfigKey::WorkflowPollSeconds => {
+            let default = Config::default().workflow_poll_seconds;
+            let mut config = Config::load()?;
+            config.set_workflow_poll_seconds(default);
+            config.save()?;
+            println!("Workflow poll interval reset to default: {}s", default);
+        }
+        ConfigKey::CredentialFileFallback => {
+            let mut config = Config::load()?;
+            config.set_credential_file_fallback(false);
+            config.save()?;
+            println!("Credential file fallback reset to default: false");
+        }
+        ConfigKey::GithubRetries => {
+            let default = Config::default().github_retries;
+            let mut config = Config::load()?;
+            config.set_github_retries(default);
+            config.save()?;
+            println!("GitHub API retries reset to default: {}", default);
+        }
+        ConfigKey::PrListPageSize => {
+            let default = Config::default().pr_list_page_size;
+            let mut config = Config::load()?;
+            config.set_pr_list_page_size(default);
+            config.save()?;
+            println!("PR list page size reset to default: {}", default);
+        }
+        ConfigKey::ConventionalCommits => {
+            let mut config = Config::load()?;
+            config.set_conventional_commits(false);
+            config.save()?;
+            println!("Conventional commits reset to default: false");
+        }
+        ConfigKey::CommitPromptTemplate => {
+            let mut config = Config::load()?;
+            config.set_commit_prompt_template(None);
+            config.set_use_commit_prompt_template(false);
+            config.save()?;
+            println!("Commit prompt reset to default");
+        }
     }
     Ok(())
 }
+
+/// Print the masked API key of an AI provider
+fn print_ai_key(provider: AiBackend) -> Result<()> {
+    if let Some(key) = CredentialStore::get_ai_key(provider)? {
+        println!(
+            "{} API key: {}",
+            provider.display_name(),
+            CredentialStore::mask_token(&key)
+        );
+    } else {
+        println!("{} API key: Not configured", provider.display_name());
+    }
+    Ok(())
+}
+
+/// Validate a free-form model name for OpenAI or Anthropic
+fn parse_model_name(value: &str) -> Result<String> {
+    let name = value.trim();
+    if name.is_empty() || name.contains(char::is_whitespace) {
+        return Err(GhrustError::InvalidInput(format!(
+            "Invalid model name '{}'. Model names cannot be empty or contain spaces.",
+            value
+        )));
+    }
+    Ok(name.to_string())
+}
@@ -1,34 +1,41 @@
 //! Pull request CLI command handlers
 
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 
 use chrono::{DateTime, Utc};
+use serde::Serialize;
 
-use crate::ai::GeminiClient;
+use crate::ai::AiClient;
 use crate::cli::commands::{PrCommand, PrState as CliPrState};
 use crate::core::git::GitRepository;
 use crate::core::repository::RepositoryContext;
-use crate::error::{GhrustError, Result};
-use crate::github::pull_request::{CreatePrParams, MergeMethod, PrState, PullRequestHandler};
+use crate::error::{DryRunPlan, GhrustError, Result};
+use crate::github::pull_request::{
+    CreatePrParams, CreatedPr, MergeMethod, PrState, PullRequestHandler,
+};
 use crate::github::{BranchHandler, GitHubClient};
 
 /// Handle pull request commands
-pub async fn handle_pr(command: PrCommand) -> Result<()> {
+pub async fn handle_pr(command: PrCommand, dry_run: bool) -> Result<()> {
     match command {
         PrCommand::List {
             state,
             author,
             limit,
-        } => handle_list(state, author, limit).await,
+            json,
+        } => handle_list(state, author, limit, json).await,
         PrCommand::Create {
             head,
             base,
             title,
             body,
             draft,
+            reviewers,
+            assignees,
             ai,
-        } => handle_create(head, base, title, body, draft, ai).await,
+        } => handle_create(head, base, title, body, draft, reviewers, assignees, ai).await,
         PrCommand::View { number } => handle_view(number).await,
+        PrCommand::Checkout { number } => handle_checkout(number).await,
         PrCommand::Comment { number, text } => handle_comment(number, text).await,
         PrCommand::Merge {
             number,
@@ -36,7 +43,7 @@ pub async fn handle_pr(command: PrCommand) -> Result<()> {
             squash,
             rebase,
             delete,
-        } => handle_merge(number, merge, squash, rebase, delete).await,
+        } => handle_merge(number, merge, squash, rebase, delete, dry_run).await,
     }
 }
 
@@ -49,65 +56,136 @@ fn convert_state(state: CliPrState) -> PrState {
     }
 }
 
-async fn handle_list(state: CliPrState, author: Option<String>, limit: usize) -> Result<()> {
+/// Pull request fields printed by `pr list --json`
+#[derive(Debug, Serialize)]
+struct PrSummary {
+    number: u64,
+    title: String,
+    author: String,
+    /// "open", "closed" or "merged"
+    state: &'static str,
+    head: String,
+    base: String,
+    draft: bool,
+    url: Option<String>,
+    updated_at: Option<DateTime<Utc>>,
+}
+
+impl PrSummary {
+    fn from_pr(pr: &octocrab::models::pulls::PullRequest) -> Self {
+        let state = if pr.merged_at.is_some() {
+            "merged"
+        } else if matches!(pr.state, Some(octocrab::models::IssueState::Open)) {
+            "open"
+        } else {
+            "closed"
+        };
+
+        Self {
+            number: pr.number,
+            title: pr.title.clone().unwrap_or_default(),
+            author: pr
+                .user
+                .as_ref()
+                .map(|u| u.login.clone())
+                .unwrap_or_else(|| "unknown".to_string()),
+            state,
+            head: pr.head.ref_field.clone(),
+            base: pr.base.ref_field.clone(),
+            draft: pr.draft.unwrap_or(false),
+            url: pr.html_url.as_ref().map(|u| u.to_string()),
+            updated_at: pr.updated_at,
+        }
+    }
+}
+
+async fn handle_list(
+    state: CliPrState,
+    author: Option<String>,
+    limit: usize,
+    json: bool,
+) -> Result<()> {
     let repo_ctx = RepositoryContext::detect()?;
     let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
     let handler = PullRequestHandler::new(&client);
 
     let api_state = convert_state(state);
     let limit_u8 = limit.min(100) as u8;
-    let prs = handler.list(api_state, author.as_deref(), limit_u8).await?;
+    let prs = handler
+        .list(api_state, author.as_deref(), limit_u8, 1)
+        .await?;
+    let summaries: Vec<PrSummary> = prs.iter().map(PrSummary::from_pr).collect();
 
-    if prs.is_empty() {
-        println!("No pull requests found.");
+    if json {
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
         return Ok(());
     }
 
-    println!("Pull Requests for {}/{}:\n", repo_ctx.owner, repo_ctx.name);
-
-    for pr in prs {
-        let state_marker = match pr.state {
-            Some(octocrab::models::IssueState::Open) => "●",
-            _ => "○",
-        };
-        let draft_marker = if pr.draft.unwrap_or(false) {
-            " [draft]"
-        } else {
-            ""
-        };
-        let author_name = pr
-            .user
-            .as_ref()
-            .map(|u| u.login.as_str())
-            .unwrap_or("unknown");
+    if summaries.is_empty() {
+        println!("No pull requests found.");
+        return Ok(());
+    }
 
+    // One row per PR, columns padded to the widest value
+    let number_width = summaries
+        .iter()
+        .map(|pr| pr.number.to_string().len() + 1)
+        .max()
+        .unwrap_or(0);
+    let title_width = summaries
+        .iter()
+        .map(|pr| display_title(pr).chars().count())
+        .max()
+        .unwrap_or(0);
+    let author_width = summaries
+        .iter()
+        .map(|pr| pr.author.chars().count() + 1)
+        .max()
+        .unwrap_or(0);
+
+    for pr in &summaries {
+        let updated = pr.updated_at.map(format_relative_time).unwrap_or_default();
         println!(
-            "{} #{} {} {}",
-            state_marker,
-            pr.number,
-            pr.title.as_deref().unwrap_or("(no title)"),
-            draft_marker
-        );
-        println!(
-            "   by @{} • {} → {}",
-            author_name, pr.head.ref_field, pr.base.ref_field
+            "{:<nw$}  {:<6}  {:<tw$}  {:<aw$}  {} → {}  {}",
+            format!("#{}", pr.number),
+            pr.state,
+            display_title(pr),
+            format!("@{}", pr.author),
+            pr.head,
+            pr.base,
+            updated,
+            nw = number_width,
+            tw = title_width,
+            aw = author_width,
         );
-
-        if let Some(updated) = pr.updated_at {
-            println!("   updated {}", format_relative_time(updated));
-        }
-        println!();
     }
 
     Ok(())
 }
 
+/// Title for the plain list, truncated and marked when the PR is a draft
+fn display_title(pr: &PrSummary) -> String {
+    const MAX_TITLE: usize = 60;
+
+    let mut title: String = pr.title.chars().take(MAX_TITLE).collect();
+    if pr.title.chars().count() > MAX_TITLE {
+        title.push('…');
+    }
+    if pr.draft {
+        title.push_str(" [draft]");
+    }
+    title
+}
+
+#[allow(clippy::too_many_arguments)]
 async fn handle_create(
     head: Option<String>,
     base: Option<String>,
     title: Option<String>,
     body: Option<String>,
     draft: bool,
+    reviewers: Vec<String>,
+    assignees: Vec<String>,
     ai: bool,
 ) -> Result<()> {
     let repo_ctx = RepositoryContext::detect()?;
@@ -138,10 +216,12 @@ async fn handle_create(
         title: pr_title,
         body: pr_body,
         draft,
+        reviewers,
+        assignees,
     };
 
     println!("Creating PR: {} → {}", head_branch, base_branch);
-    let pr = handler.create(params).await?;
+    let CreatedPr { pr, warnings } = handler.create(params).await?;
 
     println!("\n✓ Pull request created successfully!");
     println!("  #{}: {}", pr.number, pr.title.as_deref().unwrap_or(""));
@@ -149,6 +229,9 @@ async fn handle_create(
         "  URL: {}",
         pr.html_url.map(|u| u.to_string()).unwrap_or_default()
     );
+    for warning in warnings {
+        println!("  ⚠ {}", warning);
+    }
 
     Ok(())
 }
@@ -160,7 +243,7 @@ async fn generate_ai_pr_content(head: &str, base: &str) -> Result<(String, Optio
     // Get the diff between base and head branches
     let diff = git.branch_diff(base, head).or_else(|_| {
         // Fallback: use all changes diff if branch diff fails
-        git.all_changes_diff()
+        git.all_changes_diff().map(|d| d.text)
     })?;
 
     if diff.is_empty() {
@@ -171,8 +254,8 @@ async fn generate_ai_pr_content(head: &str, base: &str) -> Result<(String, Optio
 
     println!("Generating PR title and description with AI...");
 
-    // Create Gemini client
-    let client = GeminiClient::new()?;
+    // Create AI client for the configured provider
+    let client = AiClient::new()?;
     println!("Using model: {}", client.model_name());
 
     // Generate content
@@ -256,7 +339,81 @@ async fn handle_view(number: u64) -> Result<()> {
     Ok(())
 }
 
+async fn handle_checkout(number: u64) -> Result<()> {
+    let repo_ctx = RepositoryContext::detect()?;
+    let git = GitRepository::open_current_dir()?;
+
+    let local_branch = format!("pr/{}", number);
+    if git.branch_exists(&local_branch) {
+        return Err(GhrustError::InvalidInput(format!(
+            "Branch '{}' already exists locally. Switch to it with: git checkout {}",
+            local_branch, local_branch
+        )));
+    }
+
+    let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
+    let handler = PullRequestHandler::new(&client);
+    let pr = handler.get(number).await?;
+    let head_ref = pr.head.ref_field.clone();
+
+    // Fork PRs live in another repository; fetch them from the fork's URL.
+    // A deleted fork has no head repo, but GitHub still serves pull/<n>/head.
+    let head_owner = pr
+        .head
+        .repo
+        .as_ref()
+        .and_then(|r| r.owner.as_ref())
+        .map(|o| o.login.clone());
+    let fork_url = pr
+        .head
+        .repo
+        .as_ref()
+        .and_then(|r| r.clone_url.as_ref())
+        .map(|u| u.to_string());
+    let (remote, remote_ref) = match (head_owner, fork_url) {
+        (Some(owner), _) if owner.eq_ignore_ascii_case(&repo_ctx.owner) => {
+            ("origin".to_string(), head_ref.clone())
+        }
+        (Some(owner), Some(url)) => {
+            println!("PR #{} comes from the fork {}", number, owner);
+            (url, head_ref.clone())
+        }
+        _ => ("origin".to_string(), format!("pull/{}/head", number)),
+    };
+
+    println!("Fetching {} from {}...", remote_ref, remote);
+    git.fetch_into_branch(&remote, &remote_ref, &local_branch)?;
+    git.checkout(&local_branch)?;
+
+    println!(
+        "✓ Switched to '{}' ({} → {})",
+        local_branch, head_ref, pr.base.ref_field
+    );
+
+    Ok(())
+}
+
+/// Resolve the comment body, reading it from `input` when `text` is `-`,
+/// e.g. `git log -1 | gr pr comment 12 -`
+fn comment_body(text: String, mut input: impl Read) -> Result<String> {
+    let text = if text == "-" {
+        let mut body = String::new();
+        input.read_to_string(&mut body)?;
+        body.trim_end().to_string()
+    } else {
+        text
+    };
+    if text.trim().is_empty() {
+        return Err(GhrustError::InvalidInput(
+            "Comment body cannot be empty".to_string(),
+        ));
+    }
+    Ok(text)
+}
+
 async fn handle_comment(number: u64, text: String) -> Result<()> {
+    let text = comment_body(text, io::stdin())?;
+
     let repo_ctx = RepositoryContext::detect()?;
     let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
     let handler = PullRequestHandler::new(&client);
@@ -275,10 +432,11 @@ async fn handle_merge(
     squash: bool,
     rebase: bool,
     delete: bool,
+    dry_run: bool,
 ) -> Result<()> {
     let repo_ctx = RepositoryContext::detect()?;
     let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
-    let pr_handler = PullRequestHandler::new(&client);
+    let pr_handler = PullRequestHandler::new(&client).with_dry_run(dry_run);
 
     // Get the PR first to know the head branch
     let pr = pr_handler.get(number).await?;
@@ -293,25 +451,24 @@ async fn handle_merge(
         MergeMethod::Merge
     };
 
-    let method_name = match method {
-        MergeMethod::Merge => "merge commit",
-        MergeMethod::Squash => "squash",
-        MergeMethod::Rebase => "rebase",
-    };
+    // In dry-run each step is recorded and all of them are reported at the end
+    let mut plan = DryRunPlan::default();
 
-    println!("Merging PR #{} using {}...", number, method_name);
-    pr_handler.merge(number, method, None, None).await?;
-    println!("✓ PR #{} merged successfully!", number);
+    println!("Merging PR #{} using {}...", number, method.label());
+    if plan.step(pr_handler.merge(number, method, None, None).await)? {
+        println!("✓ PR #{} merged successfully!", number);
+    }
 
     // Delete branch if requested
     if delete {
         println!("Deleting branch '{}'...", head_branch);
-        let branch_handler = BranchHandler::new(&client);
-        branch_handler.delete(&head_branch).await?;
-        println!("✓ Branch '{}' deleted", head_branch);
+        let branch_handler = BranchHandler::new(&client).with_dry_run(dry_run);
+        if plan.step(branch_handler.delete(&head_branch).await)? {
+            println!("✓ Branch '{}' deleted", head_branch);
+        }
     }
 
-    Ok(())
+    plan.finish()
 }
 
 /// Format a datetime as relative time (e.g., "2 hours ago")
@@ -331,3 +488,32 @@ fn format_relative_time(dt: DateTime<Utc>) -> String {
         "just now".to_string()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_comment_body_reads_stdin_for_dash() {
+        let body = comment_body("-".to_string(), "Looks good\n\nThanks!\n\n".as_bytes());
+        assert_eq!(body.unwrap(), "Looks good\n\nThanks!");
+    }
+
+    #[test]
+    fn test_comment_body_keeps_inline_text() {
+        let body = comment_body("Ship it".to_string(), "ignored".as_bytes());
+        assert_eq!(body.unwrap(), "Ship it");
+    }
+
+    #[test]
+    fn test_comment_body_rejects_empty() {
+        for (text, input) in [("-", "  \n\n"), ("   ", "")] {
+            let result = comment_body(text.to_string(), input.as_bytes());
+            assert!(
+                matches!(result, Err(GhrustError::InvalidInput(_))),
+                "{:?}",
+                text
+            );
+        }
+    }
+}
@@ -1,12 +1,13 @@
 //! Push CLI command handlers
 
+use crate::cli::branch::confirm_protected_branch;
 use crate::cli::commands::PushArgs;
 use crate::core::git::GitRepository;
-use crate::error::Result;
+use crate::error::{DryRunPlan, Result};
 
 /// Handle push commands
-pub async fn handle_push(args: PushArgs) -> Result<()> {
-    let git = GitRepository::open_current_dir()?;
+pub async fn handle_push(args: PushArgs, dry_run: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
     let branch = git.current_branch()?;
 
     // Show what we're doing
@@ -20,11 +21,17 @@ pub async fn handle_push(args: PushArgs) -> Result<()> {
         println!("  {} ahead, {} behind", ahead, behind);
     }
 
-    // Set upstream if requested
+    confirm_protected_branch(&git, &branch, "Push", args.allow_protected).await?;
+
+    // In dry-run each step is recorded and all of them are reported at the end
+    let mut plan = DryRunPlan::default();
+
+    // Set upstream if requested (git push -u also pushes the branch)
     if args.set_upstream {
         let upstream = format!("origin/{}", branch);
-        git.set_upstream(&upstream)?;
-        println!("Branch '{}' set up to track '{}'.", branch, upstream);
+        if plan.step(git.push_with_upstream(&branch, "origin"))? {
+            println!("Branch '{}' set up to track '{}'.", branch, upstream);
+        }
     }
 
     // Push
@@ -34,15 +41,17 @@ pub async fn handle_push(args: PushArgs) -> Result<()> {
         println!("Pushing to origin/{}...", branch);
     }
 
-    git.push(args.force)?;
-    println!("✓ Pushed to origin/{}", branch);
+    if plan.step(git.push(args.force))? {
+        println!("✓ Pushed to origin/{}", branch);
+    }
 
     // Push tags if requested
     if args.tags {
         println!("Pushing tags...");
-        git.push_tags()?;
-        println!("✓ Tags pushed");
+        if plan.step(git.push_tags())? {
+            println!("✓ Tags pushed");
+        }
     }
 
-    Ok(())
+    plan.finish()
 }
@@ -5,44 +5,54 @@ use std::io::{self, Write};
 use crate::cli::commands::TagCommand;
 use crate::core::git::GitRepository;
 use crate::core::repository::RepositoryContext;
-use crate::error::{GhrustError, Result};
-use crate::github::{GitHubClient, TagHandler};
+use crate::error::{DryRunPlan, GhrustError, Result};
+use crate::github::{GitHubClient, TagHandler, TagInfo};
 
 /// Handle tag commands
-pub async fn handle_tag(command: TagCommand) -> Result<()> {
+pub async fn handle_tag(command: TagCommand, dry_run: bool) -> Result<()> {
     match command {
         TagCommand::List { local, remote } => handle_list(local, remote).await,
         TagCommand::Create {
             name,
             message,
             no_push,
-        } => handle_create(name, message, no_push).await,
+        } => handle_create(name, message, no_push, dry_run).await,
         TagCommand::Delete {
             name,
             force,
             remote,
-        } => handle_delete(name, force, remote).await,
-        TagCommand::Push { name, all } => handle_push(name, all).await,
+        } => handle_delete(name, force, remote, dry_run).await,
+        TagCommand::Push { name, all } => handle_push(name, all, dry_run).await,
     }
 }
 
 async fn handle_list(local_only: bool, remote_only: bool) -> Result<()> {
-    let repo_ctx = RepositoryContext::detect()?;
+    // Remote tags come from the GitHub API; local tags work on any repository
+    let repo_ctx = if remote_only {
+        Some(RepositoryContext::detect()?)
+    } else {
+        RepositoryContext::detect_optional()?
+    };
     let git = GitRepository::open_current_dir()?;
 
-    println!("Tags for {}/{}:\n", repo_ctx.owner, repo_ctx.name);
+    match &repo_ctx {
+        Some(ctx) => println!("Tags for {}/{}:\n", ctx.owner, ctx.name),
+        None => println!("Tags (no GitHub remote, showing local tags only):\n"),
+    }
 
     // Get local tags
     let local_tags = git.list_tags()?;
 
     // Get remote tags if not local-only
-    let remote_tags = if !local_only {
-        let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
-        let handler = TagHandler::new(&client);
-        handler.list().await?
-    } else {
-        vec![]
+    let remote_tags = match &repo_ctx {
+        Some(ctx) if !local_only => {
+            let client = GitHubClient::new(ctx.owner.clone(), ctx.name.clone()).await?;
+            let handler = TagHandler::new(&client);
+            handler.list().await?
+        }
+        _ => vec![],
     };
+    let local_only = local_only || repo_ctx.is_none();
 
     let remote_tag_names: std::collections::HashSet<_> =
         remote_tags.iter().map(|t| t.name.as_str()).collect();
@@ -108,14 +118,14 @@ async fn handle_list(local_only: bool, remote_only: bool) -> Result<()> {
             } else {
                 println!("  Remote tags:");
                 for tag in &remote_tags {
-                    println!("    {}  {}", tag.name, tag.sha);
+                    println!("    {}", format_remote_tag(tag));
                 }
             }
         } else if !remote_only_tags.is_empty() {
             // Show only remote-only tags
             println!("  Remote only (not fetched locally):");
             for tag in remote_only_tags {
-                println!("    {}  {}", tag.name, tag.sha);
+                println!("    {}", format_remote_tag(tag));
             }
         }
     }
@@ -123,37 +133,62 @@ async fn handle_list(local_only: bool, remote_only: bool) -> Result<()> {
     Ok(())
 }
 
-async fn handle_create(name: String, message: Option<String>, no_push: bool) -> Result<()> {
-    let git = GitRepository::open_current_dir()?;
+/// Format a remote tag line with its type and release name
+fn format_remote_tag(tag: &TagInfo) -> String {
+    let type_indicator = if tag.is_annotated {
+        "(annotated)"
+    } else {
+        "(lightweight)"
+    };
+    let release = tag
+        .release_name
+        .as_ref()
+        .map(|r| format!("  release: {}", r))
+        .unwrap_or_default();
+
+    format!("{}  {}  {}{}", tag.name, tag.sha, type_indicator, release)
+}
+
+async fn handle_create(
+    name: String,
+    message: Option<String>,
+    no_push: bool,
+    dry_run: bool,
+) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
     // Check if tag already exists
     if git.tag_exists(&name)? {
         return Err(GhrustError::TagAlreadyExists(name));
     }
 
+    // In dry-run each step is recorded and all of them are reported at the end
+    let mut plan = DryRunPlan::default();
+
     // Create the tag
     if let Some(msg) = &message {
-        git.create_annotated_tag(&name, msg)?;
-        println!("✓ Created annotated tag: {}", name);
-    } else {
-        git.create_tag(&name)?;
+        if plan.step(git.create_annotated_tag(&name, msg))? {
+            println!("✓ Created annotated tag: {}", name);
+        }
+    } else if plan.step(git.create_tag(&name))? {
         println!("✓ Created lightweight tag: {}", name);
     }
 
     // Push by default unless --no-push
     if !no_push {
         println!("Pushing to origin...");
-        git.push_tag(&name)?;
-        println!("✓ Pushed tag: {}", name);
+        if plan.step(git.push_tag(&name))? {
+            println!("✓ Pushed tag: {}", name);
+        }
     } else {
         println!("  (use 'gr tag push {}' to push later)", name);
     }
 
-    Ok(())
+    plan.finish()
 }
 
-async fn handle_delete(name: String, force: bool, remote: bool) -> Result<()> {
-    let git = GitRepository::open_current_dir()?;
+async fn handle_delete(name: String, force: bool, remote: bool, dry_run: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
     // Check if tag exists locally
     let exists_locally = git.tag_exists(&name)?;
@@ -181,23 +216,24 @@ async fn handle_delete(name: String, force: bool, remote: bool) -> Result<()> {
         }
     }
 
+    // In dry-run each step is recorded and all of them are reported at the end
+    let mut plan = DryRunPlan::default();
+
     // Delete locally if it exists
-    if exists_locally {
-        git.delete_tag(&name)?;
+    if exists_locally && plan.step(git.delete_tag(&name))? {
         println!("✓ Deleted local tag: {}", name);
     }
 
     // Delete from remote if requested
-    if remote {
-        git.delete_remote_tag(&name)?;
+    if remote && plan.step(git.delete_remote_tag(&name))? {
         println!("✓ Deleted remote tag: {}", name);
     }
 
-    Ok(())
+    plan.finish()
 }
 
-async fn handle_push(name: Option<String>, all: bool) -> Result<()> {
-    let git = GitRepository::open_current_dir()?;
+async fn handle_push(name: Option<String>, all: bool, dry_run: bool) -> Result<()> {
+    let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
     if all {
         // Push all tags
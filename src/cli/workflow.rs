@@ -1,31 +1,103 @@
 //! Workflow CLI command handlers
 
+use std::time::Duration;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+
 use crate::cli::commands::WorkflowCommand;
+use crate::core::git::GitRepository;
 use crate::core::repository::RepositoryContext;
-use crate::error::Result;
-use crate::github::{GitHubClient, WorkflowConclusion, WorkflowHandler, WorkflowRunStatus};
+use crate::error::{GhrustError, Result};
+use crate::github::{
+    GitHubClient, WorkflowConclusion, WorkflowHandler, WorkflowRunInfo, WorkflowRunStatus,
+};
 
 /// Handle workflow commands
 pub async fn handle_workflow(command: WorkflowCommand) -> Result<()> {
     match command {
         WorkflowCommand::List {
             branch,
+            actor,
             status,
             limit,
-        } => handle_list(branch, status, limit).await,
+            json,
+        } => handle_list(branch, actor, status, limit, json).await,
         WorkflowCommand::View { run_id } => handle_view(run_id).await,
+        WorkflowCommand::Watch { branch, interval } => handle_watch(branch, interval).await,
+    }
+}
+
+/// Workflow run fields printed by `workflow list --json`
+#[derive(Debug, Serialize)]
+struct WorkflowRunSummary<'a> {
+    id: u64,
+    run_number: u64,
+    name: &'a str,
+    status: WorkflowRunStatus,
+    conclusion: Option<WorkflowConclusion>,
+    branch: &'a str,
+    sha: &'a str,
+    event: &'a str,
+    actor: &'a str,
+    /// Seconds from start to the last update (to now while the run is active)
+    duration: u64,
+    created_at: DateTime<Utc>,
+    url: &'a str,
+}
+
+impl<'a> WorkflowRunSummary<'a> {
+    fn from_run(run: &'a WorkflowRunInfo) -> Self {
+        Self {
+            id: run.id,
+            run_number: run.run_number,
+            name: &run.name,
+            status: run.status,
+            conclusion: run.conclusion,
+            branch: &run.head_branch,
+            sha: &run.head_sha,
+            event: &run.event,
+            actor: &run.actor,
+            duration: run.duration_seconds(),
+            created_at: run.created_at,
+            url: &run.html_url,
+        }
     }
 }
 
-async fn handle_list(branch: Option<String>, status: Option<String>, limit: u8) -> Result<()> {
+async fn handle_list(
+    branch: Option<String>,
+    actor: Option<String>,
+    status: Option<String>,
+    limit: u8,
+    json: bool,
+) -> Result<()> {
     let repo_ctx = RepositoryContext::detect()?;
     let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
     let handler = WorkflowHandler::new(&client);
 
+    // Resolve @me to the authenticated user
+    let actor = match actor.as_deref() {
+        Some("@me") => Some(client.current_user_login().await?),
+        _ => actor,
+    };
+
     let runs = handler
-        .list_runs(branch.as_deref(), status.as_deref(), limit)
+        .list_runs(
+            branch.as_deref(),
+            actor.as_deref(),
+            status.as_deref(),
+            limit,
+        )
         .await?;
 
+    if json {
+        let summaries: Vec<WorkflowRunSummary> =
+            runs.iter().map(WorkflowRunSummary::from_run).collect();
+        println!("{}", serde_json::to_string_pretty(&summaries)?);
+        return Ok(());
+    }
+
     if runs.is_empty() {
         println!("No workflow runs found.");
         return Ok(());
@@ -51,7 +123,7 @@ async fn handle_list(branch: Option<String>, status: Option<String>, limit: u8)
             run.run_number,
             name,
             branch,
-            run.head_sha_short,
+            run.head_sha_short(),
             run.event,
             run.duration_string()
         );
@@ -78,7 +150,7 @@ async fn handle_view(run_id: u64) -> Result<()> {
         println!("  Conclusion: {}", conclusion);
     }
     println!("  Branch:     {}", run.head_branch);
-    println!("  Commit:     {}", run.head_sha_short);
+    println!("  Commit:     {}", run.head_sha_short());
     println!("  Event:      {}", run.event);
     println!("  Actor:      {}", run.actor);
     println!("  Duration:   {}", run.duration_string());
@@ -99,6 +171,80 @@ async fn handle_view(run_id: u64) -> Result<()> {
     Ok(())
 }
 
+async fn handle_watch(branch: Option<String>, interval: u64) -> Result<()> {
+    let branch = match branch {
+        Some(b) => b,
+        None => GitRepository::open_current_dir()?.current_branch()?,
+    };
+
+    let repo_ctx = RepositoryContext::detect()?;
+    let client = GitHubClient::new(repo_ctx.owner.clone(), repo_ctx.name.clone()).await?;
+    let handler = WorkflowHandler::new(&client);
+    let interval = Duration::from_secs(interval.max(1));
+
+    println!("Watching workflow runs on '{}' (Ctrl+c to stop)...", branch);
+
+    let mut watched: Option<(u64, WorkflowRunStatus)> = None;
+    let mut waiting_shown = false;
+
+    loop {
+        let runs = handler.list_runs(Some(&branch), None, None, 1).await?;
+
+        let Some(run) = runs.into_iter().next() else {
+            if !waiting_shown {
+                println!("  No runs yet, waiting...");
+                waiting_shown = true;
+            }
+            tokio::time::sleep(interval).await;
+            continue;
+        };
+
+        // Print the run header when it first appears or a newer run replaces it
+        if watched.map(|(id, _)| id) != Some(run.id) {
+            println!(
+                "\n  Run #{} - {} ({}, {})",
+                run.run_number,
+                run.name,
+                run.head_sha_short(),
+                run.event
+            );
+            watched = None;
+        }
+
+        if watched.map(|(_, status)| status) != Some(run.status) {
+            println!(
+                "  {} {}  [{}]",
+                status_icon(run.status, run.conclusion),
+                run.status,
+                run.duration_string()
+            );
+        }
+        watched = Some((run.id, run.status));
+
+        if !run.status.is_active() {
+            return match run.conclusion {
+                Some(
+                    WorkflowConclusion::Success
+                    | WorkflowConclusion::Skipped
+                    | WorkflowConclusion::Neutral,
+                ) => {
+                    println!("✓ Run #{} succeeded", run.run_number);
+                    Ok(())
+                }
+                conclusion => Err(GhrustError::WorkflowRunFailed {
+                    run_number: run.run_number,
+                    conclusion: conclusion
+                        .map(|c| c.to_string())
+                        .unwrap_or_else(|| "unknown".to_string()),
+                    url: run.html_url,
+                }),
+            };
+        }
+
+        tokio::time::sleep(interval).await;
+    }
+}
+
 fn status_icon(status: WorkflowRunStatus, conclusion: Option<WorkflowConclusion>) -> &'static str {
     if status.is_active() {
         "⏳"
@@ -0,0 +1,161 @@
+//! Branch name suggestion and validation
+//!
+//! Derives a branch name from a commit subject (e.g. `feat(auth): add login`
+//! becomes `feat/add-login`) and validates names against git ref rules.
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Conventional commit subject: `type(scope)!: description`
+static CONVENTIONAL_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^([a-zA-Z]+)(?:\([^)]*\))?!?:\s*(.+)$").unwrap());
+
+/// Ticket reference such as `ABC-123`
+static TICKET_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"\b([A-Z][A-Z0-9]+-\d+)\b").unwrap());
+
+/// Maximum number of words kept from the description
+const MAX_WORDS: usize = 6;
+
+/// Maximum length of a suggested branch name
+const MAX_LEN: usize = 60;
+
+/// Suggest a branch name from a commit subject line
+///
+/// Returns `None` if nothing usable could be derived.
+pub fn suggest_from_subject(subject: &str) -> Option<String> {
+    let subject = subject.lines().next().unwrap_or("").trim();
+    if subject.is_empty() {
+        return None;
+    }
+
+    let ticket = TICKET_REGEX
+        .captures(subject)
+        .map(|caps| caps[1].to_string());
+
+    let (prefix, description) = match CONVENTIONAL_REGEX.captures(subject) {
+        Some(caps) => (
+            Some(caps[1].to_lowercase()),
+            caps.get(2).map_or("", |m| m.as_str()).to_string(),
+        ),
+        None => (None, subject.to_string()),
+    };
+
+    // Don't repeat the ticket in the slug
+    let description = match &ticket {
+        Some(t) => description.replace(t.as_str(), ""),
+        None => description,
+    };
+
+    let slug = slugify(&description);
+    let slug = match &ticket {
+        Some(t) if slug.is_empty() => t.clone(),
+        Some(t) => format!("{}-{}", t, slug),
+        None => slug,
+    };
+    if slug.is_empty() {
+        return None;
+    }
+
+    let name = match prefix {
+        Some(p) => format!("{}/{}", p, slug),
+        None => slug,
+    };
+    let name = truncate_at_dash(&name, MAX_LEN);
+
+    is_valid(&name).then_some(name)
+}
+
+/// Normalize free-form text (e.g. an AI response) into a branch name
+///
+/// Keeps `/` separators so prefixes like `feat/` survive.
+pub fn sanitize(raw: &str) -> String {
+    let line = raw
+        .lines()
+        .map(|l| l.trim().trim_matches(|c| c == '`' || c == '"' || c == '\''))
+        .find(|l| !l.is_empty())
+        .unwrap_or("");
+
+    let name = line
+        .split('/')
+        .map(slugify)
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("/");
+
+    truncate_at_dash(&name, MAX_LEN)
+}
+
+/// Check whether a name is a valid git branch name
+pub fn is_valid(name: &str) -> bool {
+    !name.is_empty() && git2::Branch::name_is_valid(name).unwrap_or(false)
+}
+
+/// Lowercase text and join its alphanumeric words with dashes
+fn slugify(text: &str) -> String {
+    text.split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .take(MAX_WORDS)
+        .map(|w| w.to_lowercase())
+        .collect::<Vec<_>>()
+        .join("-")
+}
+
+/// Truncate a name to `max_len`, cutting at the last dash when possible
+fn truncate_at_dash(name: &str, max_len: usize) -> String {
+    if name.len() <= max_len {
+        return name.to_string();
+    }
+    let cut = &name[..max_len];
+    match cut.rfind('-') {
+        Some(idx) if idx > 0 => cut[..idx].to_string(),
+        _ => cut.trim_end_matches('-').to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_suggest_conventional_subject() {
+        assert_eq!(
+            suggest_from_subject("feat(auth): Add login screen").as_deref(),
+            Some("feat/add-login-screen")
+        );
+        assert_eq!(
+            suggest_from_subject("fix!: handle empty diff").as_deref(),
+            Some("fix/handle-empty-diff")
+        );
+    }
+
+    #[test]
+    fn test_suggest_plain_subject() {
+        assert_eq!(
+            suggest_from_subject("Update README with install steps").as_deref(),
+            Some("update-readme-with-install-steps")
+        );
+        assert_eq!(suggest_from_subject("   "), None);
+    }
+
+    #[test]
+    fn test_suggest_with_ticket() {
+        assert_eq!(
+            suggest_from_subject("fix: PROJ-42 crash on startup").as_deref(),
+            Some("fix/PROJ-42-crash-on-startup")
+        );
+    }
+
+    #[test]
+    fn test_sanitize_ai_output() {
+        assert_eq!(sanitize("`feat/Add Login`\n"), "feat/add-login");
+        assert_eq!(sanitize("\"fix/bad  name!!\""), "fix/bad-name");
+    }
+
+    #[test]
+    fn test_is_valid() {
+        assert!(is_valid("feat/add-login"));
+        assert!(!is_valid(""));
+        assert!(!is_valid("feat..login"));
+        assert!(!is_valid("feat/login.lock"));
+    }
+}
@@ -0,0 +1,31 @@
+//! Clipboard access
+//!
+//! Uses the system clipboard through `arboard` and falls back to the OSC 52
+//! terminal escape when there is none (e.g. over SSH), which most terminals
+//! still honor.
+
+use std::io::Write;
+
+use base64::Engine;
+
+use crate::error::Result;
+
+/// Copy text to the system clipboard
+pub fn copy_to_clipboard(text: &str) -> Result<()> {
+    match arboard::Clipboard::new().and_then(|mut clipboard| clipboard.set_text(text)) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            tracing::debug!("System clipboard unavailable, using OSC 52: {}", e);
+            copy_osc52(text)
+        }
+    }
+}
+
+/// Ask the terminal to set the clipboard via the OSC 52 escape sequence
+fn copy_osc52(text: &str) -> Result<()> {
+    let encoded = base64::engine::general_purpose::STANDARD.encode(text);
+    let mut stdout = std::io::stdout();
+    write!(stdout, "\x1b]52;c;{}\x07", encoded)?;
+    stdout.flush()?;
+    Ok(())
+}
@@ -1,7 +1,12 @@
 //! Application configuration management
 //!
 //! Handles loading and saving application settings including:
-//! - Gemini model selection
+//! - AI provider and model selection
+//! - Protected branches
+//! - `q` key behavior in the TUI
+//! - HTML handling in markdown
+//! - TUI list selection memory
+//! - External editor command
 //! - Other user preferences
 
 use std::fs;
@@ -23,18 +28,32 @@ pub enum GeminiModel {
     #[default]
     #[serde(rename = "gemini-2.5-flash")]
     Gemini25Flash,
+    /// Gemini 2.5 Flash-Lite
+    #[serde(rename = "gemini-2.5-flash-lite")]
+    Gemini25FlashLite,
+    /// Gemini 2.5 Pro
+    #[serde(rename = "gemini-2.5-pro")]
+    Gemini25Pro,
     /// Gemini 3 Flash Preview
     #[serde(rename = "gemini-3-flash-preview")]
     Gemini3FlashPreview,
+    /// Any other model, named by `Config::gemini_custom_model`
+    #[serde(rename = "custom")]
+    Custom,
 }
 
 impl GeminiModel {
     /// Get the API model identifier
+    ///
+    /// `Custom` returns `"custom"`; use `Config::gemini_model_name` for the real name.
     pub fn api_name(&self) -> &'static str {
         match self {
             GeminiModel::Gemini20Flash => "gemini-2.0-flash",
             GeminiModel::Gemini25Flash => "gemini-2.5-flash",
+            GeminiModel::Gemini25FlashLite => "gemini-2.5-flash-lite",
+            GeminiModel::Gemini25Pro => "gemini-2.5-pro",
             GeminiModel::Gemini3FlashPreview => "gemini-3-flash-preview",
+            GeminiModel::Custom => "custom",
         }
     }
 
@@ -43,28 +62,50 @@ impl GeminiModel {
         match self {
             GeminiModel::Gemini20Flash => "Gemini 2.0 Flash",
             GeminiModel::Gemini25Flash => "Gemini 2.5 Flash",
+            GeminiModel::Gemini25FlashLite => "Gemini 2.5 Flash-Lite",
+            GeminiModel::Gemini25Pro => "Gemini 2.5 Pro",
             GeminiModel::Gemini3FlashPreview => "Gemini 3 Flash Preview",
+            GeminiModel::Custom => "Custom",
         }
     }
 
-    /// Parse from string
+    /// Parse a built-in model from its API name
     pub fn parse(s: &str) -> Option<Self> {
         match s {
             "gemini-2.0-flash" => Some(GeminiModel::Gemini20Flash),
             "gemini-2.5-flash" => Some(GeminiModel::Gemini25Flash),
+            "gemini-2.5-flash-lite" => Some(GeminiModel::Gemini25FlashLite),
+            "gemini-2.5-pro" => Some(GeminiModel::Gemini25Pro),
             "gemini-3-flash-preview" => Some(GeminiModel::Gemini3FlashPreview),
             _ => None,
         }
     }
 
-    /// Get all available models
+    /// Get all available models, with `Custom` last
     pub fn all() -> &'static [GeminiModel] {
         &[
             GeminiModel::Gemini20Flash,
             GeminiModel::Gemini25Flash,
+            GeminiModel::Gemini25FlashLite,
+            GeminiModel::Gemini25Pro,
             GeminiModel::Gemini3FlashPreview,
+            GeminiModel::Custom,
         ]
     }
+
+    /// Normalize a user-entered model name
+    ///
+    /// Strips surrounding whitespace and a leading `models/`. Returns `None`
+    /// unless the name is non-empty and only uses letters, digits, `-`, `.` and `_`.
+    pub fn normalize_custom_name(name: &str) -> Option<String> {
+        let name = name.trim();
+        let name = name.strip_prefix("models/").unwrap_or(name);
+        let valid = !name.is_empty()
+            && name
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '.' | '_'));
+        valid.then(|| name.to_string())
+    }
 }
 
 impl std::fmt::Display for GeminiModel {
@@ -73,27 +114,320 @@ impl std::fmt::Display for GeminiModel {
     }
 }
 
+/// AI service used for commit messages, PR descriptions and other suggestions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum AiBackend {
+    /// Google Gemini (default)
+    #[default]
+    Gemini,
+    /// OpenAI chat completions
+    #[serde(rename = "openai")]
+    OpenAi,
+    /// Anthropic messages API
+    Anthropic,
+}
+
+impl AiBackend {
+    /// Get the config value name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AiBackend::Gemini => "gemini",
+            AiBackend::OpenAi => "openai",
+            AiBackend::Anthropic => "anthropic",
+        }
+    }
+
+    /// Get a human-readable display name
+    pub fn display_name(&self) -> &'static str {
+        match self {
+            AiBackend::Gemini => "Gemini",
+            AiBackend::OpenAi => "OpenAI",
+            AiBackend::Anthropic => "Anthropic",
+        }
+    }
+
+    /// Config key for this provider's API key (`gr config set <key>`)
+    pub fn key_config_name(&self) -> &'static str {
+        match self {
+            AiBackend::Gemini => "gemini-key",
+            AiBackend::OpenAi => "openai-key",
+            AiBackend::Anthropic => "anthropic-key",
+        }
+    }
+
+    /// Parse from string
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "gemini" => Some(AiBackend::Gemini),
+            "openai" => Some(AiBackend::OpenAi),
+            "anthropic" => Some(AiBackend::Anthropic),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for AiBackend {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// What the `q` key does on the TUI dashboard
+///
+/// On sub-screens `q` always navigates back; this only decides whether it
+/// can exit the application. Ctrl+c always quits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case")]
+pub enum QKeyBehavior {
+    /// Only navigate back; never quit the application
+    Back,
+    /// Quit immediately from the dashboard (default)
+    #[default]
+    Quit,
+    /// Ask for confirmation before quitting from the dashboard
+    Prompt,
+}
+
+impl QKeyBehavior {
+    /// Get the config value name
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            QKeyBehavior::Back => "back",
+            QKeyBehavior::Quit => "quit",
+            QKeyBehavior::Prompt => "prompt",
+        }
+    }
+
+    /// Parse from string
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "back" => Some(QKeyBehavior::Back),
+            "quit" => Some(QKeyBehavior::Quit),
+            "prompt" => Some(QKeyBehavior::Prompt),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for QKeyBehavior {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
 /// Application configuration
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Config {
+    /// AI provider used for generated text
+    #[serde(default)]
+    pub ai_provider: AiBackend,
+
+    /// OpenAI model name
+    #[serde(default = "default_openai_model")]
+    pub openai_model: String,
+
+    /// Anthropic model name
+    #[serde(default = "default_anthropic_model")]
+    pub anthropic_model: String,
+
+    /// Seconds before an AI request is abandoned
+    #[serde(default = "default_ai_timeout_seconds")]
+    pub ai_timeout_seconds: u16,
+
     /// Selected Gemini model for AI features
     #[serde(default)]
     pub gemini_model: GeminiModel,
 
+    /// Model name used when `gemini_model` is `custom`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub gemini_custom_model: Option<String>,
+
     /// Polling interval for PR comments in seconds
     #[serde(default = "default_poll_interval")]
     pub poll_interval_secs: u64,
+
+    /// Branches that should not be committed or pushed to directly
+    /// (empty means only the repository's default branch)
+    #[serde(default)]
+    pub protected_branches: Vec<String>,
+
+    /// What the `q` key does on the TUI dashboard
+    #[serde(default)]
+    pub q_behavior: QKeyBehavior,
+
+    /// Render HTML in PR descriptions and comments instead of stripping it
+    #[serde(default)]
+    pub render_html: bool,
+
+    /// Keep each TUI list's selection when navigating away and back
+    #[serde(default = "default_remember_selection")]
+    pub remember_selection: bool,
+
+    /// Capture the mouse in the TUI for clicking and scrolling
+    /// (disable to keep the terminal's own text selection)
+    #[serde(default = "default_mouse")]
+    pub mouse: bool,
+
+    /// Editor command for long text (falls back to `$VISUAL` / `$EDITOR`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub editor: Option<String>,
+
+    /// Report merges, pushes and deletions instead of performing them
+    #[serde(default)]
+    pub dry_run: bool,
+
+    /// Number of pull requests fetched per page in the TUI PR list
+    #[serde(default = "default_pr_list_page_size")]
+    pub pr_list_page_size: u8,
+
+    /// Seconds between TUI workflow run refreshes while a run is active
+    #[serde(default = "default_workflow_poll_seconds")]
+    pub workflow_poll_seconds: u8,
+
+    /// Keep credentials in a file in the config directory when the system keychain is unavailable
+    #[serde(default)]
+    pub credential_file_fallback: bool,
+
+    /// Retries for GitHub API reads that fail with a server or connection error
+    #[serde(default = "default_github_retries")]
+    pub github_retries: u8,
+
+    /// GitHub API base URL for GitHub Enterprise Server (e.g. `https://github.example.com/api/v3`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub github_base_url: Option<String>,
+
+    /// Custom AI commit message prompt; `{diff}` is replaced with the staged diff
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_prompt_template: Option<String>,
+
+    /// Use `commit_prompt_template` instead of the built-in commit prompt
+    #[serde(default)]
+    pub use_commit_prompt_template: bool,
+
+    /// Ask the AI for conventional-commit subjects scoped by the changed directories
+    #[serde(default)]
+    pub conventional_commits: bool,
+
+    /// TUI color theme (`[theme]` section)
+    #[serde(default, skip_serializing_if = "ThemeConfig::is_empty")]
+    pub theme: ThemeConfig,
+}
+
+/// TUI color settings from the `[theme]` config section
+///
+/// Colors accept names ("cyan", "dark-gray"), ANSI indexes ("208") or hex
+/// ("#1e90ff"). Unset values come from the preset.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ThemeConfig {
+    /// Built-in preset: dark (default), light or high-contrast
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<String>,
+
+    /// Header text color
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub header: Option<String>,
+
+    /// Background of the selected list row
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub selection: Option<String>,
+
+    /// Status bar background
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status_bar: Option<String>,
+
+    /// Accent color; also used for the header and selection unless set
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub accent: Option<String>,
+}
+
+impl ThemeConfig {
+    /// Whether no theme setting is configured
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Smallest and largest PR list page sizes accepted by the GitHub API
+pub const PR_LIST_PAGE_SIZE_RANGE: std::ops::RangeInclusive<u8> = 1..=100;
+
+/// Accepted workflow auto-poll intervals, in seconds
+pub const WORKFLOW_POLL_SECONDS_RANGE: std::ops::RangeInclusive<u8> = 2..=60;
+
+/// Names of the built-in TUI theme presets
+pub const THEME_PRESETS: [&str; 3] = ["dark", "light", "high-contrast"];
+
+/// GitHub API base URL used when none is configured
+pub const DEFAULT_GITHUB_BASE_URL: &str = "https://api.github.com";
+
+/// Accepted AI request timeouts, in seconds
+pub const AI_TIMEOUT_SECONDS_RANGE: std::ops::RangeInclusive<u16> = 5..=600;
+
+/// Accepted retry counts for transient GitHub API failures
+pub const GITHUB_RETRIES_RANGE: std::ops::RangeInclusive<u8> = 0..=10;
+
+fn default_openai_model() -> String {
+    "gpt-4o-mini".to_string()
+}
+
+fn default_anthropic_model() -> String {
+    "claude-sonnet-4-5".to_string()
+}
+
+fn default_ai_timeout_seconds() -> u16 {
+    30
 }
 
 fn default_poll_interval() -> u64 {
     30
 }
 
+fn default_remember_selection() -> bool {
+    true
+}
+
+fn default_mouse() -> bool {
+    true
+}
+
+fn default_pr_list_page_size() -> u8 {
+    30
+}
+
+fn default_workflow_poll_seconds() -> u8 {
+    7
+}
+
+fn default_github_retries() -> u8 {
+    3
+}
+
 impl Default for Config {
     fn default() -> Self {
         Self {
+            ai_provider: AiBackend::default(),
+            openai_model: default_openai_model(),
+            anthropic_model: default_anthropic_model(),
+            ai_timeout_seconds: default_ai_timeout_seconds(),
             gemini_model: GeminiModel::default(),
+            gemini_custom_model: None,
             poll_interval_secs: default_poll_interval(),
+            protected_branches: Vec::new(),
+            q_behavior: QKeyBehavior::default(),
+            render_html: false,
+            remember_selection: default_remember_selection(),
+            mouse: default_mouse(),
+            editor: None,
+            dry_run: false,
+            pr_list_page_size: default_pr_list_page_size(),
+            workflow_poll_seconds: default_workflow_poll_seconds(),
+            credential_file_fallback: false,
+            github_retries: default_github_retries(),
+            github_base_url: None,
+            commit_prompt_template: None,
+            use_commit_prompt_template: false,
+            conventional_commits: false,
+            theme: ThemeConfig::default(),
         }
     }
 }
@@ -147,6 +481,214 @@ impl Config {
     pub fn set_gemini_model(&mut self, model: GeminiModel) {
         self.gemini_model = model;
     }
+
+    /// Set the protected branch list (empty restores the default)
+    pub fn set_protected_branches(&mut self, branches: Vec<String>) {
+        self.protected_branches = branches;
+    }
+
+    /// Set the `q` key behavior
+    pub fn set_q_behavior(&mut self, behavior: QKeyBehavior) {
+        self.q_behavior = behavior;
+    }
+
+    /// Set whether HTML in markdown is rendered or stripped
+    pub fn set_render_html(&mut self, render: bool) {
+        self.render_html = render;
+    }
+
+    /// Set whether TUI lists remember their selection
+    pub fn set_remember_selection(&mut self, remember: bool) {
+        self.remember_selection = remember;
+    }
+
+    /// Set whether the TUI captures the mouse
+    pub fn set_mouse(&mut self, mouse: bool) {
+        self.mouse = mouse;
+    }
+
+    /// Set the external editor command (`None` uses `$VISUAL` / `$EDITOR`)
+    pub fn set_editor(&mut self, editor: Option<String>) {
+        self.editor = editor;
+    }
+
+    /// Set whether destructive actions only report what they would do
+    pub fn set_dry_run(&mut self, dry_run: bool) {
+        self.dry_run = dry_run;
+    }
+
+    /// Set the PR list page size, clamped to what the GitHub API accepts
+    pub fn set_pr_list_page_size(&mut self, size: u8) {
+        self.pr_list_page_size = clamp_pr_list_page_size(size);
+    }
+
+    /// Set the workflow auto-poll interval, clamped to `WORKFLOW_POLL_SECONDS_RANGE`
+    pub fn set_workflow_poll_seconds(&mut self, seconds: u8) {
+        self.workflow_poll_seconds = clamp_workflow_poll_seconds(seconds);
+    }
+
+    /// Set whether credentials may be stored in a file when the keychain is unavailable
+    pub fn set_credential_file_fallback(&mut self, enabled: bool) {
+        self.credential_file_fallback = enabled;
+    }
+
+    /// Set the GitHub API retry count, clamped to `GITHUB_RETRIES_RANGE`
+    pub fn set_github_retries(&mut self, retries: u8) {
+        self.github_retries = retries.min(*GITHUB_RETRIES_RANGE.end());
+    }
+
+    /// Set the TUI theme preset; `None` restores the default dark theme
+    pub fn set_theme_preset(&mut self, preset: Option<String>) {
+        self.theme.preset = preset;
+    }
+
+    /// Set the AI provider
+    pub fn set_ai_provider(&mut self, provider: AiBackend) {
+        self.ai_provider = provider;
+    }
+
+    /// Set the OpenAI model name
+    pub fn set_openai_model(&mut self, model: String) {
+        self.openai_model = model;
+    }
+
+    /// Set the Anthropic model name
+    pub fn set_anthropic_model(&mut self, model: String) {
+        self.anthropic_model = model;
+    }
+
+    /// Set the AI request timeout, clamped to `AI_TIMEOUT_SECONDS_RANGE`
+    pub fn set_ai_timeout_seconds(&mut self, seconds: u16) {
+        self.ai_timeout_seconds = clamp_ai_timeout_seconds(seconds);
+    }
+
+    /// Select a custom Gemini model by name
+    pub fn set_gemini_custom_model(&mut self, name: String) {
+        self.gemini_model = GeminiModel::Custom;
+        self.gemini_custom_model = Some(name);
+    }
+
+    /// Set the GitHub API base URL (`None` uses github.com)
+    pub fn set_github_base_url(&mut self, url: Option<String>) {
+        self.github_base_url = url;
+    }
+
+    /// Set the custom AI commit prompt template (`None` removes it)
+    pub fn set_commit_prompt_template(&mut self, template: Option<String>) {
+        self.commit_prompt_template = template;
+    }
+
+    /// Set whether the custom AI commit prompt template is used
+    pub fn set_use_commit_prompt_template(&mut self, enabled: bool) {
+        self.use_commit_prompt_template = enabled;
+    }
+
+    /// Set whether AI commit messages use the conventional-commit format
+    pub fn set_conventional_commits(&mut self, enabled: bool) {
+        self.conventional_commits = enabled;
+    }
+
+    /// Custom commit prompt template if enabled and not blank
+    ///
+    /// An empty template falls back to the built-in prompt.
+    pub fn active_commit_prompt_template(&self) -> Option<&str> {
+        if !self.use_commit_prompt_template {
+            return None;
+        }
+        self.commit_prompt_template
+            .as_deref()
+            .filter(|template| !template.trim().is_empty())
+    }
+
+    /// PR list page size, clamped in case the config file was edited by hand
+    pub fn pr_list_page_size(&self) -> u8 {
+        clamp_pr_list_page_size(self.pr_list_page_size)
+    }
+
+    /// Workflow auto-poll interval, clamped in case the config file was edited by hand
+    pub fn workflow_poll_seconds(&self) -> u8 {
+        clamp_workflow_poll_seconds(self.workflow_poll_seconds)
+    }
+
+    /// AI request timeout, clamped in case the config file was edited by hand
+    pub fn ai_timeout(&self) -> std::time::Duration {
+        std::time::Duration::from_secs(clamp_ai_timeout_seconds(self.ai_timeout_seconds).into())
+    }
+
+    /// GitHub API retry count, clamped in case the config file was edited by hand
+    pub fn github_retries(&self) -> u8 {
+        self.github_retries.min(*GITHUB_RETRIES_RANGE.end())
+    }
+
+    /// Gemini model identifier for API requests
+    ///
+    /// A `Custom` model without a name falls back to the default model.
+    pub fn gemini_model_name(&self) -> &str {
+        match self.gemini_model {
+            GeminiModel::Custom => self
+                .gemini_custom_model
+                .as_deref()
+                .filter(|name| !name.is_empty())
+                .unwrap_or(GeminiModel::default().api_name()),
+            model => model.api_name(),
+        }
+    }
+
+    /// Model identifier for the configured AI provider
+    pub fn ai_model_name(&self) -> &str {
+        match self.ai_provider {
+            AiBackend::Gemini => self.gemini_model_name(),
+            AiBackend::OpenAi => &self.openai_model,
+            AiBackend::Anthropic => &self.anthropic_model,
+        }
+    }
+
+    /// GitHub API base URL, falling back to `DEFAULT_GITHUB_BASE_URL`
+    pub fn github_base_url(&self) -> &str {
+        self.github_base_url
+            .as_deref()
+            .unwrap_or(DEFAULT_GITHUB_BASE_URL)
+    }
+
+    /// Check whether a branch is protected from direct commits and pushes
+    pub fn is_protected_branch(&self, branch: &str, default_branch: &str) -> bool {
+        is_protected_branch(&self.protected_branches, branch, default_branch)
+    }
+}
+
+/// Clamp a PR list page size into `PR_LIST_PAGE_SIZE_RANGE`
+fn clamp_pr_list_page_size(size: u8) -> u8 {
+    size.clamp(
+        *PR_LIST_PAGE_SIZE_RANGE.start(),
+        *PR_LIST_PAGE_SIZE_RANGE.end(),
+    )
+}
+
+/// Clamp a workflow poll interval into `WORKFLOW_POLL_SECONDS_RANGE`
+fn clamp_workflow_poll_seconds(seconds: u8) -> u8 {
+    seconds.clamp(
+        *WORKFLOW_POLL_SECONDS_RANGE.start(),
+        *WORKFLOW_POLL_SECONDS_RANGE.end(),
+    )
+}
+
+/// Clamp an AI request timeout into `AI_TIMEOUT_SECONDS_RANGE`
+fn clamp_ai_timeout_seconds(seconds: u16) -> u16 {
+    seconds.clamp(
+        *AI_TIMEOUT_SECONDS_RANGE.start(),
+        *AI_TIMEOUT_SECONDS_RANGE.end(),
+    )
+}
+
+/// Check a branch against a protected branch list
+///
+/// An empty list protects only the repository's default branch.
+pub fn is_protected_branch(protected: &[String], branch: &str, default_branch: &str) -> bool {
+    if protected.is_empty() {
+        branch == default_branch
+    } else {
+        protected.iter().any(|b| b == branch)
+    }
 }
 
 #[cfg(test)]
@@ -167,7 +709,43 @@ mod tests {
             GeminiModel::parse("gemini-3-flash-preview"),
             Some(GeminiModel::Gemini3FlashPreview)
         );
+        assert_eq!(
+            GeminiModel::parse("gemini-2.5-pro"),
+            Some(GeminiModel::Gemini25Pro)
+        );
         assert_eq!(GeminiModel::parse("invalid"), None);
+        assert_eq!(GeminiModel::parse("custom"), None);
+    }
+
+    #[test]
+    fn test_ai_provider_parse_and_model() {
+        assert_eq!(AiBackend::parse("openai"), Some(AiBackend::OpenAi));
+        assert_eq!(AiBackend::parse("anthropic"), Some(AiBackend::Anthropic));
+        assert_eq!(AiBackend::parse("claude"), None);
+
+        let config: Config = toml::from_str("ai_provider = \"openai\"").unwrap();
+        assert_eq!(config.ai_provider, AiBackend::OpenAi);
+        assert_eq!(config.ai_model_name(), "gpt-4o-mini");
+        assert_eq!(Config::default().ai_model_name(), "gemini-2.5-flash");
+    }
+
+    #[test]
+    fn test_gemini_custom_model() {
+        assert_eq!(
+            GeminiModel::normalize_custom_name(" models/gemini-exp-1206 "),
+            Some("gemini-exp-1206".to_string())
+        );
+        assert_eq!(GeminiModel::normalize_custom_name(""), None);
+        assert_eq!(GeminiModel::normalize_custom_name("bad/name"), None);
+
+        let mut config = Config::default();
+        assert_eq!(config.gemini_model_name(), "gemini-2.5-flash");
+        config.set_gemini_custom_model("gemini-exp-1206".to_string());
+        assert_eq!(config.gemini_model, GeminiModel::Custom);
+        assert_eq!(config.gemini_model_name(), "gemini-exp-1206");
+
+        let config: Config = toml::from_str("gemini_model = \"custom\"").unwrap();
+        assert_eq!(config.gemini_model_name(), "gemini-2.5-flash");
     }
 
     #[test]
@@ -175,5 +753,123 @@ mod tests {
         let config = Config::default();
         assert_eq!(config.gemini_model, GeminiModel::Gemini25Flash);
         assert_eq!(config.poll_interval_secs, 30);
+        assert!(config.protected_branches.is_empty());
+        assert_eq!(config.q_behavior, QKeyBehavior::Quit);
+        assert!(config.remember_selection);
+        assert!(config.mouse);
+        assert_eq!(config.pr_list_page_size, 30);
+        assert_eq!(config.workflow_poll_seconds, 7);
+    }
+
+    #[test]
+    fn test_commit_prompt_template_fallback() {
+        let mut config = Config::default();
+        assert_eq!(config.active_commit_prompt_template(), None);
+
+        config.set_commit_prompt_template(Some("Summarize:\n{diff}".to_string()));
+        assert_eq!(config.active_commit_prompt_template(), None);
+
+        config.set_use_commit_prompt_template(true);
+        assert_eq!(
+            config.active_commit_prompt_template(),
+            Some("Summarize:\n{diff}")
+        );
+
+        config.set_commit_prompt_template(Some("   ".to_string()));
+        assert_eq!(config.active_commit_prompt_template(), None);
+    }
+
+    #[test]
+    fn test_ai_timeout_clamped() {
+        let mut config = Config::default();
+        assert_eq!(config.ai_timeout().as_secs(), 30);
+        config.set_ai_timeout_seconds(1);
+        assert_eq!(config.ai_timeout_seconds, 5);
+        let config: Config = toml::from_str("ai_timeout_seconds = 9000").unwrap();
+        assert_eq!(config.ai_timeout().as_secs(), 600);
+    }
+
+    #[test]
+    fn test_workflow_poll_seconds_clamped() {
+        let mut config = Config::default();
+        config.set_workflow_poll_seconds(1);
+        assert_eq!(config.workflow_poll_seconds, 2);
+        config.set_workflow_poll_seconds(90);
+        assert_eq!(config.workflow_poll_seconds, 60);
+        let config: Config = toml::from_str("workflow_poll_seconds = 0").unwrap();
+        assert_eq!(config.workflow_poll_seconds(), 2);
+    }
+
+    #[test]
+    fn test_github_retries_clamped() {
+        let mut config = Config::default();
+        assert_eq!(config.github_retries(), 3);
+        config.set_github_retries(0);
+        assert_eq!(config.github_retries(), 0);
+        config.set_github_retries(50);
+        assert_eq!(config.github_retries, 10);
+        let config: Config = toml::from_str("github_retries = 99").unwrap();
+        assert_eq!(config.github_retries(), 10);
+    }
+
+    #[test]
+    fn test_github_base_url_default() {
+        let mut config = Config::default();
+        assert_eq!(config.github_base_url(), "https://api.github.com");
+        config.set_github_base_url(Some("https://github.example.com/api/v3".to_string()));
+        assert_eq!(
+            config.github_base_url(),
+            "https://github.example.com/api/v3"
+        );
+    }
+
+    #[test]
+    fn test_pr_list_page_size_clamped() {
+        let mut config = Config::default();
+        config.set_pr_list_page_size(0);
+        assert_eq!(config.pr_list_page_size, 1);
+        config.set_pr_list_page_size(250);
+        assert_eq!(config.pr_list_page_size, 100);
+        let config: Config = toml::from_str("pr_list_page_size = 200").unwrap();
+        assert_eq!(config.pr_list_page_size(), 100);
+    }
+
+    #[test]
+    fn test_q_behavior_parse() {
+        assert_eq!(QKeyBehavior::parse("back"), Some(QKeyBehavior::Back));
+        assert_eq!(QKeyBehavior::parse("prompt"), Some(QKeyBehavior::Prompt));
+        assert_eq!(QKeyBehavior::parse("never"), None);
+        let config: Config = toml::from_str("q_behavior = \"prompt\"").unwrap();
+        assert_eq!(config.q_behavior, QKeyBehavior::Prompt);
+    }
+
+    #[test]
+    fn test_protected_branches() {
+        let mut config = Config::default();
+        assert!(config.is_protected_branch("main", "main"));
+        assert!(!config.is_protected_branch("feature", "main"));
+
+        config.set_protected_branches(vec!["release".to_string(), "develop".to_string()]);
+        assert!(config.is_protected_branch("release", "main"));
+        assert!(!config.is_protected_branch("main", "main"));
+    }
+
+    #[test]
+    fn test_theme_section() {
+        let config = Config::default();
+        assert!(config.theme.is_empty());
+        assert!(!toml::to_string(&config).unwrap().contains("[theme]"));
+
+        let config: Config = toml::from_str(
+            "dry_run = true\n[theme]\npreset = \"light\"\nstatus_bar = \"#202020\"\n",
+        )
+        .unwrap();
+        assert!(config.dry_run);
+        assert_eq!(config.theme.preset.as_deref(), Some("light"));
+        assert_eq!(config.theme.status_bar.as_deref(), Some("#202020"));
+        assert_eq!(config.theme.header, None);
+
+        let round_trip: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+        assert_eq!(round_trip.theme, config.theme);
     }
 }
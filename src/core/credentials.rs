@@ -2,19 +2,26 @@
 //!
 //! This module handles secure storage of sensitive credentials:
 //! - GitHub OAuth tokens (with refresh token support)
-//! - Gemini API keys
+//! - AI provider API keys (Gemini, OpenAI, Anthropic), each stored separately
 //!
 //! Uses the system keyring (macOS Keychain, Linux Secret Service) with
 //! in-memory caching to minimize keychain prompts.
 //!
+//! When `credential_file_fallback` is enabled in the config and the keyring
+//! cannot be reached, credentials are kept in `credentials.json` in the config
+//! directory instead (readable only by the owner on Unix).
+//!
 //! ## Environment Variable Fallback
 //!
 //! For development and CI, you can set credentials via environment variables:
-//! - `GITHUB_TOKEN` - GitHub OAuth token
-//! - `GEMINI_API_KEY` - Gemini API key
+//! - `ARGO_GITHUB_TOKEN` or `GITHUB_TOKEN` - GitHub token, used when none is stored
+//! - `GEMINI_API_KEY`, `OPENAI_API_KEY`, `ANTHROPIC_API_KEY` - AI provider API keys
 //!
-//! Priority: env var > cache > keyring
+//! GitHub token priority: cache > keyring > env var
+//! AI key priority: env var > cache > keyring
 
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::RwLock;
 
@@ -24,6 +31,7 @@ use once_cell::sync::Lazy;
 use secrecy::{ExposeSecret, SecretString};
 use serde::{Deserialize, Serialize};
 
+use crate::core::config::{AiBackend, Config};
 use crate::error::{GhrustError, Result};
 use crate::github::auth::{OAuthTokenData, StoredTokenData};
 
@@ -35,10 +43,14 @@ const GEMINI_API_KEY_NAME: &str = "gemini_api_key";
 // Unified credentials key (new single-entry approach)
 const UNIFIED_CREDENTIALS_KEY: &str = "argo_credentials";
 const UNIFIED_CREDENTIALS_VERSION: u8 = 1;
+// Fallback file (in the config directory) used when the keyring is unavailable
+const CREDENTIALS_FILE_NAME: &str = "credentials.json";
 
-// Environment variable names
-const GITHUB_TOKEN_ENV: &str = "GITHUB_TOKEN";
+// Environment variable names (GitHub token variables are checked in order)
+const GITHUB_TOKEN_ENVS: [&str; 2] = ["ARGO_GITHUB_TOKEN", "GITHUB_TOKEN"];
 const GEMINI_API_KEY_ENV: &str = "GEMINI_API_KEY";
+const OPENAI_API_KEY_ENV: &str = "OPENAI_API_KEY";
+const ANTHROPIC_API_KEY_ENV: &str = "ANTHROPIC_API_KEY";
 
 // In-memory credential cache
 // Option<Option<T>>:
@@ -51,6 +63,9 @@ static GITHUB_TOKEN_DATA_CACHE: Lazy<RwLock<Option<Option<OAuthTokenData>>>> =
     Lazy::new(|| RwLock::new(None));
 static GEMINI_KEY_CACHE: Lazy<RwLock<Option<Option<SecretString>>>> =
     Lazy::new(|| RwLock::new(None));
+// Keys of the other AI providers (Gemini uses `GEMINI_KEY_CACHE`)
+static AI_KEY_CACHE: Lazy<RwLock<HashMap<AiBackend, Option<SecretString>>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
 
 // Migration tracking - ensures migration runs only once per process
 static MIGRATION_COMPLETED: AtomicBool = AtomicBool::new(false);
@@ -69,6 +84,23 @@ struct UnifiedCredentials {
     /// Gemini API key for AI features
     #[serde(skip_serializing_if = "Option::is_none")]
     gemini_api_key: Option<String>,
+    /// OpenAI API key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    openai_api_key: Option<String>,
+    /// Anthropic API key
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    anthropic_api_key: Option<String>,
+}
+
+impl UnifiedCredentials {
+    /// Stored API key slot for an AI provider
+    fn ai_key_mut(&mut self, provider: AiBackend) -> &mut Option<String> {
+        match provider {
+            AiBackend::Gemini => &mut self.gemini_api_key,
+            AiBackend::OpenAi => &mut self.openai_api_key,
+            AiBackend::Anthropic => &mut self.anthropic_api_key,
+        }
+    }
 }
 
 /// Credential store for secure token management
@@ -93,25 +125,43 @@ impl CredentialStore {
         Ok(())
     }
 
-    /// Retrieve the stored GitHub OAuth token
+    /// Retrieve the GitHub OAuth token
     ///
-    /// Priority: environment variable > cache > unified credentials
+    /// Priority: stored token (cache > unified credentials) > environment variable.
+    /// A keychain error is ignored when the environment provides a token.
     pub fn get_github_token() -> Result<Option<SecretString>> {
-        // Priority 1: Check environment variable
-        if let Ok(token) = std::env::var(GITHUB_TOKEN_ENV) {
-            if !token.is_empty() {
-                return Ok(Some(SecretString::from(token)));
-            }
+        match Self::get_stored_github_token() {
+            Ok(Some(token)) => Ok(Some(token)),
+            Ok(None) => Ok(Self::github_token_from_env()),
+            Err(e) => Self::github_token_from_env().map(Some).ok_or(e),
         }
+    }
 
-        // Priority 2: Check cache
+    /// Read a GitHub token from `ARGO_GITHUB_TOKEN` or `GITHUB_TOKEN`
+    ///
+    /// `ARGO_GITHUB_TOKEN` wins when both are set; empty values are ignored.
+    pub fn github_token_from_env() -> Option<SecretString> {
+        let name = Self::github_token_env_var()?;
+        std::env::var(name).ok().map(SecretString::from)
+    }
+
+    /// Name of the environment variable that provides the GitHub token, if any
+    pub fn github_token_env_var() -> Option<&'static str> {
+        github_token_var_in(|name| std::env::var(name).ok())
+    }
+
+    /// Retrieve the GitHub OAuth token from the keyring, ignoring the environment
+    ///
+    /// Priority: cache > unified credentials
+    pub fn get_stored_github_token() -> Result<Option<SecretString>> {
+        // Priority 1: Check cache
         if let Ok(cache) = GITHUB_TOKEN_CACHE.read() {
             if let Some(cached_value) = cache.as_ref() {
                 return Ok(cached_value.clone());
             }
         }
 
-        // Priority 3: Fetch from unified credentials
+        // Priority 2: Fetch from unified credentials
         let result = Self::fetch_github_token_from_unified()?;
 
         // Update cache
@@ -151,9 +201,13 @@ impl CredentialStore {
         Ok(())
     }
 
-    /// Check if a GitHub token is stored
+    /// Check if a GitHub token is stored or provided by the environment
     pub fn has_github_token() -> Result<bool> {
-        Ok(Self::get_github_token()?.is_some())
+        // Checking the environment first avoids a keychain prompt on CI
+        if Self::github_token_from_env().is_some() {
+            return Ok(true);
+        }
+        Ok(Self::get_stored_github_token()?.is_some())
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -289,14 +343,22 @@ impl CredentialStore {
             }
         }
 
-        // Priority 2: Check cache
+        // Priority 2: Stored key
+        Self::get_stored_gemini_key()
+    }
+
+    /// Retrieve the Gemini API key from the keyring, ignoring the environment
+    ///
+    /// Priority: cache > unified credentials
+    pub fn get_stored_gemini_key() -> Result<Option<SecretString>> {
+        // Priority 1: Check cache
         if let Ok(cache) = GEMINI_KEY_CACHE.read() {
             if let Some(cached_value) = cache.as_ref() {
                 return Ok(cached_value.clone());
             }
         }
 
-        // Priority 3: Fetch from unified credentials
+        // Priority 2: Fetch from unified credentials
         let result = Self::fetch_gemini_key_from_unified()?;
 
         // Update cache
@@ -345,6 +407,107 @@ impl CredentialStore {
         Ok(Self::get_gemini_key()?.is_some())
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // AI Provider Keys
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Store the API key of an AI provider securely
+    ///
+    /// Gemini keys go through `store_gemini_key`; each provider has its own slot.
+    pub fn store_ai_key(provider: AiBackend, key: &str) -> Result<()> {
+        if provider == AiBackend::Gemini {
+            return Self::store_gemini_key(key);
+        }
+
+        let key_string = key.to_string();
+        Self::update_unified_credentials(|creds| {
+            *creds.ai_key_mut(provider) = Some(key_string.clone());
+        })?;
+
+        if let Ok(mut cache) = AI_KEY_CACHE.write() {
+            cache.insert(provider, Some(SecretString::from(key_string)));
+        }
+        Ok(())
+    }
+
+    /// Retrieve the API key of an AI provider
+    ///
+    /// Priority: environment variable > cache > unified credentials
+    pub fn get_ai_key(provider: AiBackend) -> Result<Option<SecretString>> {
+        if provider == AiBackend::Gemini {
+            return Self::get_gemini_key();
+        }
+
+        let env_name = match provider {
+            AiBackend::OpenAi => OPENAI_API_KEY_ENV,
+            _ => ANTHROPIC_API_KEY_ENV,
+        };
+        if let Ok(key) = std::env::var(env_name) {
+            if !key.is_empty() {
+                return Ok(Some(SecretString::from(key)));
+            }
+        }
+
+        Self::get_stored_ai_key(provider)
+    }
+
+    /// Retrieve the stored API key of an AI provider, ignoring the environment
+    ///
+    /// Priority: cache > unified credentials
+    pub fn get_stored_ai_key(provider: AiBackend) -> Result<Option<SecretString>> {
+        if provider == AiBackend::Gemini {
+            return Self::get_stored_gemini_key();
+        }
+
+        if let Ok(cache) = AI_KEY_CACHE.read() {
+            if let Some(cached_value) = cache.get(&provider) {
+                return Ok(cached_value.clone());
+            }
+        }
+
+        Self::migrate_to_unified_if_needed()?;
+        let result = Self::load_unified_credentials()?
+            .and_then(|mut creds| creds.ai_key_mut(provider).take())
+            .map(SecretString::from);
+
+        if let Ok(mut cache) = AI_KEY_CACHE.write() {
+            cache.insert(provider, result.clone());
+        }
+        Ok(result)
+    }
+
+    /// Delete the stored API key of an AI provider
+    pub fn delete_ai_key(provider: AiBackend) -> Result<()> {
+        if provider == AiBackend::Gemini {
+            return Self::delete_gemini_key();
+        }
+
+        Self::update_unified_credentials(|creds| {
+            *creds.ai_key_mut(provider) = None;
+        })?;
+
+        if let Ok(mut cache) = AI_KEY_CACHE.write() {
+            cache.insert(provider, None);
+        }
+        Ok(())
+    }
+
+    /// Check if an API key is available for an AI provider
+    pub fn has_ai_key(provider: AiBackend) -> Result<bool> {
+        Ok(Self::get_ai_key(provider)?.is_some())
+    }
+
+    /// Get the API key of an AI provider, returning an error if not configured
+    pub fn require_ai_key(provider: AiBackend) -> Result<SecretString> {
+        if provider == AiBackend::Gemini {
+            return Self::require_gemini_key();
+        }
+        Self::get_ai_key(provider)?.ok_or_else(|| GhrustError::AiNotConfigured {
+            provider: provider.display_name().to_string(),
+            key: provider.key_config_name().to_string(),
+        })
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Utility Methods
     // ─────────────────────────────────────────────────────────────────────────
@@ -373,8 +536,42 @@ impl CredentialStore {
     // Unified Credentials (Internal)
     // ─────────────────────────────────────────────────────────────────────────
 
-    /// Load unified credentials from keyring
+    /// Load unified credentials from keyring, or the fallback file if enabled
     fn load_unified_credentials() -> Result<Option<UnifiedCredentials>> {
+        match Self::load_unified_from_keyring() {
+            Err(e) if Self::file_fallback_enabled() => {
+                tracing::debug!("Keyring unavailable, reading credentials file: {}", e);
+                read_credentials_file(&Self::credentials_file_path()?)
+            }
+            result => result,
+        }
+    }
+
+    /// Save unified credentials to keyring, or the fallback file if enabled
+    fn save_unified_credentials(creds: &UnifiedCredentials) -> Result<()> {
+        match Self::save_unified_to_keyring(creds) {
+            Err(e) if Self::file_fallback_enabled() => {
+                tracing::debug!("Keyring unavailable, writing credentials file: {}", e);
+                write_credentials_file(&Self::credentials_file_path()?, creds)
+            }
+            result => result,
+        }
+    }
+
+    /// Whether the config allows storing credentials in a file
+    fn file_fallback_enabled() -> bool {
+        Config::load()
+            .map(|config| config.credential_file_fallback)
+            .unwrap_or(false)
+    }
+
+    /// Path of the fallback credentials file
+    fn credentials_file_path() -> Result<PathBuf> {
+        Ok(Config::config_dir()?.join(CREDENTIALS_FILE_NAME))
+    }
+
+    /// Load unified credentials from keyring
+    fn load_unified_from_keyring() -> Result<Option<UnifiedCredentials>> {
         let entry = Entry::new(SERVICE_NAME, UNIFIED_CREDENTIALS_KEY)?;
         match entry.get_password() {
             Ok(json) => {
@@ -392,7 +589,7 @@ impl CredentialStore {
     }
 
     /// Save unified credentials to keyring
-    fn save_unified_credentials(creds: &UnifiedCredentials) -> Result<()> {
+    fn save_unified_to_keyring(creds: &UnifiedCredentials) -> Result<()> {
         let json = serde_json::to_string(creds)
             .map_err(|e| GhrustError::Config(format!("Failed to serialize credentials: {}", e)))?;
         let entry = Entry::new(SERVICE_NAME, UNIFIED_CREDENTIALS_KEY)?;
@@ -425,8 +622,7 @@ impl CredentialStore {
         // Attempt to read legacy entries
         let mut unified = UnifiedCredentials {
             version: UNIFIED_CREDENTIALS_VERSION,
-            github_token_data: None,
-            gemini_api_key: None,
+            ..Default::default()
         };
 
         let mut has_legacy_data = false;
@@ -477,8 +673,7 @@ impl CredentialStore {
 
         let mut creds = Self::load_unified_credentials()?.unwrap_or(UnifiedCredentials {
             version: UNIFIED_CREDENTIALS_VERSION,
-            github_token_data: None,
-            gemini_api_key: None,
+            ..Default::default()
         });
 
         updater(&mut creds);
@@ -486,6 +681,56 @@ impl CredentialStore {
     }
 }
 
+/// Read unified credentials from a file (`None` if it doesn't exist)
+fn read_credentials_file(path: &Path) -> Result<Option<UnifiedCredentials>> {
+    let json = match std::fs::read_to_string(path) {
+        Ok(json) => json,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(e.into()),
+    };
+    let creds = serde_json::from_str(&json)
+        .map_err(|e| GhrustError::Config(format!("Invalid credentials file format: {}", e)))?;
+    Ok(Some(creds))
+}
+
+/// First GitHub token variable that `lookup` finds set to a non-empty value
+fn github_token_var_in(lookup: impl Fn(&str) -> Option<String>) -> Option<&'static str> {
+    GITHUB_TOKEN_ENVS
+        .iter()
+        .copied()
+        .find(|name| lookup(name).is_some_and(|token| !token.is_empty()))
+}
+
+/// Write unified credentials to a file only the current user can read
+fn write_credentials_file(path: &Path, creds: &UnifiedCredentials) -> Result<()> {
+    let json = serde_json::to_string(creds)
+        .map_err(|e| GhrustError::Config(format!("Failed to serialize credentials: {}", e)))?;
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+
+    let mut file = options.open(path)?;
+    // `mode` only applies on creation; tighten a file that already existed too
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        file.set_permissions(std::fs::Permissions::from_mode(0o600))?;
+    }
+
+    use std::io::Write;
+    file.write_all(json.as_bytes())?;
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -499,6 +744,72 @@ mod tests {
         assert_eq!(CredentialStore::mask_token(&long), "ghp_...cdef");
     }
 
+    #[test]
+    fn test_github_token_var_prefers_argo_variable() {
+        let env = |vars: &'static [(&'static str, &'static str)]| {
+            move |name: &str| {
+                vars.iter()
+                    .find(|(key, _)| *key == name)
+                    .map(|(_, value)| value.to_string())
+            }
+        };
+
+        let both = env(&[("GITHUB_TOKEN", "gh"), ("ARGO_GITHUB_TOKEN", "argo")]);
+        assert_eq!(github_token_var_in(both), Some("ARGO_GITHUB_TOKEN"));
+
+        let empty_argo = env(&[("GITHUB_TOKEN", "gh"), ("ARGO_GITHUB_TOKEN", "")]);
+        assert_eq!(github_token_var_in(empty_argo), Some("GITHUB_TOKEN"));
+
+        assert_eq!(github_token_var_in(env(&[])), None);
+    }
+
+    #[test]
+    fn test_credentials_file_round_trip() {
+        let path = std::env::temp_dir().join(format!(
+            "argo-rs-credentials-test-{}.json",
+            std::process::id()
+        ));
+        assert!(read_credentials_file(&path).unwrap().is_none());
+
+        let creds = UnifiedCredentials {
+            version: UNIFIED_CREDENTIALS_VERSION,
+            github_token_data: None,
+            gemini_api_key: Some("test-key".to_string()),
+            ..Default::default()
+        };
+        write_credentials_file(&path, &creds).unwrap();
+        let loaded = read_credentials_file(&path).unwrap().unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(loaded.gemini_api_key.as_deref(), Some("test-key"));
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_credentials_file_write_tightens_existing_permissions() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!(
+            "argo-rs-credentials-perms-test-{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, "{}").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        write_credentials_file(&path, &UnifiedCredentials::default()).unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(mode & 0o777, 0o600);
+    }
+
     #[test]
     fn test_unified_credentials_serialization_empty() {
         let creds = UnifiedCredentials::default();
@@ -522,6 +833,7 @@ mod tests {
             version: UNIFIED_CREDENTIALS_VERSION,
             github_token_data: None,
             gemini_api_key: Some("test-gemini-key".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&creds).unwrap();
@@ -551,6 +863,7 @@ mod tests {
             version: UNIFIED_CREDENTIALS_VERSION,
             github_token_data: Some(token_data),
             gemini_api_key: Some("gemini-key".to_string()),
+            ..Default::default()
         };
 
         let json = serde_json::to_string(&creds).unwrap();
@@ -14,9 +14,29 @@ use git2::{DiffOptions, Repository, Signature, StatusOptions};
 
 use crate::error::{GhrustError, Result};
 
+/// Maximum diff size kept in memory; larger diffs are cut at a line boundary
+pub const MAX_DIFF_BYTES: usize = 1024 * 1024;
+
+/// Diff output in patch format
+#[derive(Debug, Clone, Default)]
+pub struct DiffText {
+    /// Patch text
+    pub text: String,
+    /// Whether the diff was cut short at the size limit
+    pub truncated: bool,
+}
+
+impl DiffText {
+    /// Whether the diff has no content
+    pub fn is_empty(&self) -> bool {
+        self.text.is_empty()
+    }
+}
+
 /// Wrapper for local git repository operations
 pub struct GitRepository {
     repo: Repository,
+    dry_run: bool,
 }
 
 impl GitRepository {
@@ -28,7 +48,17 @@ impl GitRepository {
     /// Discover a git repository from the given path
     pub fn discover<P: AsRef<Path>>(path: P) -> Result<Self> {
         let repo = Repository::discover(path).map_err(|_| GhrustError::NotGitRepository)?;
-        Ok(Self { repo })
+        Ok(Self {
+            repo,
+            dry_run: false,
+        })
+    }
+
+    /// Report checkouts, branch and tag changes and pushes as [`GhrustError::DryRun`]
+    /// instead of running them; staging only updates the in-memory index
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 
     /// Check if the current directory is a git repository
@@ -78,6 +108,15 @@ impl GitRepository {
         self.remote_url("origin")
     }
 
+    /// Get the remote's default branch from `refs/remotes/origin/HEAD`, if known
+    pub fn default_branch(&self) -> Option<String> {
+        let reference = self.repo.find_reference("refs/remotes/origin/HEAD").ok()?;
+        let target = reference.symbolic_target()?;
+        target
+            .strip_prefix("refs/remotes/origin/")
+            .map(|name| name.to_string())
+    }
+
     /// List all local branch names
     pub fn local_branches(&self) -> Result<Vec<String>> {
         let branches = self.repo.branches(Some(git2::BranchType::Local))?;
@@ -117,7 +156,9 @@ impl GitRepository {
     }
 
     /// Get the diff of staged changes
-    pub fn staged_diff(&self) -> Result<String> {
+    ///
+    /// Output is capped at `MAX_DIFF_BYTES`; check `DiffText::truncated`.
+    pub fn staged_diff(&self) -> Result<DiffText> {
         let head = self.repo.head()?.peel_to_tree()?;
         let index = self.repo.index()?;
 
@@ -127,30 +168,20 @@ impl GitRepository {
             Some(&mut DiffOptions::new()),
         )?;
 
-        let mut diff_text = String::new();
-        diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
-            diff_text.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
-            true
-        })?;
-
-        Ok(diff_text)
+        diff_to_text(&diff, MAX_DIFF_BYTES)
     }
 
     /// Get the diff of all changes (staged + unstaged)
-    pub fn all_changes_diff(&self) -> Result<String> {
+    ///
+    /// Output is capped at `MAX_DIFF_BYTES`; check `DiffText::truncated`.
+    pub fn all_changes_diff(&self) -> Result<DiffText> {
         let head = self.repo.head()?.peel_to_tree()?;
 
         let diff = self
             .repo
             .diff_tree_to_workdir_with_index(Some(&head), Some(&mut DiffOptions::new()))?;
 
-        let mut diff_text = String::new();
-        diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
-            diff_text.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
-            true
-        })?;
-
-        Ok(diff_text)
+        diff_to_text(&diff, MAX_DIFF_BYTES)
     }
 
     /// Get the diff between two branches
@@ -170,13 +201,24 @@ impl GitRepository {
             Some(&mut DiffOptions::new()),
         )?;
 
-        let mut diff_text = String::new();
-        diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
-            diff_text.push_str(std::str::from_utf8(line.content()).unwrap_or(""));
-            true
-        })?;
+        Ok(diff_to_text(&diff, MAX_DIFF_BYTES)?.text)
+    }
+
+    /// Get the diff introduced by the HEAD commit
+    pub fn head_commit_diff(&self) -> Result<String> {
+        let head = self.repo.head()?.peel_to_commit()?;
+        let parent_tree = match head.parent(0) {
+            Ok(parent) => Some(parent.tree()?),
+            Err(_) => None,
+        };
 
-        Ok(diff_text)
+        let diff = self.repo.diff_tree_to_tree(
+            parent_tree.as_ref(),
+            Some(&head.tree()?),
+            Some(&mut DiffOptions::new()),
+        )?;
+
+        Ok(diff_to_text(&diff, MAX_DIFF_BYTES)?.text)
     }
 
     /// Resolve a branch name to a commit, trying multiple formats
@@ -225,6 +267,18 @@ impl GitRepository {
         Ok(messages)
     }
 
+    /// Count commits ahead of and behind base for head (base...head)
+    /// Returns (ahead, behind) counts, resolving branches like `get_commits_between`
+    pub fn commits_count_between(&self, base: &str, head: &str) -> Result<(usize, usize)> {
+        let base_commit = self.resolve_branch_to_commit(base)?;
+        let head_commit = self.resolve_branch_to_commit(head)?;
+
+        let (ahead, behind) = self
+            .repo
+            .graph_ahead_behind(head_commit.id(), base_commit.id())?;
+        Ok((ahead, behind))
+    }
+
     /// Get list of files with changes (for staging UI)
     pub fn changed_files(&self) -> Result<Vec<FileStatus>> {
         let mut opts = StatusOptions::new();
@@ -280,7 +334,7 @@ impl GitRepository {
             index.remove_path(path_obj)?;
         }
 
-        index.write()?;
+        self.write_index(&mut index)?;
         Ok(())
     }
 
@@ -292,11 +346,132 @@ impl GitRepository {
         Ok(())
     }
 
+    /// Get the diff of a single file: staged (HEAD to index) or unstaged (index to worktree)
+    ///
+    /// Untracked files show their full content as added lines. Output is
+    /// capped at `MAX_DIFF_BYTES`.
+    pub fn file_diff(&self, path: &str, staged: bool) -> Result<String> {
+        let diff = if staged {
+            self.staged_file_diff(path, false)?
+        } else {
+            let mut opts = DiffOptions::new();
+            opts.pathspec(path)
+                .disable_pathspec_match(true)
+                .include_untracked(true)
+                .recurse_untracked_dirs(true)
+                .show_untracked_content(true);
+            self.repo.diff_index_to_workdir(None, Some(&mut opts))?
+        };
+
+        Ok(diff_to_text(&diff, MAX_DIFF_BYTES)?.text)
+    }
+
+    /// List a file's hunks: staged ones (HEAD to index) first, then unstaged (index to worktree)
+    pub fn file_hunks(&self, path: &str) -> Result<Vec<FileHunk>> {
+        let mut hunks = collect_hunks(&self.staged_file_diff(path, false)?, true)?;
+        hunks.extend(collect_hunks(&self.unstaged_file_diff(path)?, false)?);
+        Ok(hunks)
+    }
+
+    /// Stage one unstaged hunk of a file (`hunk_index` counts unstaged hunks only)
+    pub fn stage_hunk(&self, path: &str, hunk_index: usize) -> Result<()> {
+        let diff = self.unstaged_file_diff(path)?;
+        self.apply_hunk_to_index(&diff, hunk_index)
+    }
+
+    /// Unstage one staged hunk of a file (`hunk_index` counts staged hunks only)
+    pub fn unstage_hunk(&self, path: &str, hunk_index: usize) -> Result<()> {
+        // The reversed diff turns the staged change back into the HEAD version
+        let diff = self.staged_file_diff(path, true)?;
+        self.apply_hunk_to_index(&diff, hunk_index)
+    }
+
+    /// Diff of a file's unstaged changes (index to worktree)
+    fn unstaged_file_diff(&self, path: &str) -> Result<git2::Diff<'_>> {
+        let mut opts = DiffOptions::new();
+        opts.pathspec(path).disable_pathspec_match(true);
+        Ok(self.repo.diff_index_to_workdir(None, Some(&mut opts))?)
+    }
+
+    /// Diff of a file's staged changes (HEAD to index, or back when `reverse`)
+    fn staged_file_diff(&self, path: &str, reverse: bool) -> Result<git2::Diff<'_>> {
+        // Before the first commit everything in the index is staged
+        let head = match self.repo.head() {
+            Ok(head) => Some(head.peel_to_tree()?),
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => None,
+            Err(e) => return Err(e.into()),
+        };
+
+        let mut opts = DiffOptions::new();
+        opts.pathspec(path)
+            .disable_pathspec_match(true)
+            .reverse(reverse);
+        Ok(self
+            .repo
+            .diff_tree_to_index(head.as_ref(), None, Some(&mut opts))?)
+    }
+
+    /// Apply only the `hunk_index`-th hunk of a diff to the index
+    fn apply_hunk_to_index(&self, diff: &git2::Diff<'_>, hunk_index: usize) -> Result<()> {
+        let hunk_count = collect_hunks(diff, false)?.len();
+        if hunk_index >= hunk_count {
+            return Err(GhrustError::InvalidInput(format!(
+                "Hunk {} not found (the file has {} hunks)",
+                hunk_index + 1,
+                hunk_count
+            )));
+        }
+
+        let mut seen = 0;
+        let mut opts = git2::ApplyOptions::new();
+        opts.hunk_callback(|hunk| {
+            let keep = hunk.is_some() && seen == hunk_index;
+            seen += 1;
+            keep
+        });
+
+        self.repo
+            .apply(diff, git2::ApplyLocation::Index, Some(&mut opts))?;
+        Ok(())
+    }
+
+    /// Discard a file's unstaged changes
+    ///
+    /// Tracked files are restored from the index, so staged changes are kept.
+    /// Untracked files are deleted from disk.
+    pub fn discard_file(&self, path: &str) -> Result<()> {
+        let path_obj = Path::new(path);
+        let status = self.repo.status_file(path_obj)?;
+
+        if status.contains(git2::Status::WT_NEW) {
+            let full_path = self.root_dir()?.join(path_obj);
+            std::fs::remove_file(full_path)?;
+            return Ok(());
+        }
+
+        let mut checkout = git2::build::CheckoutBuilder::new();
+        checkout.force().update_index(false).path(path_obj);
+        self.repo.checkout_index(None, Some(&mut checkout))?;
+        Ok(())
+    }
+
+    /// Save staged changes to disk
+    ///
+    /// In a dry run the index is left unwritten: the staged entries only live
+    /// in this repository's in-memory index, so later status and diff calls
+    /// on the same [`GitRepository`] preview them without touching disk.
+    fn write_index(&self, index: &mut git2::Index) -> Result<()> {
+        if !self.dry_run {
+            index.write()?;
+        }
+        Ok(())
+    }
+
     /// Stage all modified files
     pub fn stage_all(&self) -> Result<()> {
         let mut index = self.repo.index()?;
         index.add_all(["*"].iter(), git2::IndexAddOption::DEFAULT, None)?;
-        index.write()?;
+        self.write_index(&mut index)?;
         Ok(())
     }
 
@@ -306,7 +481,7 @@ impl GitRepository {
         // Use glob pattern to match all files under the directory
         let pattern = format!("{}/*", dir.display());
         index.add_all([&pattern].iter(), git2::IndexAddOption::DEFAULT, None)?;
-        index.write()?;
+        self.write_index(&mut index)?;
         Ok(())
     }
 
@@ -324,7 +499,7 @@ impl GitRepository {
                 index.remove_path(path)?;
             }
         }
-        index.write()?;
+        self.write_index(&mut index)?;
         Ok(())
     }
 
@@ -338,8 +513,43 @@ impl GitRepository {
         Ok(())
     }
 
+    /// Paths with unresolved merge conflicts in the index
+    pub fn conflicted_files(&self) -> Result<Vec<String>> {
+        let index = self.repo.index()?;
+        if !index.has_conflicts() {
+            return Ok(Vec::new());
+        }
+
+        let mut files = Vec::new();
+        for conflict in index.conflicts()? {
+            let conflict = conflict?;
+            // Any side may be missing (e.g. deleted on one branch)
+            let entry = conflict.our.or(conflict.their).or(conflict.ancestor);
+            if let Some(entry) = entry {
+                let path = String::from_utf8_lossy(&entry.path).into_owned();
+                if !files.contains(&path) {
+                    files.push(path);
+                }
+            }
+        }
+        files.sort();
+        Ok(files)
+    }
+
     /// Create a commit with the staged changes
     pub fn commit(&self, message: &str) -> Result<String> {
+        let conflicts = self.conflicted_files()?;
+        if !conflicts.is_empty() {
+            return Err(GhrustError::UnresolvedConflicts(conflicts));
+        }
+
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "commit the staged changes as \"{}\"",
+                message.lines().next().unwrap_or_default()
+            )));
+        }
+
         let mut index = self.repo.index()?;
         let tree_id = index.write_tree()?;
         let tree = self.repo.find_tree(tree_id)?;
@@ -364,6 +574,88 @@ impl GitRepository {
         Ok(commit_id.to_string())
     }
 
+    /// Replace the HEAD commit with one containing the staged changes
+    ///
+    /// The new commit keeps HEAD's parents and author. `None` keeps the
+    /// existing message.
+    pub fn commit_amend(&self, message: Option<&str>) -> Result<String> {
+        let conflicts = self.conflicted_files()?;
+        if !conflicts.is_empty() {
+            return Err(GhrustError::UnresolvedConflicts(conflicts));
+        }
+
+        let head = self.head_commit()?;
+
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "amend commit {}",
+                &head.id().to_string()[..7]
+            )));
+        }
+
+        let mut index = self.repo.index()?;
+        let tree_id = index.write_tree()?;
+        let tree = self.repo.find_tree(tree_id)?;
+
+        let signature = self.repo.signature().or_else(|_| {
+            // Fallback signature if not configured
+            Signature::now("ghrust", "ghrust@localhost")
+        })?;
+
+        let commit_id = head.amend(
+            Some("HEAD"),
+            None,
+            Some(&signature),
+            None,
+            message,
+            Some(&tree),
+        )?;
+
+        Ok(commit_id.to_string())
+    }
+
+    /// Get the full message of the HEAD commit
+    pub fn head_commit_message(&self) -> Result<String> {
+        let head = self.head_commit()?;
+        Ok(head.message().unwrap_or_default().to_string())
+    }
+
+    /// HEAD commit, with a clear error when the branch has no commits yet
+    fn head_commit(&self) -> Result<git2::Commit<'_>> {
+        match self.repo.head() {
+            Ok(head) => Ok(head.peel_to_commit()?),
+            Err(e) if e.code() == git2::ErrorCode::UnbornBranch => Err(GhrustError::InvalidInput(
+                "The current branch has no commits yet".to_string(),
+            )),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Read the commit message template configured via `commit.template`
+    ///
+    /// `~/` is expanded and relative paths are resolved against the repository
+    /// root. Returns `None` if no template is configured or the file is missing.
+    pub fn commit_template(&self) -> Result<Option<String>> {
+        let config = self.repo.config()?;
+        let path = match config.get_path("commit.template") {
+            Ok(path) => path,
+            Err(e) if e.code() == git2::ErrorCode::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+
+        let path = if path.is_relative() {
+            self.root_dir()?.join(path)
+        } else {
+            path
+        };
+
+        match std::fs::read_to_string(&path) {
+            Ok(template) => Ok(Some(template)),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Get the repository root directory
     pub fn root_dir(&self) -> Result<std::path::PathBuf> {
         self.repo
@@ -384,6 +676,15 @@ impl GitRepository {
 
     /// Push a specific branch to a remote using system git
     pub fn push_branch(&self, branch: &str, remote_name: &str, force: bool) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "{}push '{}' to {}",
+                if force { "force-" } else { "" },
+                branch,
+                remote_name
+            )));
+        }
+
         let mut cmd = Command::new("git");
         cmd.arg("push").arg(remote_name).arg(branch);
 
@@ -461,8 +762,20 @@ impl GitRepository {
         // Parse upstream (e.g., "origin/main" -> remote="origin", branch="main")
         let (remote, _remote_branch) = upstream.split_once('/').unwrap_or(("origin", &branch));
 
+        self.push_with_upstream(&branch, remote)
+    }
+
+    /// Push a branch and set it to track the remote branch using git push -u
+    pub fn push_with_upstream(&self, branch: &str, remote: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "push '{}' to {} and track it as upstream",
+                branch, remote
+            )));
+        }
+
         let output = Command::new("git")
-            .args(["push", "-u", remote, &branch])
+            .args(["push", "-u", remote, branch])
             .output()
             .map_err(|e| GhrustError::Custom(format!("Failed to execute git push -u: {}", e)))?;
 
@@ -479,6 +792,13 @@ impl GitRepository {
 
     /// Checkout a local branch
     pub fn checkout(&self, branch_name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "check out branch '{}'",
+                branch_name
+            )));
+        }
+
         let output = Command::new("git")
             .args(["checkout", branch_name])
             .output()
@@ -495,8 +815,21 @@ impl GitRepository {
         Ok(())
     }
 
+    /// Get the summary line of the HEAD commit
+    pub fn head_commit_summary(&self) -> Result<Option<String>> {
+        let head = self.repo.head()?.peel_to_commit()?;
+        Ok(head.summary().map(|s| s.to_string()))
+    }
+
     /// Create a new branch from current HEAD and switch to it
     pub fn create_branch(&self, branch_name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "create branch '{}' and switch to it",
+                branch_name
+            )));
+        }
+
         let output = Command::new("git")
             .args(["checkout", "-b", branch_name])
             .output()
@@ -521,6 +854,13 @@ impl GitRepository {
 
     /// Create a lightweight tag at HEAD
     pub fn create_tag(&self, name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "create lightweight tag '{}' at HEAD",
+                name
+            )));
+        }
+
         let head = self.repo.head()?.peel_to_commit()?;
         self.repo.tag_lightweight(name, head.as_object(), false)?;
         Ok(())
@@ -528,6 +868,13 @@ impl GitRepository {
 
     /// Create an annotated tag at HEAD
     pub fn create_annotated_tag(&self, name: &str, message: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "create annotated tag '{}' at HEAD",
+                name
+            )));
+        }
+
         let head = self.repo.head()?.peel_to_commit()?;
         let signature = self
             .repo
@@ -541,6 +888,10 @@ impl GitRepository {
 
     /// Push all tags to origin using system git
     pub fn push_tags(&self) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun("push all tags to origin".to_string()));
+        }
+
         let output = Command::new("git")
             .args(["push", "--tags"])
             .output()
@@ -561,6 +912,13 @@ impl GitRepository {
 
     /// Push a specific tag to origin using system git
     pub fn push_tag(&self, tag_name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "push tag '{}' to origin",
+                tag_name
+            )));
+        }
+
         let output = Command::new("git")
             .args(["push", "origin", tag_name])
             .output()
@@ -620,6 +978,158 @@ impl GitRepository {
         Ok(tags)
     }
 
+    /// Commit a local tag points to, peeling annotated tags
+    pub fn tag_target_summary(&self, name: &str) -> Result<TagTarget> {
+        let commit = self
+            .repo
+            .revparse_single(&format!("refs/tags/{}", name))
+            .map_err(|_| GhrustError::TagNotFound(name.to_string()))?
+            .peel_to_commit()
+            .map_err(|e| {
+                GhrustError::Custom(format!("Cannot get commit for tag '{}': {}", name, e))
+            })?;
+
+        let author = commit.author().name().unwrap_or("").to_string();
+        Ok(TagTarget {
+            sha: commit.id().to_string(),
+            subject: commit.summary().unwrap_or("").to_string(),
+            author,
+        })
+    }
+
+    /// Highest local tag that parses as a semantic version (`1.2.3` or `v1.2.3`)
+    pub fn latest_version_tag(&self) -> Result<Option<String>> {
+        let latest = self
+            .list_tags()?
+            .into_iter()
+            .filter_map(|tag| parse_version_tag(&tag.name).map(|version| (version, tag.name)))
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .map(|(_, name)| name);
+
+        Ok(latest)
+    }
+
+    /// Delete a local branch with git2
+    ///
+    /// Refuses the checked-out branch, and branches whose commits are not in
+    /// HEAD or their upstream unless `force` is set.
+    pub fn delete_local_branch(&self, name: &str, force: bool) -> Result<()> {
+        if self.current_branch().is_ok_and(|current| current == name) {
+            return Err(GhrustError::InvalidInput(format!(
+                "Cannot delete '{}': it is your current branch",
+                name
+            )));
+        }
+
+        let mut branch = self
+            .repo
+            .find_branch(name, git2::BranchType::Local)
+            .map_err(|e| {
+                if e.code() == git2::ErrorCode::NotFound {
+                    GhrustError::BranchNotFound(name.to_string())
+                } else {
+                    e.into()
+                }
+            })?;
+
+        if !force && !self.is_branch_merged(&branch)? {
+            return Err(GhrustError::InvalidInput(format!(
+                "Branch '{}' is not fully merged; deleting it would lose commits\n\n  → Pass --force to delete it anyway.",
+                name
+            )));
+        }
+
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "delete local branch '{}'",
+                name
+            )));
+        }
+
+        branch.delete()?;
+        Ok(())
+    }
+
+    /// Whether a branch's tip is contained in HEAD or in its upstream, like `git branch -d`
+    fn is_branch_merged(&self, branch: &git2::Branch<'_>) -> Result<bool> {
+        let Some(tip) = branch.get().target() else {
+            return Ok(true);
+        };
+
+        let mut bases = Vec::new();
+        if let Some(head) = self.repo.head().ok().and_then(|h| h.target()) {
+            bases.push(head);
+        }
+        if let Some(upstream) = branch.upstream().ok().and_then(|u| u.get().target()) {
+            bases.push(upstream);
+        }
+
+        for base in bases {
+            if base == tip || self.repo.graph_descendant_of(base, tip)? {
+                return Ok(true);
+            }
+        }
+        Ok(false)
+    }
+
+    /// Check if origin has a branch, according to the last fetch
+    pub fn remote_branch_exists(&self, name: &str) -> bool {
+        self.repo
+            .find_reference(&format!("refs/remotes/origin/{}", name))
+            .is_ok()
+    }
+
+    /// Delete a branch from origin using system git
+    pub fn delete_remote_branch(&self, name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "delete branch '{}' from origin",
+                name
+            )));
+        }
+
+        let output = Command::new("git")
+            .args(["push", "origin", "--delete", name])
+            .output()
+            .map_err(|e| {
+                GhrustError::Custom(format!("Failed to execute git push --delete: {}", e))
+            })?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GhrustError::Custom(format!(
+                "Delete remote branch failed: {}",
+                stderr.trim()
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Check if a local branch exists
+    pub fn branch_exists(&self, name: &str) -> bool {
+        self.repo.find_branch(name, git2::BranchType::Local).is_ok()
+    }
+
+    /// Fetch `remote_ref` from a remote name or URL into a new local branch
+    pub fn fetch_into_branch(&self, remote: &str, remote_ref: &str, branch: &str) -> Result<()> {
+        let refspec = format!("{}:refs/heads/{}", remote_ref, branch);
+        let output = Command::new("git")
+            .args(["fetch", remote, &refspec])
+            .output()
+            .map_err(|e| GhrustError::Custom(format!("Failed to execute git fetch: {}", e)))?;
+
+        if !output.status.success() {
+            let stderr = String::from_utf8_lossy(&output.stderr);
+            return Err(GhrustError::Custom(format!(
+                "Fetch failed: {}",
+                stderr.trim()
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Check if a tag exists locally
     pub fn tag_exists(&self, name: &str) -> Result<bool> {
         let refname = format!("refs/tags/{}", name);
@@ -628,6 +1138,10 @@ impl GitRepository {
 
     /// Delete a local tag
     pub fn delete_tag(&self, name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!("delete local tag '{}'", name)));
+        }
+
         self.repo.tag_delete(name).map_err(|e| {
             if e.code() == git2::ErrorCode::NotFound {
                 GhrustError::TagNotFound(name.to_string())
@@ -639,6 +1153,13 @@ impl GitRepository {
 
     /// Delete a tag from remote using system git
     pub fn delete_remote_tag(&self, tag_name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "delete tag '{}' from origin",
+                tag_name
+            )));
+        }
+
         let output = Command::new("git")
             .args(["push", "origin", "--delete", tag_name])
             .output()
@@ -658,6 +1179,126 @@ impl GitRepository {
     }
 }
 
+/// One hunk of a file's diff
+#[derive(Debug, Clone)]
+pub struct FileHunk {
+    /// The `@@ -a,b +c,d @@` header line
+    pub header: String,
+    /// Hunk body lines, each prefixed with `+`, `-` or a space
+    pub lines: Vec<String>,
+    /// Whether the hunk is staged (HEAD to index) rather than unstaged
+    pub staged: bool,
+    /// Position among the file's staged or unstaged hunks, for staging calls
+    pub index: usize,
+}
+
+/// Split a diff into hunks with their lines
+fn collect_hunks(diff: &git2::Diff<'_>, staged: bool) -> Result<Vec<FileHunk>> {
+    let mut hunks: Vec<FileHunk> = Vec::new();
+
+    diff.print(git2::DiffFormat::Patch, |_delta, hunk, line| {
+        let Some(hunk) = hunk else {
+            return true; // File header lines
+        };
+        let header = String::from_utf8_lossy(hunk.header())
+            .trim_end()
+            .to_string();
+
+        // Hunk header lines arrive first; each starts a new hunk
+        if line.origin() == 'H' {
+            let index = hunks.len();
+            hunks.push(FileHunk {
+                header,
+                lines: Vec::new(),
+                staged,
+                index,
+            });
+            return true;
+        }
+
+        if let Some(current) = hunks.last_mut() {
+            let content = String::from_utf8_lossy(line.content());
+            let content = content.trim_end_matches(['\n', '\r']);
+            match line.origin() {
+                origin @ ('+' | '-' | ' ') => current.lines.push(format!("{}{}", origin, content)),
+                // "\ No newline at end of file" markers
+                _ => current.lines.push(content.to_string()),
+            }
+        }
+        true
+    })?;
+
+    Ok(hunks)
+}
+
+/// Version component to increment when suggesting the next tag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum VersionBump {
+    #[default]
+    Patch,
+    Minor,
+    Major,
+}
+
+impl VersionBump {
+    /// Next bump level in the patch -> minor -> major cycle
+    pub fn next(self) -> Self {
+        match self {
+            VersionBump::Patch => VersionBump::Minor,
+            VersionBump::Minor => VersionBump::Major,
+            VersionBump::Major => VersionBump::Patch,
+        }
+    }
+
+    /// Previous bump level in the cycle
+    pub fn previous(self) -> Self {
+        self.next().next()
+    }
+
+    /// Display label
+    pub fn label(self) -> &'static str {
+        match self {
+            VersionBump::Patch => "patch",
+            VersionBump::Minor => "minor",
+            VersionBump::Major => "major",
+        }
+    }
+}
+
+/// Parse a tag name as a semantic version, allowing a leading `v`
+pub fn parse_version_tag(name: &str) -> Option<semver::Version> {
+    semver::Version::parse(name.strip_prefix('v').unwrap_or(name)).ok()
+}
+
+/// Suggest the next tag name by bumping `latest`, keeping its `v` prefix
+///
+/// A prerelease is released rather than bumped when it already is a
+/// prerelease of the requested kind: `v1.2.3-rc.1` becomes `v1.2.3` for a
+/// patch bump and `v1.3.0-rc.1` becomes `v1.3.0` for a minor one. Falls back
+/// to `v0.1.0` when there is no semver tag to bump.
+pub fn suggest_version_tag(latest: Option<&str>, bump: VersionBump) -> String {
+    let Some((name, version)) = latest.and_then(|name| Some((name, parse_version_tag(name)?)))
+    else {
+        return "v0.1.0".to_string();
+    };
+
+    let (major, minor, patch) = (version.major, version.minor, version.patch);
+    let release = !version.pre.is_empty();
+    let next = match bump {
+        VersionBump::Major if release && minor == 0 && patch == 0 => {
+            semver::Version::new(major, 0, 0)
+        }
+        VersionBump::Major => semver::Version::new(major + 1, 0, 0),
+        VersionBump::Minor if release && patch == 0 => semver::Version::new(major, minor, 0),
+        VersionBump::Minor => semver::Version::new(major, minor + 1, 0),
+        VersionBump::Patch if release => semver::Version::new(major, minor, patch),
+        VersionBump::Patch => semver::Version::new(major, minor, patch + 1),
+    };
+
+    let prefix = if name.starts_with('v') { "v" } else { "" };
+    format!("{}{}", prefix, next)
+}
+
 /// Information about a local tag
 #[derive(Debug, Clone)]
 pub struct LocalTagInfo {
@@ -671,6 +1312,17 @@ pub struct LocalTagInfo {
     pub message: Option<String>,
 }
 
+/// Commit a tag points to
+#[derive(Debug, Clone)]
+pub struct TagTarget {
+    /// Full commit SHA
+    pub sha: String,
+    /// First line of the commit message
+    pub subject: String,
+    /// Commit author name
+    pub author: String,
+}
+
 /// Status of a file in the working directory
 #[derive(Debug, Clone)]
 pub struct FileStatus {
@@ -708,4 +1360,315 @@ impl FileStatus {
             ' '
         }
     }
+
+    /// Get the directory containing this file ("." for the repository root)
+    pub fn directory(&self) -> String {
+        std::path::Path::new(&self.path)
+            .parent()
+            .map(|p| p.to_string_lossy().to_string())
+            .filter(|s| !s.is_empty())
+            .unwrap_or_else(|| ".".to_string())
+    }
+}
+
+/// Directories of the staged files, sorted and without duplicates
+///
+/// Used as conventional-commit scope candidates; the repository root is left
+/// out since it says nothing about the area of the change.
+pub fn staged_directories<'a>(files: impl IntoIterator<Item = &'a FileStatus>) -> Vec<String> {
+    let directories: std::collections::BTreeSet<String> = files
+        .into_iter()
+        .filter(|f| f.is_staged)
+        .map(FileStatus::directory)
+        .filter(|dir| dir != ".")
+        .collect();
+    directories.into_iter().collect()
+}
+
+/// Render a diff as patch text, stopping once `max_bytes` would be exceeded
+fn diff_to_text(diff: &git2::Diff<'_>, max_bytes: usize) -> Result<DiffText> {
+    let mut output = DiffText::default();
+
+    let result = diff.print(git2::DiffFormat::Patch, |_delta, _hunk, line| {
+        let content = std::str::from_utf8(line.content()).unwrap_or("");
+        // Content lines carry their +/-/space marker in `origin`, not in the text
+        let origin = match line.origin() {
+            c @ ('+' | '-' | ' ') => Some(c),
+            _ => None,
+        };
+
+        let len = content.len() + usize::from(origin.is_some());
+        if output.text.len() + len > max_bytes {
+            // Returning false aborts the print early
+            output.truncated = true;
+            return false;
+        }
+
+        if let Some(c) = origin {
+            output.text.push(c);
+        }
+        output.text.push_str(content);
+        true
+    });
+
+    match result {
+        Ok(()) => Ok(output),
+        Err(_) if output.truncated => Ok(output),
+        Err(e) => Err(e.into()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::DryRunPlan;
+
+    /// Fresh directory under the system temp dir
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let nanos = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_nanos();
+        let dir = std::env::temp_dir().join(format!(
+            "argo-git-{}-{}-{}",
+            name,
+            std::process::id(),
+            nanos
+        ));
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Repository with one commit, a `v1.0.0` tag and a bare `origin`
+    fn repo_with_origin() -> (std::path::PathBuf, std::path::PathBuf) {
+        let work = temp_dir("work");
+        let origin = temp_dir("origin");
+        Repository::init_bare(&origin).unwrap();
+
+        let repo = Repository::init(&work).unwrap();
+        std::fs::write(work.join("README.md"), "hello\n").unwrap();
+        let mut index = repo.index().unwrap();
+        index.add_path(Path::new("README.md")).unwrap();
+        index.write().unwrap();
+        let tree = repo.find_tree(index.write_tree().unwrap()).unwrap();
+        let signature = Signature::now("test", "test@localhost").unwrap();
+        let commit = repo
+            .commit(Some("HEAD"), &signature, &signature, "init", &tree, &[])
+            .unwrap();
+        repo.tag_lightweight("v1.0.0", &repo.find_object(commit, None).unwrap(), false)
+            .unwrap();
+        repo.branch("feature", &repo.find_commit(commit).unwrap(), false)
+            .unwrap();
+        repo.remote("origin", origin.to_str().unwrap()).unwrap();
+
+        (work, origin)
+    }
+
+    /// Every reference and its target, plus HEAD
+    fn refs(path: &Path) -> Vec<(String, String)> {
+        let repo = Repository::open(path).unwrap();
+        let mut refs: Vec<(String, String)> = repo
+            .references()
+            .unwrap()
+            .flatten()
+            .map(|r| {
+                let target = r
+                    .target()
+                    .map(|oid| oid.to_string())
+                    .or_else(|| r.symbolic_target().map(str::to_string))
+                    .unwrap_or_default();
+                (r.name().unwrap_or_default().to_string(), target)
+            })
+            .collect();
+        if let Ok(head) = repo.head() {
+            refs.push((
+                "HEAD".to_string(),
+                head.name().unwrap_or_default().to_string(),
+            ));
+        }
+        refs.sort();
+        refs
+    }
+
+    #[test]
+    fn test_dry_run_changes_no_refs() {
+        let (work, origin) = repo_with_origin();
+        let local_before = refs(&work);
+        let remote_before = refs(&origin);
+
+        let git = GitRepository::discover(&work).unwrap().with_dry_run(true);
+        let results = [
+            git.commit("message").map(|_| ()),
+            git.commit_amend(None).map(|_| ()),
+            git.create_tag("v2.0.0"),
+            git.create_annotated_tag("v2.0.0", "release"),
+            git.create_branch("new-branch"),
+            git.checkout("feature"),
+            git.push(false),
+            git.push_branch("feature", "origin", true),
+            git.set_upstream("origin/feature"),
+            git.push_with_upstream("feature", "origin"),
+            git.push_tag("v1.0.0"),
+            git.push_tags(),
+            git.delete_tag("v1.0.0"),
+            git.delete_remote_tag("v1.0.0"),
+            git.delete_local_branch("feature", false),
+            git.delete_remote_branch("feature"),
+        ];
+        for (i, result) in results.into_iter().enumerate() {
+            assert!(
+                matches!(result, Err(GhrustError::DryRun(_))),
+                "step {} was not skipped: {:?}",
+                i,
+                result
+            );
+        }
+
+        assert_eq!(refs(&work), local_before);
+        assert_eq!(refs(&origin), remote_before);
+
+        let _ = std::fs::remove_dir_all(&work);
+        let _ = std::fs::remove_dir_all(&origin);
+    }
+
+    #[test]
+    fn test_delete_local_branch_refuses_unmerged() {
+        let (work, origin) = repo_with_origin();
+        {
+            // Give `topic` a commit that HEAD does not have
+            let repo = Repository::open(&work).unwrap();
+            let head = repo.head().unwrap().peel_to_commit().unwrap();
+            let signature = Signature::now("test", "test@localhost").unwrap();
+            let tree = head.tree().unwrap();
+            repo.branch("topic", &head, false).unwrap();
+            repo.commit(
+                Some("refs/heads/topic"),
+                &signature,
+                &signature,
+                "topic work",
+                &tree,
+                &[&head],
+            )
+            .unwrap();
+        }
+
+        let git = GitRepository::discover(&work).unwrap();
+        assert!(matches!(
+            git.delete_local_branch("topic", false),
+            Err(GhrustError::InvalidInput(_))
+        ));
+        assert!(git.branch_exists("topic"));
+
+        // Merged branches go without --force; dry-run still refuses unmerged ones first
+        let dry = GitRepository::discover(&work).unwrap().with_dry_run(true);
+        assert!(matches!(
+            dry.delete_local_branch("topic", false),
+            Err(GhrustError::InvalidInput(_))
+        ));
+        git.delete_local_branch("feature", false).unwrap();
+        git.delete_local_branch("topic", true).unwrap();
+        assert!(!git.branch_exists("feature"));
+        assert!(!git.branch_exists("topic"));
+
+        let _ = std::fs::remove_dir_all(&work);
+        let _ = std::fs::remove_dir_all(&origin);
+    }
+
+    #[test]
+    fn test_dry_run_plan_lists_every_action() {
+        let (work, origin) = repo_with_origin();
+        let git = GitRepository::discover(&work).unwrap().with_dry_run(true);
+
+        let mut plan = DryRunPlan::default();
+        assert!(!plan.step(git.create_tag("v2.0.0")).unwrap());
+        assert!(!plan.step(git.push_tag("v2.0.0")).unwrap());
+        match plan.finish() {
+            Err(GhrustError::DryRun(action)) => assert_eq!(
+                action,
+                "create lightweight tag 'v2.0.0' at HEAD, then push tag 'v2.0.0' to origin"
+            ),
+            other => panic!("expected a dry run, got {:?}", other),
+        }
+        assert!(!git.tag_exists("v2.0.0").unwrap());
+        assert!(DryRunPlan::default().finish().is_ok());
+
+        let _ = std::fs::remove_dir_all(&work);
+        let _ = std::fs::remove_dir_all(&origin);
+    }
+
+    #[test]
+    fn test_dry_run_stages_in_memory_only() {
+        let (work, origin) = repo_with_origin();
+        std::fs::write(work.join("README.md"), "hello\nworld\n").unwrap();
+        std::fs::write(work.join("NEW.md"), "new\n").unwrap();
+
+        let dry = GitRepository::discover(&work).unwrap().with_dry_run(true);
+        dry.stage_all().unwrap();
+        let staged: Vec<String> = dry
+            .changed_files()
+            .unwrap()
+            .into_iter()
+            .filter(|f| f.is_staged)
+            .map(|f| f.path)
+            .collect();
+        assert_eq!(staged, vec!["NEW.md".to_string(), "README.md".to_string()]);
+        assert!(dry.staged_diff().unwrap().text.contains("+world"));
+
+        // The on-disk index is untouched
+        let fresh = GitRepository::discover(&work).unwrap();
+        assert!(fresh.staged_diff().unwrap().is_empty());
+
+        let _ = std::fs::remove_dir_all(&work);
+        let _ = std::fs::remove_dir_all(&origin);
+    }
+
+    #[test]
+    fn test_parse_version_tag() {
+        let cases = [
+            ("v1.2.3", Some((1, 2, 3, ""))),
+            ("1.2.3", Some((1, 2, 3, ""))),
+            ("v1.2.3-rc.1", Some((1, 2, 3, "rc.1"))),
+            ("v1.2", None),
+            ("release-1.2.3", None),
+            ("", None),
+        ];
+        for (name, expected) in cases {
+            let parsed = parse_version_tag(name)
+                .map(|v| (v.major, v.minor, v.patch, v.pre.as_str().to_string()));
+            let expected =
+                expected.map(|(major, minor, patch, pre)| (major, minor, patch, pre.to_string()));
+            assert_eq!(parsed, expected, "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_suggest_version_tag() {
+        use VersionBump::{Major, Minor, Patch};
+
+        let cases = [
+            (None, Patch, "v0.1.0"),
+            (None, Major, "v0.1.0"),
+            (Some("nightly"), Minor, "v0.1.0"),
+            (Some("v1.2.3"), Patch, "v1.2.4"),
+            (Some("v1.2.3"), Minor, "v1.3.0"),
+            (Some("v1.2.3"), Major, "v2.0.0"),
+            (Some("1.2.3"), Patch, "1.2.4"),
+            (Some("1.2.3"), Major, "2.0.0"),
+            (Some("v1.2.3-rc.1"), Patch, "v1.2.3"),
+            (Some("v1.2.3-rc.1"), Minor, "v1.3.0"),
+            (Some("v1.2.3-rc.1"), Major, "v2.0.0"),
+            (Some("v1.3.0-beta"), Minor, "v1.3.0"),
+            (Some("v2.0.0-alpha.2"), Major, "v2.0.0"),
+            (Some("v2.0.0-alpha.2"), Patch, "v2.0.0"),
+        ];
+        for (latest, bump, expected) in cases {
+            assert_eq!(
+                suggest_version_tag(latest, bump),
+                expected,
+                "{:?} {:?}",
+                latest,
+                bump
+            );
+        }
+    }
 }
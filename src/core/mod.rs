@@ -2,12 +2,16 @@
 //!
 //! This module contains shared business logic including:
 //! - Git repository operations
+//! - System clipboard access
+//! - Branch name suggestion and validation
 //! - Repository context detection
 //! - Credential management
 //! - Token lifecycle management
 //! - Application configuration
 //! - Auto-update functionality
 
+pub mod branch_name;
+pub mod clipboard;
 pub mod config;
 pub mod credentials;
 pub mod git;
@@ -25,19 +25,37 @@ impl RepositoryContext {
     /// Detect repository context from the current directory
     pub fn detect() -> Result<Self> {
         let git_repo = GitRepository::open_current_dir()?;
-        let remote_url = git_repo.origin_url()?;
+        // A missing `origin` is reported the same as a non-GitHub one
+        let remote_url = git_repo
+            .origin_url()
+            .map_err(|_| GhrustError::NoGitHubRemote)?;
         let (owner, name) = parse_github_url(&remote_url)?;
         let current_branch = git_repo.current_branch()?;
+        // Best guess from origin/HEAD; may be updated from the GitHub API
+        let default_branch = git_repo
+            .default_branch()
+            .unwrap_or_else(|| "main".to_string());
 
         Ok(Self {
             owner,
             name,
             current_branch,
-            // Will be updated when we fetch from GitHub API
-            default_branch: "main".to_string(),
+            default_branch,
         })
     }
 
+    /// Detect repository context, returning `None` if `origin` is not on GitHub
+    ///
+    /// Used by commands that work on the local repository and only use
+    /// GitHub when it is available.
+    pub fn detect_optional() -> Result<Option<Self>> {
+        match Self::detect() {
+            Ok(ctx) => Ok(Some(ctx)),
+            Err(GhrustError::NoGitHubRemote | GhrustError::InvalidGitHubUrl(_)) => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+
     /// Get the full repository name (owner/name)
     pub fn full_name(&self) -> String {
         format!("{}/{}", self.owner, self.name)
@@ -48,6 +66,11 @@ impl RepositoryContext {
         format!("https://github.com/{}/{}", self.owner, self.name)
     }
 
+    /// Get the GitHub URL for a pull request in this repository
+    pub fn pull_request_url(&self, number: u64) -> String {
+        format!("{}/pull/{}", self.github_url(), number)
+    }
+
     /// Update the default branch from GitHub API response
     pub fn set_default_branch(&mut self, branch: String) {
         self.default_branch = branch;
@@ -112,6 +135,20 @@ fn parse_owner_repo_path(path: &str) -> Result<(String, String)> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_pull_request_url() {
+        let ctx = RepositoryContext {
+            owner: "owner".to_string(),
+            name: "repo".to_string(),
+            current_branch: "main".to_string(),
+            default_branch: "main".to_string(),
+        };
+        assert_eq!(
+            ctx.pull_request_url(42),
+            "https://github.com/owner/repo/pull/42"
+        );
+    }
+
     #[test]
     fn test_parse_https_url() {
         let (owner, repo) = parse_github_url("https://github.com/owner/repo.git").unwrap();
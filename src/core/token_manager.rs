@@ -7,9 +7,9 @@
 //!
 //! ## Token Priority
 //!
-//! 1. Environment variable (`GITHUB_TOKEN`) - bypasses refresh logic, assumed valid
-//! 2. Stored token data with refresh capability
-//! 3. Legacy token (plain access token without metadata)
+//! 1. Stored token data with refresh capability
+//! 2. Legacy token (plain access token without metadata)
+//! 3. Environment variable (`ARGO_GITHUB_TOKEN` / `GITHUB_TOKEN`) - no refresh, assumed valid
 
 use once_cell::sync::Lazy;
 use secrecy::{ExposeSecret, SecretString};
@@ -38,9 +38,11 @@ impl TokenManager {
     ///
     /// ## Priority
     ///
-    /// 1. Environment variable (`GITHUB_TOKEN`) - returned as-is, no refresh
-    /// 2. Stored token data - refreshed if expired
-    /// 3. Legacy token (no metadata) - returned as-is, may fail with 401
+    /// 1. Stored token data - refreshed if expired
+    /// 2. Legacy token (no metadata) - returned as-is, may fail with 401
+    /// 3. Environment variable (`ARGO_GITHUB_TOKEN` / `GITHUB_TOKEN`) - returned as-is
+    ///
+    /// When the keychain cannot be read, the environment variable is still used.
     ///
     /// ## Errors
     ///
@@ -48,50 +50,78 @@ impl TokenManager {
     /// - `TokenRefreshExpired` - Both access and refresh tokens expired
     /// - `TokenRefreshFailed` - Refresh attempt failed
     pub async fn get_valid_token() -> Result<SecretString> {
-        // Priority 1: Check environment variable (bypass all refresh logic)
-        if let Ok(token) = std::env::var("GITHUB_TOKEN") {
-            if !token.is_empty() {
-                return Ok(SecretString::from(token));
-            }
-        }
+        // Priority 1: Check for stored token data (new format with refresh support)
+        if let Some(token_data) = stored_or_env(CredentialStore::get_github_token_data())? {
+            tracing::debug!("Using stored GitHub token");
 
-        // Priority 2: Check for stored token data (new format with refresh support)
-        if let Some(token_data) = CredentialStore::get_github_token_data()? {
             // Check if access token is still valid
             if !CredentialStore::is_token_expired(&token_data) {
                 return Ok(token_data.access_token.clone());
             }
 
             // Access token expired, try to refresh
-            return Self::refresh_and_get_token().await;
+            return Self::refresh_and_get_token(None).await;
         }
 
-        // Priority 3: Fall back to legacy token (no metadata)
-        if let Some(token) = CredentialStore::get_github_token()? {
+        // Priority 2: Fall back to legacy token (no metadata)
+        if let Some(token) = stored_or_env(CredentialStore::get_stored_github_token())? {
             // Legacy token - no expiration info, return as-is
             // If it's actually expired, the API call will fail with 401
+            tracing::debug!("Using stored legacy GitHub token");
+            return Ok(token);
+        }
+
+        // Priority 3: Environment variable (bypass all refresh logic)
+        if let Some(token) = CredentialStore::github_token_from_env() {
+            tracing::debug!("Using GitHub token from environment");
             return Ok(token);
         }
 
         Err(GhrustError::NotAuthenticated)
     }
 
-    /// Force a token refresh
+    /// Check whether the stored access token is expired or about to expire
+    ///
+    /// Tokens without expiry metadata (legacy, PAT or environment tokens) are
+    /// never reported as expired.
+    pub fn is_expired() -> Result<bool> {
+        Ok(CredentialStore::get_github_token_data()?
+            .map(|token_data| CredentialStore::is_token_expired(&token_data))
+            .unwrap_or(false))
+    }
+
+    /// Refresh the stored access token using the OAuth refresh grant
+    ///
+    /// Used when an API call returns 401: GitHub rejected `rejected` even
+    /// though our local expiration check passed. If another task already
+    /// replaced that token, the stored one is returned without refreshing again.
+    ///
+    /// ## Errors
     ///
-    /// Useful when an API call returns 401, indicating the token is invalid
-    /// even if our local expiration check passed.
-    pub async fn force_refresh() -> Result<SecretString> {
-        Self::refresh_and_get_token().await
+    /// - `NotAuthenticated` - No refreshable token is stored
+    /// - `TokenRefreshExpired` - The refresh token expired or is missing
+    /// - `TokenRefreshFailed` - GitHub rejected the refresh
+    pub async fn refresh(rejected: &SecretString) -> Result<SecretString> {
+        Self::refresh_and_get_token(Some(rejected)).await
     }
 
     /// Perform the actual token refresh with mutex protection
-    async fn refresh_and_get_token() -> Result<SecretString> {
+    ///
+    /// Without `rejected` the refresh is skipped while the stored token has
+    /// not expired.
+    async fn refresh_and_get_token(rejected: Option<&SecretString>) -> Result<SecretString> {
         // Acquire lock to prevent concurrent refresh attempts
         let _lock = REFRESH_LOCK.lock().await;
 
         // Double-check: another task might have refreshed while we waited for the lock
         if let Some(token_data) = CredentialStore::get_github_token_data()? {
-            if !CredentialStore::is_token_expired(&token_data) {
+            let still_usable = match rejected {
+                Some(rejected) => {
+                    token_data.access_token.expose_secret() != rejected.expose_secret()
+                }
+                None => !CredentialStore::is_token_expired(&token_data),
+            };
+            if still_usable {
                 // Token was refreshed by another task while we waited
                 return Ok(token_data.access_token.clone());
             }
@@ -133,15 +163,18 @@ impl TokenManager {
     /// Check if we have any form of GitHub authentication
     ///
     /// Returns true if either:
-    /// - `GITHUB_TOKEN` environment variable is set
+    /// - `ARGO_GITHUB_TOKEN` or `GITHUB_TOKEN` environment variable is set
     /// - Token data is stored in keyring
     /// - Legacy token is stored in keyring
     pub fn is_authenticated() -> Result<bool> {
-        // Check env var
-        if let Ok(token) = std::env::var("GITHUB_TOKEN") {
-            if !token.is_empty() {
-                return Ok(true);
-            }
+        Self::is_authenticated_with(CredentialStore::github_token_from_env())
+    }
+
+    /// [`Self::is_authenticated`] with the environment token passed in
+    fn is_authenticated_with(env_token: Option<SecretString>) -> Result<bool> {
+        // Check env var (first, so CI never touches the keychain)
+        if env_token.is_some() {
+            return Ok(true);
         }
 
         // Check stored token data
@@ -150,7 +183,19 @@ impl TokenManager {
         }
 
         // Check legacy token
-        CredentialStore::has_github_token()
+        Ok(CredentialStore::get_stored_github_token()?.is_some())
+    }
+}
+
+/// Treat a keychain error as "nothing stored" when the environment provides a token
+fn stored_or_env<T>(result: Result<Option<T>>) -> Result<Option<T>> {
+    match result {
+        Err(e) if CredentialStore::github_token_from_env().is_none() => Err(e),
+        Err(e) => {
+            tracing::debug!("Keychain unavailable, using environment token: {}", e);
+            Ok(None)
+        }
+        ok => ok,
     }
 }
 
@@ -160,11 +205,9 @@ mod tests {
 
     #[test]
     fn test_is_authenticated_with_env_var() {
-        // Set env var to test the env var path (avoids keyring access)
-        std::env::set_var("GITHUB_TOKEN", "test_token");
-        let result = TokenManager::is_authenticated();
+        // An environment token short-circuits before any keyring access
+        let result = TokenManager::is_authenticated_with(Some(SecretString::from("test_token")));
         assert!(result.is_ok());
-        assert!(result.unwrap()); // Should be true since env var is set
-        std::env::remove_var("GITHUB_TOKEN");
+        assert!(result.unwrap());
     }
 }
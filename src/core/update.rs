@@ -72,6 +72,18 @@ impl UpdatePersistentState {
         Ok(())
     }
 
+    /// Delete the update state file
+    ///
+    /// Returns whether a file was removed.
+    pub fn delete() -> Result<bool> {
+        let path = Self::state_path()?;
+        match fs::remove_file(&path) {
+            Ok(()) => Ok(true),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(false),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     /// Get path to update state file
     fn state_path() -> Result<PathBuf> {
         let config_dir = Config::config_dir()?;
@@ -115,19 +127,59 @@ impl UpdatePersistentState {
 }
 
 /// Get the expected release asset name for the current platform
+///
+/// On Linux this includes the libc (`gnu` or `musl`), since binaries built
+/// against one do not run on the other.
 pub fn platform_asset_name() -> Option<String> {
-    let os = std::env::consts::OS;
-    let arch = std::env::consts::ARCH;
+    platform_asset_candidates().into_iter().next()
+}
 
+/// Release asset names acceptable for the current platform, most specific first
+///
+/// The exact arch + libc name comes first, followed by the generic
+/// arch-only name used by older releases.
+pub fn platform_asset_candidates() -> Vec<String> {
+    let env = if cfg!(target_env = "musl") {
+        "musl"
+    } else {
+        "gnu"
+    };
+    asset_candidates_for(std::env::consts::OS, std::env::consts::ARCH, env)
+}
+
+/// Candidate asset names for an OS / arch / libc triple
+fn asset_candidates_for(os: &str, arch: &str, env: &str) -> Vec<String> {
     let platform = match (os, arch) {
         ("macos", "aarch64") => "macos-aarch64",
         ("macos", "x86_64") => "macos-x86_64",
         ("linux", "x86_64") => "linux-x86_64",
         ("linux", "aarch64") => "linux-aarch64",
-        _ => return None, // Unsupported platform
+        _ => return Vec::new(), // Unsupported platform
     };
 
-    Some(format!("argo-{}", platform))
+    let generic = format!("argo-{}", platform);
+    if os == "linux" {
+        vec![format!("{}-{}", generic, env), generic]
+    } else {
+        vec![generic]
+    }
+}
+
+/// Pick the release asset that best matches the current platform
+///
+/// Returns the index into `names` of the most specific match. Both plain
+/// binaries and `.tar.gz` archives are accepted.
+pub fn select_platform_asset<S: AsRef<str>>(names: &[S]) -> Option<usize> {
+    select_asset(&platform_asset_candidates(), names)
+}
+
+fn select_asset<S: AsRef<str>>(candidates: &[String], names: &[S]) -> Option<usize> {
+    candidates.iter().find_map(|candidate| {
+        let archive = format!("{}.tar.gz", candidate);
+        names
+            .iter()
+            .position(|n| n.as_ref() == candidate || n.as_ref() == archive)
+    })
 }
 
 /// Get the current application version from Cargo.toml
@@ -152,15 +204,28 @@ pub fn current_binary_path() -> Result<PathBuf> {
         .map_err(|e| GhrustError::Config(format!("Cannot determine binary path: {}", e)))
 }
 
-/// Clean up any partial downloads from interrupted sessions
+/// Clean up stale partial downloads from interrupted sessions
+///
+/// Partial files for versions newer than the running one are kept so the
+/// next download can resume them.
 pub fn cleanup_partial_downloads() -> Result<()> {
     let staging = staging_path()?;
     if staging.exists() {
+        let current = current_version();
         for entry in fs::read_dir(&staging)? {
             let entry = entry?;
             let path = entry.path();
             if path.extension().map(|e| e == "partial").unwrap_or(false) {
-                let _ = fs::remove_file(&path);
+                let resumable = path
+                    .file_stem()
+                    .and_then(|s| s.to_str())
+                    .and_then(|s| s.strip_prefix("argo-"))
+                    .and_then(|v| Version::parse(v).ok())
+                    .map(|v| v > current)
+                    .unwrap_or(false);
+                if !resumable {
+                    let _ = fs::remove_file(&path);
+                }
             }
         }
     }
@@ -207,6 +272,38 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_asset_candidates() {
+        assert_eq!(
+            asset_candidates_for("linux", "x86_64", "musl"),
+            vec!["argo-linux-x86_64-musl", "argo-linux-x86_64"]
+        );
+        assert_eq!(
+            asset_candidates_for("macos", "aarch64", "gnu"),
+            vec!["argo-macos-aarch64"]
+        );
+        assert!(asset_candidates_for("windows", "x86_64", "msvc").is_empty());
+    }
+
+    #[test]
+    fn test_select_asset_prefers_exact_match() {
+        let candidates = asset_candidates_for("linux", "x86_64", "musl");
+        let names = [
+            "argo-linux-x86_64.tar.gz",
+            "argo-linux-x86_64-gnu.tar.gz",
+            "argo-linux-x86_64-musl.tar.gz",
+        ];
+        assert_eq!(select_asset(&candidates, &names), Some(2));
+
+        // Older releases only ship the generic name
+        let names = ["argo-macos-aarch64", "argo-linux-x86_64"];
+        assert_eq!(select_asset(&candidates, &names), Some(1));
+
+        // Never pick the other libc
+        let names = ["argo-linux-x86_64-gnu"];
+        assert_eq!(select_asset(&candidates, &names), None);
+    }
+
     #[test]
     fn test_update_state_default() {
         let state = UpdateState::default();
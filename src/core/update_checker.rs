@@ -13,8 +13,8 @@ use serde::Deserialize;
 use sha2::{Digest, Sha256};
 
 use crate::core::update::{
-    current_binary_path, current_version, is_prerelease, platform_asset_name, staging_path,
-    UpdatePersistentState,
+    current_binary_path, current_version, is_prerelease, platform_asset_name,
+    select_platform_asset, staging_path, UpdatePersistentState,
 };
 use crate::error::{GhrustError, Result};
 
@@ -188,15 +188,16 @@ pub async fn check_for_update() -> Result<UpdateCheckResult> {
         return Ok(UpdateCheckResult::UpToDate);
     }
 
-    // Find the asset for this platform
-    let asset_name = platform_asset_name()
-        .ok_or_else(|| GhrustError::Custom("Unsupported platform for auto-update".into()))?;
+    // Find the asset for this platform, preferring an exact arch + libc match
+    if platform_asset_name().is_none() {
+        return Err(GhrustError::Custom(
+            "Unsupported platform for auto-update".into(),
+        ));
+    }
 
-    // Try both plain binary and tar.gz variants
-    let asset = release
-        .assets
-        .iter()
-        .find(|a| a.name == asset_name || a.name == format!("{}.tar.gz", asset_name))
+    let names: Vec<&str> = release.assets.iter().map(|a| a.name.as_str()).collect();
+    let asset = select_platform_asset(&names)
+        .map(|idx| &release.assets[idx])
         .ok_or_else(|| GhrustError::Custom("No release asset for this platform".into()))?;
 
     Ok(UpdateCheckResult::Available {
@@ -206,6 +207,11 @@ pub async fn check_for_update() -> Result<UpdateCheckResult> {
     })
 }
 
+/// Total size from a `Content-Range` header such as `bytes 100-199/200`
+fn content_range_total(header: &str) -> Option<u64> {
+    header.rsplit_once('/')?.1.trim().parse().ok()
+}
+
 /// Download progress callback type
 pub type ProgressCallback = Box<dyn Fn(f32) + Send + Sync>;
 
@@ -232,8 +238,20 @@ pub async fn download_update(
     state.partial_download = true;
     let _ = state.save();
 
-    // Perform download
-    let response = client.get(download_url).send().await?;
+    // Resume from an earlier interrupted attempt if a partial file exists
+    let existing = fs::metadata(&partial_path).map(|m| m.len()).unwrap_or(0);
+
+    let mut request = client.get(download_url);
+    if existing > 0 {
+        request = request.header(reqwest::header::RANGE, format!("bytes={}-", existing));
+    }
+    let mut response = request.send().await?;
+
+    // The partial file is already complete or stale; start over
+    if response.status() == reqwest::StatusCode::RANGE_NOT_SATISFIABLE {
+        let _ = fs::remove_file(&partial_path);
+        response = client.get(download_url).send().await?;
+    }
 
     if !response.status().is_success() {
         return Err(GhrustError::Custom(format!(
@@ -242,13 +260,30 @@ pub async fn download_update(
         )));
     }
 
-    let total_size = response.content_length().unwrap_or(0);
-    let mut downloaded: u64 = 0;
+    // A 206 means the server honoured the range; anything else is the full file
+    let resuming = response.status() == reqwest::StatusCode::PARTIAL_CONTENT;
+    let (mut downloaded, total_size) = if resuming {
+        let total = response
+            .headers()
+            .get(reqwest::header::CONTENT_RANGE)
+            .and_then(|v| v.to_str().ok())
+            .and_then(content_range_total)
+            .or_else(|| response.content_length().map(|len| existing + len))
+            .unwrap_or(0);
+        (existing, total)
+    } else {
+        (0, response.content_length().unwrap_or(0))
+    };
 
-    let mut file = File::create(&partial_path)?;
+    let mut file = if resuming {
+        fs::OpenOptions::new().append(true).open(&partial_path)?
+    } else {
+        File::create(&partial_path)?
+    };
     let mut stream = response.bytes_stream();
 
     while let Some(chunk) = stream.next().await {
+        // Keep the partial file on errors so the next attempt can resume
         let chunk = chunk?;
         file.write_all(&chunk)?;
         downloaded += chunk.len() as u64;
@@ -260,6 +295,13 @@ pub async fn download_update(
         }
     }
 
+    if total_size > 0 && downloaded != total_size {
+        return Err(GhrustError::Custom(format!(
+            "Incomplete download: got {} of {} bytes",
+            downloaded, total_size
+        )));
+    }
+
     file.sync_all()?;
     drop(file);
 
@@ -448,4 +490,11 @@ mod tests {
         assert!(GITHUB_REPO.contains('/'));
         assert!(!GITHUB_REPO.is_empty());
     }
+
+    #[test]
+    fn test_content_range_total() {
+        assert_eq!(content_range_total("bytes 100-199/200"), Some(200));
+        assert_eq!(content_range_total("bytes 0-0/*"), None);
+        assert_eq!(content_range_total("garbage"), None);
+    }
 }
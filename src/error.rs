@@ -4,6 +4,25 @@
 
 use thiserror::Error;
 
+/// Process exit codes for CLI errors, grouped by category
+///
+/// Scripts can use these to tell transient failures (network, rate limit)
+/// apart from ones that need user action.
+pub mod exit_code {
+    /// Any error without a more specific category
+    pub const FAILURE: i32 = 1;
+    /// Not a git repository, or no usable GitHub remote
+    pub const NOT_A_REPOSITORY: i32 = 2;
+    /// Not logged in, expired session, or access denied
+    pub const AUTH: i32 = 3;
+    /// PR, branch, tag or repository not found
+    pub const NOT_FOUND: i32 = 4;
+    /// Network request failed
+    pub const NETWORK: i32 = 5;
+    /// GitHub API rate limit exceeded
+    pub const RATE_LIMITED: i32 = 6;
+}
+
 /// Main error type for the argo-rs application
 #[derive(Error, Debug)]
 pub enum GhrustError {
@@ -12,7 +31,7 @@ pub enum GhrustError {
     NotGitRepository,
 
     /// No GitHub remote found
-    #[error("No GitHub remote found in this repository.\n\n  → Make sure 'origin' points to a GitHub URL.\n  → Run 'git remote -v' to check your remotes.\n  → Example: git remote add origin https://github.com/user/repo.git")]
+    #[error("No GitHub remote found in this repository.\n\n  → Make sure 'origin' points to a GitHub URL.\n  → Run 'git remote -v' to check your remotes.\n  → Example: git remote add origin https://github.com/user/repo.git\n  → Local commands (commit, push, tags) work without a GitHub remote.")]
     NoGitHubRemote,
 
     /// Invalid GitHub URL format
@@ -37,14 +56,26 @@ pub enum GhrustError {
     )]
     TokenRefreshExpired,
 
+    /// GitHub rejected the access token and it could not be refreshed
+    #[error("Your GitHub token has expired or was revoked.\n\n  → Run 'gr auth login' to authenticate again.")]
+    TokenExpired,
+
     /// Token refresh failed with specific reason
     #[error("Failed to refresh GitHub token: {0}\n\n  → Run 'gr auth login' to re-authenticate.")]
     TokenRefreshFailed(String),
 
+    /// GitHub API rate limit exceeded
+    #[error("GitHub API rate limit exceeded.\n\n  → Wait a few minutes and try again.")]
+    RateLimited,
+
     /// GitHub API error
     #[error("GitHub API request failed: {0}\n\n  → Check your internet connection.\n  → Your token may have expired - try 'gr auth logout' then 'gr auth login'.")]
     GitHubApi(String),
 
+    /// GitHub answered 404; the resource is missing or hidden from this token
+    #[error("{0}\n\n  → Check the repository name and that your token can access it.")]
+    GitHubNotFound(String),
+
     /// Organization has not installed the GitHub App
     #[error(
         "Access denied to the '{org_name}' organization.\n\n  \
@@ -114,10 +145,34 @@ pub enum GhrustError {
     #[error("AI generation failed: {0}\n\n  → Check your Gemini API key with 'gr config get gemini-key'.")]
     GeminiApi(String),
 
+    /// OpenAI or Anthropic API error
+    #[error("AI generation failed: {message}\n\n  → Check your {provider} API key with 'gr config get {key}'.")]
+    AiApi {
+        provider: String,
+        key: String,
+        message: String,
+    },
+
+    /// AI request took longer than the configured timeout
+    #[error("{provider} did not respond within {seconds}s.\n\n  → Try again, or raise the limit with 'gr config set ai-timeout-seconds N'.")]
+    AiTimeout { provider: String, seconds: u64 },
+
+    /// Watched workflow run did not succeed
+    #[error("Workflow run #{run_number} finished with conclusion: {conclusion}\n\n  → View the run: {url}")]
+    WorkflowRunFailed {
+        run_number: u64,
+        conclusion: String,
+        url: String,
+    },
+
     /// Gemini API not configured
     #[error("Gemini API key is not set up.\n\n  → Get an API key from https://aistudio.google.com/apikey\n  → Run 'gr config set gemini-key YOUR_KEY' to configure it.")]
     GeminiNotConfigured,
 
+    /// API key for the configured OpenAI or Anthropic provider is missing
+    #[error("{provider} API key is not set up.\n\n  → Run 'gr config set {key} YOUR_KEY' to configure it.\n  → Or switch providers with 'gr config set ai-provider gemini'.")]
+    AiNotConfigured { provider: String, key: String },
+
     /// Pull request not found
     #[error("Pull request #{0} does not exist.\n\n  → Run 'gr pr list' to see available PRs.")]
     PullRequestNotFound(u64),
@@ -153,6 +208,10 @@ pub enum GhrustError {
     #[error("Cannot merge this PR: {0}\n\n  → Resolve conflicts locally and push, or try a different merge method.")]
     MergeConflict(String),
 
+    /// Working tree has unresolved merge conflicts
+    #[error("Unresolved merge conflicts in:\n  {}\n\n  → Resolve the conflicts in your editor, stage the files, then commit.", .0.join("\n  "))]
+    UnresolvedConflicts(Vec<String>),
+
     /// Invalid input from user
     #[error("{0}")]
     InvalidInput(String),
@@ -161,11 +220,133 @@ pub enum GhrustError {
     #[error("Operation cancelled.")]
     Cancelled,
 
+    /// Destructive action skipped because dry-run mode is enabled
+    #[error("Dry run: would {0}. Nothing was changed.")]
+    DryRun(String),
+
     /// Generic error with custom message
     #[error("{0}")]
     Custom(String),
 }
 
+impl GhrustError {
+    /// Stable machine-readable code for this error, used in JSON output
+    pub fn code(&self) -> &'static str {
+        match self {
+            GhrustError::NotGitRepository => "not_git_repository",
+            GhrustError::NoGitHubRemote => "no_github_remote",
+            GhrustError::InvalidGitHubUrl(_) => "invalid_github_url",
+            GhrustError::NotAuthenticated => "not_authenticated",
+            GhrustError::AuthenticationFailed(_) => "authentication_failed",
+            GhrustError::AuthenticationExpired => "authentication_expired",
+            GhrustError::TokenRefreshExpired => "token_refresh_expired",
+            GhrustError::TokenExpired => "token_expired",
+            GhrustError::TokenRefreshFailed(_) => "token_refresh_failed",
+            GhrustError::RateLimited => "rate_limited",
+            GhrustError::GitHubApi(_) => "github_api",
+            GhrustError::GitHubNotFound(_) => "github_not_found",
+            GhrustError::OrgAccessRestricted { .. } => "org_access_restricted",
+            GhrustError::RepoAccessDenied { .. } => "repo_access_denied",
+            GhrustError::Git(_) => "git",
+            GhrustError::Credential(_) => "credential",
+            GhrustError::Config(_) => "config",
+            GhrustError::Io(_) => "io",
+            GhrustError::Network(_) => "network",
+            GhrustError::Json(_) => "json",
+            GhrustError::Toml(_) => "toml",
+            GhrustError::Terminal(_) => "terminal",
+            GhrustError::GeminiApi(_) => "gemini_api",
+            GhrustError::WorkflowRunFailed { .. } => "workflow_run_failed",
+            GhrustError::GeminiNotConfigured => "gemini_not_configured",
+            GhrustError::AiApi { .. } => "ai_api",
+            GhrustError::AiNotConfigured { .. } => "ai_not_configured",
+            GhrustError::AiTimeout { .. } => "ai_timeout",
+            GhrustError::PullRequestNotFound(_) => "pull_request_not_found",
+            GhrustError::BranchNotFound(_) => "branch_not_found",
+            GhrustError::TagAlreadyExists(_) => "tag_already_exists",
+            GhrustError::PullRequestAlreadyExists { .. } => "pull_request_already_exists",
+            GhrustError::TagNotFound(_) => "tag_not_found",
+            GhrustError::MergeConflict(_) => "merge_conflict",
+            GhrustError::UnresolvedConflicts(_) => "unresolved_conflicts",
+            GhrustError::InvalidInput(_) => "invalid_input",
+            GhrustError::Cancelled => "cancelled",
+            GhrustError::DryRun(_) => "dry_run",
+            GhrustError::Custom(_) => "other",
+        }
+    }
+
+    /// Process exit code for this error's category (see [`exit_code`])
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            GhrustError::NotGitRepository
+            | GhrustError::NoGitHubRemote
+            | GhrustError::InvalidGitHubUrl(_) => exit_code::NOT_A_REPOSITORY,
+            GhrustError::NotAuthenticated
+            | GhrustError::AuthenticationFailed(_)
+            | GhrustError::AuthenticationExpired
+            | GhrustError::TokenRefreshExpired
+            | GhrustError::TokenExpired
+            | GhrustError::TokenRefreshFailed(_)
+            | GhrustError::OrgAccessRestricted { .. }
+            | GhrustError::RepoAccessDenied { .. } => exit_code::AUTH,
+            GhrustError::PullRequestNotFound(_)
+            | GhrustError::BranchNotFound(_)
+            | GhrustError::TagNotFound(_)
+            | GhrustError::GitHubNotFound(_) => exit_code::NOT_FOUND,
+            GhrustError::Network(_) | GhrustError::AiTimeout { .. } => exit_code::NETWORK,
+            GhrustError::RateLimited => exit_code::RATE_LIMITED,
+            _ => exit_code::FAILURE,
+        }
+    }
+
+    /// Structured details for errors that carry them (owner/repo, numbers, names)
+    pub fn context(&self) -> Option<serde_json::Value> {
+        use serde_json::json;
+
+        match self {
+            GhrustError::OrgAccessRestricted {
+                org_name,
+                install_url,
+            } => Some(json!({ "org": org_name, "install_url": install_url })),
+            GhrustError::RepoAccessDenied {
+                owner,
+                repo,
+                install_url,
+            } => Some(json!({ "owner": owner, "repo": repo, "install_url": install_url })),
+            GhrustError::WorkflowRunFailed {
+                run_number,
+                conclusion,
+                url,
+            } => Some(json!({ "run_number": run_number, "conclusion": conclusion, "url": url })),
+            GhrustError::PullRequestNotFound(number) => Some(json!({ "number": number })),
+            GhrustError::BranchNotFound(branch) => Some(json!({ "branch": branch })),
+            GhrustError::UnresolvedConflicts(files) => Some(json!({ "files": files })),
+            GhrustError::TagAlreadyExists(tag) | GhrustError::TagNotFound(tag) => {
+                Some(json!({ "tag": tag }))
+            }
+            GhrustError::PullRequestAlreadyExists {
+                head,
+                base,
+                number,
+                url,
+            } => Some(json!({ "head": head, "base": base, "number": number, "url": url })),
+            _ => None,
+        }
+    }
+
+    /// JSON representation for `--format json`
+    pub fn to_json(&self) -> serde_json::Value {
+        let mut error = serde_json::json!({
+            "code": self.code(),
+            "message": self.to_string(),
+        });
+        if let Some(context) = self.context() {
+            error["context"] = context;
+        }
+        serde_json::json!({ "error": error })
+    }
+}
+
 impl From<keyring::Error> for GhrustError {
     fn from(err: keyring::Error) -> Self {
         GhrustError::Credential(err.to_string())
@@ -193,3 +374,35 @@ impl From<octocrab::Error> for GhrustError {
 
 /// Result type alias using GhrustError
 pub type Result<T> = std::result::Result<T, GhrustError>;
+
+/// Collects the actions a dry run skipped so multi-step commands report all of them
+///
+/// Each step's [`GhrustError::DryRun`] is recorded instead of ending the
+/// command early; [`DryRunPlan::finish`] then reports every skipped action.
+#[derive(Debug, Default)]
+pub struct DryRunPlan {
+    actions: Vec<String>,
+}
+
+impl DryRunPlan {
+    /// Record a step: `Ok(true)` if it ran, `Ok(false)` if dry-run skipped it
+    pub fn step(&mut self, result: Result<()>) -> Result<bool> {
+        match result {
+            Ok(()) => Ok(true),
+            Err(GhrustError::DryRun(action)) => {
+                self.actions.push(action);
+                Ok(false)
+            }
+            Err(e) => Err(e),
+        }
+    }
+
+    /// `Ok` when every step ran, otherwise a [`GhrustError::DryRun`] listing the skipped actions
+    pub fn finish(self) -> Result<()> {
+        if self.actions.is_empty() {
+            Ok(())
+        } else {
+            Err(GhrustError::DryRun(self.actions.join(", then ")))
+        }
+    }
+}
@@ -1,6 +1,6 @@
 //! Branch operations
 
-use crate::error::Result;
+use crate::error::{GhrustError, Result};
 use crate::github::client::GitHubClient;
 
 /// Information about a remote branch
@@ -19,20 +19,39 @@ pub struct BranchInfo {
 /// Branch operations handler
 pub struct BranchHandler<'a> {
     client: &'a GitHubClient,
+    dry_run: bool,
 }
 
 impl<'a> BranchHandler<'a> {
     /// Create a new handler
     pub fn new(client: &'a GitHubClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            dry_run: false,
+        }
+    }
+
+    /// Report destructive actions as [`GhrustError::DryRun`] instead of performing them
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
     }
 
     /// List remote branches
     pub async fn list(&self) -> Result<Vec<BranchInfo>> {
-        let branches = self.client.repos().list_branches().send().await?;
+        let (owner, name) = (&self.client.owner, &self.client.repo);
+        let branches = self
+            .client
+            .with_retry(|octocrab| async move {
+                octocrab.repos(owner, name).list_branches().send().await
+            })
+            .await?;
 
         // Get repo info to determine default branch
-        let repo = self.client.repos().get().await?;
+        let repo = self
+            .client
+            .with_retry(|octocrab| async move { octocrab.repos(owner, name).get().await })
+            .await?;
 
         let default_branch = repo.default_branch.unwrap_or_else(|| "main".to_string());
 
@@ -52,6 +71,13 @@ impl<'a> BranchHandler<'a> {
 
     /// Delete a remote branch by name
     pub async fn delete(&self, name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "delete remote branch '{}'",
+                name
+            )));
+        }
+
         // GitHub API: DELETE /repos/{owner}/{repo}/git/refs/heads/{branch}
         let route = format!(
             "/repos/{}/{}/git/refs/heads/{}",
@@ -66,6 +92,23 @@ impl<'a> BranchHandler<'a> {
         Ok(())
     }
 
+    /// Check whether a branch has GitHub branch protection enabled
+    pub async fn is_protected(&self, name: &str) -> Result<bool> {
+        #[derive(serde::Deserialize)]
+        struct BranchProtection {
+            protected: bool,
+        }
+
+        let route = format!(
+            "/repos/{}/{}/branches/{}",
+            self.client.owner, self.client.repo, name
+        );
+
+        let branch: BranchProtection = self.client.octocrab().get(&route, None::<&()>).await?;
+
+        Ok(branch.protected)
+    }
+
     /// Check if a branch exists
     pub async fn exists(&self, name: &str) -> Result<bool> {
         let branches = self.list().await?;
@@ -1,10 +1,38 @@
 //! GitHub API client wrapper using octocrab
 
+use std::future::Future;
+use std::time::Duration;
+
 use octocrab::Octocrab;
-use secrecy::ExposeSecret;
+use secrecy::{ExposeSecret, SecretString};
 
+use crate::core::config::Config;
 use crate::core::TokenManager;
-use crate::error::Result;
+use crate::error::{GhrustError, Result};
+use crate::github::error_handler::{classify_github_error, is_retriable_error};
+
+/// Delay before the first retry; doubles for each further attempt
+const RETRY_BASE_DELAY: Duration = Duration::from_millis(500);
+
+/// Core REST API quota for the authenticated token
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RateLimit {
+    /// Requests left in the current window
+    pub remaining: usize,
+    /// Requests allowed per window
+    pub limit: usize,
+    /// Unix timestamp (seconds) when the window resets
+    pub reset: u64,
+}
+
+impl RateLimit {
+    /// Whether the remaining quota is low enough to warn about
+    ///
+    /// Low means under 10% of the limit or under 100 requests, whichever is larger.
+    pub fn is_low(&self) -> bool {
+        self.remaining < (self.limit / 10).max(100)
+    }
+}
 
 /// GitHub API client wrapper
 ///
@@ -12,30 +40,36 @@ use crate::error::Result;
 pub struct GitHubClient {
     /// The octocrab instance
     inner: Octocrab,
+    /// Access token `inner` authenticates with
+    token: SecretString,
     /// Repository owner
     pub owner: String,
     /// Repository name
     pub repo: String,
+    /// Retries for calls wrapped in `with_retry`
+    max_retries: u8,
 }
 
 impl GitHubClient {
     /// Create a new GitHub client for the given repository
     ///
     /// Obtains a valid token via `TokenManager`, which handles:
-    /// - Environment variable override (`GITHUB_TOKEN`)
     /// - Automatic token refresh if the access token is expired
     /// - Fallback to legacy tokens
+    /// - Fallback to `ARGO_GITHUB_TOKEN` / `GITHUB_TOKEN` when nothing is stored
     pub async fn new(owner: String, repo: String) -> Result<Self> {
         let token = TokenManager::get_valid_token().await?;
 
-        let octocrab = Octocrab::builder()
-            .personal_token(token.expose_secret().to_string())
-            .build()?;
+        let config = Config::load().unwrap_or_default();
+        let octocrab = build_octocrab(&config, &token)?;
+        let max_retries = config.github_retries();
 
         Ok(Self {
             inner: octocrab,
+            token,
             owner,
             repo,
+            max_retries,
         })
     }
 
@@ -44,6 +78,54 @@ impl GitHubClient {
         &self.inner
     }
 
+    /// Run a GitHub API call, retrying transient failures with exponential backoff
+    ///
+    /// `call` is invoked again for each attempt with the octocrab instance to
+    /// use, so it must build a fresh request from it. Only server errors (5xx)
+    /// and connection failures are retried, up to the configured
+    /// `github_retries`; errors are then classified with `classify_github_error`.
+    ///
+    /// When GitHub rejects the token (401), it is refreshed once and the call
+    /// repeated with the new token; `TokenExpired` is returned only if the
+    /// refresh fails or the new token is rejected too.
+    pub async fn with_retry<T, F, Fut>(&self, mut call: F) -> Result<T>
+    where
+        F: FnMut(Octocrab) -> Fut,
+        Fut: Future<Output = std::result::Result<T, octocrab::Error>>,
+    {
+        let mut octocrab = self.inner.clone();
+        let mut refreshed = false;
+        let mut attempt: u8 = 0;
+        loop {
+            match call(octocrab.clone()).await {
+                Ok(value) => return Ok(value),
+                Err(err) => {
+                    let retriable = is_retriable_error(&err);
+                    let err = classify_github_error(err);
+                    if matches!(err, GhrustError::TokenExpired) && !refreshed {
+                        refreshed = true;
+                        match TokenManager::refresh(&self.token).await {
+                            Ok(token) => {
+                                let config = Config::load().unwrap_or_default();
+                                octocrab = build_octocrab(&config, &token)?;
+                                continue;
+                            }
+                            Err(e) => {
+                                tracing::debug!("Token refresh after 401 failed: {}", e);
+                                return Err(err);
+                            }
+                        }
+                    }
+                    if attempt >= self.max_retries || !retriable {
+                        return Err(err);
+                    }
+                    tokio::time::sleep(RETRY_BASE_DELAY * 2u32.pow(attempt.into())).await;
+                    attempt += 1;
+                }
+            }
+        }
+    }
+
     /// Get pulls handler for this repository
     pub fn pulls(&self) -> octocrab::pulls::PullRequestHandler<'_> {
         self.inner.pulls(&self.owner, &self.repo)
@@ -58,4 +140,30 @@ impl GitHubClient {
     pub fn repos(&self) -> octocrab::repos::RepoHandler<'_> {
         self.inner.repos(&self.owner, &self.repo)
     }
+
+    /// Get the login of the authenticated user
+    pub async fn current_user_login(&self) -> Result<String> {
+        let user = self.inner.current().user().await?;
+        Ok(user.login)
+    }
+
+    /// Get the core REST API rate limit for the authenticated token
+    ///
+    /// Querying `/rate_limit` does not count against the quota.
+    pub async fn rate_limit(&self) -> Result<RateLimit> {
+        let core = self.inner.ratelimit().get().await?.resources.core;
+        Ok(RateLimit {
+            remaining: core.remaining,
+            limit: core.limit,
+            reset: core.reset,
+        })
+    }
+}
+
+/// Build an octocrab instance for the configured API base URL
+fn build_octocrab(config: &Config, token: &SecretString) -> Result<Octocrab> {
+    Ok(Octocrab::builder()
+        .base_uri(config.github_base_url())?
+        .personal_token(token.expose_secret().to_string())
+        .build()?)
 }
@@ -5,6 +5,7 @@
 
 use once_cell::sync::Lazy;
 use regex::Regex;
+use reqwest::StatusCode;
 use std::process::Command;
 
 use crate::error::GhrustError;
@@ -17,36 +18,51 @@ static ORG_RESTRICTION_PATTERN: Lazy<Regex> = Lazy::new(|| {
 
 /// Classifies an octocrab error into a more specific GhrustError if possible
 ///
-/// This function examines the error message to detect specific error conditions
-/// like organization access restrictions (403 with OAuth App restrictions).
+/// API error responses are classified by status code, e.g. organization
+/// access restrictions (403 with OAuth App restrictions) or a rejected token (401).
 pub fn classify_github_error(err: octocrab::Error) -> GhrustError {
-    // Get the error message using Debug format (Display only returns "GitHub")
-    let error_message = format!("{:?}", err);
-
-    // Check for organization access restriction (403)
-    if let Some(org_name) = extract_org_from_access_error(&error_message) {
-        return GhrustError::OrgAccessRestricted {
-            org_name,
-            install_url: build_app_install_url(),
-        };
+    if let octocrab::Error::GitHub { source, .. } = &err {
+        if let Some(classified) = classify_api_error(source.status_code, &source.message) {
+            return classified;
+        }
     }
 
-    // Check for rate limiting
-    if is_rate_limit_error(&error_message) {
-        return GhrustError::GitHubApi(
-            "API rate limit exceeded. Please wait a few minutes and try again.".to_string(),
-        );
-    }
+    // Default: return as generic GitHub API error (Display only returns "GitHub")
+    GhrustError::GitHubApi(format!("{:?}", err))
+}
 
-    // Check for not found (404) - could be private repo without access
-    if is_not_found_error(&error_message) {
-        return GhrustError::GitHubApi(
+/// Map a GitHub API error response to a specific error, if there is one
+fn classify_api_error(status: StatusCode, message: &str) -> Option<GhrustError> {
+    match status {
+        // A rejected token - expired, revoked or mistyped
+        StatusCode::UNAUTHORIZED => Some(GhrustError::TokenExpired),
+        StatusCode::FORBIDDEN | StatusCode::TOO_MANY_REQUESTS if is_rate_limit_error(message) => {
+            Some(GhrustError::RateLimited)
+        }
+        StatusCode::FORBIDDEN => extract_org_from_access_error(message).map(|org_name| {
+            GhrustError::OrgAccessRestricted {
+                org_name,
+                install_url: build_app_install_url(),
+            }
+        }),
+        // Could be a private repo without access
+        StatusCode::NOT_FOUND => Some(GhrustError::GitHubNotFound(
             "Repository not found. It may be private or you may not have access.".to_string(),
-        );
+        )),
+        _ => None,
     }
+}
 
-    // Default: return as generic GitHub API error
-    GhrustError::GitHubApi(error_message)
+/// Check whether a failed GitHub API call is worth retrying
+///
+/// Server errors (5xx) and connection failures are transient. Client errors
+/// (4xx), including rate limits and access restrictions, are not.
+pub fn is_retriable_error(err: &octocrab::Error) -> bool {
+    match err {
+        octocrab::Error::GitHub { source, .. } => source.status_code.is_server_error(),
+        octocrab::Error::Hyper { .. } | octocrab::Error::Service { .. } => true,
+        _ => false,
+    }
 }
 
 /// Extract organization name from OAuth access restriction error message
@@ -63,15 +79,9 @@ fn extract_org_from_access_error(error_message: &str) -> Option<String> {
         .map(|m| m.as_str().to_string())
 }
 
-/// Check if error is a rate limit error
+/// Check if an error message reports a (primary or secondary) rate limit
 fn is_rate_limit_error(error_message: &str) -> bool {
-    error_message.contains("rate limit")
-        || (error_message.contains("403") && error_message.contains("limit exceeded"))
-}
-
-/// Check if error is a 404 not found
-fn is_not_found_error(error_message: &str) -> bool {
-    error_message.contains("404") || error_message.contains("Not Found")
+    error_message.to_lowercase().contains("rate limit")
 }
 
 /// GitHub App name (used for installation URLs)
@@ -145,16 +155,77 @@ mod tests {
 
     #[test]
     fn test_rate_limit_detection() {
-        assert!(is_rate_limit_error("API rate limit exceeded"));
-        assert!(is_rate_limit_error("403 limit exceeded"));
+        assert!(is_rate_limit_error(
+            "API rate limit exceeded for user ID 1."
+        ));
+        assert!(is_rate_limit_error(
+            "You have exceeded a secondary rate limit. Please wait a few minutes."
+        ));
         assert!(!is_rate_limit_error("Some other error"));
     }
 
     #[test]
-    fn test_not_found_detection() {
-        assert!(is_not_found_error("404 Not Found"));
-        assert!(is_not_found_error("Resource Not Found"));
-        assert!(!is_not_found_error("Some other error"));
+    fn test_classify_api_error_by_status() {
+        let classify = |status: u16, message: &str| {
+            classify_api_error(StatusCode::from_u16(status).unwrap(), message)
+        };
+
+        assert!(matches!(
+            classify(401, "Bad credentials"),
+            Some(GhrustError::TokenExpired)
+        ));
+        assert!(matches!(
+            classify(403, "API rate limit exceeded"),
+            Some(GhrustError::RateLimited)
+        ));
+        assert!(matches!(
+            classify(429, "You have exceeded a secondary rate limit"),
+            Some(GhrustError::RateLimited)
+        ));
+        assert!(matches!(
+            classify(403, "the `acme` organization has enabled OAuth App access restrictions"),
+            Some(GhrustError::OrgAccessRestricted { org_name, .. }) if org_name == "acme"
+        ));
+        assert!(matches!(
+            classify(404, "Not Found"),
+            Some(GhrustError::GitHubNotFound(_))
+        ));
+        assert_eq!(
+            classify(404, "Not Found").unwrap().exit_code(),
+            crate::error::exit_code::NOT_FOUND
+        );
+        // Only the status decides: a 422 mentioning 404 stays unclassified
+        assert!(classify(422, "Validation Failed: 404 Not Found").is_none());
+        assert!(classify(403, "Resource not accessible by integration").is_none());
+        assert!(classify(502, "Bad Gateway").is_none());
+    }
+
+    #[test]
+    fn test_retriable_detection() {
+        use std::backtrace::Backtrace;
+
+        let service = octocrab::Error::Service {
+            source: "connection reset".into(),
+            backtrace: Backtrace::disabled(),
+        };
+        let other = octocrab::Error::Other {
+            source: "invalid input".into(),
+            backtrace: Backtrace::disabled(),
+        };
+        assert!(is_retriable_error(&service));
+        assert!(!is_retriable_error(&other));
+    }
+
+    #[test]
+    fn test_unclassified_errors_keep_their_details() {
+        let err = octocrab::Error::Other {
+            source: "invalid input".into(),
+            backtrace: std::backtrace::Backtrace::disabled(),
+        };
+        match classify_github_error(err) {
+            GhrustError::GitHubApi(message) => assert!(message.contains("invalid input")),
+            other => panic!("expected a GitHub API error, got {:?}", other),
+        }
     }
 
     #[test]
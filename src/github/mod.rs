@@ -20,8 +20,13 @@ pub mod workflow;
 
 pub use auth::DeviceFlowAuth;
 pub use branch::{BranchHandler, BranchInfo};
-pub use client::GitHubClient;
+pub use client::{GitHubClient, RateLimit};
 pub use error_handler::{classify_github_error, open_browser};
-pub use pull_request::{CreatePrParams, MergeMethod, PrState, PullRequestHandler};
+pub use pull_request::{
+    CombinedStatus, CommitState, CreatePrParams, CreatedPr, MergeMethod, PrState,
+    PullRequestHandler, ReviewEvent, ReviewInfo, ReviewState,
+};
 pub use tag::{TagHandler, TagInfo};
-pub use workflow::{WorkflowConclusion, WorkflowHandler, WorkflowRunInfo, WorkflowRunStatus};
+pub use workflow::{
+    WorkflowConclusion, WorkflowHandler, WorkflowJobInfo, WorkflowRunInfo, WorkflowRunStatus,
+};
@@ -1,5 +1,6 @@
 //! Pull request operations
 
+use chrono::{DateTime, Utc};
 use octocrab::models::issues::Comment;
 use octocrab::models::pulls::PullRequest;
 use octocrab::params::pulls::Sort;
@@ -21,6 +22,17 @@ pub enum MergeMethod {
     Rebase,
 }
 
+impl MergeMethod {
+    /// Human-readable name of the merge method
+    pub fn label(&self) -> &'static str {
+        match self {
+            MergeMethod::Merge => "merge commit",
+            MergeMethod::Squash => "squash",
+            MergeMethod::Rebase => "rebase",
+        }
+    }
+}
+
 /// Parameters for creating a pull request
 #[derive(Debug, Clone)]
 pub struct CreatePrParams {
@@ -34,10 +46,22 @@ pub struct CreatePrParams {
     pub body: Option<String>,
     /// Create as draft
     pub draft: bool,
+    /// Users to request reviews from
+    pub reviewers: Vec<String>,
+    /// Users to assign
+    pub assignees: Vec<String>,
+}
+
+/// A newly created pull request and any follow-up steps that failed
+#[derive(Debug)]
+pub struct CreatedPr {
+    pub pr: PullRequest,
+    /// Non-fatal problems, e.g. reviewers or assignees GitHub rejected
+    pub warnings: Vec<String>,
 }
 
 /// PR list filter state
-#[derive(Debug, Clone, Copy, Default)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub enum PrState {
     #[default]
     Open,
@@ -45,6 +69,26 @@ pub enum PrState {
     All,
 }
 
+impl PrState {
+    /// Next filter in the Open -> Closed -> All cycle
+    pub fn next(self) -> Self {
+        match self {
+            PrState::Open => PrState::Closed,
+            PrState::Closed => PrState::All,
+            PrState::All => PrState::Open,
+        }
+    }
+
+    /// Human-readable label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            PrState::Open => "Open",
+            PrState::Closed => "Closed",
+            PrState::All => "All",
+        }
+    }
+}
+
 impl From<PrState> for State {
     fn from(state: PrState) -> Self {
         match state {
@@ -55,7 +99,49 @@ impl From<PrState> for State {
     }
 }
 
-/// Reaction type for PR comments (main 4 reactions)
+/// Verdict of a pull request review
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReviewEvent {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl ReviewEvent {
+    /// All review events in picker order
+    pub fn all() -> [ReviewEvent; 3] {
+        [
+            ReviewEvent::Approve,
+            ReviewEvent::RequestChanges,
+            ReviewEvent::Comment,
+        ]
+    }
+
+    /// Human-readable label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => "Approve",
+            ReviewEvent::RequestChanges => "Request changes",
+            ReviewEvent::Comment => "Comment",
+        }
+    }
+
+    /// Event value expected by the reviews API
+    fn api_value(self) -> &'static str {
+        match self {
+            ReviewEvent::Approve => "APPROVE",
+            ReviewEvent::RequestChanges => "REQUEST_CHANGES",
+            ReviewEvent::Comment => "COMMENT",
+        }
+    }
+
+    /// Whether GitHub rejects this event without a body
+    pub fn requires_body(self) -> bool {
+        self != ReviewEvent::Approve
+    }
+}
+
+/// Reaction type for PRs and their comments (all 8 GitHub reactions)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub enum ReactionType {
     /// 👍 thumbs up
@@ -70,6 +156,18 @@ pub enum ReactionType {
     /// 🎉 hooray/tada
     #[serde(rename = "hooray")]
     Hooray,
+    /// 😄 laugh
+    #[serde(rename = "laugh")]
+    Laugh,
+    /// 😕 confused
+    #[serde(rename = "confused")]
+    Confused,
+    /// 🚀 rocket
+    #[serde(rename = "rocket")]
+    Rocket,
+    /// 👀 eyes
+    #[serde(rename = "eyes")]
+    Eyes,
 }
 
 impl ReactionType {
@@ -80,16 +178,24 @@ impl ReactionType {
             ReactionType::ThumbsDown => "👎",
             ReactionType::Heart => "❤️",
             ReactionType::Hooray => "🎉",
+            ReactionType::Laugh => "😄",
+            ReactionType::Confused => "😕",
+            ReactionType::Rocket => "🚀",
+            ReactionType::Eyes => "👀",
         }
     }
 
-    /// Get all reaction types
-    pub fn all() -> [ReactionType; 4] {
+    /// Get all reaction types, in picker and summary order
+    pub fn all() -> [ReactionType; 8] {
         [
             ReactionType::ThumbsUp,
             ReactionType::ThumbsDown,
             ReactionType::Heart,
             ReactionType::Hooray,
+            ReactionType::Laugh,
+            ReactionType::Confused,
+            ReactionType::Rocket,
+            ReactionType::Eyes,
         ]
     }
 
@@ -100,6 +206,10 @@ impl ReactionType {
             ReactionType::ThumbsDown => "-1",
             ReactionType::Heart => "heart",
             ReactionType::Hooray => "hooray",
+            ReactionType::Laugh => "laugh",
+            ReactionType::Confused => "confused",
+            ReactionType::Rocket => "rocket",
+            ReactionType::Eyes => "eyes",
         }
     }
 }
@@ -132,31 +242,218 @@ impl Reaction {
     }
 }
 
+/// State of a pull request review
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewState {
+    Approved,
+    ChangesRequested,
+    Commented,
+    Dismissed,
+    Pending,
+    #[serde(other)]
+    Unknown,
+}
+
+impl ReviewState {
+    /// Human-readable label for display
+    pub fn label(self) -> &'static str {
+        match self {
+            ReviewState::Approved => "approved",
+            ReviewState::ChangesRequested => "changes requested",
+            ReviewState::Commented => "commented",
+            ReviewState::Dismissed => "dismissed",
+            ReviewState::Pending => "pending",
+            ReviewState::Unknown => "unknown",
+        }
+    }
+}
+
+/// A review on a pull request
+#[derive(Debug, Clone, Deserialize)]
+pub struct ReviewInfo {
+    /// Unique review ID
+    pub id: u64,
+    /// Reviewer
+    pub user: Option<octocrab::models::Author>,
+    /// Review verdict
+    pub state: ReviewState,
+    /// When the review was submitted (absent while pending)
+    pub submitted_at: Option<DateTime<Utc>>,
+}
+
+impl ReviewInfo {
+    /// Reviewer login, or "ghost" for deleted accounts
+    pub fn reviewer(&self) -> &str {
+        self.user
+            .as_ref()
+            .map(|u| u.login.as_str())
+            .unwrap_or("ghost")
+    }
+}
+
+/// State of a commit status (or of all statuses combined)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum CommitState {
+    Success,
+    Pending,
+    Failure,
+    Error,
+    #[serde(other)]
+    Unknown,
+}
+
+/// A single commit status reported by a CI service
+#[derive(Debug, Clone, Deserialize)]
+pub struct CommitStatusInfo {
+    /// Status name, e.g. "ci/circleci"
+    pub context: String,
+    /// Status state
+    pub state: CommitState,
+}
+
+/// A check run reported by a GitHub App such as GitHub Actions
+#[derive(Debug, Clone, Deserialize)]
+struct CheckRunInfo {
+    name: String,
+    /// "queued", "in_progress" or "completed"
+    status: String,
+    /// Set once the run completed, e.g. "success" or "failure"
+    conclusion: Option<String>,
+}
+
+impl CheckRunInfo {
+    /// The check run as a commit status, so both kinds can be combined
+    fn to_status(&self) -> CommitStatusInfo {
+        let state = match (self.status.as_str(), self.conclusion.as_deref()) {
+            ("completed", Some("success" | "neutral" | "skipped")) => CommitState::Success,
+            ("completed", Some("stale")) | ("completed", None) => CommitState::Pending,
+            ("completed", Some(_)) => CommitState::Failure,
+            _ => CommitState::Pending,
+        };
+        CommitStatusInfo {
+            context: self.name.clone(),
+            state,
+        }
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct CheckRunsResponse {
+    check_runs: Vec<CheckRunInfo>,
+}
+
+/// Combined result of all commit statuses and check runs on a commit
+#[derive(Debug, Clone, Deserialize)]
+pub struct CombinedStatus {
+    /// Overall state (failure if any status failed, pending if any is pending)
+    pub state: CommitState,
+    /// Number of statuses and check runs reported
+    pub total_count: u64,
+    /// Latest status per context, followed by the check runs
+    #[serde(default)]
+    pub statuses: Vec<CommitStatusInfo>,
+}
+
+impl CombinedStatus {
+    /// Add check runs to the commit statuses and recompute the overall state
+    ///
+    /// GitHub reports "pending" for a commit without statuses, so the state
+    /// is derived from the entries: failing wins over pending, which wins
+    /// over success.
+    fn add_check_runs(&mut self, check_runs: &[CheckRunInfo]) {
+        if check_runs.is_empty() {
+            return;
+        }
+        self.statuses
+            .extend(check_runs.iter().map(CheckRunInfo::to_status));
+        self.total_count = self.statuses.len() as u64;
+
+        let has = |state: CommitState| self.statuses.iter().any(|s| s.state == state);
+        self.state = if has(CommitState::Failure) || has(CommitState::Error) {
+            CommitState::Failure
+        } else if has(CommitState::Pending) || has(CommitState::Unknown) {
+            CommitState::Pending
+        } else {
+            CommitState::Success
+        };
+    }
+
+    /// Whether any reported status is failing or still running
+    pub fn is_unsettled(&self) -> bool {
+        self.total_count > 0
+            && matches!(
+                self.state,
+                CommitState::Pending | CommitState::Failure | CommitState::Error
+            )
+    }
+
+    /// Short description for display, e.g. "failing (2/3 passed)"
+    ///
+    /// Commit statuses and check runs are counted together.
+    pub fn summary(&self) -> String {
+        if self.total_count == 0 {
+            return "no checks".to_string();
+        }
+        let passing = self
+            .statuses
+            .iter()
+            .filter(|s| s.state == CommitState::Success)
+            .count();
+        let label = match self.state {
+            CommitState::Success => "passing",
+            CommitState::Pending => "pending",
+            CommitState::Failure | CommitState::Error => "failing",
+            CommitState::Unknown => "unknown",
+        };
+        format!("{} ({}/{} passed)", label, passing, self.total_count)
+    }
+}
+
 /// Pull request operations handler
 pub struct PullRequestHandler<'a> {
     client: &'a GitHubClient,
+    dry_run: bool,
 }
 
 impl<'a> PullRequestHandler<'a> {
     /// Create a new handler
     pub fn new(client: &'a GitHubClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            dry_run: false,
+        }
     }
 
-    /// List pull requests with optional filters
+    /// Report destructive actions as [`GhrustError::DryRun`] instead of performing them
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// List one page of pull requests (pages start at 1) with optional filters
     pub async fn list(
         &self,
         state: PrState,
         author: Option<&str>,
         limit: u8,
+        page: u32,
     ) -> Result<Vec<PullRequest>> {
-        let pulls_handler = self.client.pulls();
-        let prs = pulls_handler
-            .list()
-            .state(state.into())
-            .sort(Sort::Updated)
-            .per_page(limit)
-            .send()
+        let (owner, repo) = (&self.client.owner, &self.client.repo);
+        let prs = self
+            .client
+            .with_retry(|octocrab| async move {
+                octocrab
+                    .pulls(owner, repo)
+                    .list()
+                    .state(state.into())
+                    .sort(Sort::Updated)
+                    .per_page(limit)
+                    .page(page)
+                    .send()
+                    .await
+            })
             .await?;
 
         // Note: octocrab doesn't have direct author filter, we filter client-side
@@ -193,12 +490,69 @@ impl<'a> PullRequestHandler<'a> {
 
     /// Get a specific pull request by number
     pub async fn get(&self, number: u64) -> Result<PullRequest> {
-        let pr = self.client.pulls().get(number).await?;
+        let (owner, repo) = (&self.client.owner, &self.client.repo);
+        self.client
+            .with_retry(|octocrab| async move { octocrab.pulls(owner, repo).get(number).await })
+            .await
+    }
+
+    /// Retarget a pull request to a different base branch
+    pub async fn change_base(&self, number: u64, new_base: &str) -> Result<PullRequest> {
+        let pr = self
+            .client
+            .pulls()
+            .update(number)
+            .base(new_base)
+            .send()
+            .await?;
+        Ok(pr)
+    }
+
+    /// Update a pull request's title and body, leaving its draft state untouched
+    pub async fn update(&self, number: u64, title: &str, body: &str) -> Result<PullRequest> {
+        let pr = self
+            .client
+            .pulls()
+            .update(number)
+            .title(title)
+            .body(body)
+            .send()
+            .await?;
+        Ok(pr)
+    }
+
+    /// Close or reopen a pull request without merging
+    pub async fn set_state(&self, number: u64, state: PrState) -> Result<PullRequest> {
+        use octocrab::params::pulls::State as PullState;
+
+        let (action, new_state) = match state {
+            PrState::Open => ("reopen", PullState::Open),
+            PrState::Closed => ("close", PullState::Closed),
+            PrState::All => {
+                return Err(GhrustError::InvalidInput(
+                    "A PR can only be set to open or closed".to_string(),
+                ))
+            }
+        };
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!("{} PR #{}", action, number)));
+        }
+
+        let pr = self
+            .client
+            .pulls()
+            .update(number)
+            .state(new_state)
+            .send()
+            .await?;
         Ok(pr)
     }
 
     /// Create a new pull request
-    pub async fn create(&self, params: CreatePrParams) -> Result<PullRequest> {
+    ///
+    /// Reviewers and assignees are added after the PR exists; if GitHub rejects
+    /// them the PR is still returned, with the problem reported in `warnings`.
+    pub async fn create(&self, params: CreatePrParams) -> Result<CreatedPr> {
         // Check for existing open PR between these branches
         if let Some(existing) = self.find_existing_pr(&params.head, &params.base).await? {
             let url = existing.html_url.map(|u| u.to_string()).unwrap_or_else(|| {
@@ -228,7 +582,40 @@ impl<'a> PullRequestHandler<'a> {
         }
 
         let pr = builder.send().await?;
-        Ok(pr)
+        let mut warnings = Vec::new();
+
+        if !params.reviewers.is_empty() {
+            if let Err(e) = self
+                .client
+                .pulls()
+                .request_reviews(pr.number, params.reviewers.clone(), Vec::<String>::new())
+                .await
+            {
+                warnings.push(format!(
+                    "Could not request reviewers ({}): {}",
+                    params.reviewers.join(", "),
+                    GhrustError::from(e)
+                ));
+            }
+        }
+
+        if !params.assignees.is_empty() {
+            let assignees: Vec<&str> = params.assignees.iter().map(String::as_str).collect();
+            if let Err(e) = self
+                .client
+                .issues()
+                .add_assignees(pr.number, &assignees)
+                .await
+            {
+                warnings.push(format!(
+                    "Could not assign {}: {}",
+                    params.assignees.join(", "),
+                    GhrustError::from(e)
+                ));
+            }
+        }
+
+        Ok(CreatedPr { pr, warnings })
     }
 
     /// Merge a pull request
@@ -239,6 +626,14 @@ impl<'a> PullRequestHandler<'a> {
         commit_title: Option<&str>,
         commit_message: Option<&str>,
     ) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "merge PR #{} using {}",
+                number,
+                method.label()
+            )));
+        }
+
         let octocrab_method = match method {
             MergeMethod::Merge => octocrab::params::pulls::MergeMethod::Merge,
             MergeMethod::Squash => octocrab::params::pulls::MergeMethod::Squash,
@@ -260,6 +655,153 @@ impl<'a> PullRequestHandler<'a> {
         Ok(())
     }
 
+    /// Submit a review on a pull request
+    ///
+    /// An empty body is only allowed when approving.
+    pub async fn submit_review(&self, number: u64, event: ReviewEvent, body: &str) -> Result<()> {
+        let body = body.trim();
+        if body.is_empty() && event.requires_body() {
+            return Err(GhrustError::InvalidInput(format!(
+                "A review that is '{}' needs a comment",
+                event.label().to_lowercase()
+            )));
+        }
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "submit a '{}' review on PR #{}",
+                event.label().to_lowercase(),
+                number
+            )));
+        }
+
+        let route = format!(
+            "/repos/{}/{}/pulls/{}/reviews",
+            self.client.owner, self.client.repo, number
+        );
+
+        #[derive(Serialize)]
+        struct ReviewRequest<'a> {
+            event: &'static str,
+            #[serde(skip_serializing_if = "str::is_empty")]
+            body: &'a str,
+        }
+
+        let request = ReviewRequest {
+            event: event.api_value(),
+            body,
+        };
+
+        let _: serde_json::Value = self.client.octocrab().post(&route, Some(&request)).await?;
+
+        Ok(())
+    }
+
+    /// Get the combined status of the statuses and check runs reported on a commit
+    ///
+    /// GitHub Actions only reports check runs, which the commit status API
+    /// doesn't include, so both are read and combined.
+    pub async fn combined_status(&self, sha: &str) -> Result<CombinedStatus> {
+        let route = format!(
+            "/repos/{}/{}/commits/{}/status",
+            self.client.owner, self.client.repo, sha
+        );
+        let mut status: CombinedStatus = self.client.octocrab().get(&route, None::<&()>).await?;
+
+        let route = format!(
+            "/repos/{}/{}/commits/{}/check-runs?per_page=100",
+            self.client.owner, self.client.repo, sha
+        );
+        let checks: CheckRunsResponse = self.client.octocrab().get(&route, None::<&()>).await?;
+        status.add_check_runs(&checks.check_runs);
+
+        Ok(status)
+    }
+
+    /// List the current review of each reviewer, in the order they first reviewed
+    ///
+    /// Like GitHub's sidebar, a later plain comment does not replace an approval
+    /// or change request, and pending (unsubmitted) reviews are skipped.
+    pub async fn list_reviews(&self, number: u64) -> Result<Vec<ReviewInfo>> {
+        let route = format!(
+            "/repos/{}/{}/pulls/{}/reviews?per_page=100",
+            self.client.owner, self.client.repo, number
+        );
+
+        let reviews: Vec<ReviewInfo> = self.client.octocrab().get(&route, None::<&()>).await?;
+
+        let mut latest: Vec<ReviewInfo> = Vec::new();
+        for review in reviews {
+            if review.state == ReviewState::Pending {
+                continue;
+            }
+            match latest
+                .iter_mut()
+                .find(|r| r.reviewer() == review.reviewer())
+            {
+                Some(existing) => {
+                    let keeps_verdict = review.state == ReviewState::Commented
+                        && matches!(
+                            existing.state,
+                            ReviewState::Approved | ReviewState::ChangesRequested
+                        );
+                    if !keeps_verdict {
+                        *existing = review;
+                    }
+                }
+                None => latest.push(review),
+            }
+        }
+
+        Ok(latest)
+    }
+
+    /// Enable auto-merge so GitHub merges the PR once its requirements pass
+    ///
+    /// Uses the GraphQL `enablePullRequestAutoMerge` mutation; GitHub's error is
+    /// returned as-is when the repository does not allow auto-merge.
+    pub async fn enable_auto_merge(&self, number: u64, method: MergeMethod) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!(
+                "enable auto-merge ({}) for PR #{}",
+                method.label(),
+                number
+            )));
+        }
+
+        let node_id =
+            self.get(number).await?.node_id.ok_or_else(|| {
+                GhrustError::Custom(format!("PR #{} has no GraphQL node id", number))
+            })?;
+        let merge_method = match method {
+            MergeMethod::Merge => "MERGE",
+            MergeMethod::Squash => "SQUASH",
+            MergeMethod::Rebase => "REBASE",
+        };
+
+        let payload = serde_json::json!({
+            "query": "mutation($id: ID!, $method: PullRequestMergeMethod!) { \
+                enablePullRequestAutoMerge(input: {pullRequestId: $id, mergeMethod: $method}) { \
+                    pullRequest { number } } }",
+            "variables": { "id": node_id, "method": merge_method },
+        });
+
+        let response: serde_json::Value = self.client.octocrab().graphql(&payload).await?;
+
+        // GraphQL reports failures in the body of a successful response
+        if let Some(errors) = response.get("errors").and_then(|e| e.as_array()) {
+            let messages: Vec<&str> = errors
+                .iter()
+                .filter_map(|e| e.get("message").and_then(|m| m.as_str()))
+                .collect();
+            return Err(GhrustError::Custom(format!(
+                "Could not enable auto-merge: {}",
+                messages.join("; ")
+            )));
+        }
+
+        Ok(())
+    }
+
     /// Add a comment to a pull request (uses issues API)
     pub async fn add_comment(&self, number: u64, body: &str) -> Result<Comment> {
         let comment = self.client.issues().create_comment(number, body).await?;
@@ -272,17 +814,28 @@ impl<'a> PullRequestHandler<'a> {
         Ok(comments.items)
     }
 
-    /// Get the diff for a pull request
-    pub async fn get_diff(&self, number: u64) -> Result<String> {
-        // Use the octocrab instance directly for custom media type request
-        let route = format!(
-            "/repos/{}/{}/pulls/{}",
-            self.client.owner, self.client.repo, number
-        );
+    /// List the commit messages of a pull request (oldest first)
+    pub async fn list_commit_messages(&self, number: u64) -> Result<Vec<String>> {
+        let commits = self
+            .client
+            .pulls()
+            .pr_commits(number)
+            .per_page(100u8)
+            .send()
+            .await?;
 
-        let response: String = self.client.octocrab().get(&route, None::<&()>).await?;
+        Ok(commits
+            .items
+            .into_iter()
+            .map(|c| c.commit.message)
+            .collect())
+    }
 
-        Ok(response)
+    /// Get the unified diff for a pull request
+    pub async fn get_diff(&self, number: u64) -> Result<String> {
+        // Requested with the `application/vnd.github.v3.diff` media type
+        let diff = self.client.pulls().get_diff(number).await?;
+        Ok(diff)
     }
 
     /// List reactions on a comment
@@ -322,6 +875,43 @@ impl<'a> PullRequestHandler<'a> {
         Ok(new_reaction)
     }
 
+    /// List reactions on the PR itself (its description)
+    pub async fn list_issue_reactions(&self, number: u64) -> Result<Vec<Reaction>> {
+        let route = format!(
+            "/repos/{}/{}/issues/{}/reactions",
+            self.client.owner, self.client.repo, number
+        );
+
+        let reactions: Vec<Reaction> = self.client.octocrab().get(&route, None::<&()>).await?;
+
+        Ok(reactions)
+    }
+
+    /// Add a reaction to the PR itself (its description)
+    pub async fn add_issue_reaction(
+        &self,
+        number: u64,
+        reaction: ReactionType,
+    ) -> Result<Reaction> {
+        let route = format!(
+            "/repos/{}/{}/issues/{}/reactions",
+            self.client.owner, self.client.repo, number
+        );
+
+        #[derive(Serialize)]
+        struct ReactionRequest {
+            content: &'static str,
+        }
+
+        let body = ReactionRequest {
+            content: reaction.content(),
+        };
+
+        let new_reaction: Reaction = self.client.octocrab().post(&route, Some(&body)).await?;
+
+        Ok(new_reaction)
+    }
+
     /// Delete a reaction from a comment
     pub async fn delete_comment_reaction(&self, comment_id: u64, reaction_id: u64) -> Result<()> {
         let route = format!(
@@ -337,3 +927,70 @@ impl<'a> PullRequestHandler<'a> {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn check_run(status: &str, conclusion: Option<&str>) -> CheckRunInfo {
+        CheckRunInfo {
+            name: "build".to_string(),
+            status: status.to_string(),
+            conclusion: conclusion.map(str::to_string),
+        }
+    }
+
+    /// GitHub's answer for a commit without commit statuses
+    fn no_statuses() -> CombinedStatus {
+        CombinedStatus {
+            state: CommitState::Pending,
+            total_count: 0,
+            statuses: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_check_runs_decide_the_state() {
+        let mut status = no_statuses();
+        status.add_check_runs(&[]);
+        assert!(!status.is_unsettled());
+
+        status.add_check_runs(&[check_run("completed", Some("success"))]);
+        assert_eq!(status.state, CommitState::Success);
+        assert_eq!(status.total_count, 1);
+
+        let mut status = no_statuses();
+        status.add_check_runs(&[
+            check_run("completed", Some("skipped")),
+            check_run("in_progress", None),
+        ]);
+        assert_eq!(status.state, CommitState::Pending);
+        assert!(status.is_unsettled());
+
+        let mut status = no_statuses();
+        status.add_check_runs(&[
+            check_run("in_progress", None),
+            check_run("completed", Some("timed_out")),
+        ]);
+        assert_eq!(status.state, CommitState::Failure);
+    }
+
+    #[test]
+    fn test_summary_counts_statuses_and_check_runs() {
+        assert_eq!(no_statuses().summary(), "no checks");
+
+        let mut status = CombinedStatus {
+            state: CommitState::Success,
+            total_count: 1,
+            statuses: vec![CommitStatusInfo {
+                context: "ci/circleci".to_string(),
+                state: CommitState::Success,
+            }],
+        };
+        status.add_check_runs(&[
+            check_run("completed", Some("success")),
+            check_run("completed", Some("failure")),
+        ]);
+        assert_eq!(status.summary(), "failing (2/3 passed)");
+    }
+}
@@ -1,8 +1,16 @@
 //! Tag operations
 
-use crate::error::Result;
+use std::collections::HashMap;
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::error::{GhrustError, Result};
 use crate::github::client::GitHubClient;
 
+/// Page size used when listing tags, refs and releases
+const PER_PAGE: usize = 100;
+
 /// Information about a remote tag
 #[derive(Debug, Clone)]
 pub struct TagInfo {
@@ -10,46 +18,112 @@ pub struct TagInfo {
     pub name: String,
     /// Commit SHA the tag points to
     pub sha: String,
+    /// Whether the tag is annotated (points to a tag object)
+    pub is_annotated: bool,
+    /// Name of the GitHub release created from this tag, if any
+    pub release_name: Option<String>,
+}
+
+/// Query parameters for paginated list endpoints
+#[derive(Debug, Serialize)]
+struct PageParams {
+    per_page: usize,
+    page: u32,
+}
+
+/// Git ref as returned by the matching-refs API
+#[derive(Debug, Deserialize)]
+struct GitRef {
+    #[serde(rename = "ref")]
+    ref_name: String,
+    object: GitRefObject,
+}
+
+#[derive(Debug, Deserialize)]
+struct GitRefObject {
+    #[serde(rename = "type")]
+    object_type: String,
+}
+
+/// Release entry (only the fields we need)
+#[derive(Debug, Deserialize)]
+struct Release {
+    tag_name: String,
+    name: Option<String>,
 }
 
 /// Tag operations handler
 pub struct TagHandler<'a> {
     client: &'a GitHubClient,
+    dry_run: bool,
 }
 
 impl<'a> TagHandler<'a> {
     /// Create a new handler
     pub fn new(client: &'a GitHubClient) -> Self {
-        Self { client }
+        Self {
+            client,
+            dry_run: false,
+        }
     }
 
-    /// List remote tags
+    /// Report destructive actions as [`GhrustError::DryRun`] instead of performing them
+    pub fn with_dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
+    /// List all remote tags, including annotation and release info
     pub async fn list(&self) -> Result<Vec<TagInfo>> {
         // GitHub API: GET /repos/{owner}/{repo}/tags
-        let route = format!("/repos/{}/{}/tags", self.client.owner, self.client.repo);
+        let tags: Vec<octocrab::models::repos::Tag> = self.get_all_pages("tags").await?;
+
+        // Annotation and release details are best-effort; a missing scope or
+        // disabled releases should not hide the tag list itself
+        let annotated: HashMap<String, bool> = self
+            .get_all_pages::<GitRef>("git/matching-refs/tags")
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|r| {
+                let name = r.ref_name.strip_prefix("refs/tags/")?.to_string();
+                Some((name, r.object.object_type == "tag"))
+            })
+            .collect();
 
-        let tags: Vec<octocrab::models::repos::Tag> =
-            self.client.octocrab().get(&route, None::<&()>).await?;
+        let releases: HashMap<String, String> = self
+            .get_all_pages::<Release>("releases")
+            .await
+            .unwrap_or_default()
+            .into_iter()
+            .map(|r| {
+                let name = r
+                    .name
+                    .filter(|n| !n.is_empty())
+                    .unwrap_or_else(|| r.tag_name.clone());
+                (r.tag_name, name)
+            })
+            .collect();
 
         let tag_infos = tags
             .into_iter()
             .map(|t| TagInfo {
-                name: t.name,
                 sha: t.commit.sha[..7.min(t.commit.sha.len())].to_string(),
+                is_annotated: annotated.get(&t.name).copied().unwrap_or(false),
+                release_name: releases.get(&t.name).cloned(),
+                name: t.name,
             })
             .collect();
 
         Ok(tag_infos)
     }
 
-    /// Check if a tag exists on remote
-    pub async fn exists(&self, name: &str) -> Result<bool> {
-        let tags = self.list().await?;
-        Ok(tags.iter().any(|t| t.name == name))
-    }
-
     /// Delete a remote tag by name
     pub async fn delete(&self, name: &str) -> Result<()> {
+        if self.dry_run {
+            return Err(GhrustError::DryRun(format!("delete remote tag '{}'", name)));
+        }
+
         // GitHub API: DELETE /repos/{owner}/{repo}/git/refs/tags/{tag}
         let route = format!(
             "/repos/{}/{}/git/refs/tags/{}",
@@ -63,4 +137,28 @@ impl<'a> TagHandler<'a> {
 
         Ok(())
     }
+
+    /// Fetch every page of a repository list endpoint
+    async fn get_all_pages<T: DeserializeOwned>(&self, path: &str) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        let mut page: u32 = 1;
+
+        loop {
+            let route = format!("/repos/{}/{}/{}", self.client.owner, self.client.repo, path);
+            let params = PageParams {
+                per_page: PER_PAGE,
+                page,
+            };
+            let batch: Vec<T> = self.client.octocrab().get(&route, Some(&params)).await?;
+            let done = batch.len() < PER_PAGE;
+            items.extend(batch);
+
+            if done {
+                break;
+            }
+            page += 1;
+        }
+
+        Ok(items)
+    }
 }
@@ -1,12 +1,14 @@
 //! GitHub Actions workflow operations
 
 use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
 
-use crate::error::Result;
+use crate::error::{GhrustError, Result};
 use crate::github::client::GitHubClient;
 
 /// Status of a workflow run
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum WorkflowRunStatus {
     Queued,
     InProgress,
@@ -40,7 +42,8 @@ impl std::fmt::Display for WorkflowRunStatus {
 }
 
 /// Conclusion of a completed workflow run
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum WorkflowConclusion {
     Success,
     Failure,
@@ -84,8 +87,8 @@ pub struct WorkflowRunInfo {
     pub conclusion: Option<WorkflowConclusion>,
     /// Branch name
     pub head_branch: String,
-    /// Short commit SHA (first 7 chars)
-    pub head_sha_short: String,
+    /// Full commit SHA
+    pub head_sha: String,
     /// When the run started
     pub created_at: DateTime<Utc>,
     /// When the run was last updated
@@ -99,17 +102,85 @@ pub struct WorkflowRunInfo {
 }
 
 impl WorkflowRunInfo {
+    /// Short commit SHA (first 7 chars) for display
+    pub fn head_sha_short(&self) -> &str {
+        self.head_sha.get(..7).unwrap_or(&self.head_sha)
+    }
+
     /// Calculate duration string (e.g., "2m 35s")
     pub fn duration_string(&self) -> String {
-        let duration = if self.status.is_active() {
+        format_duration(self.duration())
+    }
+
+    /// Duration in whole seconds (runs still active count up to now)
+    pub fn duration_seconds(&self) -> u64 {
+        self.duration().num_seconds().max(0) as u64
+    }
+
+    fn duration(&self) -> chrono::Duration {
+        if self.status.is_active() {
             Utc::now().signed_duration_since(self.created_at)
         } else {
             self.updated_at.signed_duration_since(self.created_at)
+        }
+    }
+}
+
+/// Simplified info about one job of a workflow run
+#[derive(Debug, Clone)]
+pub struct WorkflowJobInfo {
+    /// Job ID
+    pub id: u64,
+    /// Job name
+    pub name: String,
+    /// Current status
+    pub status: WorkflowRunStatus,
+    /// Conclusion (if completed)
+    pub conclusion: Option<WorkflowConclusion>,
+    /// When the job started running
+    pub started_at: Option<DateTime<Utc>>,
+    /// When the job finished
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+impl WorkflowJobInfo {
+    /// Calculate duration string (e.g., "2m 35s"), or "-" if not started
+    pub fn duration_string(&self) -> String {
+        let Some(started_at) = self.started_at else {
+            return "-".to_string();
         };
-        format_duration(duration)
+        let end = match self.completed_at {
+            Some(completed_at) if !self.status.is_active() => completed_at,
+            _ => Utc::now(),
+        };
+        format_duration(end.signed_duration_since(started_at))
     }
 }
 
+/// Jobs page as returned by the run jobs API
+#[derive(Debug, Deserialize)]
+struct JobsResponse {
+    jobs: Vec<JobResponse>,
+}
+
+/// Job fields used from the run jobs API (statuses are kept as strings so
+/// values newer than this client still parse)
+#[derive(Debug, Deserialize)]
+struct JobResponse {
+    id: u64,
+    name: String,
+    status: String,
+    conclusion: Option<String>,
+    started_at: Option<DateTime<Utc>>,
+    completed_at: Option<DateTime<Utc>>,
+}
+
+/// Query parameters for the run jobs API
+#[derive(Debug, Serialize)]
+struct JobsParams {
+    per_page: u8,
+}
+
 fn format_duration(duration: chrono::Duration) -> String {
     let secs = duration.num_seconds().max(0);
     if secs < 60 {
@@ -165,25 +236,32 @@ impl<'a> WorkflowHandler<'a> {
     pub async fn list_runs(
         &self,
         branch: Option<&str>,
+        actor: Option<&str>,
         status: Option<&str>,
         limit: u8,
     ) -> Result<Vec<WorkflowRunInfo>> {
-        let workflows = self
+        let (owner, repo) = (&self.client.owner, &self.client.repo);
+        let runs = self
             .client
-            .octocrab()
-            .workflows(&self.client.owner, &self.client.repo);
+            .with_retry(|octocrab| async move {
+                let workflows = octocrab.workflows(owner, repo);
+                let mut builder = workflows.list_all_runs();
 
-        let mut builder = workflows.list_all_runs();
+                if let Some(branch) = branch {
+                    builder = builder.branch(branch);
+                }
 
-        if let Some(branch) = branch {
-            builder = builder.branch(branch);
-        }
+                if let Some(actor) = actor {
+                    builder = builder.actor(actor);
+                }
 
-        if let Some(status) = status {
-            builder = builder.status(status);
-        }
+                if let Some(status) = status {
+                    builder = builder.status(status);
+                }
 
-        let runs = builder.per_page(limit).send().await?;
+                builder.per_page(limit).send().await
+            })
+            .await?;
 
         let run_infos = runs
             .items
@@ -195,7 +273,7 @@ impl<'a> WorkflowHandler<'a> {
                 status: parse_status(&run.status),
                 conclusion: run.conclusion.as_deref().map(parse_conclusion),
                 head_branch: run.head_branch,
-                head_sha_short: run.head_sha.chars().take(7).collect(),
+                head_sha: run.head_sha,
                 created_at: run.created_at,
                 updated_at: run.updated_at,
                 event: run.event,
@@ -223,7 +301,7 @@ impl<'a> WorkflowHandler<'a> {
             status: parse_status(&run.status),
             conclusion: run.conclusion.as_deref().map(parse_conclusion),
             head_branch: run.head_branch,
-            head_sha_short: run.head_sha.chars().take(7).collect(),
+            head_sha: run.head_sha,
             created_at: run.created_at,
             updated_at: run.updated_at,
             event: run.event,
@@ -231,4 +309,169 @@ impl<'a> WorkflowHandler<'a> {
             html_url: run.html_url.to_string(),
         })
     }
+
+    /// List the jobs of a workflow run's latest attempt
+    pub async fn list_jobs(&self, run_id: u64) -> Result<Vec<WorkflowJobInfo>> {
+        let route = format!(
+            "/repos/{}/{}/actions/runs/{}/jobs",
+            self.client.owner, self.client.repo, run_id
+        );
+        let params = JobsParams { per_page: 100 };
+        let response: JobsResponse = self.client.octocrab().get(&route, Some(&params)).await?;
+
+        Ok(response
+            .jobs
+            .into_iter()
+            .map(|job| WorkflowJobInfo {
+                id: job.id,
+                name: job.name,
+                status: parse_status(&job.status),
+                conclusion: job.conclusion.as_deref().map(parse_conclusion),
+                started_at: job.started_at,
+                completed_at: job.completed_at,
+            })
+            .collect())
+    }
+
+    /// Re-run every job of a completed workflow run
+    pub async fn rerun(&self, run_id: u64) -> Result<()> {
+        self.post_run_action(run_id, "rerun").await
+    }
+
+    /// Re-run only the failed jobs (and their dependents) of a workflow run
+    pub async fn rerun_failed_jobs(&self, run_id: u64) -> Result<()> {
+        self.post_run_action(run_id, "rerun-failed-jobs").await
+    }
+
+    /// Cancel a queued or in-progress workflow run
+    pub async fn cancel(&self, run_id: u64) -> Result<()> {
+        self.post_run_action(run_id, "cancel").await
+    }
+
+    /// POST to an action endpoint of a run; these reply with an empty body
+    async fn post_run_action(&self, run_id: u64, action: &str) -> Result<()> {
+        let route = format!(
+            "/repos/{}/{}/actions/runs/{}/{}",
+            self.client.owner, self.client.repo, run_id, action
+        );
+
+        let response = self.client.octocrab()._post(route, None::<&()>).await?;
+        octocrab::map_github_error(response).await?;
+
+        Ok(())
+    }
+
+    /// Download a run's logs as one text, with a header line per job
+    ///
+    /// GitHub serves the logs as a zip archive holding one file per job at the
+    /// top level (plus per-step copies in subdirectories, which are skipped).
+    pub async fn download_logs(&self, run_id: u64) -> Result<String> {
+        let archive = self
+            .client
+            .octocrab()
+            .actions()
+            .download_workflow_run_logs(&self.client.owner, &self.client.repo, run_id.into())
+            .await?;
+
+        let mut entries = read_zip_entries(&archive)?;
+        let has_job_logs = entries.iter().any(|(name, _)| !name.contains('/'));
+        entries.retain(|(name, _)| !name.ends_with('/') && (!has_job_logs || !name.contains('/')));
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let mut logs = String::new();
+        for (name, data) in entries {
+            let job = name.trim_end_matches(".txt");
+            logs.push_str(&format!("── {} ──\n", job));
+            logs.push_str(&String::from_utf8_lossy(&data));
+            if !logs.ends_with('\n') {
+                logs.push('\n');
+            }
+        }
+
+        Ok(logs)
+    }
+}
+
+/// Largest total of uncompressed log data read from an archive
+const MAX_LOG_BYTES: u64 = 256 * 1024 * 1024;
+
+/// Read every file of a zip archive as `(name, contents)`
+fn read_zip_entries(archive: &[u8]) -> Result<Vec<(String, Vec<u8>)>> {
+    use std::io::Read;
+
+    let invalid =
+        |e: zip::result::ZipError| GhrustError::GitHubApi(format!("Invalid log archive: {}", e));
+
+    let mut zip = zip::ZipArchive::new(std::io::Cursor::new(archive)).map_err(invalid)?;
+    let mut entries = Vec::with_capacity(zip.len());
+    let mut remaining = MAX_LOG_BYTES;
+
+    for i in 0..zip.len() {
+        let file = zip.by_index(i).map_err(invalid)?;
+        let name = file.name().to_string();
+
+        // Read one byte past the budget to tell a full budget from an overrun
+        let mut contents = Vec::new();
+        file.take(remaining + 1).read_to_end(&mut contents)?;
+        remaining = remaining
+            .checked_sub(contents.len() as u64)
+            .ok_or_else(|| GhrustError::GitHubApi("Log archive is too large".to_string()))?;
+
+        entries.push((name, contents));
+    }
+
+    Ok(entries)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    /// Build a zip archive from `(name, contents, deflate)` entries
+    fn zip(files: &[(&str, &[u8], bool)]) -> Vec<u8> {
+        let mut writer = zip::ZipWriter::new(std::io::Cursor::new(Vec::new()));
+
+        for (name, contents, deflate) in files {
+            let method = if *deflate {
+                zip::CompressionMethod::Deflated
+            } else {
+                zip::CompressionMethod::Stored
+            };
+            let options = zip::write::SimpleFileOptions::default().compression_method(method);
+            writer.start_file(*name, options).unwrap();
+            writer.write_all(contents).unwrap();
+        }
+
+        writer.finish().unwrap().into_inner()
+    }
+
+    #[test]
+    fn test_read_zip_entries() {
+        let build_log = "Run cargo build\n".repeat(50);
+        let archive = zip(&[
+            ("0_build.txt", build_log.as_bytes(), true),
+            ("build/1_Checkout.txt", b"checkout\n", false),
+        ]);
+
+        let entries = read_zip_entries(&archive).unwrap();
+        assert_eq!(
+            entries,
+            vec![
+                ("0_build.txt".to_string(), build_log.into_bytes()),
+                ("build/1_Checkout.txt".to_string(), b"checkout\n".to_vec()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_read_zip_entries_rejects_invalid_data() {
+        assert!(read_zip_entries(b"not a zip").is_err());
+
+        // Cut short before the central directory the end record points at
+        let archive = zip(&[("0_build.txt", b"hello", false)]);
+        let mut truncated = archive[..35].to_vec();
+        truncated.extend_from_slice(&archive[archive.len() - 22..]);
+        assert!(read_zip_entries(&truncated).is_err());
+    }
 }
@@ -10,33 +10,65 @@ use std::io::{self, Write};
 use clap::Parser;
 use tracing_subscriber::EnvFilter;
 
-use argo_rs::cli::commands::{AuthCommand, Cli, Commands};
+use argo_rs::cli::commands::{AuthCommand, Cli, Commands, OutputFormat};
 use argo_rs::cli::{auth, branch, commit, config, pr, push, tag, update, workflow};
+use argo_rs::core::config::Config;
 use argo_rs::core::git::GitRepository;
 use argo_rs::core::repository::RepositoryContext;
 use argo_rs::core::update::{cleanup_partial_downloads, UpdatePersistentState};
 use argo_rs::core::update_checker::apply_pending_update;
 use argo_rs::error::{GhrustError, Result};
+use argo_rs::tui::theme::no_color_requested;
 use argo_rs::tui::App;
 
 #[tokio::main]
 async fn main() {
-    // Initialize logging
-    let filter = EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn"));
-
-    tracing_subscriber::fmt().with_env_filter(filter).init();
-
-    if let Err(e) = run().await {
-        handle_error(e).await;
-        std::process::exit(1);
+    let cli = Cli::parse();
+    let format = cli.format;
+
+    // Initialize logging; -q / -v take precedence over RUST_LOG
+    let filter = match cli.log_level() {
+        Some(level) => EnvFilter::new(level),
+        None => EnvFilter::try_from_default_env().unwrap_or_else(|_| EnvFilter::new("warn")),
+    };
+
+    // Log to stderr so verbose output doesn't mix with command output
+    tracing_subscriber::fmt()
+        .with_env_filter(filter)
+        .with_writer(io::stderr)
+        .init();
+
+    tracing::debug!("Running command: {:?}", cli.command);
+
+    match run(cli).await {
+        Ok(()) => {}
+        // A dry run reports every action it skipped; that is a success
+        Err(GhrustError::DryRun(action)) if format == OutputFormat::Json => {
+            println!(
+                "{}",
+                serde_json::json!({ "dry_run": true, "action": action })
+            );
+        }
+        Err(GhrustError::DryRun(action)) => {
+            println!("[dry-run] Would {}. Nothing was changed.", action);
+        }
+        Err(e) if format == OutputFormat::Json => {
+            eprintln!("{}", e.to_json());
+            std::process::exit(e.exit_code());
+        }
+        Err(e) => {
+            let code = e.exit_code();
+            handle_error(e).await;
+            std::process::exit(code);
+        }
     }
 }
 
 /// Handle errors with special cases for org authorization
 async fn handle_error(e: GhrustError) {
     match &e {
-        // GitHub API error that might be org-related (404/Not Found)
-        GhrustError::GitHubApi(msg) if is_repo_not_found(msg) => {
+        // A 404 that might be org-related
+        GhrustError::GitHubNotFound(_) => {
             if let Ok(ctx) = RepositoryContext::detect() {
                 eprintln!();
                 eprintln!("Cannot access '{}/{}'.", ctx.owner, ctx.name);
@@ -106,14 +138,7 @@ async fn offer_pat_auth() {
     }
 }
 
-/// Check if error message indicates repo not found
-fn is_repo_not_found(msg: &str) -> bool {
-    msg.contains("not found") || msg.contains("Not Found") || msg.contains("404")
-}
-
-async fn run() -> Result<()> {
-    let cli = Cli::parse();
-
+async fn run(cli: Cli) -> Result<()> {
     // Try to apply any pending update before doing anything else
     // (silent failure - don't block normal operation)
     if let Ok(true) = apply_pending_update() {
@@ -124,9 +149,12 @@ async fn run() -> Result<()> {
     // Clean up any partial downloads from interrupted sessions
     let _ = cleanup_partial_downloads();
 
+    // Dry-run can be enabled per invocation or persistently in config
+    let dry_run = cli.dry_run || Config::load().map(|c| c.dry_run).unwrap_or_default();
+
     match cli.command {
         // No subcommand - launch TUI mode
-        None => run_tui().await,
+        None => run_tui(dry_run, cli.no_color || no_color_requested()).await,
 
         // Auth commands don't require git repository
         Some(Commands::Auth(args)) => auth::handle_auth(args.command).await,
@@ -146,11 +174,11 @@ async fn run() -> Result<()> {
             spawn_background_update_check();
 
             match command {
-                Commands::Pr(args) => pr::handle_pr(args.command).await,
-                Commands::Branch(args) => branch::handle_branch(args.command).await,
-                Commands::Tag(args) => tag::handle_tag(args.command).await,
-                Commands::Commit(args) => commit::handle_commit(args).await,
-                Commands::Push(args) => push::handle_push(args).await,
+                Commands::Pr(args) => pr::handle_pr(args.command, dry_run).await,
+                Commands::Branch(args) => branch::handle_branch(args.command, dry_run).await,
+                Commands::Tag(args) => tag::handle_tag(args.command, dry_run).await,
+                Commands::Commit(args) => commit::handle_commit(args, dry_run).await,
+                Commands::Push(args) => push::handle_push(args, dry_run).await,
                 Commands::Workflow(args) => workflow::handle_workflow(args.command).await,
                 Commands::Auth(_) | Commands::Config(_) | Commands::Update(_) => unreachable!(),
             }
@@ -169,15 +197,21 @@ fn spawn_background_update_check() {
 }
 
 /// Run the TUI application
-async fn run_tui() -> Result<()> {
+async fn run_tui(dry_run: bool, no_color: bool) -> Result<()> {
     // Check for git repository
     ensure_git_repository()?;
 
-    // Detect repository context
-    let repo_context = RepositoryContext::detect()?;
-
-    // Create and run the TUI app
-    let mut app = App::new().with_repository(repo_context);
+    // Create and run the TUI app; GitHub screens are disabled without a GitHub remote
+    let mut app = match RepositoryContext::detect_optional()? {
+        Some(repo_context) => App::new().with_repository(repo_context),
+        None => App::new(),
+    };
+    if dry_run {
+        app.dry_run = true;
+    }
+    if no_color {
+        app.theme = app.theme.without_color();
+    }
     app.run().await
 }
 
@@ -5,7 +5,10 @@ use std::collections::HashMap;
 use std::io::{self, Stdout};
 use std::time::Duration;
 
-use crossterm::event::{KeyCode, KeyEvent};
+use crossterm::event::{
+    DisableMouseCapture, EnableMouseCapture, KeyCode, KeyEvent, MouseButton, MouseEvent,
+    MouseEventKind,
+};
 use crossterm::execute;
 use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
@@ -13,29 +16,45 @@ use crossterm::terminal::{
 use octocrab::models::pulls::PullRequest;
 use ratatui::prelude::*;
 use ratatui::Terminal;
+use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
+use tokio::task::AbortHandle;
 
-use crate::ai::GeminiClient;
-use crate::core::config::{Config, GeminiModel};
+use crate::ai::{AiClient, PrContent};
+use crate::core::branch_name;
+use crate::core::config::{is_protected_branch, AiBackend, Config, GeminiModel, QKeyBehavior};
 use crate::core::credentials::CredentialStore;
-use crate::core::git::{FileStatus, GitRepository};
+use crate::core::git::{self, FileStatus, GitRepository};
 use crate::core::repository::RepositoryContext;
-use crate::error::{GhrustError, Result};
+use crate::error::{DryRunPlan, GhrustError, Result};
 use crate::github::branch::{BranchHandler, BranchInfo};
-use crate::github::client::GitHubClient;
+use crate::github::client::{GitHubClient, RateLimit};
 use crate::github::pull_request::{
-    CreatePrParams, MergeMethod, PrState, PullRequestHandler, Reaction, ReactionType,
+    CombinedStatus, CreatePrParams, MergeMethod, PrState, PullRequestHandler, Reaction,
+    ReactionType, ReviewEvent, ReviewInfo,
+};
+use crate::github::workflow::{
+    WorkflowConclusion, WorkflowHandler, WorkflowJobInfo, WorkflowRunInfo, WorkflowRunStatus,
 };
-use crate::github::workflow::{WorkflowHandler, WorkflowRunInfo};
+use crate::tui::diff::{DiffIndex, DiffJump};
+use crate::tui::editor::{self, EditorTarget};
 use crate::tui::event::{is_back_key, is_quit_key, AppEvent, EventHandler};
-use crate::tui::split_lines_preserve_trailing;
+use crate::tui::session::SessionState;
+use crate::tui::text_input;
+use crate::tui::theme::Theme;
 use crate::tui::ui;
 
 /// Message type for async operation results
 #[derive(Debug)]
 pub enum AsyncMessage {
     /// PR list loaded successfully
-    PrListLoaded(Vec<PullRequest>),
+    PrListLoaded {
+        prs: Vec<PullRequest>,
+        /// State filter the list was fetched with
+        state: PrState,
+        /// Page that was fetched (1 replaces the list, later pages append)
+        page: u32,
+    },
     /// PR list load failed
     PrListError(String),
     /// Single PR loaded
@@ -43,23 +62,47 @@ pub enum AsyncMessage {
     /// PR load failed
     PrError(String),
     /// Authentication status checked
-    AuthStatus { github: bool, gemini: bool },
+    AuthStatus { github: bool, ai: bool },
     /// Branches loaded for PR creation
     BranchesLoaded(Vec<BranchInfo>),
     /// Branch loading failed
     BranchesError(String),
     /// PR created successfully
-    PrCreated(Box<PullRequest>),
+    PrCreated {
+        pr: Box<PullRequest>,
+        /// Non-fatal problems, e.g. reviewers GitHub rejected
+        warnings: Vec<String>,
+    },
     /// PR creation failed
     PrCreateError(String),
     /// AI-generated PR content
-    AiContentGenerated { title: String, body: String },
+    AiContentGenerated {
+        request: u64,
+        content: PrContent,
+        /// Cache key of the inputs the content was generated from
+        key: u64,
+        /// Reused from the cache instead of requested
+        cached: bool,
+    },
     /// AI content generation failed
-    AiContentError(String),
-    /// AI-generated commit message
-    AiCommitMessageGenerated(String),
+    AiContentError { request: u64, error: String },
+    /// Partial AI commit message text, appended while the response streams in
+    AiCommitMessageChunk { request: u64, chunk: String },
+    /// AI-generated commit message (marks the end of streaming)
+    AiCommitMessageGenerated {
+        request: u64,
+        message: String,
+        /// Cache key of the inputs the message was generated from
+        key: u64,
+        /// Reused from the cache instead of requested
+        cached: bool,
+    },
     /// AI commit message generation failed
-    AiCommitMessageError(String),
+    AiCommitMessageError { request: u64, error: String },
+    /// AI-suggested branch name for the new branch prompt
+    AiBranchNameGenerated { request: u64, name: String },
+    /// AI branch name suggestion failed
+    AiBranchNameError { request: u64, error: String },
     /// Push completed successfully
     PushCompleted(String), // tracking branch name
     /// Push failed
@@ -73,13 +116,45 @@ pub enum AsyncMessage {
         runs: Vec<WorkflowRunInfo>,
         /// Run ID to restore selection to (for silent auto-refresh)
         preserve_selection_id: Option<u64>,
+        /// Login used for the "my runs" filter, if it was applied
+        actor: Option<String>,
     },
     /// Workflow runs load failed
     WorkflowRunsError(String),
+    /// Re-run of a workflow run requested
+    WorkflowRerun { run_id: u64, failed_only: bool },
+    /// Re-run request failed
+    WorkflowRerunError(String),
+    /// Jobs of a workflow run loaded
+    WorkflowJobsLoaded {
+        run_id: u64,
+        jobs: Vec<WorkflowJobInfo>,
+    },
+    /// Workflow run jobs load failed
+    WorkflowJobsError { run_id: u64, error: String },
+    /// API rate limit fetched for the status bar
+    RateLimitLoaded(RateLimit),
+    /// Cancellation of a workflow run requested
+    WorkflowCancelled(u64),
+    /// Cancellation request failed
+    WorkflowCancelError(String),
+    /// Logs of a workflow run downloaded
+    WorkflowLogsLoaded { run_id: u64, logs: String },
+    /// Workflow run logs download failed
+    WorkflowLogsError { run_id: u64, error: String },
     /// PR comments loaded
     PrCommentsLoaded(Vec<octocrab::models::issues::Comment>),
     /// PR comments load failed
     PrCommentsError(String),
+    /// Combined commit status of a PR's head commit loaded
+    PrCombinedStatusLoaded { number: u64, status: CombinedStatus },
+    /// PR reviews loaded (latest review per reviewer)
+    PrReviewsLoaded {
+        number: u64,
+        reviews: Vec<ReviewInfo>,
+    },
+    /// PR reviews load failed
+    PrReviewsError { number: u64, error: String },
     /// PR comment added successfully
     PrCommentAdded(Box<octocrab::models::issues::Comment>),
     /// PR comment add failed
@@ -95,8 +170,14 @@ pub enum AsyncMessage {
         comment_id: u64,
         reaction: Box<Reaction>,
     },
+    /// Reactions on the PR description loaded
+    PrReactionsLoaded(Vec<Reaction>),
+    /// Reaction added to the PR description
+    PrReactionAdded(Box<Reaction>),
     /// Reaction add failed
     ReactionAddError(String),
+    /// Authenticated user's login resolved
+    CurrentUserLoaded(String),
     /// Reaction removed from a comment
     ReactionRemoved { comment_id: u64, reaction_id: u64 },
     /// Reaction remove failed
@@ -109,6 +190,40 @@ pub enum AsyncMessage {
     PrMerged(u64),
     /// PR merge failed
     PrMergeError(String),
+    /// Branches loaded for the base branch picker
+    BaseBranchesLoaded(Vec<BranchInfo>),
+    /// PR base branch changed
+    PrBaseChanged { number: u64, base: String },
+    /// PR base branch change failed
+    PrBaseChangeError(String),
+    /// PR diff loaded
+    PrDiffLoaded { number: u64, diff: String },
+    /// PR diff load failed
+    PrDiffError { number: u64, error: String },
+    /// PR closed or reopened
+    PrStateChanged(u64),
+    /// PR close/reopen failed
+    PrStateChangeError(String),
+    /// Auto-merge enabled for a PR
+    AutoMergeEnabled(u64),
+    /// Enabling auto-merge failed
+    AutoMergeError(String),
+    /// PR review submitted
+    ReviewSubmitted { number: u64, event: ReviewEvent },
+    /// PR review submission failed
+    ReviewError(String),
+    /// PR title/body updated
+    PrUpdated(Box<PullRequest>),
+    /// PR title/body update failed
+    PrUpdateError(String),
+    /// AI-generated squash commit body for PR `number`
+    AiSquashMessageGenerated {
+        request: u64,
+        number: u64,
+        message: String,
+    },
+    /// AI squash commit body generation failed
+    AiSquashMessageError { request: u64, error: String },
 
     // ─────────────────────────────────────────────────────────────────────────
     // Tag messages
@@ -116,7 +231,7 @@ pub enum AsyncMessage {
     /// Tags loaded successfully
     TagsLoaded {
         local_tags: Vec<crate::core::git::LocalTagInfo>,
-        remote_tags: Vec<String>,
+        remote_tags: Vec<crate::github::TagInfo>,
     },
     /// Tags load failed
     TagsError(String),
@@ -132,6 +247,20 @@ pub enum AsyncMessage {
     TagPushed(String),
     /// Tag push failed
     TagPushError(String),
+    /// A destructive action was skipped because dry-run mode is on
+    DryRun(String),
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Branch messages
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Switched to a branch (`created` when it was made with `n`)
+    BranchCheckedOut { name: String, created: bool },
+    /// Checkout or branch creation failed
+    BranchCheckoutError { error: String, created: bool },
+    /// Local branch deleted
+    BranchDeleted(String),
+    /// Local branch deletion failed
+    BranchDeleteError(String),
 
     // ─────────────────────────────────────────────────────────────────────────
     // Update messages
@@ -152,19 +281,41 @@ pub enum AsyncMessage {
 }
 
 /// Current screen in the TUI
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Screen {
+    #[default]
     Dashboard,
     PrList,
     PrDetail(u64),
+    PrDiff(u64),
     PrCreate,
     Commit,
     Tags,
+    Branches,
     Settings,
     Auth,
     WorkflowRuns,
+    WorkflowLogs(u64),
 }
 
+/// Number of items PageUp/PageDown move in list screens
+const LIST_PAGE_SIZE: usize = 10;
+
+/// Most workflow log lines kept in memory; older lines are dropped
+const WORKFLOW_LOG_MAX_LINES: usize = 10_000;
+
+/// Workflow poll intervals (seconds) offered on the settings screen
+const WORKFLOW_POLL_SECONDS: [u8; 6] = [2, 5, 7, 15, 30, 60];
+
+/// Largest factor the workflow poll interval is stretched by after API errors
+const WORKFLOW_POLL_MAX_BACKOFF: u32 = 16;
+
+/// Milliseconds between ticks of the event loop
+const TICK_RATE_MS: u64 = 250;
+
+/// PR list page sizes offered on the settings screen
+const PR_LIST_PAGE_SIZES: [u8; 4] = [10, 30, 50, 100];
+
 /// List selection state
 #[derive(Debug, Default)]
 pub struct ListState {
@@ -190,6 +341,41 @@ impl ListState {
             self.selected = self.selected.checked_sub(1).unwrap_or(self.total - 1);
         }
     }
+
+    /// Move down by `n` items, wrapping past the end
+    pub fn next_by(&mut self, n: usize) {
+        if self.total > 0 {
+            self.selected = (self.selected + n % self.total) % self.total;
+        }
+    }
+
+    /// Move up by `n` items, wrapping past the start
+    pub fn previous_by(&mut self, n: usize) {
+        if self.total > 0 {
+            self.selected = (self.selected + self.total - n % self.total) % self.total;
+        }
+    }
+
+    /// Select the first item
+    pub fn first(&mut self) {
+        self.selected = 0;
+    }
+
+    /// Select the last item (the first one when the list is empty)
+    pub fn last(&mut self) {
+        self.selected = self.total.saturating_sub(1);
+    }
+
+    /// Apply a PageUp/PageDown/Home/End key; other keys are ignored
+    pub fn handle_jump_key(&mut self, code: KeyCode) {
+        match code {
+            KeyCode::PageDown => self.next_by(LIST_PAGE_SIZE),
+            KeyCode::PageUp => self.previous_by(LIST_PAGE_SIZE),
+            KeyCode::Home => self.first(),
+            KeyCode::End => self.last(),
+            _ => {}
+        }
+    }
 }
 
 /// Error popup for displaying important errors that require user acknowledgment
@@ -224,6 +410,53 @@ impl FileGroup {
     }
 }
 
+/// Group files by directory, sorted by directory name
+///
+/// Groups that already exist in `previous` keep their expansion state.
+fn group_files(files: &[FileStatus], previous: &[FileGroup]) -> Vec<FileGroup> {
+    use std::collections::BTreeMap;
+
+    let mut groups: BTreeMap<String, Vec<FileStatus>> = BTreeMap::new();
+    for file in files {
+        groups
+            .entry(file.directory())
+            .or_default()
+            .push(file.clone());
+    }
+
+    let was_expanded: HashMap<&str, bool> = previous
+        .iter()
+        .map(|g| (g.directory.as_str(), g.expanded))
+        .collect();
+
+    groups
+        .into_iter()
+        .map(|(directory, files)| FileGroup {
+            expanded: *was_expanded.get(directory.as_str()).unwrap_or(&true),
+            directory,
+            files,
+        })
+        .collect()
+}
+
+/// File at a grouped selection, as shown on the commit screen
+fn grouped_file(
+    groups: &[FileGroup],
+    group_idx: usize,
+    file_idx: Option<usize>,
+) -> Option<&FileStatus> {
+    groups.get(group_idx)?.files.get(file_idx?)
+}
+
+/// Add a reaction unless it is already listed
+///
+/// GitHub answers a repeated reaction with the existing one, same id.
+fn push_reaction(reactions: &mut Vec<Reaction>, reaction: Reaction) {
+    if !reactions.iter().any(|r| r.id == reaction.id) {
+        reactions.push(reaction);
+    }
+}
+
 /// Push mode for commit screen - controls push prompt UI
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum PushMode {
@@ -236,6 +469,60 @@ pub enum PushMode {
     NewBranch,
 }
 
+/// Last AI results, keyed by a hash of their input, to skip identical requests
+#[derive(Debug, Default)]
+pub struct AiResultCache {
+    /// Generated commit message for a staged diff and branch
+    commit: Option<(u64, String)>,
+    /// Generated PR content for a branch diff and branch pair
+    pr: Option<(u64, PrContent)>,
+    /// A commit message is being generated; cached when it arrives unless
+    /// the inputs changed in the meantime
+    pending_commit: bool,
+    /// PR content is being generated; cached when it arrives unless the
+    /// inputs changed in the meantime
+    pending_pr: bool,
+}
+
+impl AiResultCache {
+    /// Hash the inputs of a generation, including the prompt settings
+    pub fn key(parts: &[&str]) -> u64 {
+        use std::hash::{Hash, Hasher};
+
+        let mut hasher = std::collections::hash_map::DefaultHasher::new();
+        parts.hash(&mut hasher);
+        hasher.finish()
+    }
+
+    /// Cached commit message for a key
+    pub fn commit_message(&self, key: u64) -> Option<&String> {
+        self.commit
+            .as_ref()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, message)| message)
+    }
+
+    /// Cached PR content for a key
+    pub fn pr_content(&self, key: u64) -> Option<&PrContent> {
+        self.pr
+            .as_ref()
+            .filter(|(k, _)| *k == key)
+            .map(|(_, content)| content)
+    }
+
+    /// Forget the commit message (staged files changed)
+    pub fn invalidate_commit(&mut self) {
+        self.commit = None;
+        self.pending_commit = false;
+    }
+
+    /// Forget the PR content (branches changed)
+    pub fn invalidate_pr(&mut self) {
+        self.pr = None;
+        self.pending_pr = false;
+    }
+}
+
 /// Main TUI application
 pub struct App {
     /// Whether the app is running
@@ -246,6 +533,14 @@ pub struct App {
     pub navigation_stack: Vec<Screen>,
     /// Repository context
     pub repository: Option<RepositoryContext>,
+    /// Commits ahead of / behind the tracking branch, refreshed every few ticks
+    pub branch_ahead_behind: Option<(usize, usize)>,
+    /// Tick of the last ahead/behind refresh (`None` refreshes on the next tick)
+    pub branch_status_last_tick: Option<u64>,
+    /// Core API quota shown in the status bar, refreshed every minute
+    pub api_rate_limit: Option<RateLimit>,
+    /// Tick of the last rate-limit request (`None` fetches on the next tick)
+    pub rate_limit_last_tick: Option<u64>,
     /// Dashboard menu selection
     pub dashboard_selection: ListState,
     /// PR list selection
@@ -254,6 +549,38 @@ pub struct App {
     pub status_message: Option<String>,
     /// Whether to show the help overlay
     pub show_help: bool,
+    /// What `q` does on the dashboard (from config)
+    pub q_behavior: QKeyBehavior,
+    /// Whether the quit confirmation prompt is shown
+    pub quit_prompt_open: bool,
+    /// Field to open in the external editor on the next loop iteration
+    pub pending_editor: Option<EditorTarget>,
+    /// Render HTML in markdown instead of stripping it (from config)
+    pub render_html: bool,
+    /// Report merges, pushes and deletions instead of performing them
+    pub dry_run: bool,
+    /// Number of pull requests fetched per page in the PR list (from config)
+    pub pr_list_page_size: u8,
+    /// Seconds between workflow run refreshes while a run is active (from config)
+    pub workflow_poll_seconds: u8,
+    /// Whether AI commit messages use the custom prompt template (from config)
+    pub use_commit_prompt_template: bool,
+    /// Whether list selections are restored when returning to a screen (from config)
+    pub remember_selection: bool,
+    /// Whether the mouse is captured for clicking and scrolling (from config)
+    pub mouse_enabled: bool,
+    /// Color theme (from config)
+    pub theme: Theme,
+    /// Inner area of the PR list at last render (for mouse clicks)
+    pub pr_list_area: Cell<Rect>,
+    /// Inner area of the commit file list at last render (for mouse clicks)
+    pub commit_files_area: Cell<Rect>,
+    /// Inner area of the workflow runs list at last render (for mouse clicks)
+    pub workflow_runs_area: Cell<Rect>,
+    /// Job rows shown under the selected workflow run at last render
+    pub workflow_job_rows: Cell<usize>,
+    /// Last selected list index per screen, restored after reloads
+    pub selection_memory: HashMap<Screen, usize>,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Async communication
@@ -274,12 +601,28 @@ pub struct App {
     pub pr_list_fetched: bool,
     /// Error message if PR list failed to load
     pub pr_list_error: Option<String>,
+    /// Last page of pull requests loaded into the list
+    pub pr_list_page: u32,
+    /// Whether GitHub may have more pages after `pr_list_page`
+    pub pr_list_has_more: bool,
+    /// Whether the next page is being fetched (the list stays visible)
+    pub pr_list_loading_more: bool,
+    /// State filter applied to the PR list (cycled with 'f')
+    pub pr_list_state_filter: PrState,
+    /// Whether the PR list search box is capturing input
+    pub pr_list_search_mode: bool,
+    /// Search query filtering the PR list by title or author
+    pub pr_list_search_query: String,
 
     // ─────────────────────────────────────────────────────────────────────────
     // PR Detail data
     // ─────────────────────────────────────────────────────────────────────────
     /// Currently selected PR details
     pub selected_pr: Option<PullRequest>,
+    /// PR number and body from the last load, to detect description edits
+    pub pr_last_seen_body: Option<(u64, Option<String>)>,
+    /// Whether the description changed since the PR was first loaded
+    pub pr_description_edited: bool,
     /// Whether PR detail is loading
     pub pr_detail_loading: bool,
     /// Scroll position for PR detail
@@ -290,6 +633,14 @@ pub struct App {
     pub pr_comments_loading: bool,
     /// PR comments error
     pub pr_comments_error: Option<String>,
+    /// Combined commit status of the current PR's head commit
+    pub pr_combined_status: Option<CombinedStatus>,
+    /// Latest review of each reviewer on the current PR
+    pub pr_reviews: Vec<ReviewInfo>,
+    /// PR whose reviews are loading
+    pub pr_reviews_loading: Option<u64>,
+    /// Error message if PR reviews failed to load
+    pub pr_reviews_error: Option<String>,
     /// Selection state for comments list
     pub pr_comments_selection: ListState,
     /// Whether viewing expanded comment
@@ -306,15 +657,19 @@ pub struct App {
     pub pr_description_expanded: bool,
     /// Scroll position within expanded PR description
     pub pr_description_scroll: usize,
+    /// Whether `<details>` sections are expanded in the description overlay
+    pub pr_description_details_expanded: bool,
     /// Maximum scroll position for expanded comment (updated during render)
     pub pr_comment_max_scroll: Cell<usize>,
     /// Maximum scroll position for expanded description (updated during render)
     pub pr_description_max_scroll: Cell<usize>,
     /// Reactions per comment (comment_id -> reactions)
     pub pr_comment_reactions: HashMap<u64, Vec<Reaction>>,
+    /// Reactions on the PR description itself
+    pub pr_reactions: Vec<Reaction>,
     /// Whether reaction picker is open
     pub reaction_picker_open: bool,
-    /// Selected reaction in picker (0-3 for the 4 reaction types)
+    /// Selected reaction in picker (0-7 for the 8 reaction types)
     pub reaction_picker_selection: usize,
     /// Whether a reaction is being submitted
     pub reaction_submitting: bool,
@@ -334,14 +689,94 @@ pub struct App {
     pub merge_delete_branch: bool,
     /// Whether merge is in progress
     pub merge_in_progress: bool,
+    /// Focused part of the merge dialog: 0=method/options, 1=commit title, 2=commit message
+    pub merge_dialog_field: usize,
+    /// Commit title for merge and squash merges (empty = GitHub default)
+    pub merge_commit_title: String,
+    /// Commit message for merge and squash merges (empty = GitHub default)
+    pub merge_commit_message: String,
+    /// Cursor position in the commit message (row, col)
+    pub merge_commit_message_cursor: (usize, usize),
+    /// Whether the squash commit body is being generated
+    pub merge_ai_loading: bool,
+    /// Whether the user pressed Enter once despite failing or pending checks
+    pub merge_force_confirmed: bool,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR base branch picker
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Whether the base branch picker is open
+    pub base_picker_open: bool,
+    /// Candidate base branches (excluding the PR's head and current base)
+    pub base_picker_branches: Vec<String>,
+    /// Selection in the base branch picker
+    pub base_picker_selection: ListState,
+    /// Whether branches are loading or the base change is being submitted
+    pub base_picker_loading: bool,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR edit (title/body)
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Whether the PR edit form is open
+    pub pr_edit_open: bool,
+    /// Focused field: 0=title, 1=body
+    pub pr_edit_field: usize,
+    /// Title being edited
+    pub pr_edit_title: String,
+    /// Body being edited
+    pub pr_edit_body: String,
+    /// Cursor position in the body (row, col)
+    pub pr_edit_body_cursor: (usize, usize),
+    /// Whether the update is being submitted
+    pub pr_edit_submitting: bool,
+    /// Whether a close/reopen request is in flight
+    pub pr_state_changing: bool,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR review dialog
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Whether the review dialog is open
+    pub review_dialog_open: bool,
+    /// Selected review event (index into `ReviewEvent::all()`)
+    pub review_event_selection: usize,
+    /// Review body being typed
+    pub review_body: String,
+    /// Whether the review is being submitted
+    pub review_submitting: bool,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // PR diff
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Unified diff of the PR being viewed
+    pub pr_diff: Option<String>,
+    /// File and hunk offsets within the diff
+    pub pr_diff_index: DiffIndex,
+    /// Number of lines in the diff
+    pub pr_diff_line_count: usize,
+    /// PR whose diff is loading, if any
+    pub pr_diff_loading: Option<u64>,
+    /// Error message if the diff failed to load
+    pub pr_diff_error: Option<String>,
+    /// Scroll offset (first visible line)
+    pub pr_diff_scroll: usize,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Auth/Settings data
     // ─────────────────────────────────────────────────────────────────────────
     /// GitHub authentication status
     pub github_authenticated: bool,
-    /// Gemini API key configured
-    pub gemini_configured: bool,
+    /// API key configured for the active AI provider
+    pub ai_configured: bool,
+    /// AI provider selected in config
+    pub ai_provider: AiBackend,
+    /// Model of the active AI provider (OpenAI/Anthropic; Gemini uses `gemini_model`)
+    pub ai_model_name: String,
+    /// Handle of the in-flight AI generation, aborted when cancelled with Esc
+    pub ai_task: Option<AbortHandle>,
+    /// Id of the latest AI request; bumped on cancel so late results are dropped
+    pub ai_request: u64,
+    /// Last generated commit message and PR content
+    pub ai_cache: AiResultCache,
     /// Settings selection
     pub settings_selection: ListState,
     /// Whether we're in input mode for settings
@@ -350,6 +785,10 @@ pub struct App {
     pub settings_api_key_input: String,
     /// Current Gemini model selection
     pub gemini_model: GeminiModel,
+    /// Model name used when `gemini_model` is `Custom`
+    pub gemini_custom_model: Option<String>,
+    /// Input buffer for the custom model name (editing while `settings_input_mode` is on row 2)
+    pub settings_model_input: String,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Commit screen data
@@ -362,8 +801,28 @@ pub struct App {
     pub commit_message_mode: bool,
     /// The commit message being typed
     pub commit_message: String,
+    /// Undo/redo snapshots of the commit message
+    pub commit_message_history: text_input::UndoHistory,
+    /// Comment lines from the commit template, shown as hints while typing
+    pub commit_template_hints: Vec<String>,
     /// Whether AI is generating a commit message
     pub commit_ai_loading: bool,
+    /// Streamed AI text has started replacing the commit message
+    pub commit_ai_streaming: bool,
+    /// Files with unresolved merge conflicts (commits are blocked until resolved)
+    pub commit_conflicts: Vec<String>,
+    /// File awaiting confirmation to discard its changes (path, is untracked)
+    pub commit_discard_confirm: Option<(String, bool)>,
+    /// Whether the amend confirmation prompt is shown
+    pub commit_amend_confirm: bool,
+    /// File whose hunks are listed for hunk-level staging (`None` shows files)
+    pub commit_hunk_path: Option<String>,
+    /// Staged and unstaged hunks of `commit_hunk_path`
+    pub commit_hunks: Vec<crate::core::git::FileHunk>,
+    /// Hunk list selection
+    pub commit_hunk_selection: ListState,
+    /// Whether the commit message input amends HEAD instead of committing
+    pub commit_amend_mode: bool,
     /// Whether showing push confirmation prompt after commit
     pub commit_push_prompt: bool,
     /// Whether push is in progress
@@ -382,6 +841,18 @@ pub struct App {
     pub commit_file_scroll: usize,
     /// Viewport height for commit file list (updated during render)
     pub commit_viewport_height: Cell<usize>,
+    /// File shown in the diff preview pane
+    pub commit_diff_path: Option<String>,
+    /// Diff previews keyed by path (cleared when the file list refreshes)
+    pub commit_diff_cache: HashMap<String, String>,
+    /// Scroll offset of the diff preview pane
+    pub commit_diff_scroll: u16,
+    /// Whether j/k scroll the diff preview instead of moving through files (toggled with Tab)
+    pub commit_diff_focused: bool,
+    /// Configured protected branches (empty = repository default branch)
+    pub protected_branches: Vec<String>,
+    /// Protected branch the user already confirmed acting on (one-shot)
+    pub protected_branch_confirmed: Option<String>,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Push branch selection
@@ -410,6 +881,10 @@ pub struct App {
     pub pr_create_base: String,
     /// Create as draft PR
     pub pr_create_draft: bool,
+    /// Comma-separated reviewers to request
+    pub pr_create_reviewers: String,
+    /// Comma-separated users to assign
+    pub pr_create_assignees: String,
     /// Available branches for selection
     pub pr_create_branches: Vec<BranchInfo>,
     /// Whether branches are loading
@@ -428,10 +903,22 @@ pub struct App {
     pub pr_create_body_cursor: (usize, usize),
     /// Body text scroll offset
     pub pr_create_body_scroll: usize,
+    /// Body text width at last render (for moving the cursor by displayed line)
+    pub pr_create_body_width: Cell<usize>,
+    /// Undo/redo snapshots of the body
+    pub pr_create_body_history: text_input::UndoHistory,
+    /// Show the body rendered as markdown instead of the raw editor
+    pub pr_create_body_preview: bool,
     /// Whether AI content is being generated
     pub pr_create_ai_loading: bool,
+    /// Guidance for regenerating the AI title/body (e.g. "make it shorter")
+    pub pr_create_ai_feedback: String,
+    /// Whether the AI feedback prompt is open
+    pub pr_create_feedback_open: bool,
     /// Commits between head and base branches for display
     pub pr_create_commits: Vec<String>,
+    /// Whether the commit range between head and base could be resolved locally
+    pub pr_create_commits_resolved: bool,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Workflow Runs data
@@ -452,22 +939,66 @@ pub struct App {
     pub workflow_runs_last_poll_tick: u64,
     /// Branch filter for workflow runs (set when viewing from PR detail)
     pub pr_workflow_branch: Option<String>,
+    /// Whether to only show runs triggered by the authenticated user
+    pub workflow_runs_mine_only: bool,
+    /// Login of the authenticated user (fetched at startup)
+    pub current_user: Option<String>,
+    /// Factor the poll interval is multiplied by; doubles on each failed load
+    pub workflow_poll_backoff_multiplier: u32,
+    /// Only show runs with this conclusion (client-side)
+    pub workflow_runs_conclusion_filter: Option<WorkflowConclusion>,
+    /// Only show runs triggered by this event (client-side)
+    pub workflow_runs_event_filter: Option<String>,
+    /// Whether the selected run's jobs are listed beneath it
+    pub workflow_jobs_shown: bool,
+    /// Loaded jobs by run ID
+    pub workflow_jobs: HashMap<u64, Vec<WorkflowJobInfo>>,
+    /// Run whose jobs are loading
+    pub workflow_jobs_loading: Option<u64>,
+    /// Run whose jobs failed to load, with the error
+    pub workflow_jobs_error: Option<(u64, String)>,
+    /// Run awaiting re-run confirmation (id, run number, has failed jobs)
+    pub workflow_rerun_confirm: Option<(u64, u64, bool)>,
+    /// Whether a re-run request is in flight
+    pub workflow_rerunning: bool,
+    /// Run awaiting cancel confirmation (id, run number)
+    pub workflow_cancel_confirm: Option<(u64, u64)>,
+    /// Whether a cancel request is in flight
+    pub workflow_cancelling: bool,
+    /// Lines of the run shown on the workflow logs screen (the last lines if capped)
+    pub workflow_logs: Vec<String>,
+    /// Run whose logs are downloading
+    pub workflow_logs_loading: Option<u64>,
+    /// Error message if the logs download failed
+    pub workflow_logs_error: Option<String>,
+    /// Number of leading log lines dropped to stay under the cap
+    pub workflow_logs_truncated: usize,
+    /// Scroll offset (in lines) of the workflow logs
+    pub workflow_logs_scroll: usize,
 
     // ─────────────────────────────────────────────────────────────────────────
     // Tags data
     // ─────────────────────────────────────────────────────────────────────────
     /// List of local tags
     pub tags_local: Vec<crate::core::git::LocalTagInfo>,
-    /// List of remote tag names
-    pub tags_remote: Vec<String>,
+    /// List of remote tags (with annotation/release details)
+    pub tags_remote: Vec<crate::github::TagInfo>,
     /// Whether tags are loading
     pub tags_loading: bool,
     /// Whether we've attempted to fetch tags
     pub tags_fetched: bool,
     /// Error message if tags fetch failed
     pub tags_error: Option<String>,
-    /// Tags list selection
+    /// Tags list selection (local tags, then remote-only tags)
     pub tags_selection: ListState,
+    /// Tag awaiting confirmation to be deleted (name, also delete from origin)
+    pub tag_delete_confirm: Option<(String, bool)>,
+    /// Selected local tag and its target commit, while the detail popup is open
+    pub tag_detail: Option<(crate::core::git::LocalTagInfo, crate::core::git::TagTarget)>,
+    /// Scroll position within the tag detail popup
+    pub tag_detail_scroll: usize,
+    /// Maximum scroll position for the tag detail popup (updated during render)
+    pub tag_detail_max_scroll: Cell<usize>,
     /// Tag creation mode active
     pub tag_create_mode: bool,
     /// Tag name being entered
@@ -478,10 +1009,34 @@ pub struct App {
     pub tag_create_message_cursor: (usize, usize),
     /// Current field in tag creation (0=name, 1=message, 2=confirm)
     pub tag_create_field: usize,
+    /// Highest existing semver tag, used to suggest the next tag name
+    pub tag_create_latest: Option<String>,
+    /// Version component bumped for the suggested tag name
+    pub tag_create_bump: crate::core::git::VersionBump,
 
     /// Post-commit tag creation prompt
     pub commit_tag_prompt: bool,
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Branches data
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Local branch names
+    pub branches_local: Vec<String>,
+    /// Branch checked out when the list was loaded
+    pub branches_current: Option<String>,
+    /// Error message if listing branches failed
+    pub branches_error: Option<String>,
+    /// Branch list selection
+    pub branches_selection: ListState,
+    /// Whether a checkout, creation or deletion is running
+    pub branch_switching: bool,
+    /// Branch creation input active
+    pub branch_create_mode: bool,
+    /// Branch name being entered
+    pub branch_create_name: String,
+    /// Branch awaiting confirmation to be deleted
+    pub branch_delete_confirm: Option<String>,
+
     // ─────────────────────────────────────────────────────────────────────────
     // Update state
     // ─────────────────────────────────────────────────────────────────────────
@@ -499,6 +1054,14 @@ pub struct App {
     // ─────────────────────────────────────────────────────────────────────────
     /// Error popup to display (requires user dismissal)
     pub error_popup: Option<ErrorPopup>,
+
+    // ─────────────────────────────────────────────────────────────────────────
+    // Session persistence
+    // ─────────────────────────────────────────────────────────────────────────
+    /// Session saved by the previous run, applied when the event loop starts
+    pub pending_session: Option<SessionState>,
+    /// PR reopened from the previous session, until it is confirmed to still exist
+    pub restored_pr: Option<u64>,
 }
 
 impl App {
@@ -508,17 +1071,42 @@ impl App {
 
         // Check auth status synchronously at startup
         let github_authenticated = CredentialStore::has_github_token().unwrap_or(false);
-        let gemini_configured = CredentialStore::has_gemini_key().unwrap_or(false);
+        let config = Config::load().unwrap_or_default();
+        let ai_configured = CredentialStore::has_ai_key(config.ai_provider).unwrap_or(false);
 
         Self {
             running: true,
             current_screen: Screen::Dashboard,
             navigation_stack: Vec::new(),
             repository: None,
-            dashboard_selection: ListState::new(7), // 7 menu items (including Tags, Workflows)
+            branch_ahead_behind: None,
+            branch_status_last_tick: None,
+            api_rate_limit: None,
+            rate_limit_last_tick: None,
+            dashboard_selection: ListState::new(7), // 7 menu items (including Tags, Branches, Workflows)
             pr_list_selection: ListState::default(),
             status_message: None,
             show_help: false,
+            q_behavior: Config::load().map(|c| c.q_behavior).unwrap_or_default(),
+            quit_prompt_open: false,
+            pending_editor: None,
+            render_html: Config::load().map(|c| c.render_html).unwrap_or_default(),
+            dry_run: Config::load().map(|c| c.dry_run).unwrap_or_default(),
+            use_commit_prompt_template: Config::load()
+                .map(|c| c.use_commit_prompt_template)
+                .unwrap_or_default(),
+            pr_list_page_size: Config::load().map(|c| c.pr_list_page_size()).unwrap_or(30),
+            workflow_poll_seconds: Config::load()
+                .map(|c| c.workflow_poll_seconds())
+                .unwrap_or(7),
+            remember_selection: Config::load().map(|c| c.remember_selection).unwrap_or(true),
+            mouse_enabled: Config::load().map(|c| c.mouse).unwrap_or(true),
+            theme: Theme::from_config(&config),
+            pr_list_area: Cell::new(Rect::default()),
+            commit_files_area: Cell::new(Rect::default()),
+            workflow_runs_area: Cell::new(Rect::default()),
+            workflow_job_rows: Cell::new(0),
+            selection_memory: HashMap::new(),
 
             // Async
             async_tx,
@@ -528,15 +1116,27 @@ impl App {
             pr_list: Vec::new(),
             pr_list_loading: false,
             pr_list_fetched: false,
+            pr_list_page: 0,
+            pr_list_has_more: false,
+            pr_list_loading_more: false,
             pr_list_error: None,
+            pr_list_state_filter: PrState::default(),
+            pr_list_search_mode: false,
+            pr_list_search_query: String::new(),
 
             // PR detail
             selected_pr: None,
+            pr_last_seen_body: None,
+            pr_description_edited: false,
             pr_detail_loading: false,
             pr_detail_scroll: 0,
             pr_comments: Vec::new(),
             pr_comments_loading: false,
             pr_comments_error: None,
+            pr_combined_status: None,
+            pr_reviews: Vec::new(),
+            pr_reviews_loading: None,
+            pr_reviews_error: None,
             pr_comments_selection: ListState::default(),
             pr_comment_expanded: false,
             pr_comment_input_mode: false,
@@ -545,9 +1145,11 @@ impl App {
             pr_comment_scroll: 0,
             pr_description_expanded: false,
             pr_description_scroll: 0,
+            pr_description_details_expanded: false,
             pr_comment_max_scroll: Cell::new(0),
             pr_description_max_scroll: Cell::new(0),
             pr_comment_reactions: HashMap::new(),
+            pr_reactions: Vec::new(),
             reaction_picker_open: false,
             reaction_picker_selection: 0,
             reaction_submitting: false,
@@ -559,21 +1161,73 @@ impl App {
             merge_method_selection: 0,
             merge_delete_branch: false, // Default to NOT deleting branch
             merge_in_progress: false,
+            merge_force_confirmed: false,
+            merge_dialog_field: 0,
+            merge_commit_title: String::new(),
+            merge_commit_message: String::new(),
+            merge_commit_message_cursor: (0, 0),
+            merge_ai_loading: false,
+
+            // PR base branch picker
+            base_picker_open: false,
+            base_picker_branches: Vec::new(),
+            base_picker_selection: ListState::default(),
+            base_picker_loading: false,
+
+            // PR edit
+            pr_edit_open: false,
+            pr_edit_field: 0,
+            pr_edit_title: String::new(),
+            pr_edit_body: String::new(),
+            pr_edit_body_cursor: (0, 0),
+            pr_edit_submitting: false,
+            pr_state_changing: false,
+
+            // PR review dialog
+            review_dialog_open: false,
+            review_event_selection: 0,
+            review_body: String::new(),
+            review_submitting: false,
+
+            // PR diff
+            pr_diff: None,
+            pr_diff_index: DiffIndex::default(),
+            pr_diff_line_count: 0,
+            pr_diff_loading: None,
+            pr_diff_error: None,
+            pr_diff_scroll: 0,
 
             // Auth/Settings
             github_authenticated,
-            gemini_configured,
-            settings_selection: ListState::new(3), // GitHub, Gemini Key, Model
+            ai_configured,
+            ai_provider: config.ai_provider,
+            ai_model_name: config.ai_model_name().to_string(),
+            ai_task: None,
+            ai_request: 0,
+            ai_cache: AiResultCache::default(),
+            settings_selection: ListState::new(8), // GitHub, Gemini Key, Model, Commit prompt, HTML, Page size, Poll, Dry run
             settings_input_mode: false,
             settings_api_key_input: String::new(),
             gemini_model: Config::load().map(|c| c.gemini_model).unwrap_or_default(),
+            gemini_custom_model: Config::load().ok().and_then(|c| c.gemini_custom_model),
+            settings_model_input: String::new(),
 
             // Commit screen
             changed_files: Vec::new(),
             commit_file_selection: ListState::default(),
             commit_message_mode: false,
             commit_message: String::new(),
+            commit_message_history: text_input::UndoHistory::default(),
+            commit_template_hints: Vec::new(),
             commit_ai_loading: false,
+            commit_ai_streaming: false,
+            commit_conflicts: Vec::new(),
+            commit_discard_confirm: None,
+            commit_amend_confirm: false,
+            commit_hunk_path: None,
+            commit_hunks: Vec::new(),
+            commit_hunk_selection: ListState::default(),
+            commit_amend_mode: false,
             commit_push_prompt: false,
             commit_push_loading: false,
             last_commit_hash: None,
@@ -583,6 +1237,14 @@ impl App {
             selected_file_in_group: None,
             commit_file_scroll: 0,
             commit_viewport_height: Cell::new(0),
+            commit_diff_path: None,
+            commit_diff_cache: HashMap::new(),
+            commit_diff_scroll: 0,
+            commit_diff_focused: false,
+            protected_branches: Config::load()
+                .map(|c| c.protected_branches)
+                .unwrap_or_default(),
+            protected_branch_confirmed: None,
 
             // Push branch selection
             push_mode: PushMode::Simple,
@@ -597,6 +1259,8 @@ impl App {
             pr_create_head: String::new(),
             pr_create_base: String::new(),
             pr_create_draft: false,
+            pr_create_reviewers: String::new(),
+            pr_create_assignees: String::new(),
             pr_create_branches: Vec::new(),
             pr_create_loading: false,
             pr_create_submitting: false,
@@ -606,8 +1270,14 @@ impl App {
             pr_create_base_selection: ListState::default(),
             pr_create_body_cursor: (0, 0),
             pr_create_body_scroll: 0,
+            pr_create_body_width: Cell::new(0),
+            pr_create_body_history: text_input::UndoHistory::default(),
+            pr_create_body_preview: false,
             pr_create_ai_loading: false,
+            pr_create_ai_feedback: String::new(),
+            pr_create_feedback_open: false,
             pr_create_commits: Vec::new(),
+            pr_create_commits_resolved: false,
 
             // Workflow runs
             workflow_runs: Vec::new(),
@@ -618,6 +1288,24 @@ impl App {
             tick_counter: 0,
             workflow_runs_last_poll_tick: 0,
             pr_workflow_branch: None,
+            workflow_runs_mine_only: false,
+            workflow_poll_backoff_multiplier: 1,
+            workflow_runs_conclusion_filter: None,
+            workflow_runs_event_filter: None,
+            workflow_jobs_shown: false,
+            workflow_jobs: HashMap::new(),
+            workflow_jobs_loading: None,
+            workflow_jobs_error: None,
+            workflow_rerun_confirm: None,
+            workflow_rerunning: false,
+            workflow_cancel_confirm: None,
+            workflow_cancelling: false,
+            workflow_logs: Vec::new(),
+            workflow_logs_loading: None,
+            workflow_logs_error: None,
+            workflow_logs_truncated: 0,
+            workflow_logs_scroll: 0,
+            current_user: None,
 
             // Tags
             tags_local: Vec::new(),
@@ -626,12 +1314,26 @@ impl App {
             tags_fetched: false,
             tags_error: None,
             tags_selection: ListState::default(),
+            tag_delete_confirm: None,
+            tag_detail: None,
+            tag_detail_scroll: 0,
+            tag_detail_max_scroll: Cell::new(0),
             tag_create_mode: false,
             tag_create_name: String::new(),
             tag_create_message: String::new(),
             tag_create_message_cursor: (0, 0),
             tag_create_field: 0,
+            tag_create_latest: None,
+            tag_create_bump: crate::core::git::VersionBump::Patch,
             commit_tag_prompt: false,
+            branches_local: Vec::new(),
+            branches_current: None,
+            branches_error: None,
+            branches_selection: ListState::default(),
+            branch_switching: false,
+            branch_create_mode: false,
+            branch_create_name: String::new(),
+            branch_delete_confirm: None,
 
             // Update state
             update_state: crate::core::UpdateState::Idle,
@@ -641,6 +1343,10 @@ impl App {
 
             // Error popup
             error_popup: None,
+
+            // Session persistence
+            pending_session: SessionState::load().ok(),
+            restored_pr: None,
         }
     }
 
@@ -651,10 +1357,14 @@ impl App {
     }
 
     /// Setup terminal for TUI
-    fn setup_terminal() -> Result<Terminal<CrosstermBackend<Stdout>>> {
+    fn setup_terminal(mouse: bool) -> Result<Terminal<CrosstermBackend<Stdout>>> {
         enable_raw_mode().map_err(|e| GhrustError::Terminal(e.to_string()))?;
         let mut stdout = io::stdout();
         execute!(stdout, EnterAlternateScreen).map_err(|e| GhrustError::Terminal(e.to_string()))?;
+        if mouse {
+            execute!(stdout, EnableMouseCapture)
+                .map_err(|e| GhrustError::Terminal(e.to_string()))?;
+        }
         let backend = CrosstermBackend::new(stdout);
         let terminal = Terminal::new(backend).map_err(|e| GhrustError::Terminal(e.to_string()))?;
         Ok(terminal)
@@ -663,18 +1373,37 @@ impl App {
     /// Restore terminal to normal state
     fn restore_terminal(terminal: &mut Terminal<CrosstermBackend<Stdout>>) -> Result<()> {
         disable_raw_mode().map_err(|e| GhrustError::Terminal(e.to_string()))?;
-        execute!(terminal.backend_mut(), LeaveAlternateScreen)
-            .map_err(|e| GhrustError::Terminal(e.to_string()))?;
+        execute!(
+            terminal.backend_mut(),
+            DisableMouseCapture,
+            LeaveAlternateScreen
+        )
+        .map_err(|e| GhrustError::Terminal(e.to_string()))?;
         terminal
             .show_cursor()
             .map_err(|e| GhrustError::Terminal(e.to_string()))?;
         Ok(())
     }
 
+    /// Restore the terminal before a panic message is printed, so a crash
+    /// doesn't leave the shell in raw mode with mouse capture on
+    fn install_panic_hook() {
+        let default_hook = std::panic::take_hook();
+        std::panic::set_hook(Box::new(move |info| {
+            let _ = disable_raw_mode();
+            let _ = execute!(io::stdout(), DisableMouseCapture, LeaveAlternateScreen);
+            default_hook(info);
+        }));
+    }
+
     /// Run the TUI application
     pub async fn run(&mut self) -> Result<()> {
-        let mut terminal = Self::setup_terminal()?;
-        let mut events = EventHandler::new(Duration::from_millis(250));
+        Self::install_panic_hook();
+        let mut terminal = Self::setup_terminal(self.mouse_enabled)?;
+        let mut events = EventHandler::new(Duration::from_millis(TICK_RATE_MS));
+
+        self.restore_session();
+        self.fetch_current_user();
 
         // Main event loop
         while self.running {
@@ -689,10 +1418,29 @@ impl App {
                 .draw(|frame| ui::render(frame, self))
                 .map_err(|e| GhrustError::Terminal(e.to_string()))?;
 
+            // Hand off to an external editor if a key requested it
+            if let Some(target) = self.pending_editor.take() {
+                // Stop reading keys so the editor gets the keyboard
+                drop(events);
+                tokio::time::sleep(Duration::from_millis(60)).await;
+                Self::restore_terminal(&mut terminal)?;
+
+                let result = editor::edit_text(&self.editor_initial_text(target), target);
+
+                terminal = Self::setup_terminal(self.mouse_enabled)?;
+                terminal
+                    .clear()
+                    .map_err(|e| GhrustError::Terminal(e.to_string()))?;
+                events = EventHandler::new(Duration::from_millis(TICK_RATE_MS));
+                self.apply_editor_result(target, result);
+                continue;
+            }
+
             // Handle events THIRD
             if let Some(event) = events.next().await {
                 match event {
                     AppEvent::Key(key) => self.handle_key_event(key),
+                    AppEvent::Mouse(mouse) => self.handle_mouse_event(mouse),
                     AppEvent::Resize(_, _) => {
                         // Terminal resize is handled automatically by ratatui
                     }
@@ -703,6 +1451,12 @@ impl App {
                         // Check if we should auto-poll workflow runs
                         self.maybe_poll_workflow_runs();
 
+                        // Keep the status bar's ahead/behind counts current
+                        self.maybe_refresh_branch_status();
+
+                        // Keep the API quota indicator current
+                        self.maybe_refresh_rate_limit();
+
                         // Check for updates on first tick (only once per session)
                         if !self.update_check_triggered {
                             self.spawn_update_check();
@@ -716,27 +1470,104 @@ impl App {
         Ok(())
     }
 
+    /// Current text for the field being handed off to the editor
+    fn editor_initial_text(&self, target: EditorTarget) -> String {
+        match target {
+            EditorTarget::PrBody => self.pr_create_body.clone(),
+            EditorTarget::PrEditBody => self.pr_edit_body.clone(),
+            EditorTarget::CommitMessage => {
+                // Include template hints as comments; they're stripped on return
+                let mut text = self.commit_message.clone();
+                for hint in &self.commit_template_hints {
+                    text.push_str(&format!("\n# {}", hint));
+                }
+                text
+            }
+        }
+    }
+
+    /// Store text returned from the editor in the field it was opened for
+    fn apply_editor_result(&mut self, target: EditorTarget, result: Result<String>) {
+        let text = match result {
+            Ok(text) => text,
+            Err(e) => {
+                self.status_message = Some(format!("Editor failed: {}", e));
+                return;
+            }
+        };
+
+        match target {
+            EditorTarget::PrBody => {
+                self.pr_create_body_history.record(&self.pr_create_body);
+                self.pr_create_body_cursor = text_input::cursor_at_end(&text);
+                self.pr_create_body = text;
+            }
+            EditorTarget::PrEditBody => {
+                self.pr_edit_body_cursor = text_input::cursor_at_end(&text);
+                self.pr_edit_body = text;
+            }
+            EditorTarget::CommitMessage => {
+                self.commit_message_history.record(&self.commit_message);
+                self.commit_message = text
+                    .lines()
+                    .filter(|l| !l.starts_with('#'))
+                    .collect::<Vec<_>>()
+                    .join("\n")
+                    .trim()
+                    .to_string();
+                self.commit_template_hints.clear();
+            }
+        }
+        self.status_message = Some("Updated from editor".to_string());
+    }
+
     /// Handle async message from background tasks
     fn handle_async_message(&mut self, msg: AsyncMessage) {
         match msg {
-            AsyncMessage::PrListLoaded(prs) => {
+            AsyncMessage::PrListLoaded { prs, state, page } => {
+                // The filter changed while this request was in flight; fetch again
+                if state != self.pr_list_state_filter {
+                    self.pr_list_loading = false;
+                    self.pr_list_loading_more = false;
+                    if page == 1 {
+                        self.fetch_pr_list();
+                    }
+                    return;
+                }
+
+                if page > 1 {
+                    self.append_pr_list_page(prs, page);
+                    return;
+                }
+
                 // Debug: log fetch results to help diagnose intermittent empty results
                 #[cfg(debug_assertions)]
                 eprintln!("[DEBUG] PR list loaded: {} PRs", prs.len());
 
+                self.pr_list_has_more = !prs.is_empty();
+                self.pr_list_page = 1;
                 self.pr_list = prs;
                 self.pr_list_loading = false;
                 self.pr_list_fetched = true;
                 self.pr_list_error = None;
-                self.pr_list_selection = ListState::new(self.pr_list.len());
+                self.pr_list_selection = ListState::new(self.filtered_pr_list().len());
+                self.restore_selection(Screen::PrList);
                 if self.pr_list.is_empty() {
-                    self.status_message = Some("No open pull requests".to_string());
+                    self.status_message =
+                        Some(format!("No {} pull requests", state.label().to_lowercase()));
                 } else {
                     self.status_message =
                         Some(format!("Loaded {} pull requests", self.pr_list.len()));
                 }
             }
             AsyncMessage::PrListError(err) => {
+                if self.pr_list_loading_more {
+                    // Keep the pages already loaded and report the failure
+                    self.pr_list_loading_more = false;
+                    self.status_message = Some(format!("Failed to load more: {}", err));
+                    return;
+                }
+
                 self.pr_list_loading = false;
                 self.pr_list_fetched = true;
 
@@ -768,19 +1599,45 @@ impl App {
                 }
             }
             AsyncMessage::PrLoaded(pr) => {
+                self.restored_pr = None;
+                // Flag description edits made since this PR was last loaded
+                self.pr_description_edited = match &self.pr_last_seen_body {
+                    Some((number, body)) if *number == pr.number => {
+                        self.pr_description_edited || *body != pr.body
+                    }
+                    _ => false,
+                };
+                self.pr_last_seen_body = Some((pr.number, pr.body.clone()));
+                // Keep the CI status of the same PR until its refresh arrives
+                if self.selected_pr.as_ref().map(|p| p.number) != Some(pr.number) {
+                    self.pr_combined_status = None;
+                }
                 self.selected_pr = Some(*pr);
                 self.pr_detail_loading = false;
                 self.pr_detail_scroll = 0;
-                // Now that PR is loaded, fetch workflow runs for this PR
+                // Now that PR is loaded, fetch workflow runs and CI status for this PR
                 self.fetch_pr_workflow_runs();
+                self.fetch_pr_combined_status();
             }
             AsyncMessage::PrError(err) => {
                 self.pr_detail_loading = false;
+                // A PR reopened from the last session may have been deleted or moved
+                if let Some(number) = self.restored_pr.take() {
+                    if self.current_screen == Screen::PrDetail(number) {
+                        self.navigation_stack.clear();
+                        self.current_screen = Screen::Dashboard;
+                        self.status_message = Some(format!(
+                            "PR #{} from the last session is unavailable",
+                            number
+                        ));
+                        return;
+                    }
+                }
                 self.status_message = Some(format!("Error: {}", err));
             }
-            AsyncMessage::AuthStatus { github, gemini } => {
+            AsyncMessage::AuthStatus { github, ai } => {
                 self.github_authenticated = github;
-                self.gemini_configured = gemini;
+                self.ai_configured = ai;
             }
             AsyncMessage::BranchesLoaded(branches) => {
                 self.pr_create_branches = branches;
@@ -804,9 +1661,13 @@ impl App {
                 self.pr_create_error = Some(err.clone());
                 self.status_message = Some(format!("Error loading branches: {}", err));
             }
-            AsyncMessage::PrCreated(pr) => {
+            AsyncMessage::PrCreated { pr, warnings } => {
                 self.pr_create_submitting = false;
-                self.status_message = Some(format!("PR #{} created successfully!", pr.number));
+                self.status_message = Some(if warnings.is_empty() {
+                    format!("PR #{} created successfully!", pr.number)
+                } else {
+                    format!("PR #{} created. {}", pr.number, warnings.join("; "))
+                });
                 // Navigate to the new PR detail
                 self.selected_pr = Some(*pr.clone());
                 self.current_screen = Screen::PrDetail(pr.number);
@@ -819,28 +1680,97 @@ impl App {
                     message: err,
                 });
             }
-            AsyncMessage::AiContentGenerated { title, body } => {
+            // A request cancelled with Esc may still have sent its result
+            AsyncMessage::AiContentGenerated { request, .. }
+            | AsyncMessage::AiContentError { request, .. }
+            | AsyncMessage::AiCommitMessageChunk { request, .. }
+            | AsyncMessage::AiCommitMessageGenerated { request, .. }
+            | AsyncMessage::AiCommitMessageError { request, .. }
+            | AsyncMessage::AiBranchNameGenerated { request, .. }
+            | AsyncMessage::AiBranchNameError { request, .. }
+            | AsyncMessage::AiSquashMessageGenerated { request, .. }
+            | AsyncMessage::AiSquashMessageError { request, .. }
+                if request != self.ai_request => {}
+            AsyncMessage::AiContentGenerated {
+                content,
+                key,
+                cached,
+                ..
+            } => {
                 self.pr_create_ai_loading = false;
-                self.pr_create_title = title;
-                self.pr_create_body = body;
-                self.status_message = Some("AI generated title and description".to_string());
+                if std::mem::take(&mut self.ai_cache.pending_pr) && !cached {
+                    self.ai_cache.pr = Some((key, content.clone()));
+                }
+                self.pr_create_title = content.title;
+                self.pr_create_body_history.record(&self.pr_create_body);
+                self.pr_create_body_cursor = text_input::cursor_at_end(&content.body);
+                self.pr_create_body = content.body;
+                self.status_message = Some(if cached {
+                    "Using cached AI result (diff unchanged)".to_string()
+                } else {
+                    "AI generated title and description".to_string()
+                });
             }
-            AsyncMessage::AiContentError(err) => {
+            AsyncMessage::AiContentError { error, .. } => {
                 self.pr_create_ai_loading = false;
-                self.pr_create_error = Some(err.clone());
-                self.status_message = Some(format!("AI generation failed: {}", err));
+                self.ai_cache.pending_pr = false;
+                self.pr_create_error = Some(error.clone());
+                self.status_message = Some(format!("AI generation failed: {}", error));
+            }
+            // Chunks that arrive after the generation was cancelled are ignored
+            AsyncMessage::AiCommitMessageChunk { chunk, .. } if self.commit_ai_loading => {
+                // The first chunk replaces any template content
+                if !self.commit_ai_streaming {
+                    self.commit_ai_streaming = true;
+                    self.commit_message_history.record(&self.commit_message);
+                    self.commit_message.clear();
+                    self.commit_template_hints.clear();
+                    self.commit_message_mode = true;
+                }
+                self.commit_message.push_str(&chunk);
             }
-            AsyncMessage::AiCommitMessageGenerated(message) => {
+            AsyncMessage::AiCommitMessageChunk { .. } => {}
+            AsyncMessage::AiCommitMessageGenerated {
+                message,
+                key,
+                cached,
+                ..
+            } => {
                 self.commit_ai_loading = false;
+                // A streamed message was recorded when its first chunk arrived
+                if !std::mem::take(&mut self.commit_ai_streaming) {
+                    self.commit_message_history.record(&self.commit_message);
+                }
+                if std::mem::take(&mut self.ai_cache.pending_commit) && !cached {
+                    self.ai_cache.commit = Some((key, message.clone()));
+                }
+                // AI output replaces any template content
                 self.commit_message = message;
+                self.commit_template_hints.clear();
                 self.commit_message_mode = true;
-                self.status_message = Some(
-                    "AI generated message (Enter to commit, Ctrl+g to regenerate)".to_string(),
-                );
+                self.status_message = Some(if cached {
+                    "Using cached AI result (staged changes unchanged)".to_string()
+                } else {
+                    "AI generated message (Enter to commit, Ctrl+g to regenerate)".to_string()
+                });
             }
-            AsyncMessage::AiCommitMessageError(err) => {
+            AsyncMessage::AiCommitMessageError { error, .. } => {
                 self.commit_ai_loading = false;
-                self.status_message = Some(format!("AI generation failed: {}", err));
+                self.commit_ai_streaming = false;
+                self.ai_cache.pending_commit = false;
+                self.status_message = Some(format!("AI generation failed: {}", error));
+            }
+            AsyncMessage::AiBranchNameGenerated { name, .. } => {
+                self.commit_ai_loading = false;
+                if self.push_mode == PushMode::NewBranch {
+                    self.push_new_branch_name = name;
+                    self.status_message =
+                        Some("AI suggested branch name (edit or Enter to push)".to_string());
+                }
+            }
+            AsyncMessage::AiBranchNameError { error, .. } => {
+                self.commit_ai_loading = false;
+                self.status_message = Some(format!("AI suggestion failed: {}", error));
             }
             AsyncMessage::PushCompleted(tracking) => {
                 self.commit_push_loading = false;
@@ -849,6 +1779,7 @@ impl App {
                 self.commit_tracking_branch = None;
                 self.push_mode = PushMode::Simple; // Reset push mode
                 self.push_new_branch_name.clear();
+                self.branch_status_last_tick = None;
                 self.status_message = Some(format!("✓ Pushed to {}", tracking));
             }
             AsyncMessage::PushError(err) => {
@@ -872,25 +1803,42 @@ impl App {
             AsyncMessage::WorkflowRunsLoaded {
                 runs,
                 preserve_selection_id,
+                actor,
             } => {
+                if actor.is_some() {
+                    self.current_user = actor;
+                }
                 self.workflow_runs = runs;
                 self.workflow_runs_loading = false;
                 self.workflow_runs_fetched = true;
                 self.workflow_runs_error = None;
+                self.workflow_poll_backoff_multiplier = 1;
 
                 // Determine new selection: try to restore by run ID, or default to 0
+                let visible = self.filtered_workflow_runs();
                 let new_selected = if let Some(run_id) = preserve_selection_id {
-                    self.workflow_runs
-                        .iter()
-                        .position(|r| r.id == run_id)
-                        .unwrap_or(0)
+                    visible.iter().position(|r| r.id == run_id).unwrap_or(0)
                 } else {
                     0
                 };
+                let total = visible.len();
 
-                self.workflow_runs_selection = ListState::new(self.workflow_runs.len());
-                self.workflow_runs_selection.selected =
-                    new_selected.min(self.workflow_runs.len().saturating_sub(1));
+                self.workflow_runs_selection = ListState::new(total);
+                self.workflow_runs_selection.selected = new_selected.min(total.saturating_sub(1));
+                if preserve_selection_id.is_none() {
+                    self.restore_selection(Screen::WorkflowRuns);
+                }
+
+                // Jobs of runs that are (or were) still going have changed since
+                let runs = &self.workflow_runs;
+                self.workflow_jobs.retain(|run_id, jobs| {
+                    let run_active = runs
+                        .iter()
+                        .find(|r| r.id == *run_id)
+                        .is_none_or(|r| r.status.is_active());
+                    !run_active && !jobs.iter().any(|j| j.status.is_active())
+                });
+                self.load_selected_run_jobs();
 
                 // Only show status message for manual refresh (preserve_selection_id is None)
                 if preserve_selection_id.is_none() {
@@ -906,7 +1854,14 @@ impl App {
                 self.workflow_runs_loading = false;
                 self.workflow_runs_fetched = true;
                 self.workflow_runs_error = Some(err.clone());
-                self.status_message = Some(format!("Error: {}", err));
+                // Back off so rate limits (403/429) get a chance to reset
+                self.workflow_poll_backoff_multiplier =
+                    (self.workflow_poll_backoff_multiplier * 2).min(WORKFLOW_POLL_MAX_BACKOFF);
+                self.status_message = Some(format!(
+                    "Error: {} (next refresh in {}s)",
+                    err,
+                    self.workflow_poll_interval_secs()
+                ));
             }
             AsyncMessage::PrCommentsLoaded(comments) => {
                 self.pr_comments_selection = ListState::new(comments.len());
@@ -919,6 +1874,29 @@ impl App {
                 self.pr_comments_error = Some(err.clone());
                 self.status_message = Some(format!("Error loading comments: {}", err));
             }
+            AsyncMessage::PrCombinedStatusLoaded { number, status } => {
+                if self
+                    .selected_pr
+                    .as_ref()
+                    .is_some_and(|pr| pr.number == number)
+                {
+                    self.pr_combined_status = Some(status);
+                }
+            }
+            // Results of an older fetch (the user switched PRs since) are dropped
+            AsyncMessage::PrReviewsLoaded { number, reviews } => {
+                if self.pr_reviews_loading == Some(number) {
+                    self.pr_reviews_loading = None;
+                    self.pr_reviews = reviews;
+                    self.pr_reviews_error = None;
+                }
+            }
+            AsyncMessage::PrReviewsError { number, error } => {
+                if self.pr_reviews_loading == Some(number) {
+                    self.pr_reviews_loading = None;
+                    self.pr_reviews_error = Some(error);
+                }
+            }
             AsyncMessage::PrCommentAdded(comment) => {
                 self.pr_comment_submitting = false;
                 self.pr_comment_input_mode = false;
@@ -949,16 +1927,26 @@ impl App {
                 self.reaction_submitting = false;
                 self.reaction_picker_open = false;
                 // Add reaction to local state
-                self.pr_comment_reactions
-                    .entry(comment_id)
-                    .or_default()
-                    .push(*reaction);
+                let reactions = self.pr_comment_reactions.entry(comment_id).or_default();
+                push_reaction(reactions, *reaction);
+                self.status_message = Some("Reaction added!".to_string());
+            }
+            AsyncMessage::PrReactionsLoaded(reactions) => {
+                self.pr_reactions = reactions;
+            }
+            AsyncMessage::PrReactionAdded(reaction) => {
+                self.reaction_submitting = false;
+                self.reaction_picker_open = false;
+                push_reaction(&mut self.pr_reactions, *reaction);
                 self.status_message = Some("Reaction added!".to_string());
             }
             AsyncMessage::ReactionAddError(err) => {
                 self.reaction_submitting = false;
                 self.status_message = Some(format!("Reaction failed: {}", err));
             }
+            AsyncMessage::CurrentUserLoaded(login) => {
+                self.current_user = Some(login);
+            }
             AsyncMessage::ReactionRemoved {
                 comment_id,
                 reaction_id,
@@ -985,6 +1973,187 @@ impl App {
                 // Also fetch comments in case there are new auto-comments
                 self.fetch_pr_comments(pr_number);
             }
+            AsyncMessage::BaseBranchesLoaded(branches) => {
+                self.base_picker_loading = false;
+                if let Some(pr) = &self.selected_pr {
+                    let (head, base) = (&pr.head.ref_field, &pr.base.ref_field);
+                    self.base_picker_branches = branches
+                        .into_iter()
+                        .map(|b| b.name)
+                        .filter(|name| name != head && name != base)
+                        .collect();
+                }
+                self.base_picker_selection = ListState::new(self.base_picker_branches.len());
+            }
+            AsyncMessage::PrBaseChanged { number, base } => {
+                self.base_picker_loading = false;
+                self.base_picker_open = false;
+                self.status_message = Some(format!("PR #{} now targets {}", number, base));
+                // Refresh so the branches line shows the new base
+                self.fetch_pr_detail(number);
+            }
+            AsyncMessage::WorkflowRerun {
+                run_id,
+                failed_only,
+            } => {
+                self.workflow_rerunning = false;
+                // GitHub queues the run shortly; mark it active so polling keeps following it
+                if let Some(run) = self.workflow_runs.iter_mut().find(|r| r.id == run_id) {
+                    run.status = WorkflowRunStatus::Queued;
+                    run.conclusion = None;
+                    self.status_message = Some(format!(
+                        "Re-running {} of #{}",
+                        if failed_only {
+                            "failed jobs"
+                        } else {
+                            "all jobs"
+                        },
+                        run.run_number
+                    ));
+                }
+                // Refresh now so the re-run shows up without waiting a poll interval
+                self.workflow_runs_last_poll_tick = self.tick_counter;
+                if self.current_screen == Screen::WorkflowRuns {
+                    self.fetch_workflow_runs_with_selection(Some(run_id));
+                }
+            }
+            AsyncMessage::WorkflowRerunError(err) => {
+                self.workflow_rerunning = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Re-run Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::WorkflowJobsLoaded { run_id, jobs } => {
+                self.workflow_jobs_loading = None;
+                self.workflow_jobs.insert(run_id, jobs);
+                // The selection may have moved on while this run was loading
+                self.load_selected_run_jobs();
+            }
+            AsyncMessage::WorkflowJobsError { run_id, error } => {
+                self.workflow_jobs_loading = None;
+                self.workflow_jobs_error = Some((run_id, error));
+            }
+            AsyncMessage::RateLimitLoaded(rate_limit) => {
+                self.api_rate_limit = Some(rate_limit);
+            }
+            AsyncMessage::WorkflowCancelled(run_id) => {
+                self.workflow_cancelling = false;
+                if let Some(run) = self.workflow_runs.iter().find(|r| r.id == run_id) {
+                    self.status_message = Some(format!("Cancelling run #{}", run.run_number));
+                }
+                // Poll again soon so the cancelled status shows up
+                self.workflow_runs_last_poll_tick = self.tick_counter;
+                if self.current_screen == Screen::WorkflowRuns {
+                    self.fetch_workflow_runs_with_selection(Some(run_id));
+                }
+            }
+            AsyncMessage::WorkflowCancelError(err) => {
+                self.workflow_cancelling = false;
+                // GitHub answers 409 when the run finished in the meantime
+                if err.to_lowercase().contains("cannot cancel") {
+                    self.status_message = Some("Run can no longer be cancelled".to_string());
+                } else {
+                    self.error_popup = Some(ErrorPopup {
+                        title: "Cancel Failed".to_string(),
+                        message: err,
+                    });
+                }
+            }
+            AsyncMessage::WorkflowLogsLoaded { run_id, logs } => {
+                if self.workflow_logs_loading == Some(run_id) {
+                    self.workflow_logs_loading = None;
+                }
+                // Ignore logs for a run we've since navigated away from
+                if self.current_screen == Screen::WorkflowLogs(run_id) {
+                    let lines: Vec<String> = logs.lines().map(String::from).collect();
+                    let dropped = lines.len().saturating_sub(WORKFLOW_LOG_MAX_LINES);
+                    self.workflow_logs = lines.into_iter().skip(dropped).collect();
+                    self.workflow_logs_truncated = dropped;
+                    self.workflow_logs_scroll = 0;
+                    self.workflow_logs_error = None;
+                }
+            }
+            AsyncMessage::WorkflowLogsError { run_id, error } => {
+                if self.workflow_logs_loading == Some(run_id) {
+                    self.workflow_logs_loading = None;
+                    self.workflow_logs_error = Some(error);
+                }
+            }
+            AsyncMessage::PrDiffLoaded { number, diff } => {
+                // Ignore a diff for a PR we've since navigated away from
+                if self.pr_diff_loading == Some(number) {
+                    self.pr_diff_loading = None;
+                    self.pr_diff_index = DiffIndex::parse(&diff);
+                    self.pr_diff_line_count = diff.lines().count();
+                    self.pr_diff = Some(diff);
+                    self.pr_diff_scroll = 0;
+                    self.pr_diff_error = None;
+                }
+            }
+            AsyncMessage::PrDiffError { number, error } => {
+                if self.pr_diff_loading == Some(number) {
+                    self.pr_diff_loading = None;
+                    self.pr_diff_error = Some(error);
+                }
+            }
+            AsyncMessage::PrStateChanged(number) => {
+                self.pr_state_changing = false;
+                self.status_message = Some(format!("Updated state of PR #{}", number));
+                self.fetch_pr_detail(number);
+            }
+            AsyncMessage::PrStateChangeError(err) => {
+                self.pr_state_changing = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Change PR State Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::ReviewSubmitted { number, event } => {
+                self.review_submitting = false;
+                self.review_dialog_open = false;
+                self.review_body.clear();
+                self.status_message = Some(format!(
+                    "Review submitted on PR #{}: {}",
+                    number,
+                    event.label().to_lowercase()
+                ));
+                // Refresh so the new review shows up
+                self.fetch_pr_detail(number);
+                self.fetch_pr_comments(number);
+                self.fetch_pr_reviews(number);
+            }
+            AsyncMessage::ReviewError(err) => {
+                self.review_submitting = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Review Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::PrUpdated(pr) => {
+                self.pr_edit_submitting = false;
+                self.pr_edit_open = false;
+                // Our own edit shouldn't flag the description as changed
+                self.pr_last_seen_body = Some((pr.number, pr.body.clone()));
+                self.status_message = Some(format!("Updated PR #{}", pr.number));
+                self.fetch_pr_detail(pr.number);
+            }
+            AsyncMessage::PrUpdateError(err) => {
+                // Keep the form open so the edits aren't lost
+                self.pr_edit_submitting = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Update PR Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::PrBaseChangeError(err) => {
+                self.base_picker_loading = false;
+                self.base_picker_open = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Change Base Failed".to_string(),
+                    message: err,
+                });
+            }
             AsyncMessage::PrMergeError(err) => {
                 self.merge_in_progress = false;
                 self.merge_dialog_open = false;
@@ -993,6 +2162,38 @@ impl App {
                     message: err,
                 });
             }
+            AsyncMessage::AutoMergeEnabled(number) => {
+                self.merge_in_progress = false;
+                self.merge_dialog_open = false;
+                self.status_message = Some(format!(
+                    "Auto-merge enabled: PR #{} will merge once its requirements pass",
+                    number
+                ));
+                self.fetch_pr_detail(number);
+            }
+            AsyncMessage::AutoMergeError(err) => {
+                self.merge_in_progress = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Auto-merge Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::AiSquashMessageGenerated {
+                number, message, ..
+            } => {
+                self.merge_ai_loading = false;
+                // Drop the message if its merge dialog was closed in the meantime
+                let same_pr = self.selected_pr.as_ref().map(|pr| pr.number) == Some(number);
+                if self.merge_dialog_open && same_pr {
+                    self.merge_commit_message_cursor = text_input::cursor_at_end(&message);
+                    self.merge_commit_message = message;
+                    self.status_message = Some("AI squash message ready".to_string());
+                }
+            }
+            AsyncMessage::AiSquashMessageError { error, .. } => {
+                self.merge_ai_loading = false;
+                self.status_message = Some(format!("AI generation failed: {}", error));
+            }
 
             // Update messages
             AsyncMessage::UpdateUpToDate => {
@@ -1031,7 +2232,9 @@ impl App {
                 self.tags_loading = false;
                 self.tags_fetched = true;
                 self.tags_error = None;
-                self.tags_selection = ListState::new(self.tags_local.len());
+                self.tags_selection =
+                    ListState::new(self.tags_local.len() + self.tags_remote_only().len());
+                self.restore_selection(Screen::Tags);
                 self.status_message = Some(format!("Loaded {} local tags", self.tags_local.len()));
             }
             AsyncMessage::TagsError(err) => {
@@ -1060,6 +2263,7 @@ impl App {
             AsyncMessage::TagDeleted { name } => {
                 self.status_message = Some(format!("Deleted tag: {}", name));
                 // Refresh tags list
+                self.tags_loading = false;
                 self.tags_fetched = false;
                 self.fetch_tags();
             }
@@ -1081,6 +2285,57 @@ impl App {
                     message: err,
                 });
             }
+            AsyncMessage::BranchCheckedOut { name, created } => {
+                self.branch_switching = false;
+                if let Some(repo) = &mut self.repository {
+                    repo.current_branch = name.clone();
+                }
+                self.branch_status_last_tick = None;
+                self.refresh_local_branches();
+                self.status_message = Some(if created {
+                    format!("Created and switched to branch: {}", name)
+                } else {
+                    format!("Switched to branch: {}", name)
+                });
+            }
+            AsyncMessage::BranchCheckoutError { error, created } => {
+                self.branch_switching = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: if created {
+                        "Branch Creation Failed".to_string()
+                    } else {
+                        "Checkout Failed".to_string()
+                    },
+                    message: error,
+                });
+            }
+            AsyncMessage::BranchDeleted(name) => {
+                self.branch_switching = false;
+                self.refresh_local_branches();
+                self.status_message = Some(format!("Deleted branch: {}", name));
+            }
+            AsyncMessage::BranchDeleteError(err) => {
+                self.branch_switching = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Branch Deletion Failed".to_string(),
+                    message: err,
+                });
+            }
+            AsyncMessage::DryRun(action) => {
+                self.merge_in_progress = false;
+                self.branch_switching = false;
+                self.merge_dialog_open = false;
+                self.commit_push_loading = false;
+                self.push_mode = PushMode::Simple;
+                self.tags_loading = false;
+                self.pr_state_changing = false;
+                self.review_submitting = false;
+                self.review_dialog_open = false;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Dry Run".to_string(),
+                    message: format!("Would {}.\n\nNothing was changed.", action),
+                });
+            }
         }
     }
 
@@ -1090,28 +2345,60 @@ impl App {
             return; // Already loading
         }
 
-        let repo = match &self.repository {
-            Some(r) => r.clone(),
-            None => return,
-        };
-
+        if self.repository.is_none() {
+            return;
+        }
+
         self.pr_list_loading = true;
+        self.pr_list_loading_more = false;
+        self.pr_list_page = 0;
         self.pr_list_error = None;
         self.status_message = Some("Loading pull requests...".to_string());
 
+        self.spawn_pr_list_fetch(1);
+    }
+
+    /// Spawn a task to fetch the page after the last one loaded and append it
+    pub fn fetch_more_prs(&mut self) {
+        if self.pr_list_loading || self.pr_list_loading_more || !self.pr_list_fetched {
+            return;
+        }
+        if !self.pr_list_has_more {
+            self.status_message = Some("No more pull requests".to_string());
+            return;
+        }
+        if self.repository.is_none() {
+            return;
+        }
+
+        self.pr_list_loading_more = true;
+        self.status_message = Some("Loading more pull requests...".to_string());
+        self.spawn_pr_list_fetch(self.pr_list_page + 1);
+    }
+
+    /// Fetch one page of the PR list with the current filter and page size
+    fn spawn_pr_list_fetch(&mut self, page: u32) {
+        let Some(repo) = self.repository.clone() else {
+            return;
+        };
+
         let tx = self.async_tx.clone();
+        let state = self.pr_list_state_filter;
+        let per_page = self.pr_list_page_size;
 
         tokio::spawn(async move {
             let result = async {
                 let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
                 let handler = PullRequestHandler::new(&client);
-                handler.list(PrState::Open, None, 30).await
+                handler.list(state, None, per_page, page).await
             }
             .await;
 
             match result {
                 Ok(prs) => {
-                    let _ = tx.send(AsyncMessage::PrListLoaded(prs)).await;
+                    let _ = tx
+                        .send(AsyncMessage::PrListLoaded { prs, state, page })
+                        .await;
                 }
                 Err(e) => {
                     // Errors are displayed in the TUI, no need to log
@@ -1121,6 +2408,41 @@ impl App {
         });
     }
 
+    /// Append a later page to the PR list, keeping the current selection
+    fn append_pr_list_page(&mut self, prs: Vec<PullRequest>, page: u32) {
+        self.pr_list_loading_more = false;
+
+        // A refresh replaced the list while this page was in flight
+        if page != self.pr_list_page + 1 {
+            return;
+        }
+
+        if prs.is_empty() {
+            self.pr_list_has_more = false;
+            self.status_message = Some("No more pull requests".to_string());
+            return;
+        }
+
+        // PRs updated between requests can shift onto the next page; skip repeats
+        let before = self.pr_list.len();
+        for pr in prs {
+            if !self
+                .pr_list
+                .iter()
+                .any(|existing| existing.number == pr.number)
+            {
+                self.pr_list.push(pr);
+            }
+        }
+        self.pr_list_page = page;
+        self.pr_list_selection.total = self.filtered_pr_list().len();
+        self.status_message = Some(format!(
+            "Loaded {} more pull requests ({} total)",
+            self.pr_list.len() - before,
+            self.pr_list.len()
+        ));
+    }
+
     /// Spawn a task to fetch a single PR's details
     pub fn fetch_pr_detail(&mut self, number: u64) {
         if self.pr_detail_loading {
@@ -1170,6 +2492,7 @@ impl App {
         self.pr_comments_loading = true;
         self.pr_comments_error = None;
         self.pr_comment_reactions.clear();
+        self.pr_reactions.clear();
 
         let tx = self.async_tx.clone();
 
@@ -1179,6 +2502,12 @@ impl App {
                 let handler = PullRequestHandler::new(&client);
                 let comments = handler.list_comments(pr_number).await?;
 
+                // Reactions on the PR description (failures leave the summary empty)
+                let pr_reactions = handler
+                    .list_issue_reactions(pr_number)
+                    .await
+                    .unwrap_or_default();
+
                 // Fetch reactions for each comment
                 let mut reactions_map: HashMap<u64, Vec<Reaction>> = HashMap::new();
                 for comment in &comments {
@@ -1187,16 +2516,17 @@ impl App {
                     }
                 }
 
-                Ok::<_, crate::error::GhrustError>((comments, reactions_map))
+                Ok::<_, crate::error::GhrustError>((comments, reactions_map, pr_reactions))
             }
             .await;
 
             match result {
-                Ok((comments, reactions)) => {
+                Ok((comments, reactions, pr_reactions)) => {
                     let _ = tx.send(AsyncMessage::PrCommentsLoaded(comments)).await;
                     let _ = tx
                         .send(AsyncMessage::CommentReactionsLoaded(reactions))
                         .await;
+                    let _ = tx.send(AsyncMessage::PrReactionsLoaded(pr_reactions)).await;
                 }
                 Err(e) => {
                     let _ = tx.send(AsyncMessage::PrCommentsError(e.to_string())).await;
@@ -1205,6 +2535,84 @@ impl App {
         });
     }
 
+    /// Spawn a task to fetch the combined CI status of the selected PR's head commit
+    ///
+    /// Failures only leave the indicator empty; the status is informational.
+    fn fetch_pr_combined_status(&mut self) {
+        let (repo, number, sha) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr.number, pr.head.sha.clone()),
+            _ => return,
+        };
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client).combined_status(&sha).await
+            }
+            .await;
+
+            match result {
+                Ok(status) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrCombinedStatusLoaded { number, status })
+                        .await;
+                }
+                Err(e) => tracing::debug!("Failed to load combined status: {}", e),
+            }
+        });
+    }
+
+    /// Spawn a task to fetch the reviews of a PR
+    ///
+    /// Only a second fetch for the same PR is skipped; switching PRs starts a
+    /// new fetch and the older result is dropped when it arrives.
+    pub fn fetch_pr_reviews(&mut self, pr_number: u64) {
+        if self.pr_reviews_loading == Some(pr_number) {
+            return;
+        }
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.pr_reviews_loading = Some(pr_number);
+        self.pr_reviews_error = None;
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .list_reviews(pr_number)
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(reviews) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrReviewsLoaded {
+                            number: pr_number,
+                            reviews,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrReviewsError {
+                            number: pr_number,
+                            error: e.to_string(),
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
     /// Submit a new comment on the current PR
     fn submit_pr_comment(&mut self) {
         if self.pr_comment_submitting {
@@ -1255,6 +2663,18 @@ impl App {
         });
     }
 
+    /// Key that merges from the focused merge dialog field
+    ///
+    /// Enter edits the commit title and message fields, so only Ctrl+s
+    /// merges from there.
+    pub fn merge_confirm_key(&self) -> &'static str {
+        if self.merge_dialog_field == 0 {
+            "Enter"
+        } else {
+            "Ctrl+s"
+        }
+    }
+
     /// Spawn a task to merge the current PR
     fn merge_pr(&mut self) {
         let pr = match &self.selected_pr {
@@ -1271,6 +2691,20 @@ impl App {
             return;
         }
 
+        // Failing or pending checks need a second confirm to merge anyway
+        let unsettled = self
+            .pr_combined_status
+            .as_ref()
+            .is_some_and(|s| s.is_unsettled());
+        if unsettled && !self.merge_force_confirmed {
+            self.merge_force_confirmed = true;
+            self.status_message = Some(format!(
+                "Checks are not passing. Press {} again to merge anyway",
+                self.merge_confirm_key()
+            ));
+            return;
+        }
+
         let repo = match &self.repository {
             Some(r) => r.clone(),
             None => return,
@@ -1286,27 +2720,47 @@ impl App {
         let delete_branch = self.merge_delete_branch;
         let branch_name = pr.head.ref_field.clone();
 
+        // Blank fields keep GitHub's default; rebase merges create no merge commit
+        let non_empty = |s: &str| Some(s.trim().to_string()).filter(|s| !s.is_empty());
+        let (commit_title, commit_message) = match method {
+            MergeMethod::Rebase => (None, None),
+            _ => (
+                non_empty(&self.merge_commit_title),
+                non_empty(&self.merge_commit_message),
+            ),
+        };
+
         self.merge_in_progress = true;
         self.status_message = Some("Merging PR...".to_string());
 
         let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             let result = async {
                 let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
-                let pr_handler = PullRequestHandler::new(&client);
-
-                // Perform merge (no custom commit message per requirements)
-                pr_handler.merge(pr_number, method, None, None).await?;
+                let pr_handler = PullRequestHandler::new(&client).with_dry_run(dry_run);
+                let mut plan = DryRunPlan::default();
+
+                plan.step(
+                    pr_handler
+                        .merge(
+                            pr_number,
+                            method,
+                            commit_title.as_deref(),
+                            commit_message.as_deref(),
+                        )
+                        .await,
+                )?;
 
                 // Optionally delete branch (errors are non-fatal)
                 if delete_branch {
-                    let branch_handler = BranchHandler::new(&client);
+                    let branch_handler = BranchHandler::new(&client).with_dry_run(dry_run);
                     // Ignore branch deletion errors - may fail if branch is protected, etc.
-                    let _ = branch_handler.delete(&branch_name).await;
+                    let _ = plan.step(branch_handler.delete(&branch_name).await);
                 }
 
-                Ok::<_, GhrustError>(())
+                plan.finish()
             }
             .await;
 
@@ -1314,6 +2768,9 @@ impl App {
                 Ok(()) => {
                     let _ = tx.send(AsyncMessage::PrMerged(pr_number)).await;
                 }
+                Err(GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
                 Err(e) => {
                     let _ = tx.send(AsyncMessage::PrMergeError(e.to_string())).await;
                 }
@@ -1321,424 +2778,1521 @@ impl App {
         });
     }
 
-    /// Add a reaction to the currently selected comment
-    fn add_reaction(&mut self, reaction_type: ReactionType) {
-        if self.reaction_submitting {
-            return;
-        }
-
-        // Get the selected comment
-        let comment = match self.pr_comments.get(self.pr_comments_selection.selected) {
-            Some(c) => c,
+    /// Spawn a task to enable auto-merge on the current PR with the selected method
+    fn enable_auto_merge(&mut self) {
+        let pr = match &self.selected_pr {
+            Some(pr) if pr.state == Some(octocrab::models::IssueState::Open) => pr,
+            _ => return,
+        };
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
             None => return,
         };
 
-        let comment_id: u64 = *comment.id;
+        let pr_number = pr.number;
+        let method = match self.merge_method_selection {
+            1 => MergeMethod::Squash,
+            2 => MergeMethod::Rebase,
+            _ => MergeMethod::Merge,
+        };
+
+        self.merge_in_progress = true;
+        self.status_message = Some("Enabling auto-merge...".to_string());
+
+        let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .with_dry_run(dry_run)
+                    .enable_auto_merge(pr_number, method)
+                    .await
+            }
+            .await;
+
+            let msg = match result {
+                Ok(()) => AsyncMessage::AutoMergeEnabled(pr_number),
+                Err(GhrustError::DryRun(action)) => AsyncMessage::DryRun(action),
+                Err(e) => AsyncMessage::AutoMergeError(e.to_string()),
+            };
+            let _ = tx.send(msg).await;
+        });
+    }
 
+    /// Open the base branch picker and load candidate branches
+    fn open_base_picker(&mut self) {
         let repo = match &self.repository {
             Some(r) => r.clone(),
             None => return,
         };
 
-        self.reaction_submitting = true;
-        self.status_message = Some("Adding reaction...".to_string());
+        self.base_picker_open = true;
+        self.base_picker_loading = true;
+        self.base_picker_branches.clear();
+        self.base_picker_selection = ListState::default();
 
         let tx = self.async_tx.clone();
 
         tokio::spawn(async move {
             let result = async {
                 let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
-                let handler = PullRequestHandler::new(&client);
-                handler
-                    .add_comment_reaction(comment_id, reaction_type)
-                    .await
+                BranchHandler::new(&client).list().await
             }
             .await;
 
             match result {
-                Ok(reaction) => {
-                    let _ = tx
-                        .send(AsyncMessage::ReactionAdded {
-                            comment_id,
-                            reaction: Box::new(reaction),
-                        })
-                        .await;
+                Ok(branches) => {
+                    let _ = tx.send(AsyncMessage::BaseBranchesLoaded(branches)).await;
                 }
                 Err(e) => {
-                    let _ = tx.send(AsyncMessage::ReactionAddError(e.to_string())).await;
+                    let _ = tx
+                        .send(AsyncMessage::PrBaseChangeError(e.to_string()))
+                        .await;
                 }
             }
         });
     }
 
-    /// Toggle a reaction on the currently selected comment
-    /// If the user already has this reaction, remove it; otherwise add it
-    fn toggle_reaction(&mut self, reaction_type: ReactionType) {
-        if self.reaction_submitting {
-            return;
-        }
-
-        // Get the selected comment
-        let comment = match self.pr_comments.get(self.pr_comments_selection.selected) {
-            Some(c) => c,
-            None => return,
-        };
-
-        let _comment_id: u64 = *comment.id;
-
-        // Check if we already have this reaction (need to find our own reaction)
-        // For now, we'll just add the reaction - GitHub API handles duplicates
-        // by returning the existing reaction
-        self.add_reaction(reaction_type);
-    }
-
-    /// Spawn a task to fetch workflow runs for the current PR (by head branch)
-    pub fn fetch_pr_workflow_runs(&mut self) {
-        if self.pr_workflow_runs_loading {
+    /// Spawn a task to download a workflow run's logs
+    ///
+    /// Only a second download of the same run is skipped; logs of a run the
+    /// user already left are dropped when they arrive.
+    fn fetch_workflow_logs(&mut self, run_id: u64) {
+        if self.workflow_logs_loading == Some(run_id) {
             return;
         }
-
         let repo = match &self.repository {
             Some(r) => r.clone(),
             None => return,
         };
 
-        let head_branch = match &self.selected_pr {
-            Some(pr) => pr.head.ref_field.clone(),
-            None => return,
-        };
-
-        self.pr_workflow_runs_loading = true;
+        self.workflow_logs.clear();
+        self.workflow_logs_truncated = 0;
+        self.workflow_logs_scroll = 0;
+        self.workflow_logs_error = None;
+        self.workflow_logs_loading = Some(run_id);
 
         let tx = self.async_tx.clone();
 
         tokio::spawn(async move {
             let result = async {
                 let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
-                let handler = WorkflowHandler::new(&client);
-                // Fetch workflows for the PR's head branch, limited to recent runs
-                handler.list_runs(Some(&head_branch), None, 10).await
+                WorkflowHandler::new(&client).download_logs(run_id).await
             }
             .await;
 
             match result {
-                Ok(runs) => {
-                    let _ = tx.send(AsyncMessage::PrWorkflowRunsLoaded(runs)).await;
+                Ok(logs) => {
+                    let _ = tx
+                        .send(AsyncMessage::WorkflowLogsLoaded { run_id, logs })
+                        .await;
                 }
                 Err(e) => {
                     let _ = tx
-                        .send(AsyncMessage::PrWorkflowRunsError(e.to_string()))
+                        .send(AsyncMessage::WorkflowLogsError {
+                            run_id,
+                            error: e.to_string(),
+                        })
                         .await;
                 }
             }
         });
     }
 
-    /// Spawn a task to fetch workflow runs (with status message)
-    pub fn fetch_workflow_runs(&mut self) {
-        self.fetch_workflow_runs_impl(None, true);
-    }
-
-    /// Spawn a task to fetch workflow runs, preserving selection (silent refresh)
-    fn fetch_workflow_runs_with_selection(&mut self, preserve_run_id: Option<u64>) {
-        self.fetch_workflow_runs_impl(preserve_run_id, false);
-    }
-
-    /// Internal implementation for fetching workflow runs
-    fn fetch_workflow_runs_impl(&mut self, preserve_run_id: Option<u64>, show_status: bool) {
-        if self.workflow_runs_loading {
-            return; // Already loading
+    /// Spawn a task to fetch a PR's unified diff
+    fn fetch_pr_diff(&mut self, number: u64) {
+        if self.pr_diff_loading == Some(number) {
+            return;
         }
-
         let repo = match &self.repository {
             Some(r) => r.clone(),
             None => return,
         };
 
-        self.workflow_runs_loading = true;
-        self.workflow_runs_error = None;
-        if show_status {
-            let msg = if let Some(ref branch) = self.pr_workflow_branch {
-                format!("Loading workflow runs for branch '{}'...", branch)
-            } else {
-                "Loading workflow runs...".to_string()
-            };
-            self.status_message = Some(msg);
-        }
+        self.pr_diff = None;
+        self.pr_diff_index = DiffIndex::default();
+        self.pr_diff_line_count = 0;
+        self.pr_diff_scroll = 0;
+        self.pr_diff_error = None;
+        self.pr_diff_loading = Some(number);
 
         let tx = self.async_tx.clone();
-        let branch_filter = self.pr_workflow_branch.clone();
 
         tokio::spawn(async move {
             let result = async {
                 let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
-                let handler = WorkflowHandler::new(&client);
-                handler.list_runs(branch_filter.as_deref(), None, 30).await
+                PullRequestHandler::new(&client).get_diff(number).await
             }
             .await;
 
             match result {
-                Ok(runs) => {
-                    let _ = tx
-                        .send(AsyncMessage::WorkflowRunsLoaded {
-                            runs,
-                            preserve_selection_id: preserve_run_id,
-                        })
-                        .await;
+                Ok(diff) => {
+                    let _ = tx.send(AsyncMessage::PrDiffLoaded { number, diff }).await;
                 }
                 Err(e) => {
                     let _ = tx
-                        .send(AsyncMessage::WorkflowRunsError(e.to_string()))
+                        .send(AsyncMessage::PrDiffError {
+                            number,
+                            error: e.to_string(),
+                        })
                         .await;
                 }
             }
         });
     }
 
-    /// Returns true if any workflow run is currently active (running, queued, pending, etc.)
-    fn has_active_workflow_runs(&self) -> bool {
-        self.workflow_runs.iter().any(|run| run.status.is_active())
-    }
-
-    /// Check if we should poll workflow runs and trigger fetch if needed
-    fn maybe_poll_workflow_runs(&mut self) {
-        // Only poll when on the workflow runs screen
-        if self.current_screen != Screen::WorkflowRuns {
+    /// Close or reopen the selected PR
+    fn set_pr_state(&mut self, state: PrState) {
+        if self.pr_state_changing {
             return;
         }
+        let (repo, pr) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr),
+            _ => return,
+        };
+        let number = pr.number;
+        let is_open = pr.state == Some(octocrab::models::IssueState::Open);
 
-        // Don't poll if already loading
-        if self.workflow_runs_loading {
+        if state == PrState::Closed && pr.merged_at.is_some() {
+            self.error_popup = Some(ErrorPopup {
+                title: "Cannot Close PR".to_string(),
+                message: format!("PR #{} has already been merged.", number),
+            });
             return;
         }
-
-        // Don't poll if there are no active workflows
-        if !self.has_active_workflow_runs() {
+        if (state == PrState::Open) == is_open {
+            self.status_message = Some(format!(
+                "PR #{} is already {}",
+                number,
+                state.label().to_lowercase()
+            ));
             return;
         }
 
-        // Calculate ticks since last poll
-        // With 250ms tick rate: 28 ticks ≈ 7 seconds
-        const POLL_INTERVAL_TICKS: u64 = 28;
-
-        let ticks_since_poll = self
-            .tick_counter
-            .wrapping_sub(self.workflow_runs_last_poll_tick);
+        let verb = if state == PrState::Closed {
+            "Closing"
+        } else {
+            "Reopening"
+        };
+        self.pr_state_changing = true;
+        self.status_message = Some(format!("{} PR #{}...", verb, number));
 
-        if ticks_since_poll >= POLL_INTERVAL_TICKS {
-            // Store the current selection for restoration after refresh
-            let current_run_id = self
-                .workflow_runs
-                .get(self.workflow_runs_selection.selected)
-                .map(|run| run.id);
+        let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
 
-            // Update last poll tick BEFORE fetching to prevent rapid re-polls
-            self.workflow_runs_last_poll_tick = self.tick_counter;
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .with_dry_run(dry_run)
+                    .set_state(number, state)
+                    .await
+            }
+            .await;
 
-            // Trigger silent refresh with selection preservation
-            self.fetch_workflow_runs_with_selection(current_run_id);
-        }
+            match result {
+                Ok(_) => {
+                    let _ = tx.send(AsyncMessage::PrStateChanged(number)).await;
+                }
+                Err(GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrStateChangeError(e.to_string()))
+                        .await;
+                }
+            }
+        });
     }
 
-    /// Handle keyboard events
-    fn handle_key_event(&mut self, key: KeyEvent) {
-        // If help is shown, any key dismisses it
-        if self.show_help {
-            self.show_help = false;
-            return;
+    /// Handle key events when the review dialog is open
+    fn handle_review_dialog_key(&mut self, key: KeyEvent) {
+        if self.review_submitting {
+            return; // Ignore keys while submitting
         }
 
-        // If error popup is shown, only allow dismissal keys
-        if self.error_popup.is_some() {
-            if matches!(key.code, KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q')) {
-                self.error_popup = None;
+        let count = ReviewEvent::all().len();
+        match key.code {
+            KeyCode::Esc => {
+                self.review_dialog_open = false;
+                self.review_body.clear();
+                self.status_message = Some("Review cancelled".to_string());
             }
-            return; // Block all other input while popup is shown
+            KeyCode::Enter => self.submit_review(),
+            KeyCode::Tab | KeyCode::Down => {
+                self.review_event_selection = (self.review_event_selection + 1) % count;
+            }
+            KeyCode::BackTab | KeyCode::Up => {
+                self.review_event_selection = (self.review_event_selection + count - 1) % count;
+            }
+            KeyCode::Backspace => {
+                self.review_body.pop();
+            }
+            KeyCode::Char(c) => self.review_body.push(c),
+            _ => {}
         }
+    }
 
-        // If in settings input mode, handle it directly (bypass global handlers)
-        if self.settings_input_mode {
-            self.handle_settings_key(key);
-            return;
-        }
+    /// Submit the review typed in the review dialog
+    fn submit_review(&mut self) {
+        let (repo, number) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr.number),
+            _ => return,
+        };
+        let event = ReviewEvent::all()[self.review_event_selection];
+        let body = self.review_body.trim().to_string();
 
-        // If in commit message input mode, handle it directly (bypass global handlers)
-        if self.commit_message_mode {
-            self.handle_commit_key(key);
+        if body.is_empty() && event.requires_body() {
+            self.status_message =
+                Some(format!("Add a comment to {}", event.label().to_lowercase()));
             return;
         }
 
-        // PR comment expanded view - handle j/k scroll and close
-        if self.pr_comment_expanded {
-            self.handle_pr_detail_key(key);
-            return;
-        }
+        self.review_submitting = true;
+        self.status_message = Some(format!("Submitting review on PR #{}...", number));
 
-        // PR description expanded view - handle j/k scroll and close
-        if self.pr_description_expanded {
-            self.handle_pr_detail_key(key);
-            return;
-        }
+        let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
 
-        // PR comment input mode - handle text input
-        if self.pr_comment_input_mode {
-            self.handle_pr_detail_key(key);
-            return;
-        }
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .with_dry_run(dry_run)
+                    .submit_review(number, event, &body)
+                    .await
+            }
+            .await;
 
-        // If in PR create form on a text field, bypass global handlers for text input
-        if self.current_screen == Screen::PrCreate {
-            let is_text_field = self.pr_create_field == 0 || self.pr_create_field == 3;
-            if is_text_field {
-                // Only allow Esc to go back, otherwise handle as form input
-                if key.code == KeyCode::Esc {
-                    self.go_back();
-                    return;
+            // GitHub's own error (e.g. reviewing your own PR) is shown as-is
+            match result {
+                Ok(()) => {
+                    let _ = tx
+                        .send(AsyncMessage::ReviewSubmitted { number, event })
+                        .await;
+                }
+                Err(GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncMessage::ReviewError(e.to_string())).await;
                 }
-                self.handle_pr_create_key(key);
-                return;
             }
-        }
+        });
+    }
 
-        // If in tag creation mode, handle it directly (bypass global handlers)
-        if self.tag_create_mode {
-            self.handle_tag_create_key(key);
+    /// Open the edit form pre-filled with the selected PR's title and body
+    fn open_pr_edit(&mut self) {
+        let Some(pr) = &self.selected_pr else {
             return;
-        }
+        };
+        self.pr_edit_title = pr.title.clone().unwrap_or_default();
+        self.pr_edit_body = pr.body.clone().unwrap_or_default().replace("\r\n", "\n");
+        self.pr_edit_body_cursor = text_input::cursor_at_end(&self.pr_edit_body);
+        self.pr_edit_field = 0;
+        self.pr_edit_open = true;
+    }
 
-        // Global key handlers
-        if key.code == KeyCode::Char('?') {
-            self.show_help = true;
+    /// Submit the edited title and body
+    fn submit_pr_edit(&mut self) {
+        if self.pr_edit_submitting {
             return;
         }
-
-        if is_quit_key(&key) {
-            if self.current_screen == Screen::Dashboard {
-                self.quit();
-            } else {
-                self.go_back();
-            }
+        let (repo, number) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr.number),
+            _ => return,
+        };
+        let title = self.pr_edit_title.trim().to_string();
+        if title.is_empty() {
+            self.status_message = Some("Title cannot be empty".to_string());
             return;
         }
+        let body = self.pr_edit_body.clone();
 
-        if is_back_key(&key) {
-            self.go_back();
-            return;
-        }
+        self.pr_edit_submitting = true;
+        self.status_message = Some(format!("Updating PR #{}...", number));
 
-        // Screen-specific handlers
-        match self.current_screen {
-            Screen::Dashboard => self.handle_dashboard_key(key),
-            Screen::PrList => self.handle_pr_list_key(key),
-            Screen::PrDetail(_) => self.handle_pr_detail_key(key),
-            Screen::PrCreate => self.handle_pr_create_key(key),
-            Screen::Commit => self.handle_commit_key(key),
-            Screen::Tags => {
-                if self.tag_create_mode {
-                    self.handle_tag_create_key(key);
-                } else {
-                    self.handle_tags_key(key);
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .update(number, &title, &body)
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(pr) => {
+                    let _ = tx.send(AsyncMessage::PrUpdated(Box::new(pr))).await;
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncMessage::PrUpdateError(e.to_string())).await;
                 }
             }
-            Screen::Settings => self.handle_settings_key(key),
-            Screen::WorkflowRuns => self.handle_workflow_runs_key(key),
-            _ => {}
-        }
+        });
     }
 
-    fn handle_dashboard_key(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.dashboard_selection.next(),
-            KeyCode::Char('k') | KeyCode::Up => self.dashboard_selection.previous(),
-            KeyCode::Enter => match self.dashboard_selection.selected {
-                0 => self.navigate_to(Screen::PrList),
-                1 => self.navigate_to(Screen::Commit),
-                2 => self.navigate_to(Screen::Tags),
-                3 => self.navigate_to(Screen::WorkflowRuns),
-                4 => self.navigate_to(Screen::Settings),
-                5 => self.quit(),
-                _ => {}
-            },
-            KeyCode::Char('p') => self.navigate_to(Screen::PrList),
-            KeyCode::Char('c') => self.navigate_to(Screen::Commit),
-            KeyCode::Char('t') => self.navigate_to(Screen::Tags),
-            KeyCode::Char('w') => self.navigate_to(Screen::WorkflowRuns),
-            KeyCode::Char('s') => self.navigate_to(Screen::Settings),
-            _ => {}
-        }
-    }
+    /// Retarget the selected PR to a new base branch
+    fn change_pr_base(&mut self, base: String) {
+        let (repo, number) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr.number),
+            _ => return,
+        };
 
-    fn handle_pr_list_key(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.pr_list_selection.next(),
-            KeyCode::Char('k') | KeyCode::Up => self.pr_list_selection.previous(),
-            KeyCode::Enter => {
-                // Navigate to PR detail if there's a selection
-                if let Some(pr) = self.pr_list.get(self.pr_list_selection.selected) {
-                    let pr_number = pr.number;
-                    self.navigate_to(Screen::PrDetail(pr_number));
-                }
-            }
-            KeyCode::Char('n') => {
-                self.navigate_to(Screen::PrCreate);
-            }
-            KeyCode::Char('r') => {
-                // Force refresh
-                self.pr_list.clear();
-                self.pr_list_fetched = false;
-                self.fetch_pr_list();
+        self.base_picker_loading = true;
+        self.status_message = Some(format!("Changing base to {}...", base));
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                PullRequestHandler::new(&client)
+                    .change_base(number, &base)
+                    .await
             }
-            KeyCode::Char('o') => {
-                // Open PR in browser
-                if let Some(pr) = self.pr_list.get(self.pr_list_selection.selected) {
-                    if let Some(url) = &pr.html_url {
-                        crate::github::open_browser(url.as_str());
-                    }
+            .await;
+
+            match result {
+                Ok(_) => {
+                    let _ = tx.send(AsyncMessage::PrBaseChanged { number, base }).await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrBaseChangeError(e.to_string()))
+                        .await;
                 }
             }
-            _ => {}
+        });
+    }
+
+    /// Whether an AI generation is in flight
+    fn ai_loading(&self) -> bool {
+        self.merge_ai_loading || self.commit_ai_loading || self.pr_create_ai_loading
+    }
+
+    /// Abort the in-flight AI generation and reset the loading flags
+    fn cancel_ai_task(&mut self) {
+        if let Some(task) = self.ai_task.take() {
+            task.abort();
         }
+        self.ai_request += 1;
+        self.merge_ai_loading = false;
+        self.commit_ai_loading = false;
+        self.commit_ai_streaming = false;
+        self.pr_create_ai_loading = false;
+        self.ai_cache.pending_commit = false;
+        self.ai_cache.pending_pr = false;
+        self.status_message = Some("AI generation cancelled".to_string());
     }
 
-    /// Handle key events for PR create form
-    /// Fields: 0=title, 1=head, 2=base, 3=body, 4=draft, 5=submit
-    fn handle_pr_create_key(&mut self, key: KeyEvent) {
-        use crossterm::event::KeyModifiers;
+    /// Generate a squash commit body for the current PR using AI
+    fn generate_ai_squash_message(&mut self) {
+        if self.merge_ai_loading {
+            return;
+        }
 
-        match key.code {
-            // Ctrl+g: trigger AI generation from any field
-            KeyCode::Char('g') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                if self.gemini_configured && !self.pr_create_ai_loading {
-                    self.generate_ai_pr_content();
-                }
+        if !self.ai_configured {
+            self.status_message = Some("Configure an AI API key in Settings first".to_string());
+            return;
+        }
+
+        let (pr_number, pr_title) = match &self.selected_pr {
+            Some(pr) => (pr.number, pr.title.clone().unwrap_or_default()),
+            None => return,
+        };
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.merge_ai_loading = true;
+        self.status_message = Some("Generating squash message with AI...".to_string());
+
+        self.ai_request += 1;
+        let request = self.ai_request;
+        let tx = self.async_tx.clone();
+
+        let task = tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = PullRequestHandler::new(&client);
+                let commits = handler.list_commit_messages(pr_number).await?;
+
+                let gemini = AiClient::new()?;
+                gemini.generate_squash_message(&pr_title, &commits).await
             }
-            // Tab: move to next field
-            KeyCode::Tab => {
-                if key.modifiers.contains(KeyModifiers::SHIFT) {
-                    // Shift+Tab: previous field
-                    self.pr_create_field = if self.pr_create_field == 0 {
-                        5
-                    } else {
-                        self.pr_create_field - 1
-                    };
-                } else {
-                    // Tab: next field
-                    self.pr_create_field = (self.pr_create_field + 1) % 6;
+            .await;
+
+            match result {
+                Ok(message) => {
+                    let _ = tx
+                        .send(AsyncMessage::AiSquashMessageGenerated {
+                            request,
+                            number: pr_number,
+                            message,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::AiSquashMessageError {
+                            request,
+                            error: e.to_string(),
+                        })
+                        .await;
                 }
             }
-            // Enter: action depends on current field
-            KeyCode::Enter => {
-                match self.pr_create_field {
-                    1 => {
-                        // Head branch - select current item
-                        if let Some(branch) = self
-                            .pr_create_branches
-                            .get(self.pr_create_head_selection.selected)
-                        {
-                            self.pr_create_head = branch.name.clone();
-                            self.update_pr_commits();
-                        }
-                    }
+        });
+        self.ai_task = Some(task.abort_handle());
+    }
+
+    /// Add a reaction to the currently selected comment
+    fn add_reaction(&mut self, reaction_type: ReactionType) {
+        if self.reaction_submitting {
+            return;
+        }
+
+        // Get the selected comment
+        let comment = match self.pr_comments.get(self.pr_comments_selection.selected) {
+            Some(c) => c,
+            None => return,
+        };
+
+        let comment_id: u64 = *comment.id;
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.reaction_submitting = true;
+        self.status_message = Some("Adding reaction...".to_string());
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = PullRequestHandler::new(&client);
+                handler
+                    .add_comment_reaction(comment_id, reaction_type)
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(reaction) => {
+                    let _ = tx
+                        .send(AsyncMessage::ReactionAdded {
+                            comment_id,
+                            reaction: Box::new(reaction),
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncMessage::ReactionAddError(e.to_string())).await;
+                }
+            }
+        });
+    }
+
+    /// Add a reaction to the selected PR's description
+    fn add_pr_reaction(&mut self, reaction_type: ReactionType) {
+        if self.reaction_submitting {
+            return;
+        }
+
+        let (repo, pr_number) = match (&self.repository, &self.selected_pr) {
+            (Some(r), Some(pr)) => (r.clone(), pr.number),
+            _ => return,
+        };
+
+        self.reaction_submitting = true;
+        self.status_message = Some("Adding reaction...".to_string());
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = PullRequestHandler::new(&client);
+                handler.add_issue_reaction(pr_number, reaction_type).await
+            }
+            .await;
+
+            match result {
+                Ok(reaction) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrReactionAdded(Box::new(reaction)))
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncMessage::ReactionAddError(e.to_string())).await;
+                }
+            }
+        });
+    }
+
+    /// Toggle a reaction on the currently selected comment
+    /// If the user already has this reaction, remove it; otherwise add it
+    fn toggle_reaction(&mut self, reaction_type: ReactionType) {
+        if self.reaction_submitting {
+            return;
+        }
+
+        // The picker opened from the expanded description reacts to the PR itself
+        if self.pr_description_expanded {
+            self.add_pr_reaction(reaction_type);
+            return;
+        }
+
+        // Get the selected comment
+        let comment = match self.pr_comments.get(self.pr_comments_selection.selected) {
+            Some(c) => c,
+            None => return,
+        };
+
+        let comment_id: u64 = *comment.id;
+
+        // Find our own reaction of this type, if any
+        let own_reaction_id = self.current_user.as_deref().and_then(|login| {
+            self.pr_comment_reactions
+                .get(&comment_id)?
+                .iter()
+                .find(|r| {
+                    r.content == reaction_type.content()
+                        && r.user.as_ref().is_some_and(|u| u.login == login)
+                })
+                .map(|r| r.id)
+        });
+
+        match own_reaction_id {
+            Some(reaction_id) => self.remove_reaction(comment_id, reaction_id),
+            None => self.add_reaction(reaction_type),
+        }
+    }
+
+    /// Remove one of our reactions from a comment
+    fn remove_reaction(&mut self, comment_id: u64, reaction_id: u64) {
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.reaction_submitting = true;
+        self.status_message = Some("Removing reaction...".to_string());
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = PullRequestHandler::new(&client);
+                handler
+                    .delete_comment_reaction(comment_id, reaction_id)
+                    .await
+            }
+            .await;
+
+            match result {
+                Ok(()) => {
+                    let _ = tx
+                        .send(AsyncMessage::ReactionRemoved {
+                            comment_id,
+                            reaction_id,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::ReactionRemoveError(e.to_string()))
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// Spawn a task to fetch workflow runs for the current PR (by head branch)
+    pub fn fetch_pr_workflow_runs(&mut self) {
+        if self.pr_workflow_runs_loading {
+            return;
+        }
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        let head_branch = match &self.selected_pr {
+            Some(pr) => pr.head.ref_field.clone(),
+            None => return,
+        };
+
+        self.pr_workflow_runs_loading = true;
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = WorkflowHandler::new(&client);
+                // Fetch workflows for the PR's head branch, limited to recent runs
+                handler.list_runs(Some(&head_branch), None, None, 10).await
+            }
+            .await;
+
+            match result {
+                Ok(runs) => {
+                    let _ = tx.send(AsyncMessage::PrWorkflowRunsLoaded(runs)).await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrWorkflowRunsError(e.to_string()))
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// Spawn a task to fetch workflow runs (with status message)
+    pub fn fetch_workflow_runs(&mut self) {
+        self.fetch_workflow_runs_impl(None, true);
+    }
+
+    /// Spawn a task to fetch workflow runs, preserving selection (silent refresh)
+    fn fetch_workflow_runs_with_selection(&mut self, preserve_run_id: Option<u64>) {
+        self.fetch_workflow_runs_impl(preserve_run_id, false);
+    }
+
+    /// Internal implementation for fetching workflow runs
+    fn fetch_workflow_runs_impl(&mut self, preserve_run_id: Option<u64>, show_status: bool) {
+        if self.workflow_runs_loading {
+            return; // Already loading
+        }
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.workflow_runs_loading = true;
+        self.workflow_runs_error = None;
+        if show_status {
+            let msg = if let Some(ref branch) = self.pr_workflow_branch {
+                format!("Loading workflow runs for branch '{}'...", branch)
+            } else {
+                "Loading workflow runs...".to_string()
+            };
+            self.status_message = Some(msg);
+        }
+
+        let tx = self.async_tx.clone();
+        let branch_filter = self.pr_workflow_branch.clone();
+        let mine_only = self.workflow_runs_mine_only;
+        let known_login = self.current_user.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+
+                // Resolve the login once; later fetches reuse the cached value
+                let actor = match (mine_only, known_login) {
+                    (false, _) => None,
+                    (true, Some(login)) => Some(login),
+                    (true, None) => Some(client.current_user_login().await?),
+                };
+
+                let handler = WorkflowHandler::new(&client);
+                let runs = handler
+                    .list_runs(branch_filter.as_deref(), actor.as_deref(), None, 30)
+                    .await?;
+                Ok::<_, GhrustError>((runs, actor))
+            }
+            .await;
+
+            match result {
+                Ok((runs, actor)) => {
+                    let _ = tx
+                        .send(AsyncMessage::WorkflowRunsLoaded {
+                            runs,
+                            preserve_selection_id: preserve_run_id,
+                            actor,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::WorkflowRunsError(e.to_string()))
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// Returns true if any workflow run is currently active (running, queued, pending, etc.)
+    ///
+    /// Ignores the conclusion/event filters so hidden active runs keep polling.
+    fn has_active_workflow_runs(&self) -> bool {
+        self.workflow_runs.iter().any(|run| run.status.is_active())
+    }
+
+    /// Check if we should poll workflow runs and trigger fetch if needed
+    fn maybe_poll_workflow_runs(&mut self) {
+        // Only poll when on the workflow runs screen
+        if self.current_screen != Screen::WorkflowRuns {
+            return;
+        }
+
+        // Don't poll if already loading
+        if self.workflow_runs_loading {
+            return;
+        }
+
+        // Don't poll if there are no active workflows
+        if !self.has_active_workflow_runs() {
+            return;
+        }
+
+        // Calculate ticks since last poll
+        // Read on every check so a change in Settings applies immediately
+        let poll_interval_ticks = self.workflow_poll_interval_secs() * 1000 / TICK_RATE_MS;
+
+        let ticks_since_poll = self
+            .tick_counter
+            .wrapping_sub(self.workflow_runs_last_poll_tick);
+
+        if ticks_since_poll >= poll_interval_ticks {
+            // Store the current selection for restoration after refresh
+            let current_run_id = self.selected_workflow_run().map(|run| run.id);
+
+            // Update last poll tick BEFORE fetching to prevent rapid re-polls
+            self.workflow_runs_last_poll_tick = self.tick_counter;
+
+            // Trigger silent refresh with selection preservation
+            self.fetch_workflow_runs_with_selection(current_run_id);
+        }
+    }
+
+    /// Effective workflow poll interval: the configured one stretched by any backoff
+    pub fn workflow_poll_interval_secs(&self) -> u64 {
+        u64::from(self.workflow_poll_seconds) * u64::from(self.workflow_poll_backoff_multiplier)
+    }
+
+    /// Recompute ahead/behind counts for the status bar if they are stale
+    fn maybe_refresh_branch_status(&mut self) {
+        // With 250ms tick rate: 12 ticks ≈ 3 seconds
+        const BRANCH_STATUS_INTERVAL_TICKS: u64 = 12;
+
+        if let Some(last) = self.branch_status_last_tick {
+            if self.tick_counter.wrapping_sub(last) < BRANCH_STATUS_INTERVAL_TICKS {
+                return;
+            }
+        }
+        self.branch_status_last_tick = Some(self.tick_counter);
+
+        self.branch_ahead_behind = GitRepository::open_current_dir()
+            .and_then(|git| git.branch_status())
+            .ok();
+    }
+
+    /// Fetch the API rate limit for the status bar if it is stale
+    fn maybe_refresh_rate_limit(&mut self) {
+        // With 250ms tick rate: 240 ticks ≈ 60 seconds
+        const RATE_LIMIT_INTERVAL_TICKS: u64 = 240;
+
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+        if let Some(last) = self.rate_limit_last_tick {
+            if self.tick_counter.wrapping_sub(last) < RATE_LIMIT_INTERVAL_TICKS {
+                return;
+            }
+        }
+        self.rate_limit_last_tick = Some(self.tick_counter);
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                client.rate_limit().await
+            }
+            .await;
+
+            // Failures keep the last known value; the indicator is best-effort
+            if let Ok(rate_limit) = result {
+                let _ = tx.send(AsyncMessage::RateLimitLoaded(rate_limit)).await;
+            }
+        });
+    }
+
+    /// Spawn a task to resolve the authenticated user's login
+    ///
+    /// Failures leave it unset; features that need it resolve it lazily or degrade.
+    fn fetch_current_user(&mut self) {
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                client.current_user_login().await
+            }
+            .await;
+
+            if let Ok(login) = result {
+                let _ = tx.send(AsyncMessage::CurrentUserLoaded(login)).await;
+            }
+        });
+    }
+
+    /// Handle mouse events: wheel scrolls the expanded comment or description,
+    /// a left click selects a row in the PR list, commit files or workflow runs
+    fn handle_mouse_event(&mut self, mouse: MouseEvent) {
+        const WHEEL_LINES: usize = 3;
+
+        if mouse.kind == MouseEventKind::Moved {
+            return;
+        }
+
+        let scroll = match mouse.kind {
+            MouseEventKind::ScrollDown => Some(true),
+            MouseEventKind::ScrollUp => Some(false),
+            _ => None,
+        };
+        if let Some(down) = scroll {
+            let (offset, max) = if self.pr_comment_expanded {
+                (
+                    &mut self.pr_comment_scroll,
+                    self.pr_comment_max_scroll.get(),
+                )
+            } else if self.pr_description_expanded {
+                (
+                    &mut self.pr_description_scroll,
+                    self.pr_description_max_scroll.get(),
+                )
+            } else {
+                return;
+            };
+            *offset = if down {
+                (*offset + WHEEL_LINES).min(max)
+            } else {
+                offset.saturating_sub(WHEEL_LINES)
+            };
+            return;
+        }
+
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) || self.has_open_overlay() {
+            return;
+        }
+
+        // Row within a list's inner area, if the click landed in it
+        let row_in = |area: Rect| {
+            let inside = mouse.column >= area.x
+                && mouse.column < area.x + area.width
+                && mouse.row >= area.y
+                && mouse.row < area.y + area.height;
+            inside.then(|| (mouse.row - area.y) as usize)
+        };
+
+        match self.current_screen {
+            Screen::PrList if !self.pr_list_loading => {
+                if let Some(row) = row_in(self.pr_list_area.get()) {
+                    if row < self.filtered_pr_list().len() {
+                        self.pr_list_selection.selected = row;
+                    }
+                }
+            }
+            Screen::Commit
+                if !self.commit_message_mode
+                    && !self.commit_push_prompt
+                    && self.commit_hunk_path.is_none() =>
+            {
+                if let Some(row) = row_in(self.commit_files_area.get()) {
+                    self.select_commit_row(self.commit_file_scroll + row);
+                }
+            }
+            Screen::WorkflowRuns => {
+                if let Some(row) = row_in(self.workflow_runs_area.get()) {
+                    // Job rows are listed under the selected run
+                    let selected = self.workflow_runs_selection.selected;
+                    let jobs = self.workflow_job_rows.get();
+                    let index = if row <= selected {
+                        row
+                    } else if row <= selected + jobs {
+                        return;
+                    } else {
+                        row - jobs
+                    };
+                    if index < self.filtered_workflow_runs().len() {
+                        self.workflow_runs_selection.selected = index;
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Whether a popup or prompt covers the current screen
+    fn has_open_overlay(&self) -> bool {
+        self.show_help
+            || self.error_popup.is_some()
+            || self.quit_prompt_open
+            || self.workflow_rerun_confirm.is_some()
+            || self.workflow_cancel_confirm.is_some()
+    }
+
+    /// Select the commit file list row at `flat_row` (folder headers included)
+    fn select_commit_row(&mut self, flat_row: usize) {
+        let mut row = 0;
+        for (group_idx, group) in self.file_groups.iter().enumerate() {
+            if row == flat_row {
+                self.selected_group_idx = group_idx;
+                self.selected_file_in_group = None;
+                break;
+            }
+            row += 1;
+            if group.expanded {
+                if flat_row < row + group.files.len() {
+                    self.selected_group_idx = group_idx;
+                    self.selected_file_in_group = Some(flat_row - row);
+                    break;
+                }
+                row += group.files.len();
+            }
+        }
+
+        self.sync_legacy_selection();
+        self.adjust_commit_scroll_to_selection();
+        self.update_commit_diff_preview();
+    }
+
+    /// Handle keyboard events
+    fn handle_key_event(&mut self, key: KeyEvent) {
+        // If help is shown, any key dismisses it
+        if self.show_help {
+            self.show_help = false;
+            return;
+        }
+
+        // Quit confirmation: y/Enter quits, anything else cancels
+        if self.quit_prompt_open {
+            self.quit_prompt_open = false;
+            if matches!(
+                key.code,
+                KeyCode::Char('y') | KeyCode::Char('Y') | KeyCode::Enter
+            ) {
+                self.quit();
+            }
+            return;
+        }
+
+        // If error popup is shown, only allow dismissal and copy keys
+        if self.error_popup.is_some() {
+            match key.code {
+                KeyCode::Enter | KeyCode::Esc | KeyCode::Char('q') => self.error_popup = None,
+                KeyCode::Char('y') => self.copy_error_details(),
+                _ => {}
+            }
+            return; // Block all other input while popup is shown
+        }
+
+        // Esc cancels an in-flight AI generation before anything else
+        if key.code == KeyCode::Esc && self.ai_loading() {
+            self.cancel_ai_task();
+            return;
+        }
+
+        // If in settings input mode, handle it directly (bypass global handlers)
+        if self.settings_input_mode {
+            self.handle_settings_key(key);
+            return;
+        }
+
+        // If in commit message input mode, handle it directly (bypass global handlers)
+        if self.commit_message_mode {
+            self.handle_commit_key(key);
+            return;
+        }
+
+        // Amend prompt and hunk list use Esc themselves instead of leaving the screen
+        if self.commit_amend_confirm || self.commit_hunk_path.is_some() {
+            self.handle_commit_key(key);
+            return;
+        }
+
+        // PR comment expanded view - handle j/k scroll and close
+        if self.pr_comment_expanded {
+            self.handle_pr_detail_key(key);
+            return;
+        }
+
+        // PR description expanded view - handle j/k scroll and close
+        if self.pr_description_expanded {
+            self.handle_pr_detail_key(key);
+            return;
+        }
+
+        // AI feedback prompt on the PR create screen
+        if self.pr_create_feedback_open {
+            self.handle_pr_create_feedback_key(key);
+            return;
+        }
+
+        // PR edit form - handle text input
+        if self.pr_edit_open {
+            self.handle_pr_edit_key(key);
+            return;
+        }
+
+        // Merge dialog - text fields must not trigger back/quit keys
+        if self.merge_dialog_open {
+            self.handle_merge_dialog_key(key);
+            return;
+        }
+
+        // PR review dialog - handle event selection and body input
+        if self.review_dialog_open {
+            self.handle_review_dialog_key(key);
+            return;
+        }
+
+        // PR comment input mode - handle text input
+        if self.pr_comment_input_mode {
+            self.handle_pr_detail_key(key);
+            return;
+        }
+
+        // If in PR create form on a text field, bypass global handlers for text input
+        if self.current_screen == Screen::PrCreate {
+            let is_text_field = matches!(self.pr_create_field, 0 | 3 | 6 | 7);
+            if is_text_field {
+                // Only allow Esc to go back, otherwise handle as form input
+                if key.code == KeyCode::Esc {
+                    self.go_back();
+                    return;
+                }
+                self.handle_pr_create_key(key);
+                return;
+            }
+        }
+
+        // PR list search box - handle text input
+        if self.pr_list_search_mode {
+            self.handle_pr_list_search_key(key);
+            return;
+        }
+
+        // Esc on a filtered PR list clears the search before going back
+        if self.current_screen == Screen::PrList
+            && key.code == KeyCode::Esc
+            && !self.pr_list_search_query.is_empty()
+        {
+            self.clear_pr_list_search();
+            return;
+        }
+
+        // Tag detail popup - handle j/k scroll and close
+        if self.tag_detail.is_some() {
+            self.handle_tag_detail_key(key);
+            return;
+        }
+
+        // If in tag creation mode, handle it directly (bypass global handlers)
+        if self.tag_create_mode {
+            self.handle_tag_create_key(key);
+            return;
+        }
+
+        // Branch name input captures all keys until Enter or Esc
+        if self.branch_create_mode {
+            self.handle_branch_create_key(key);
+            return;
+        }
+
+        // Workflow re-run confirmation: a re-runs all jobs, f only the failed ones
+        if let Some((run_id, _, has_failures)) = self.workflow_rerun_confirm.take() {
+            match key.code {
+                KeyCode::Char('a') | KeyCode::Char('y') => self.rerun_workflow(run_id, false),
+                KeyCode::Char('f') if has_failures => self.rerun_workflow(run_id, true),
+                _ => self.status_message = Some("Re-run cancelled".to_string()),
+            }
+            return;
+        }
+
+        // Workflow cancel confirmation: y cancels the run, anything else keeps it
+        if let Some((run_id, _)) = self.workflow_cancel_confirm.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.cancel_workflow(run_id);
+            } else {
+                self.status_message = Some("Run left running".to_string());
+            }
+            return;
+        }
+
+        // Branch deletion confirmation: y deletes, anything else cancels
+        if let Some(name) = self.branch_delete_confirm.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.delete_local_branch(name);
+            } else {
+                self.status_message = Some("Delete cancelled".to_string());
+            }
+            return;
+        }
+
+        // Tag deletion confirmation: y deletes, anything else cancels
+        if let Some((name, remote)) = self.tag_delete_confirm.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.delete_tag(name, remote);
+            } else {
+                self.status_message = Some("Delete cancelled".to_string());
+            }
+            return;
+        }
+
+        // Global key handlers
+        if key.code == KeyCode::Char('?') {
+            self.show_help = true;
+            return;
+        }
+
+        // Ctrl+r: reload all cached data and re-check auth
+        if key.code == KeyCode::Char('r')
+            && key
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::CONTROL)
+        {
+            self.refresh_all();
+            return;
+        }
+
+        if is_quit_key(&key) {
+            if self.current_screen == Screen::Dashboard {
+                self.handle_dashboard_quit(&key);
+            } else {
+                self.go_back();
+            }
+            return;
+        }
+
+        if is_back_key(&key) {
+            self.go_back();
+            return;
+        }
+
+        // Screen-specific handlers
+        match self.current_screen {
+            Screen::Dashboard => self.handle_dashboard_key(key),
+            Screen::PrList => self.handle_pr_list_key(key),
+            Screen::PrDetail(_) => self.handle_pr_detail_key(key),
+            Screen::PrDiff(_) => self.handle_pr_diff_key(key),
+            Screen::PrCreate => self.handle_pr_create_key(key),
+            Screen::Commit => self.handle_commit_key(key),
+            Screen::Tags => {
+                if self.tag_create_mode {
+                    self.handle_tag_create_key(key);
+                } else {
+                    self.handle_tags_key(key);
+                }
+            }
+            Screen::Branches => self.handle_branches_key(key),
+            Screen::Settings => self.handle_settings_key(key),
+            Screen::WorkflowRuns => self.handle_workflow_runs_key(key),
+            Screen::WorkflowLogs(_) => self.handle_workflow_logs_key(key),
+            _ => {}
+        }
+    }
+
+    /// Handle a quit key on the dashboard according to the configured `q` behavior
+    fn handle_dashboard_quit(&mut self, key: &KeyEvent) {
+        // Ctrl+c is the explicit quit command and always exits
+        if key
+            .modifiers
+            .contains(crossterm::event::KeyModifiers::CONTROL)
+        {
+            self.quit();
+            return;
+        }
+
+        match self.q_behavior {
+            QKeyBehavior::Quit => self.quit(),
+            QKeyBehavior::Prompt => self.quit_prompt_open = true,
+            QKeyBehavior::Back => {
+                self.status_message = Some("Press Ctrl+c to quit".to_string());
+            }
+        }
+    }
+
+    fn handle_dashboard_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.dashboard_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.dashboard_selection.previous(),
+            KeyCode::Enter => match self.dashboard_selection.selected {
+                0 => self.navigate_to(Screen::PrList),
+                1 => self.navigate_to(Screen::Commit),
+                2 => self.navigate_to(Screen::Tags),
+                3 => self.navigate_to(Screen::Branches),
+                4 => self.navigate_to(Screen::WorkflowRuns),
+                5 => self.navigate_to(Screen::Settings),
+                6 => self.quit(),
+                _ => {}
+            },
+            KeyCode::Char('p') => self.navigate_to(Screen::PrList),
+            KeyCode::Char('c') => self.navigate_to(Screen::Commit),
+            KeyCode::Char('t') => self.navigate_to(Screen::Tags),
+            KeyCode::Char('b') => self.navigate_to(Screen::Branches),
+            KeyCode::Char('w') => self.navigate_to(Screen::WorkflowRuns),
+            KeyCode::Char('s') => self.navigate_to(Screen::Settings),
+            _ => {}
+        }
+    }
+
+    fn handle_pr_list_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.pr_list_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.pr_list_selection.previous(),
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                self.pr_list_selection.handle_jump_key(key.code);
+            }
+            KeyCode::Enter => {
+                // Navigate to PR detail if there's a selection
+                if let Some(pr) = self.selected_pr_list_item() {
+                    let pr_number = pr.number;
+                    self.navigate_to(Screen::PrDetail(pr_number));
+                }
+            }
+            KeyCode::Char('/') => {
+                self.pr_list_search_mode = true;
+            }
+            KeyCode::Char('n') => {
+                self.navigate_to(Screen::PrCreate);
+            }
+            KeyCode::Char('r') => {
+                // Force refresh
+                self.pr_list.clear();
+                self.pr_list_fetched = false;
+                self.fetch_pr_list();
+            }
+            KeyCode::Char('f') => {
+                // Cycle the state filter and refetch
+                self.pr_list_state_filter = self.pr_list_state_filter.next();
+                self.pr_list.clear();
+                self.pr_list_selection = ListState::default();
+                self.pr_list_fetched = false;
+                self.fetch_pr_list();
+            }
+            KeyCode::Char('o') => {
+                let number = self.selected_pr_list_item().map(|pr| pr.number);
+                self.open_pr_in_browser(number);
+            }
+            KeyCode::Char('L') => self.fetch_more_prs(),
+            _ => {}
+        }
+    }
+
+    /// Handle key events while typing in the PR list search box
+    fn handle_pr_list_search_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => self.clear_pr_list_search(),
+            KeyCode::Enter => {
+                // Keep the filter and return to list navigation
+                self.pr_list_search_mode = false;
+            }
+            KeyCode::Down => self.pr_list_selection.next(),
+            KeyCode::Up => self.pr_list_selection.previous(),
+            KeyCode::Backspace => {
+                self.pr_list_search_query.pop();
+                self.clamp_pr_list_selection();
+            }
+            KeyCode::Char(c) => {
+                self.pr_list_search_query.push(c);
+                self.clamp_pr_list_selection();
+            }
+            _ => {}
+        }
+    }
+
+    /// Open a pull request's GitHub page in the default browser
+    fn open_pr_in_browser(&mut self, number: Option<u64>) {
+        let Some(number) = number else {
+            self.status_message = Some("No pull request selected".to_string());
+            return;
+        };
+        let Some(repo) = &self.repository else {
+            self.status_message = Some("No GitHub repository detected".to_string());
+            return;
+        };
+        let url = repo.pull_request_url(number);
+        if crate::github::open_browser(&url) {
+            self.status_message = Some(format!("Opened PR #{} in browser", number));
+        } else {
+            self.status_message = Some(format!("Could not open browser: {}", url));
+        }
+    }
+
+    /// Clear the PR list search and return to normal navigation
+    fn clear_pr_list_search(&mut self) {
+        self.pr_list_search_mode = false;
+        self.pr_list_search_query.clear();
+        self.clamp_pr_list_selection();
+    }
+
+    /// PRs matching the search query (case-insensitive title or author substring)
+    pub fn filtered_pr_list(&self) -> Vec<&PullRequest> {
+        let query = self.pr_list_search_query.to_lowercase();
+        self.pr_list
+            .iter()
+            .filter(|pr| {
+                if query.is_empty() {
+                    return true;
+                }
+                let title_match = pr
+                    .title
+                    .as_deref()
+                    .is_some_and(|t| t.to_lowercase().contains(&query));
+                let author_match = pr
+                    .user
+                    .as_ref()
+                    .is_some_and(|u| u.login.to_lowercase().contains(&query));
+                title_match || author_match
+            })
+            .collect()
+    }
+
+    /// The PR under the cursor in the (filtered) PR list
+    fn selected_pr_list_item(&self) -> Option<&PullRequest> {
+        self.filtered_pr_list()
+            .get(self.pr_list_selection.selected)
+            .copied()
+    }
+
+    /// Resize the PR list selection to the filtered set, keeping the index in range
+    fn clamp_pr_list_selection(&mut self) {
+        let total = self.filtered_pr_list().len();
+        self.pr_list_selection.total = total;
+        self.pr_list_selection.selected =
+            self.pr_list_selection.selected.min(total.saturating_sub(1));
+    }
+
+    /// Handle key events for PR create form
+    /// Fields: 0=title, 1=head, 2=base, 3=body, 4=draft, 5=submit, 6=reviewers, 7=assignees
+    fn handle_pr_create_key(&mut self, key: KeyEvent) {
+        use crossterm::event::KeyModifiers;
+
+        // Ctrl+p: toggle the rendered markdown preview of the body
+        if key.code == KeyCode::Char('p') && key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.pr_create_body_preview = !self.pr_create_body_preview;
+            return;
+        }
+
+        // The preview is read-only: only field navigation leaves it
+        if self.pr_create_body_preview
+            && self.pr_create_field == 3
+            && !matches!(key.code, KeyCode::Tab | KeyCode::BackTab)
+        {
+            return;
+        }
+
+        // Ctrl+z / Ctrl+y: undo / redo body edits
+        if self.pr_create_field == 3 && key.modifiers.contains(KeyModifiers::CONTROL) {
+            let history = &mut self.pr_create_body_history;
+            let result = match key.code {
+                KeyCode::Char('z') => Some((history.undo(&mut self.pr_create_body), "undo")),
+                KeyCode::Char('y') => Some((history.redo(&mut self.pr_create_body), "redo")),
+                _ => None,
+            };
+            if let Some((changed, action)) = result {
+                if changed {
+                    self.pr_create_body_cursor = text_input::cursor_at_end(&self.pr_create_body);
+                } else {
+                    self.status_message = Some(format!("Nothing to {}", action));
+                }
+                return;
+            }
+        }
+
+        // Body field: plain editing keys go to the multi-line editor
+        if self.pr_create_field == 3 && !key.modifiers.contains(KeyModifiers::CONTROL) {
+            self.pr_create_body_history
+                .before_key(&self.pr_create_body, key.code);
+        }
+        if self.pr_create_field == 3
+            && !key.modifiers.contains(KeyModifiers::CONTROL)
+            && text_input::handle_wrapped_body_key(
+                &mut self.pr_create_body,
+                &mut self.pr_create_body_cursor,
+                key.code,
+                self.pr_create_body_width.get(),
+            )
+        {
+            return;
+        }
+
+        match key.code {
+            // Ctrl+g: trigger AI generation from any field
+            KeyCode::Char('g')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.ai_configured
+                    && !self.pr_create_ai_loading =>
+            {
+                self.generate_ai_pr_content(None);
+            }
+            // Ctrl+f: regenerate the AI title/body with feedback
+            KeyCode::Char('f')
+                if key.modifiers.contains(KeyModifiers::CONTROL)
+                    && self.ai_configured
+                    && !self.pr_create_ai_loading =>
+            {
+                self.pr_create_feedback_open = true;
+                self.status_message = Some("Describe what to change, then press Enter".to_string());
+            }
+            // Ctrl+e: edit the body in an external editor
+            KeyCode::Char('e')
+                if key.modifiers.contains(KeyModifiers::CONTROL) && self.pr_create_field == 3 =>
+            {
+                self.pending_editor = Some(EditorTarget::PrBody);
+            }
+            // Ctrl+g/Ctrl+f while AI is unavailable or busy: don't type into the field
+            KeyCode::Char('g' | 'f') if key.modifiers.contains(KeyModifiers::CONTROL) => {}
+            // Tab: move to next field
+            KeyCode::Tab => {
+                if key.modifiers.contains(KeyModifiers::SHIFT) {
+                    // Shift+Tab: previous field
+                    self.pr_create_field = if self.pr_create_field == 0 {
+                        7
+                    } else {
+                        self.pr_create_field - 1
+                    };
+                } else {
+                    // Tab: next field
+                    self.pr_create_field = (self.pr_create_field + 1) % 8;
+                }
+            }
+            // Enter: action depends on current field
+            KeyCode::Enter => {
+                match self.pr_create_field {
+                    1 => {
+                        // Head branch - select current item
+                        if let Some(branch) = self
+                            .pr_create_branches
+                            .get(self.pr_create_head_selection.selected)
+                        {
+                            self.pr_create_head = branch.name.clone();
+                            self.update_pr_commits();
+                        }
+                    }
                     2 => {
                         // Base branch - select current item
                         if let Some(branch) = self
@@ -1749,986 +4303,1905 @@ impl App {
                             self.update_pr_commits();
                         }
                     }
-                    3 => {
-                        // Body field - insert newline
-                        let lines = split_lines_preserve_trailing(&self.pr_create_body);
-                        let (row, col) = self.pr_create_body_cursor;
-
-                        // Rebuild body with newline inserted
-                        let mut new_body = String::new();
-                        for (i, line) in lines.iter().enumerate() {
-                            if i == row {
-                                let col = col.min(line.len());
-                                new_body.push_str(&line[..col]);
-                                new_body.push('\n');
-                                new_body.push_str(&line[col..]);
-                            } else {
-                                new_body.push_str(line);
-                            }
-                            if i < lines.len() - 1 {
-                                new_body.push('\n');
-                            }
-                        }
-                        // Handle empty body or cursor at end
-                        if lines.is_empty() || row >= lines.len() {
-                            new_body.push('\n');
-                        }
-                        self.pr_create_body = new_body;
-                        self.pr_create_body_cursor = (row + 1, 0);
+                    4 => {
+                        // Draft toggle
+                        self.pr_create_draft = !self.pr_create_draft;
+                    }
+                    5 => {
+                        // Submit button
+                        self.submit_pr_create();
+                    }
+                    _ => {}
+                }
+            }
+            // Up/Down: navigate within branch lists (arrow keys only)
+            KeyCode::Up => match self.pr_create_field {
+                1 => self.pr_create_head_selection.previous(),
+                2 => self.pr_create_base_selection.previous(),
+                _ => {}
+            },
+            KeyCode::Down => match self.pr_create_field {
+                1 => self.pr_create_head_selection.next(),
+                2 => self.pr_create_base_selection.next(),
+                _ => {}
+            },
+            // Backspace: delete character
+            KeyCode::Backspace => match self.pr_create_field {
+                0 => {
+                    self.pr_create_title.pop();
+                }
+                6 => {
+                    self.pr_create_reviewers.pop();
+                }
+                7 => {
+                    self.pr_create_assignees.pop();
+                }
+                _ => {}
+            },
+            // Space: toggle draft or add space to text
+            KeyCode::Char(' ') => match self.pr_create_field {
+                0 => self.pr_create_title.push(' '),
+                6 => self.pr_create_reviewers.push(' '),
+                7 => self.pr_create_assignees.push(' '),
+                4 => self.pr_create_draft = !self.pr_create_draft,
+                _ => {}
+            },
+            // Character input for text fields, with vim navigation for branch selectors
+            KeyCode::Char(c) => match self.pr_create_field {
+                0 => self.pr_create_title.push(c),
+                6 => self.pr_create_reviewers.push(c),
+                7 => self.pr_create_assignees.push(c),
+                1 => {
+                    // Branch selector: use j/k for vim navigation
+                    if c == 'j' {
+                        self.pr_create_head_selection.next();
+                    } else if c == 'k' {
+                        self.pr_create_head_selection.previous();
+                    }
+                }
+                2 => {
+                    // Branch selector: use j/k for vim navigation
+                    if c == 'j' {
+                        self.pr_create_base_selection.next();
+                    } else if c == 'k' {
+                        self.pr_create_base_selection.previous();
                     }
-                    4 => {
-                        // Draft toggle
-                        self.pr_create_draft = !self.pr_create_draft;
+                }
+                _ => {}
+            },
+            _ => {}
+        }
+    }
+
+    /// Handle key events for the AI feedback prompt
+    ///
+    /// The feedback is kept after regenerating so it can be refined.
+    fn handle_pr_create_feedback_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.pr_create_feedback_open = false;
+                self.status_message = Some("Cancelled".to_string());
+            }
+            KeyCode::Enter => {
+                let feedback = self.pr_create_ai_feedback.trim().to_string();
+                if feedback.is_empty() {
+                    self.status_message = Some("Feedback cannot be empty".to_string());
+                    return;
+                }
+                self.pr_create_feedback_open = false;
+                self.generate_ai_pr_content(Some(feedback));
+            }
+            KeyCode::Backspace => {
+                self.pr_create_ai_feedback.pop();
+            }
+            KeyCode::Char(c) => self.pr_create_ai_feedback.push(c),
+            _ => {}
+        }
+    }
+
+    /// Handle key events for the PR diff viewer
+    fn handle_pr_diff_key(&mut self, key: KeyEvent) {
+        let max_scroll = self.pr_diff_line_count.saturating_sub(1);
+
+        // `]`/`[` jump between hunks, `}`/`{` between files
+        if let Some(jump) = match key.code {
+            KeyCode::Char(c) => DiffJump::from_key(c),
+            _ => None,
+        } {
+            self.pr_diff_scroll = self.pr_diff_index.jump(self.pr_diff_scroll, jump);
+            return;
+        }
+
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.pr_diff_scroll = (self.pr_diff_scroll + 1).min(max_scroll);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.pr_diff_scroll = self.pr_diff_scroll.saturating_sub(1);
+            }
+            KeyCode::PageDown => {
+                self.pr_diff_scroll = (self.pr_diff_scroll + 20).min(max_scroll);
+            }
+            KeyCode::PageUp => {
+                self.pr_diff_scroll = self.pr_diff_scroll.saturating_sub(20);
+            }
+            KeyCode::Home => self.pr_diff_scroll = 0,
+            KeyCode::End => self.pr_diff_scroll = max_scroll,
+            KeyCode::Char('r') => {
+                if let Screen::PrDiff(number) = self.current_screen {
+                    self.fetch_pr_diff(number);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle key events for the PR edit form
+    /// Fields: 0=title, 1=body
+    fn handle_pr_edit_key(&mut self, key: KeyEvent) {
+        use crossterm::event::KeyModifiers;
+
+        if self.pr_edit_submitting {
+            return; // Ignore keys while saving
+        }
+
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.pr_edit_open = false;
+                self.status_message = Some("Edit cancelled".to_string());
+            }
+            // Ctrl+s: save
+            KeyCode::Char('s') if ctrl => self.submit_pr_edit(),
+            // Ctrl+e: edit the body in an external editor
+            KeyCode::Char('e') if ctrl && self.pr_edit_field == 1 => {
+                self.pending_editor = Some(EditorTarget::PrEditBody);
+            }
+            KeyCode::Tab | KeyCode::BackTab => {
+                self.pr_edit_field = 1 - self.pr_edit_field;
+            }
+            _ if ctrl => {}
+            // Title field
+            KeyCode::Enter if self.pr_edit_field == 0 => self.pr_edit_field = 1,
+            KeyCode::Backspace if self.pr_edit_field == 0 => {
+                self.pr_edit_title.pop();
+            }
+            KeyCode::Char(c) if self.pr_edit_field == 0 => self.pr_edit_title.push(c),
+            // Body field
+            code if self.pr_edit_field == 1 => {
+                text_input::handle_body_key(
+                    &mut self.pr_edit_body,
+                    &mut self.pr_edit_body_cursor,
+                    code,
+                );
+            }
+            _ => {}
+        }
+    }
+
+    /// Squash commit title GitHub would use, pre-filled for squash merges
+    fn squash_default_title(&self) -> String {
+        self.selected_pr
+            .as_ref()
+            .and_then(|pr| {
+                pr.title
+                    .as_ref()
+                    .map(|title| format!("{} (#{})", title, pr.number))
+            })
+            .unwrap_or_default()
+    }
+
+    /// Pre-fill the commit title for squash merges, clearing it when switching away
+    ///
+    /// A title the user typed is left alone.
+    fn select_merge_method(&mut self, selection: usize) {
+        let squash_title = self.squash_default_title();
+        let untouched =
+            self.merge_commit_title.is_empty() || self.merge_commit_title == squash_title;
+
+        self.merge_method_selection = selection;
+        if untouched {
+            self.merge_commit_title = if selection == 1 {
+                squash_title
+            } else {
+                String::new()
+            };
+        }
+    }
+
+    /// Handle key events when merge dialog is open
+    fn handle_merge_dialog_key(&mut self, key: KeyEvent) {
+        if self.merge_in_progress || self.merge_ai_loading {
+            // Block all input while merge or AI generation is in progress
+            return;
+        }
+
+        let ctrl = key
+            .modifiers
+            .contains(crossterm::event::KeyModifiers::CONTROL);
+        // Rebase merges have no merge commit to describe
+        let field_count = if self.merge_method_selection == 2 {
+            1
+        } else {
+            3
+        };
+
+        match key.code {
+            KeyCode::Esc => {
+                self.merge_dialog_open = false;
+            }
+            // Ctrl+s: merge from any field
+            KeyCode::Char('s') if ctrl => self.merge_pr(),
+            KeyCode::Tab => {
+                self.merge_dialog_field = (self.merge_dialog_field + 1) % field_count;
+            }
+            KeyCode::BackTab => {
+                self.merge_dialog_field = (self.merge_dialog_field + field_count - 1) % field_count;
+            }
+            _ if ctrl => {}
+            // Commit title field
+            KeyCode::Enter if self.merge_dialog_field == 1 => self.merge_dialog_field = 2,
+            KeyCode::Backspace if self.merge_dialog_field == 1 => {
+                self.merge_commit_title.pop();
+            }
+            KeyCode::Char(c) if self.merge_dialog_field == 1 => self.merge_commit_title.push(c),
+            // Commit message field
+            code if self.merge_dialog_field == 2 => {
+                text_input::handle_body_key(
+                    &mut self.merge_commit_message,
+                    &mut self.merge_commit_message_cursor,
+                    code,
+                );
+            }
+            KeyCode::Enter => {
+                self.merge_pr();
+            }
+            KeyCode::Char('j') | KeyCode::Down => {
+                // Cycle through merge methods (0, 1, 2)
+                self.select_merge_method((self.merge_method_selection + 1) % 3);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                // Cycle backwards through merge methods
+                self.select_merge_method((self.merge_method_selection + 2) % 3);
+            }
+            // Merge automatically once required checks and reviews pass
+            KeyCode::Char('a') => self.enable_auto_merge(),
+            KeyCode::Char('d') | KeyCode::Char(' ') => {
+                // Toggle delete branch checkbox
+                self.merge_delete_branch = !self.merge_delete_branch;
+            }
+            // AI squash message only applies to squash merges
+            KeyCode::Char('g') if self.merge_method_selection == 1 => {
+                self.generate_ai_squash_message();
+            }
+            KeyCode::Char('x') => {
+                // Clear the commit title and message (fall back to GitHub's default)
+                self.merge_commit_title.clear();
+                self.merge_commit_message.clear();
+                self.merge_commit_message_cursor = (0, 0);
+            }
+            _ => {}
+        }
+    }
+
+    fn handle_pr_detail_key(&mut self, key: KeyEvent) {
+        // If reaction picker is open, handle reaction selection
+        if self.reaction_picker_open {
+            if self.reaction_submitting {
+                return; // Ignore keys while submitting
+            }
+            let reactions = ReactionType::all();
+            match key.code {
+                KeyCode::Esc => {
+                    self.reaction_picker_open = false;
+                }
+                KeyCode::Char(c @ '1'..='8') => {
+                    let index = c as usize - '1' as usize;
+                    self.reaction_picker_open = false;
+                    self.toggle_reaction(reactions[index]);
+                }
+                KeyCode::Char('j') | KeyCode::Down | KeyCode::Char('l') | KeyCode::Right => {
+                    self.reaction_picker_selection =
+                        (self.reaction_picker_selection + 1) % reactions.len();
+                }
+                KeyCode::Char('k') | KeyCode::Up | KeyCode::Char('h') | KeyCode::Left => {
+                    self.reaction_picker_selection =
+                        (self.reaction_picker_selection + reactions.len() - 1) % reactions.len();
+                }
+                KeyCode::Enter => {
+                    // Add the selected reaction
+                    let reaction_type = reactions[self.reaction_picker_selection % reactions.len()];
+                    self.reaction_picker_open = false;
+                    self.toggle_reaction(reaction_type);
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // If merge dialog is open, handle merge dialog keys
+        if self.merge_dialog_open {
+            self.handle_merge_dialog_key(key);
+            return;
+        }
+
+        // If base branch picker is open, handle picker keys
+        if self.base_picker_open {
+            if self.base_picker_loading {
+                if key.code == KeyCode::Esc {
+                    self.base_picker_open = false;
+                }
+                return;
+            }
+            match key.code {
+                KeyCode::Esc => self.base_picker_open = false,
+                KeyCode::Char('j') | KeyCode::Down => self.base_picker_selection.next(),
+                KeyCode::Char('k') | KeyCode::Up => self.base_picker_selection.previous(),
+                KeyCode::Enter => {
+                    if let Some(base) = self
+                        .base_picker_branches
+                        .get(self.base_picker_selection.selected)
+                        .cloned()
+                    {
+                        self.change_pr_base(base);
                     }
-                    5 => {
-                        // Submit button
-                        self.submit_pr_create();
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // If viewing expanded comment, handle scroll/close
+        if self.pr_comment_expanded {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.pr_comment_expanded = false;
+                    self.pr_comment_scroll = 0;
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    let max = self.pr_comment_max_scroll.get();
+                    if self.pr_comment_scroll < max {
+                        self.pr_comment_scroll = self.pr_comment_scroll.saturating_add(1);
                     }
-                    _ => {}
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.pr_comment_scroll = self.pr_comment_scroll.saturating_sub(1);
+                }
+                KeyCode::Char('e') => {
+                    // Open reaction picker
+                    if !self.pr_comments.is_empty() {
+                        self.reaction_picker_open = true;
+                        self.reaction_picker_selection = 0;
+                    }
+                }
+                KeyCode::Char('g') => {
+                    // Jump to the first PR referenced in the comment
+                    let body = self
+                        .pr_comments
+                        .get(self.pr_comments_selection.selected)
+                        .and_then(|c| c.body.clone())
+                        .unwrap_or_default();
+                    self.open_referenced_pr(&body);
+                }
+                KeyCode::Enter => {
+                    // Close expanded view
+                    self.pr_comment_expanded = false;
+                    self.pr_comment_scroll = 0;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // If viewing expanded PR description, handle scroll/close
+        if self.pr_description_expanded {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => {
+                    self.pr_description_expanded = false;
+                    self.pr_description_scroll = 0;
+                }
+                KeyCode::Char('j') | KeyCode::Down => {
+                    let max = self.pr_description_max_scroll.get();
+                    if self.pr_description_scroll < max {
+                        self.pr_description_scroll = self.pr_description_scroll.saturating_add(1);
+                    }
+                }
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.pr_description_scroll = self.pr_description_scroll.saturating_sub(1);
+                }
+                KeyCode::Char('e') => {
+                    // Open reaction picker for the PR itself
+                    self.reaction_picker_open = true;
+                    self.reaction_picker_selection = 0;
+                }
+                KeyCode::Char('z') => {
+                    // Toggle collapsible <details> sections
+                    self.pr_description_details_expanded = !self.pr_description_details_expanded;
+                }
+                KeyCode::Char('g') => {
+                    // Jump to the first PR referenced in the description
+                    let body = self
+                        .selected_pr
+                        .as_ref()
+                        .and_then(|pr| pr.body.clone())
+                        .unwrap_or_default();
+                    self.open_referenced_pr(&body);
+                }
+                KeyCode::Enter => {
+                    // Close expanded view
+                    self.pr_description_expanded = false;
+                    self.pr_description_scroll = 0;
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // If in comment input mode, handle text input
+        if self.pr_comment_input_mode {
+            if self.pr_comment_submitting {
+                return; // Ignore keys while submitting
+            }
+            match key.code {
+                KeyCode::Esc => {
+                    self.pr_comment_input_mode = false;
+                    self.pr_comment_text.clear();
+                    self.status_message = Some("Comment cancelled".to_string());
+                }
+                KeyCode::Enter => {
+                    self.submit_pr_comment();
+                }
+                KeyCode::Backspace => {
+                    self.pr_comment_text.pop();
+                }
+                KeyCode::Char(c) => {
+                    self.pr_comment_text.push(c);
+                }
+                _ => {}
+            }
+            return;
+        }
+
+        // Normal navigation mode
+        match key.code {
+            KeyCode::Char('r') => {
+                // Refresh PR detail, comments and reviews
+                if let Screen::PrDetail(number) = self.current_screen {
+                    self.selected_pr = None;
+                    self.pr_comments.clear();
+                    self.pr_reviews.clear();
+                    self.fetch_pr_detail(number);
+                    self.fetch_pr_comments(number);
+                    self.fetch_pr_reviews(number);
+                    self.fetch_pr_workflow_runs();
                 }
             }
-            // Up/Down: navigate within branch lists or body (arrow keys only)
-            KeyCode::Up => {
-                match self.pr_create_field {
-                    1 => self.pr_create_head_selection.previous(),
-                    2 => self.pr_create_base_selection.previous(),
-                    3 => {
-                        // Move cursor up in body
-                        if self.pr_create_body_cursor.0 > 0 {
-                            self.pr_create_body_cursor.0 -= 1;
-                        }
-                    }
-                    _ => {}
+            KeyCode::Char('j') | KeyCode::Down => {
+                // Navigate comments list
+                self.pr_comments_selection.next();
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                // Navigate comments list
+                self.pr_comments_selection.previous();
+            }
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                self.pr_comments_selection.handle_jump_key(key.code);
+            }
+            KeyCode::Enter => {
+                // Expand selected comment
+                if !self.pr_comments.is_empty() {
+                    self.pr_comment_expanded = true;
+                    self.pr_comment_scroll = 0;
                 }
             }
-            KeyCode::Down => {
-                match self.pr_create_field {
-                    1 => self.pr_create_head_selection.next(),
-                    2 => self.pr_create_base_selection.next(),
-                    3 => {
-                        // Move cursor down in body
-                        let line_count = split_lines_preserve_trailing(&self.pr_create_body).len();
-                        if self.pr_create_body_cursor.0 < line_count.saturating_sub(1) {
-                            self.pr_create_body_cursor.0 += 1;
-                        }
+            KeyCode::Char('c') => {
+                self.pr_comment_input_mode = true;
+                self.pr_comment_text.clear();
+                self.status_message =
+                    Some("Enter comment (Enter to submit, Esc to cancel)".to_string());
+            }
+            KeyCode::Char('v') => {
+                // Review an open PR
+                if let Some(ref pr) = self.selected_pr {
+                    if pr.state == Some(octocrab::models::IssueState::Open) {
+                        self.review_dialog_open = true;
+                        self.review_event_selection = 0;
+                        self.review_body.clear();
+                    } else {
+                        self.status_message = Some("Cannot review: PR is not open".to_string());
                     }
-                    _ => {}
                 }
             }
-            // Left/Right: move cursor in text fields
-            KeyCode::Left => {
-                match self.pr_create_field {
-                    0 => {} // Title uses simple string, no cursor tracking needed
-                    3 => {
-                        if self.pr_create_body_cursor.1 > 0 {
-                            self.pr_create_body_cursor.1 -= 1;
-                        }
-                    }
-                    _ => {}
+            KeyCode::Char('w') => {
+                // Navigate to PR-specific workflows (full screen)
+                if let Some(pr) = &self.selected_pr {
+                    self.pr_workflow_branch = Some(pr.head.ref_field.clone());
+                    self.navigate_to(Screen::WorkflowRuns);
                 }
             }
-            KeyCode::Right => {
-                match self.pr_create_field {
-                    0 => {} // Title uses simple string
-                    3 => {
-                        let lines = split_lines_preserve_trailing(&self.pr_create_body);
-                        let (row, col) = self.pr_create_body_cursor;
-                        if let Some(line) = lines.get(row) {
-                            if col < line.len() {
-                                self.pr_create_body_cursor.1 = col + 1;
-                            }
-                        }
+            KeyCode::Char('m') => {
+                // Only allow merge if PR is open
+                if let Some(ref pr) = self.selected_pr {
+                    if pr.state == Some(octocrab::models::IssueState::Open) {
+                        self.merge_dialog_open = true;
+                        self.merge_method_selection = 0; // Reset to first option
+                        self.merge_dialog_field = 0;
+                        self.merge_commit_title.clear();
+                        self.merge_commit_message.clear();
+                        self.merge_commit_message_cursor = (0, 0);
+                        self.merge_force_confirmed = false;
+                        // Re-check CI so the warning reflects the latest runs
+                        self.fetch_pr_combined_status();
+                        // Keep delete_branch at its previous value (user preference)
+                    } else {
+                        self.status_message = Some("Cannot merge: PR is not open".to_string());
                     }
-                    _ => {}
                 }
             }
-            // Backspace: delete character
-            KeyCode::Backspace => {
-                match self.pr_create_field {
-                    0 => {
-                        self.pr_create_title.pop();
-                    }
-                    3 => {
-                        // Delete character in body at cursor
-                        if !self.pr_create_body.is_empty() {
-                            let lines = split_lines_preserve_trailing(&self.pr_create_body);
-                            let (row, col) = self.pr_create_body_cursor;
-
-                            if col > 0 {
-                                // Delete character before cursor
-                                let mut new_body = String::new();
-                                for (i, line) in lines.iter().enumerate() {
-                                    if i == row {
-                                        let col = col.min(line.len());
-                                        if col > 0 {
-                                            new_body.push_str(&line[..col - 1]);
-                                            new_body.push_str(&line[col..]);
-                                        } else {
-                                            new_body.push_str(line);
-                                        }
-                                    } else {
-                                        new_body.push_str(line);
-                                    }
-                                    if i < lines.len() - 1 {
-                                        new_body.push('\n');
-                                    }
-                                }
-                                self.pr_create_body = new_body;
-                                self.pr_create_body_cursor.1 = col.saturating_sub(1);
-                            } else if row > 0 {
-                                // Join with previous line
-                                let mut new_body = String::new();
-                                let prev_line_len =
-                                    lines.get(row - 1).map(|l| l.len()).unwrap_or(0);
-                                for (i, line) in lines.iter().enumerate() {
-                                    if i == row - 1 {
-                                        new_body.push_str(line);
-                                        // Append current line without newline
-                                    } else if i == row {
-                                        new_body.push_str(line);
-                                    } else {
-                                        new_body.push_str(line);
-                                        if i < lines.len() - 1 && i != row - 1 {
-                                            new_body.push('\n');
-                                        }
-                                    }
-                                    if i < lines.len() - 1 && i != row - 1 {
-                                        new_body.push('\n');
-                                    }
-                                }
-                                self.pr_create_body = new_body;
-                                self.pr_create_body_cursor = (row - 1, prev_line_len);
-                            }
-                        }
+            KeyCode::Char('b') => {
+                // Retarget an open PR to a different base branch
+                if let Some(ref pr) = self.selected_pr {
+                    if pr.state == Some(octocrab::models::IssueState::Open) {
+                        self.open_base_picker();
+                    } else {
+                        self.status_message =
+                            Some("Cannot change base: PR is not open".to_string());
                     }
-                    _ => {}
                 }
             }
-            // Space: toggle draft or add space to text
-            KeyCode::Char(' ') => {
-                match self.pr_create_field {
-                    0 => self.pr_create_title.push(' '),
-                    3 => {
-                        // Insert space at cursor
-                        self.insert_char_at_body_cursor(' ');
-                    }
-                    4 => self.pr_create_draft = !self.pr_create_draft,
-                    _ => {}
+            KeyCode::Char('D') => {
+                if let Screen::PrDetail(number) = self.current_screen {
+                    self.navigate_to(Screen::PrDiff(number));
                 }
             }
-            // Character input for text fields, with vim navigation for branch selectors
-            KeyCode::Char(c) => match self.pr_create_field {
-                0 => self.pr_create_title.push(c),
-                1 => {
-                    // Branch selector: use j/k for vim navigation
-                    if c == 'j' {
-                        self.pr_create_head_selection.next();
-                    } else if c == 'k' {
-                        self.pr_create_head_selection.previous();
-                    }
+            KeyCode::Char('x') => self.set_pr_state(PrState::Closed),
+            KeyCode::Char('X') => self.set_pr_state(PrState::Open),
+            // Edit the PR title and body
+            KeyCode::Char('e') if self.selected_pr.is_some() => self.open_pr_edit(),
+            KeyCode::Char('d') => {
+                // Expand PR description overlay
+                if self.selected_pr.is_some() {
+                    self.pr_description_expanded = true;
+                    self.pr_description_scroll = 0;
+                    self.pr_description_details_expanded = false;
                 }
-                2 => {
-                    // Branch selector: use j/k for vim navigation
-                    if c == 'j' {
-                        self.pr_create_base_selection.next();
-                    } else if c == 'k' {
-                        self.pr_create_base_selection.previous();
-                    }
+            }
+            KeyCode::Char('o') => {
+                let number = match self.current_screen {
+                    Screen::PrDetail(number) => Some(number),
+                    _ => None,
+                };
+                self.open_pr_in_browser(number);
+            }
+            KeyCode::Char('y') => {
+                // Copy the PR URL
+                if let (Screen::PrDetail(number), Some(repo)) =
+                    (&self.current_screen, &self.repository)
+                {
+                    let url = repo.pull_request_url(*number);
+                    self.copy_url(&url, &format!("PR #{} URL", number));
                 }
-                3 => {
-                    self.insert_char_at_body_cursor(c);
+            }
+            KeyCode::Char('Y') => {
+                // Copy the selected comment's link
+                match self.pr_comments.get(self.pr_comments_selection.selected) {
+                    Some(comment) => {
+                        let url = comment.html_url.to_string();
+                        self.copy_url(&url, "Comment link");
+                    }
+                    None => self.status_message = Some("No comment selected".to_string()),
                 }
-                _ => {}
-            },
+            }
             _ => {}
         }
     }
 
-    /// Insert a character at the current body cursor position
-    fn insert_char_at_body_cursor(&mut self, c: char) {
-        let lines = split_lines_preserve_trailing(&self.pr_create_body);
-        let (row, col) = self.pr_create_body_cursor;
+    /// Copy a URL to the clipboard and report the result in the status bar
+    fn copy_url(&mut self, url: &str, what: &str) {
+        self.status_message = Some(match crate::core::clipboard::copy_to_clipboard(url) {
+            Ok(()) => format!("{} copied to clipboard", what),
+            Err(e) => format!("Failed to copy {}: {}", what.to_lowercase(), e),
+        });
+    }
 
-        let mut new_body = String::new();
-        if lines.is_empty() {
-            new_body.push(c);
-        } else {
-            for (i, line) in lines.iter().enumerate() {
-                if i == row {
-                    let col = col.min(line.len());
-                    new_body.push_str(&line[..col]);
-                    new_body.push(c);
-                    new_body.push_str(&line[col..]);
-                } else {
-                    new_body.push_str(line);
+    fn handle_commit_key(&mut self, key: KeyEvent) {
+        // If push prompt is showing, handle push confirmation
+        if self.commit_push_prompt {
+            if self.commit_push_loading || self.push_branches_loading {
+                return; // Ignore keys while loading/pushing
+            }
+
+            match self.push_mode {
+                PushMode::Simple => {
+                    match key.code {
+                        KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
+                            let proceed = match self.current_local_branch() {
+                                Some(branch) => {
+                                    self.confirm_protected_branch(&branch, "push", "Enter")
+                                }
+                                None => true,
+                            };
+                            if proceed {
+                                self.do_push();
+                            }
+                        }
+                        KeyCode::Char('b') | KeyCode::Char('B') => {
+                            // Switch to branch selection mode
+                            self.push_mode = PushMode::BranchSelect;
+                            self.load_push_branches();
+                        }
+                        KeyCode::Char('n') | KeyCode::Char('N') if key.modifiers.is_empty() => {
+                            // Skip push (lowercase n without modifiers)
+                            self.commit_push_prompt = false;
+                            self.last_commit_hash = None;
+                            self.commit_tracking_branch = None;
+                            self.push_mode = PushMode::Simple;
+                            self.status_message = Some("Push skipped".to_string());
+                        }
+                        KeyCode::Char('c') | KeyCode::Char('C') => {
+                            // Create new branch mode, pre-filled from the last commit
+                            self.push_mode = PushMode::NewBranch;
+                            self.push_new_branch_name = GitRepository::open_current_dir()
+                                .ok()
+                                .and_then(|git| git.head_commit_summary().ok().flatten())
+                                .and_then(|subject| branch_name::suggest_from_subject(&subject))
+                                .unwrap_or_default();
+                        }
+                        KeyCode::Esc => {
+                            self.commit_push_prompt = false;
+                            self.last_commit_hash = None;
+                            self.commit_tracking_branch = None;
+                            self.push_mode = PushMode::Simple;
+                            self.status_message = Some("Push skipped".to_string());
+                        }
+                        _ => {}
+                    }
                 }
-                if i < lines.len() - 1 {
-                    new_body.push('\n');
+                PushMode::BranchSelect => match key.code {
+                    KeyCode::Esc => {
+                        self.push_mode = PushMode::Simple;
+                    }
+                    KeyCode::Char('j') | KeyCode::Down => {
+                        if !self.push_branches.is_empty() {
+                            self.push_branch_selection =
+                                (self.push_branch_selection + 1) % self.push_branches.len();
+                        }
+                    }
+                    KeyCode::Char('k') | KeyCode::Up => {
+                        if !self.push_branches.is_empty() {
+                            self.push_branch_selection = self
+                                .push_branch_selection
+                                .checked_sub(1)
+                                .unwrap_or(self.push_branches.len().saturating_sub(1));
+                        }
+                    }
+                    KeyCode::Enter => {
+                        if let Some(branch) = self.push_branches.get(self.push_branch_selection) {
+                            let branch = branch.clone();
+                            if self.confirm_protected_branch(&branch, "push", "Enter") {
+                                self.do_push_to_branch(branch);
+                            }
+                        }
+                    }
+                    _ => {}
+                },
+                PushMode::NewBranch => {
+                    match key.code {
+                        KeyCode::Esc => {
+                            self.push_mode = PushMode::Simple;
+                            self.push_new_branch_name.clear();
+                        }
+                        KeyCode::Enter => {
+                            if self.push_new_branch_name.trim().is_empty() {
+                                self.status_message =
+                                    Some("Branch name cannot be empty".to_string());
+                            } else if !branch_name::is_valid(self.push_new_branch_name.trim()) {
+                                self.status_message =
+                                    Some("Invalid branch name for git".to_string());
+                            } else {
+                                self.do_create_and_push_branch();
+                            }
+                        }
+                        KeyCode::Backspace => {
+                            self.push_new_branch_name.pop();
+                        }
+                        KeyCode::Char('g')
+                            if key
+                                .modifiers
+                                .contains(crossterm::event::KeyModifiers::CONTROL) =>
+                        {
+                            self.suggest_ai_branch_name();
+                        }
+                        // Validate branch name characters
+                        KeyCode::Char(c)
+                            if c.is_alphanumeric()
+                                || c == '-'
+                                || c == '_'
+                                || c == '/'
+                                || c == '.' =>
+                        {
+                            self.push_new_branch_name.push(c);
+                        }
+                        _ => {}
+                    }
                 }
             }
-        }
-        self.pr_create_body = new_body;
-        self.pr_create_body_cursor.1 = col + 1;
-    }
-
-    /// Handle key events when merge dialog is open
-    fn handle_merge_dialog_key(&mut self, key: KeyEvent) {
-        if self.merge_in_progress {
-            // Block all input while merge is in progress
             return;
         }
 
-        match key.code {
-            KeyCode::Esc => {
-                self.merge_dialog_open = false;
-            }
-            KeyCode::Enter => {
-                self.merge_pr();
-            }
-            KeyCode::Char('j') | KeyCode::Down => {
-                // Cycle through merge methods (0, 1, 2)
-                self.merge_method_selection = (self.merge_method_selection + 1) % 3;
-            }
-            KeyCode::Char('k') | KeyCode::Up => {
-                // Cycle backwards through merge methods
-                self.merge_method_selection = if self.merge_method_selection == 0 {
-                    2
-                } else {
-                    self.merge_method_selection - 1
-                };
-            }
-            KeyCode::Char('d') | KeyCode::Char(' ') => {
-                // Toggle delete branch checkbox
-                self.merge_delete_branch = !self.merge_delete_branch;
-            }
-            _ => {}
-        }
-    }
-
-    fn handle_pr_detail_key(&mut self, key: KeyEvent) {
-        // If reaction picker is open, handle reaction selection
-        if self.reaction_picker_open {
-            if self.reaction_submitting {
-                return; // Ignore keys while submitting
-            }
+        // Amending rewrites history: y keeps the message, e edits it, anything else cancels
+        if self.commit_amend_confirm {
+            self.commit_amend_confirm = false;
             match key.code {
-                KeyCode::Esc => {
-                    self.reaction_picker_open = false;
-                }
-                KeyCode::Char('1') => {
-                    self.reaction_picker_open = false;
-                    self.toggle_reaction(ReactionType::ThumbsUp);
-                }
-                KeyCode::Char('2') => {
-                    self.reaction_picker_open = false;
-                    self.toggle_reaction(ReactionType::ThumbsDown);
-                }
-                KeyCode::Char('3') => {
-                    self.reaction_picker_open = false;
-                    self.toggle_reaction(ReactionType::Heart);
-                }
-                KeyCode::Char('4') => {
-                    self.reaction_picker_open = false;
-                    self.toggle_reaction(ReactionType::Hooray);
-                }
-                KeyCode::Char('j') | KeyCode::Down => {
-                    self.reaction_picker_selection = (self.reaction_picker_selection + 1) % 4;
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    self.reaction_picker_selection = (self.reaction_picker_selection + 3) % 4;
-                    // +3 = -1 mod 4
-                }
-                KeyCode::Enter => {
-                    // Add the selected reaction
-                    let reaction_type = match self.reaction_picker_selection {
-                        0 => ReactionType::ThumbsUp,
-                        1 => ReactionType::ThumbsDown,
-                        2 => ReactionType::Heart,
-                        3 => ReactionType::Hooray,
-                        _ => ReactionType::ThumbsUp,
-                    };
-                    self.reaction_picker_open = false;
-                    self.toggle_reaction(reaction_type);
-                }
-                _ => {}
+                KeyCode::Char('y') | KeyCode::Char('Y') => self.do_amend(None),
+                KeyCode::Char('e') | KeyCode::Char('E') => self.enter_amend_message_mode(),
+                _ => self.status_message = Some("Amend cancelled".to_string()),
             }
             return;
         }
 
-        // If merge dialog is open, handle merge dialog keys
-        if self.merge_dialog_open {
-            self.handle_merge_dialog_key(key);
+        // Hunk staging mode has its own navigation
+        if self.commit_hunk_path.is_some() {
+            self.handle_commit_hunk_key(key);
+            return;
+        }
+
+        // If a discard is awaiting confirmation, only accept y / n
+        if let Some((path, _)) = self.commit_discard_confirm.take() {
+            if matches!(key.code, KeyCode::Char('y') | KeyCode::Char('Y')) {
+                self.discard_file_changes(&path);
+            } else {
+                self.status_message = Some("Discard cancelled".to_string());
+            }
             return;
         }
 
-        // If viewing expanded comment, handle scroll/close
-        if self.pr_comment_expanded {
+        // If tag prompt is showing after push, handle tag creation choice
+        if self.commit_tag_prompt {
             match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    self.pr_comment_expanded = false;
-                    self.pr_comment_scroll = 0;
-                }
-                KeyCode::Char('j') | KeyCode::Down => {
-                    let max = self.pr_comment_max_scroll.get();
-                    if self.pr_comment_scroll < max {
-                        self.pr_comment_scroll = self.pr_comment_scroll.saturating_add(1);
-                    }
-                }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    self.pr_comment_scroll = self.pr_comment_scroll.saturating_sub(1);
-                }
-                KeyCode::Char('e') => {
-                    // Open reaction picker
-                    if !self.pr_comments.is_empty() {
-                        self.reaction_picker_open = true;
-                        self.reaction_picker_selection = 0;
-                    }
+                KeyCode::Char('y') | KeyCode::Char('Y') => {
+                    // Navigate to tags screen with create mode active
+                    self.commit_tag_prompt = false;
+                    self.navigate_to(Screen::Tags);
+                    // Trigger tag creation mode after navigating
+                    self.open_tag_create();
                 }
-                KeyCode::Enter => {
-                    // Close expanded view
-                    self.pr_comment_expanded = false;
-                    self.pr_comment_scroll = 0;
+                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
+                    self.commit_tag_prompt = false;
+                    self.status_message = Some("Tag creation skipped".to_string());
                 }
                 _ => {}
             }
             return;
         }
 
-        // If viewing expanded PR description, handle scroll/close
-        if self.pr_description_expanded {
+        // If in message input mode, handle text input
+        if self.commit_message_mode {
             match key.code {
-                KeyCode::Esc | KeyCode::Char('q') => {
-                    self.pr_description_expanded = false;
-                    self.pr_description_scroll = 0;
+                KeyCode::Esc => {
+                    // Cancel message input
+                    self.commit_message_mode = false;
+                    self.commit_amend_mode = false;
+                    self.commit_message.clear();
+                    self.status_message = Some("Cancelled".to_string());
                 }
-                KeyCode::Char('j') | KeyCode::Down => {
-                    let max = self.pr_description_max_scroll.get();
-                    if self.pr_description_scroll < max {
-                        self.pr_description_scroll = self.pr_description_scroll.saturating_add(1);
+                KeyCode::Enter => {
+                    // Commit with the message
+                    if self.commit_message.trim().is_empty() {
+                        self.status_message = Some("Commit message cannot be empty".to_string());
+                    } else if self.commit_amend_mode {
+                        let message = self.commit_message.trim().to_string();
+                        self.do_amend(Some(&message));
+                    } else {
+                        self.do_commit();
                     }
                 }
-                KeyCode::Char('k') | KeyCode::Up => {
-                    self.pr_description_scroll = self.pr_description_scroll.saturating_sub(1);
+                KeyCode::Backspace => {
+                    self.commit_message_history
+                        .before_key(&self.commit_message, key.code);
+                    self.commit_message.pop();
                 }
-                KeyCode::Enter => {
-                    // Close expanded view
-                    self.pr_description_expanded = false;
-                    self.pr_description_scroll = 0;
+                KeyCode::Char(c) => {
+                    let ctrl = key
+                        .modifiers
+                        .contains(crossterm::event::KeyModifiers::CONTROL);
+                    if ctrl && c == 'z' {
+                        if !self.commit_message_history.undo(&mut self.commit_message) {
+                            self.status_message = Some("Nothing to undo".to_string());
+                        }
+                    } else if ctrl && c == 'y' {
+                        if !self.commit_message_history.redo(&mut self.commit_message) {
+                            self.status_message = Some("Nothing to redo".to_string());
+                        }
+                    } else if ctrl && c == 'g' {
+                        // Ctrl+g regenerates AI message
+                        self.generate_ai_commit_message();
+                    } else if ctrl && c == 'e' {
+                        // Ctrl+e opens the message in an external editor
+                        if !self.commit_ai_loading {
+                            self.pending_editor = Some(EditorTarget::CommitMessage);
+                        }
+                    } else {
+                        self.commit_message_history
+                            .before_key(&self.commit_message, key.code);
+                        self.commit_message.push(c);
+                    }
                 }
                 _ => {}
             }
             return;
         }
 
-        // If in comment input mode, handle text input
-        if self.pr_comment_input_mode {
-            if self.pr_comment_submitting {
-                return; // Ignore keys while submitting
-            }
+        // Diff preview focused: j/k scroll the diff, Tab returns to the file list
+        if self.commit_diff_focused {
             match key.code {
-                KeyCode::Esc => {
-                    self.pr_comment_input_mode = false;
-                    self.pr_comment_text.clear();
-                    self.status_message = Some("Comment cancelled".to_string());
+                KeyCode::Tab => self.commit_diff_focused = false,
+                KeyCode::Char('j') | KeyCode::Down => {
+                    self.commit_diff_scroll = self.commit_diff_scroll.saturating_add(1);
                 }
-                KeyCode::Enter => {
-                    self.submit_pr_comment();
+                KeyCode::Char('k') | KeyCode::Up => {
+                    self.commit_diff_scroll = self.commit_diff_scroll.saturating_sub(1);
                 }
-                KeyCode::Backspace => {
-                    self.pr_comment_text.pop();
+                KeyCode::PageDown => {
+                    self.commit_diff_scroll = self.commit_diff_scroll.saturating_add(20);
                 }
-                KeyCode::Char(c) => {
-                    self.pr_comment_text.push(c);
+                KeyCode::PageUp => {
+                    self.commit_diff_scroll = self.commit_diff_scroll.saturating_sub(20);
                 }
+                KeyCode::Home => self.commit_diff_scroll = 0,
                 _ => {}
             }
             return;
         }
 
-        // Normal navigation mode
+        // File/folder selection mode with grouped navigation
         match key.code {
-            KeyCode::Char('r') => {
-                // Refresh PR detail and comments
-                if let Screen::PrDetail(number) = self.current_screen {
-                    self.selected_pr = None;
-                    self.pr_comments.clear();
-                    self.fetch_pr_detail(number);
-                    self.fetch_pr_comments(number);
-                    self.fetch_pr_workflow_runs();
+            KeyCode::Char('j') | KeyCode::Down => self.commit_navigate_next(),
+            KeyCode::Char('k') | KeyCode::Up => self.commit_navigate_prev(),
+            KeyCode::Tab => {
+                if self.commit_diff_path.is_some() {
+                    self.commit_diff_focused = true;
+                } else {
+                    self.status_message = Some("Select a file to preview its diff".to_string());
                 }
             }
-            KeyCode::Char('j') | KeyCode::Down => {
-                // Navigate comments list
-                self.pr_comments_selection.next();
+            KeyCode::Char(' ') => {
+                // Toggle staging: folder (all files) or single file
+                match self.selected_file_in_group {
+                    None => {
+                        // Toggle entire folder
+                        self.toggle_folder_staging(self.selected_group_idx);
+                    }
+                    Some(_) => {
+                        // Toggle single file
+                        self.toggle_file_staging();
+                    }
+                }
             }
-            KeyCode::Char('k') | KeyCode::Up => {
-                // Navigate comments list
-                self.pr_comments_selection.previous();
+            KeyCode::Char('a') => self.stage_all_files(),
+            KeyCode::Char('u') => self.unstage_all_files(),
+            // Discard the selected file's unstaged changes (confirmed first)
+            KeyCode::Char('x') if self.selected_file_in_group.is_some() => {
+                match self.selected_commit_file() {
+                    Some(file) if file.is_new || file.is_modified => {
+                        self.commit_discard_confirm = Some((file.path.clone(), file.is_new));
+                    }
+                    Some(_) => {
+                        self.status_message = Some("No unstaged changes to discard".to_string());
+                    }
+                    None => {}
+                }
+            }
+            KeyCode::Char('r') => self.refresh_changed_files(),
+            KeyCode::Char('h') => {
+                // Stage or unstage individual hunks of the selected file
+                if let Some(file) = self.selected_commit_file() {
+                    let path = file.path.clone();
+                    self.open_hunk_staging(path);
+                }
+            }
+            KeyCode::Char('A') => {
+                // Amend needs an existing commit; check before asking to confirm
+                match GitRepository::open_current_dir().and_then(|repo| repo.head_commit_message())
+                {
+                    Ok(_) => self.commit_amend_confirm = true,
+                    Err(e) => {
+                        self.error_popup = Some(ErrorPopup {
+                            title: "Cannot Amend".to_string(),
+                            message: e.to_string(),
+                        });
+                    }
+                }
+            }
+            KeyCode::Enter
+                if key
+                    .modifiers
+                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
+            {
+                // Ctrl+Enter: enter message mode if we have staged files (works from anywhere)
+                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
+                if has_staged {
+                    self.enter_commit_message_mode();
+                } else {
+                    self.status_message =
+                        Some("Stage files first (Space to toggle, 'a' to stage all)".to_string());
+                }
             }
             KeyCode::Enter => {
-                // Expand selected comment
-                if !self.pr_comments.is_empty() {
-                    self.pr_comment_expanded = true;
-                    self.pr_comment_scroll = 0;
+                // On folder header: toggle expand/collapse
+                if self.selected_file_in_group.is_none() {
+                    if let Some(group) = self.file_groups.get_mut(self.selected_group_idx) {
+                        group.expanded = !group.expanded;
+                    }
+                }
+            }
+            KeyCode::Char('g') => {
+                // Generate AI message and enter message mode
+                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
+                if has_staged {
+                    self.generate_ai_commit_message();
+                } else {
+                    self.status_message =
+                        Some("Stage files first before generating message".to_string());
                 }
             }
             KeyCode::Char('c') => {
-                self.pr_comment_input_mode = true;
-                self.pr_comment_text.clear();
-                self.status_message =
-                    Some("Enter comment (Enter to submit, Esc to cancel)".to_string());
+                // 'c' as alternative to Enter for entering commit message mode
+                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
+                if has_staged {
+                    self.enter_commit_message_mode();
+                } else {
+                    self.status_message =
+                        Some("Stage files first (Space to toggle, 'a' to stage all)".to_string());
+                }
             }
-            KeyCode::Char('w') => {
-                // Navigate to PR-specific workflows (full screen)
-                if let Some(pr) = &self.selected_pr {
-                    self.pr_workflow_branch = Some(pr.head.ref_field.clone());
-                    self.navigate_to(Screen::WorkflowRuns);
+            _ => {}
+        }
+    }
+
+    /// Navigate to next item in commit screen (folder or file)
+    fn commit_navigate_next(&mut self) {
+        if self.file_groups.is_empty() {
+            return;
+        }
+
+        match self.selected_file_in_group {
+            None => {
+                // On folder header
+                if let Some(group) = self.file_groups.get(self.selected_group_idx) {
+                    if group.expanded && !group.files.is_empty() {
+                        // Move into the folder (first file)
+                        self.selected_file_in_group = Some(0);
+                    } else {
+                        // Move to next folder
+                        self.selected_group_idx =
+                            (self.selected_group_idx + 1) % self.file_groups.len();
+                    }
                 }
             }
-            KeyCode::Char('m') => {
-                // Only allow merge if PR is open
-                if let Some(ref pr) = self.selected_pr {
-                    if pr.state == Some(octocrab::models::IssueState::Open) {
-                        self.merge_dialog_open = true;
-                        self.merge_method_selection = 0; // Reset to first option
-                                                         // Keep delete_branch at its previous value (user preference)
+            Some(file_idx) => {
+                if let Some(group) = self.file_groups.get(self.selected_group_idx) {
+                    if file_idx + 1 < group.files.len() {
+                        // Move to next file in same folder
+                        self.selected_file_in_group = Some(file_idx + 1);
                     } else {
-                        self.status_message = Some("Cannot merge: PR is not open".to_string());
+                        // Move to next folder header
+                        self.selected_group_idx =
+                            (self.selected_group_idx + 1) % self.file_groups.len();
+                        self.selected_file_in_group = None;
                     }
                 }
             }
-            KeyCode::Char('d') => {
-                // Expand PR description overlay
-                if self.selected_pr.is_some() {
-                    self.pr_description_expanded = true;
-                    self.pr_description_scroll = 0;
+        }
+
+        // Keep legacy selection in sync for toggle_file_staging
+        self.sync_legacy_selection();
+        // Adjust scroll to keep selection visible
+        self.adjust_commit_scroll_to_selection();
+        self.update_commit_diff_preview();
+    }
+
+    /// Navigate to previous item in commit screen
+    fn commit_navigate_prev(&mut self) {
+        if self.file_groups.is_empty() {
+            return;
+        }
+
+        match self.selected_file_in_group {
+            None => {
+                // On folder header, move to previous folder's last file (if expanded) or header
+                if self.selected_group_idx == 0 {
+                    self.selected_group_idx = self.file_groups.len() - 1;
+                } else {
+                    self.selected_group_idx -= 1;
+                }
+
+                // If previous folder is expanded, go to its last file
+                if let Some(prev_group) = self.file_groups.get(self.selected_group_idx) {
+                    if prev_group.expanded && !prev_group.files.is_empty() {
+                        self.selected_file_in_group = Some(prev_group.files.len() - 1);
+                    }
                 }
             }
-            KeyCode::Char('o') => {
-                // Open PR in browser
-                if let Some(ref pr) = self.selected_pr {
-                    if let Some(url) = &pr.html_url {
-                        crate::github::open_browser(url.as_str());
-                    }
+            Some(file_idx) => {
+                if file_idx > 0 {
+                    // Move to previous file in same folder
+                    self.selected_file_in_group = Some(file_idx - 1);
+                } else {
+                    // Move to folder header
+                    self.selected_file_in_group = None;
                 }
             }
-            _ => {}
         }
+
+        // Keep legacy selection in sync for toggle_file_staging
+        self.sync_legacy_selection();
+        // Adjust scroll to keep selection visible
+        self.adjust_commit_scroll_to_selection();
+        self.update_commit_diff_preview();
     }
 
-    fn handle_commit_key(&mut self, key: KeyEvent) {
-        // If push prompt is showing, handle push confirmation
-        if self.commit_push_prompt {
-            if self.commit_push_loading || self.push_branches_loading {
-                return; // Ignore keys while loading/pushing
-            }
+    /// Point the diff preview at the selected file, loading its diff if not cached
+    fn update_commit_diff_preview(&mut self) {
+        let selected = self.selected_commit_file().cloned();
 
-            match self.push_mode {
-                PushMode::Simple => {
-                    match key.code {
-                        KeyCode::Enter | KeyCode::Char('y') | KeyCode::Char('Y') => {
-                            self.do_push();
-                        }
-                        KeyCode::Char('b') | KeyCode::Char('B') => {
-                            // Switch to branch selection mode
-                            self.push_mode = PushMode::BranchSelect;
-                            self.load_push_branches();
-                        }
-                        KeyCode::Char('n') | KeyCode::Char('N') if key.modifiers.is_empty() => {
-                            // Skip push (lowercase n without modifiers)
-                            self.commit_push_prompt = false;
-                            self.last_commit_hash = None;
-                            self.commit_tracking_branch = None;
-                            self.push_mode = PushMode::Simple;
-                            self.status_message = Some("Push skipped".to_string());
-                        }
-                        KeyCode::Char('c') | KeyCode::Char('C') => {
-                            // Create new branch mode
-                            self.push_mode = PushMode::NewBranch;
-                            self.push_new_branch_name.clear();
-                        }
-                        KeyCode::Esc => {
-                            self.commit_push_prompt = false;
-                            self.last_commit_hash = None;
-                            self.commit_tracking_branch = None;
-                            self.push_mode = PushMode::Simple;
-                            self.status_message = Some("Push skipped".to_string());
-                        }
-                        _ => {}
-                    }
-                }
-                PushMode::BranchSelect => match key.code {
-                    KeyCode::Esc => {
-                        self.push_mode = PushMode::Simple;
-                    }
-                    KeyCode::Char('j') | KeyCode::Down => {
-                        if !self.push_branches.is_empty() {
-                            self.push_branch_selection =
-                                (self.push_branch_selection + 1) % self.push_branches.len();
-                        }
-                    }
-                    KeyCode::Char('k') | KeyCode::Up => {
-                        if !self.push_branches.is_empty() {
-                            self.push_branch_selection = self
-                                .push_branch_selection
-                                .checked_sub(1)
-                                .unwrap_or(self.push_branches.len().saturating_sub(1));
-                        }
+        let Some(file) = selected else {
+            self.commit_diff_path = None;
+            return;
+        };
+
+        if self.commit_diff_path.as_deref() != Some(file.path.as_str()) {
+            self.commit_diff_scroll = 0;
+        }
+
+        if !self.commit_diff_cache.contains_key(&file.path) {
+            let diff = GitRepository::open_current_dir()
+                .and_then(|repo| {
+                    // Show staged changes first, then anything not yet staged
+                    let mut text = String::new();
+                    if file.is_staged {
+                        text.push_str(&repo.file_diff(&file.path, true)?);
                     }
-                    KeyCode::Enter => {
-                        if let Some(branch) = self.push_branches.get(self.push_branch_selection) {
-                            let branch = branch.clone();
-                            self.do_push_to_branch(branch);
-                        }
+                    if file.is_modified || file.is_new || !file.is_staged {
+                        text.push_str(&repo.file_diff(&file.path, false)?);
                     }
-                    _ => {}
-                },
-                PushMode::NewBranch => {
-                    match key.code {
-                        KeyCode::Esc => {
-                            self.push_mode = PushMode::Simple;
-                            self.push_new_branch_name.clear();
-                        }
-                        KeyCode::Enter => {
-                            if self.push_new_branch_name.trim().is_empty() {
-                                self.status_message =
-                                    Some("Branch name cannot be empty".to_string());
-                            } else {
-                                self.do_create_and_push_branch();
-                            }
-                        }
-                        KeyCode::Backspace => {
-                            self.push_new_branch_name.pop();
-                        }
-                        KeyCode::Char(c) => {
-                            // Validate branch name characters
-                            if c.is_alphanumeric() || c == '-' || c == '_' || c == '/' {
-                                self.push_new_branch_name.push(c);
-                            }
-                        }
-                        _ => {}
+                    Ok(text)
+                })
+                .unwrap_or_else(|e| format!("Could not load diff: {}", e));
+            self.commit_diff_cache.insert(file.path.clone(), diff);
+        }
+
+        self.commit_diff_path = Some(file.path);
+    }
+
+    /// File selected in the commit screen's directory groups, if a file row is selected
+    fn selected_commit_file(&self) -> Option<&FileStatus> {
+        grouped_file(
+            &self.file_groups,
+            self.selected_group_idx,
+            self.selected_file_in_group,
+        )
+    }
+
+    /// Sync the legacy flat selection with the grouped selection
+    fn sync_legacy_selection(&mut self) {
+        if let Some(file_idx) = self.selected_file_in_group {
+            if let Some(group) = self.file_groups.get(self.selected_group_idx) {
+                if let Some(file) = group.files.get(file_idx) {
+                    // Find this file's index in the flat list
+                    if let Some(flat_idx) =
+                        self.changed_files.iter().position(|f| f.path == file.path)
+                    {
+                        self.commit_file_selection.selected = flat_idx;
                     }
                 }
             }
-            return;
         }
+    }
 
-        // If tag prompt is showing after push, handle tag creation choice
-        if self.commit_tag_prompt {
-            match key.code {
-                KeyCode::Char('y') | KeyCode::Char('Y') => {
-                    // Navigate to tags screen with create mode active
-                    self.commit_tag_prompt = false;
-                    self.navigate_to(Screen::Tags);
-                    // Trigger tag creation mode after navigating
-                    self.tag_create_mode = true;
-                    self.tag_create_name.clear();
-                    self.tag_create_message.clear();
-                    self.tag_create_message_cursor = (0, 0);
-                    self.tag_create_field = 0;
+    /// Adjust scroll offset to ensure current selection is visible in the viewport
+    fn adjust_commit_scroll_to_selection(&mut self) {
+        // Calculate the flat visual index of currently selected item
+        let mut flat_idx: usize = 0;
+        for (group_idx, group) in self.file_groups.iter().enumerate() {
+            if group_idx == self.selected_group_idx {
+                // Found our group
+                if self.selected_file_in_group.is_none() {
+                    // On folder header
+                    break;
                 }
-                KeyCode::Esc | KeyCode::Char('n') | KeyCode::Char('N') => {
-                    self.commit_tag_prompt = false;
-                    self.status_message = Some("Tag creation skipped".to_string());
+                // Count folder header
+                flat_idx += 1;
+                // Add file index within folder
+                if let Some(file_idx) = self.selected_file_in_group {
+                    flat_idx += file_idx;
+                }
+                break;
+            }
+            // Count folder header
+            flat_idx += 1;
+            // Count files if expanded
+            if group.expanded {
+                flat_idx += group.files.len();
+            }
+        }
+
+        let viewport_height = self.commit_viewport_height.get();
+        if viewport_height == 0 {
+            return; // Not rendered yet
+        }
+
+        // Scroll down if selection is below viewport
+        if flat_idx >= self.commit_file_scroll + viewport_height {
+            self.commit_file_scroll = flat_idx.saturating_sub(viewport_height) + 1;
+        }
+        // Scroll up if selection is above viewport
+        if flat_idx < self.commit_file_scroll {
+            self.commit_file_scroll = flat_idx;
+        }
+    }
+
+    /// Unstage all files
+    fn unstage_all_files(&mut self) {
+        if let Ok(repo) = GitRepository::open_current_dir() {
+            for file in &self.changed_files {
+                if file.is_staged {
+                    let _ = repo.unstage_file(&file.path);
                 }
-                _ => {}
             }
+        }
+        self.ai_cache.invalidate_commit();
+        self.refresh_changed_files();
+        self.status_message = Some("Unstaged all files".to_string());
+    }
+
+    fn handle_settings_key(&mut self, key: KeyEvent) {
+        // Custom model name input (opened by cycling onto "Custom")
+        if self.settings_input_mode && self.settings_selection.selected == 2 {
+            self.handle_custom_model_input(key);
             return;
         }
 
-        // If in message input mode, handle text input
-        if self.commit_message_mode {
+        // If in input mode, handle text input
+        if self.settings_input_mode {
             match key.code {
                 KeyCode::Esc => {
-                    // Cancel message input
-                    self.commit_message_mode = false;
-                    self.commit_message.clear();
+                    // Cancel input
+                    self.settings_input_mode = false;
+                    self.settings_api_key_input.clear();
                     self.status_message = Some("Cancelled".to_string());
                 }
                 KeyCode::Enter => {
-                    // Commit with the message
-                    if self.commit_message.trim().is_empty() {
-                        self.status_message = Some("Commit message cannot be empty".to_string());
-                    } else {
-                        self.do_commit();
+                    // Save the API key
+                    if !self.settings_api_key_input.is_empty() {
+                        match CredentialStore::store_ai_key(
+                            self.ai_provider,
+                            &self.settings_api_key_input,
+                        ) {
+                            Ok(()) => {
+                                self.ai_configured = true;
+                                self.status_message = Some(format!(
+                                    "{} API key saved",
+                                    self.ai_provider.display_name()
+                                ));
+                            }
+                            Err(e) => {
+                                self.status_message = Some(format!("Error saving key: {}", e));
+                            }
+                        }
                     }
+                    self.settings_input_mode = false;
+                    self.settings_api_key_input.clear();
                 }
                 KeyCode::Backspace => {
-                    self.commit_message.pop();
+                    self.settings_api_key_input.pop();
                 }
                 KeyCode::Char(c) => {
-                    // Ctrl+g regenerates AI message
-                    if c == 'g'
-                        && key
-                            .modifiers
-                            .contains(crossterm::event::KeyModifiers::CONTROL)
-                    {
-                        self.generate_ai_commit_message();
-                    } else {
-                        self.commit_message.push(c);
+                    // Only allow printable characters, limit length
+                    if self.settings_api_key_input.len() < 100 {
+                        self.settings_api_key_input.push(c);
                     }
                 }
-                _ => {}
+                _ => {}
+            }
+            return;
+        }
+
+        // Normal navigation mode
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.settings_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.settings_selection.previous(),
+            KeyCode::Enter => {
+                match self.settings_selection.selected {
+                    0 => {
+                        // GitHub auth - show hint
+                        let msg = if self.github_authenticated {
+                            "Run: gr auth logout"
+                        } else {
+                            "Run: gr auth login"
+                        };
+                        self.status_message = Some(msg.to_string());
+                    }
+                    1 => {
+                        // AI provider API key - enter input mode
+                        self.settings_input_mode = true;
+                        self.settings_api_key_input.clear();
+                        self.status_message =
+                            Some("Enter API key (hidden) then press Enter".to_string());
+                    }
+                    2 => {
+                        // Cycle through models
+                        self.cycle_gemini_model();
+                    }
+                    3 => self.toggle_commit_prompt_template(),
+                    4 => self.toggle_render_html(),
+                    5 => self.cycle_pr_list_page_size(true),
+                    6 => self.cycle_workflow_poll_seconds(true),
+                    7 => self.toggle_dry_run(),
+                    _ => {}
+                }
             }
-            return;
+            KeyCode::Char(' ') => {
+                // Space also cycles model / page size and toggles HTML and dry run on their rows
+                match self.settings_selection.selected {
+                    2 => self.cycle_gemini_model(),
+                    3 => self.toggle_commit_prompt_template(),
+                    4 => self.toggle_render_html(),
+                    5 => self.cycle_pr_list_page_size(true),
+                    6 => self.cycle_workflow_poll_seconds(true),
+                    7 => self.toggle_dry_run(),
+                    _ => {}
+                }
+            }
+            KeyCode::Char('l') | KeyCode::Right if self.settings_selection.selected == 5 => {
+                self.cycle_pr_list_page_size(true);
+            }
+            KeyCode::Char('h') | KeyCode::Left if self.settings_selection.selected == 5 => {
+                self.cycle_pr_list_page_size(false);
+            }
+            KeyCode::Char('l') | KeyCode::Right if self.settings_selection.selected == 6 => {
+                self.cycle_workflow_poll_seconds(true);
+            }
+            KeyCode::Char('h') | KeyCode::Left if self.settings_selection.selected == 6 => {
+                self.cycle_workflow_poll_seconds(false);
+            }
+            _ => {}
         }
+    }
 
-        // File/folder selection mode with grouped navigation
+    /// Handle key events for workflow runs screen
+    fn handle_workflow_runs_key(&mut self, key: KeyEvent) {
         match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.commit_navigate_next(),
-            KeyCode::Char('k') | KeyCode::Up => self.commit_navigate_prev(),
-            KeyCode::Char(' ') => {
-                // Toggle staging: folder (all files) or single file
-                match self.selected_file_in_group {
-                    None => {
-                        // Toggle entire folder
-                        self.toggle_folder_staging(self.selected_group_idx);
-                    }
-                    Some(_) => {
-                        // Toggle single file
-                        self.toggle_file_staging();
-                    }
-                }
+            KeyCode::Char('j') | KeyCode::Down => self.workflow_runs_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.workflow_runs_selection.previous(),
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                self.workflow_runs_selection.handle_jump_key(key.code);
             }
-            KeyCode::Char('a') => self.stage_all_files(),
-            KeyCode::Char('u') => self.unstage_all_files(),
-            KeyCode::Char('r') => self.refresh_changed_files(),
-            KeyCode::Enter
-                if key
-                    .modifiers
-                    .contains(crossterm::event::KeyModifiers::CONTROL) =>
-            {
-                // Ctrl+Enter: enter message mode if we have staged files (works from anywhere)
-                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
-                if has_staged {
-                    self.commit_message_mode = true;
-                    self.commit_message.clear();
-                    self.status_message = Some("Enter commit message...".to_string());
-                } else {
-                    self.status_message =
-                        Some("Stage files first (Space to toggle, 'a' to stage all)".to_string());
+            KeyCode::Char('r') => {
+                // Reset poll timer to prevent immediate auto-poll after manual refresh
+                self.workflow_runs_last_poll_tick = self.tick_counter;
+
+                // Force refresh
+                self.workflow_runs.clear();
+                self.workflow_runs_fetched = false;
+                self.fetch_workflow_runs();
+            }
+            KeyCode::Char('m') => {
+                // Toggle "only my runs" filter
+                self.workflow_runs_mine_only = !self.workflow_runs_mine_only;
+                self.workflow_runs_last_poll_tick = self.tick_counter;
+                self.workflow_runs.clear();
+                self.workflow_runs_selection = ListState::default();
+                self.workflow_runs_fetched = false;
+                self.fetch_workflow_runs();
+            }
+            KeyCode::Char('o') => {
+                // Open workflow run in browser
+                if let Some(run) = self.selected_workflow_run() {
+                    crate::github::open_browser(&run.html_url);
                 }
             }
             KeyCode::Enter => {
-                // On folder header: toggle expand/collapse
-                if self.selected_file_in_group.is_none() {
-                    if let Some(group) = self.file_groups.get_mut(self.selected_group_idx) {
-                        group.expanded = !group.expanded;
-                    }
+                if let Some(run) = self.selected_workflow_run() {
+                    self.navigate_to(Screen::WorkflowLogs(run.id));
                 }
             }
-            KeyCode::Char('g') => {
-                // Generate AI message and enter message mode
-                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
-                if has_staged {
-                    self.generate_ai_commit_message();
-                } else {
-                    self.status_message =
-                        Some("Stage files first before generating message".to_string());
+            KeyCode::Char('F') => {
+                self.workflow_runs_conclusion_filter = match self.workflow_runs_conclusion_filter {
+                    None => Some(WorkflowConclusion::Success),
+                    Some(WorkflowConclusion::Success) => Some(WorkflowConclusion::Failure),
+                    Some(_) => None,
+                };
+                self.clamp_workflow_runs_selection();
+            }
+            KeyCode::Char('E') => {
+                // Cycle through the events present in the loaded runs
+                let mut events: Vec<&str> = self
+                    .workflow_runs
+                    .iter()
+                    .map(|r| r.event.as_str())
+                    .collect();
+                events.sort_unstable();
+                events.dedup();
+                let next = match &self.workflow_runs_event_filter {
+                    None => events.first(),
+                    Some(current) => events
+                        .iter()
+                        .position(|e| e == current)
+                        .and_then(|i| events.get(i + 1)),
+                };
+                self.workflow_runs_event_filter = next.map(|e| e.to_string());
+                self.clamp_workflow_runs_selection();
+            }
+            KeyCode::Char(' ') => {
+                self.workflow_jobs_shown = !self.workflow_jobs_shown;
+                self.workflow_jobs_error = None;
+            }
+            KeyCode::Char('R') => {
+                if let Some(run) = self.selected_workflow_run() {
+                    if self.workflow_rerunning {
+                        return;
+                    }
+                    if run.status.is_active() {
+                        self.status_message =
+                            Some(format!("Run #{} is still in progress", run.run_number));
+                        return;
+                    }
+                    let has_failures = matches!(
+                        run.conclusion,
+                        Some(
+                            WorkflowConclusion::Failure
+                                | WorkflowConclusion::Cancelled
+                                | WorkflowConclusion::TimedOut
+                                | WorkflowConclusion::StartupFailure
+                        )
+                    );
+                    self.workflow_rerun_confirm = Some((run.id, run.run_number, has_failures));
                 }
             }
             KeyCode::Char('c') => {
-                // 'c' as alternative to Enter for entering commit message mode
-                let has_staged = self.changed_files.iter().any(|f| f.is_staged);
-                if has_staged {
-                    self.commit_message_mode = true;
-                    self.commit_message.clear();
-                    self.status_message = Some("Enter commit message...".to_string());
-                } else {
-                    self.status_message =
-                        Some("Stage files first (Space to toggle, 'a' to stage all)".to_string());
+                if let Some(run) = self.selected_workflow_run() {
+                    if self.workflow_cancelling {
+                        return;
+                    }
+                    if !run.status.is_active() {
+                        self.status_message =
+                            Some(format!("Run #{} has already finished", run.run_number));
+                        return;
+                    }
+                    self.workflow_cancel_confirm = Some((run.id, run.run_number));
                 }
             }
             _ => {}
         }
+
+        self.load_selected_run_jobs();
     }
 
-    /// Navigate to next item in commit screen (folder or file)
-    fn commit_navigate_next(&mut self) {
-        if self.file_groups.is_empty() {
+    /// Workflow runs matching the conclusion and event filters
+    pub fn filtered_workflow_runs(&self) -> Vec<&WorkflowRunInfo> {
+        self.workflow_runs
+            .iter()
+            .filter(|run| {
+                self.workflow_runs_conclusion_filter
+                    .is_none_or(|c| run.conclusion == Some(c))
+            })
+            .filter(|run| {
+                self.workflow_runs_event_filter
+                    .as_ref()
+                    .is_none_or(|event| run.event == *event)
+            })
+            .collect()
+    }
+
+    /// The run under the cursor in the (filtered) workflow runs list
+    fn selected_workflow_run(&self) -> Option<&WorkflowRunInfo> {
+        self.filtered_workflow_runs()
+            .get(self.workflow_runs_selection.selected)
+            .copied()
+    }
+
+    /// Resize the workflow runs selection to the filtered set, keeping the index in range
+    fn clamp_workflow_runs_selection(&mut self) {
+        let total = self.filtered_workflow_runs().len();
+        self.workflow_runs_selection.total = total;
+        self.workflow_runs_selection.selected = self
+            .workflow_runs_selection
+            .selected
+            .min(total.saturating_sub(1));
+    }
+
+    /// Fetch the selected run's jobs if they are shown and not loaded yet
+    fn load_selected_run_jobs(&mut self) {
+        if !self.workflow_jobs_shown
+            || self.current_screen != Screen::WorkflowRuns
+            || self.workflow_jobs_loading.is_some()
+        {
+            return;
+        }
+        let Some(run_id) = self.selected_workflow_run().map(|run| run.id) else {
+            return;
+        };
+        let failed = matches!(&self.workflow_jobs_error, Some((id, _)) if *id == run_id);
+        if self.workflow_jobs.contains_key(&run_id) || failed {
             return;
         }
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
 
-        match self.selected_file_in_group {
-            None => {
-                // On folder header
-                if let Some(group) = self.file_groups.get(self.selected_group_idx) {
-                    if group.expanded && !group.files.is_empty() {
-                        // Move into the folder (first file)
-                        self.selected_file_in_group = Some(0);
-                    } else {
-                        // Move to next folder
-                        self.selected_group_idx =
-                            (self.selected_group_idx + 1) % self.file_groups.len();
-                    }
+        self.workflow_jobs_loading = Some(run_id);
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                WorkflowHandler::new(&client).list_jobs(run_id).await
+            }
+            .await;
+
+            let message = match result {
+                Ok(jobs) => AsyncMessage::WorkflowJobsLoaded { run_id, jobs },
+                Err(e) => AsyncMessage::WorkflowJobsError {
+                    run_id,
+                    error: e.to_string(),
+                },
+            };
+            let _ = tx.send(message).await;
+        });
+    }
+
+    /// Ask GitHub to re-run a completed workflow run in the background
+    fn rerun_workflow(&mut self, run_id: u64, failed_only: bool) {
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.workflow_rerunning = true;
+        self.status_message = Some("Requesting re-run...".to_string());
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                let handler = WorkflowHandler::new(&client);
+                if failed_only {
+                    handler.rerun_failed_jobs(run_id).await
+                } else {
+                    handler.rerun(run_id).await
                 }
             }
-            Some(file_idx) => {
-                if let Some(group) = self.file_groups.get(self.selected_group_idx) {
-                    if file_idx + 1 < group.files.len() {
-                        // Move to next file in same folder
-                        self.selected_file_in_group = Some(file_idx + 1);
-                    } else {
-                        // Move to next folder header
-                        self.selected_group_idx =
-                            (self.selected_group_idx + 1) % self.file_groups.len();
-                        self.selected_file_in_group = None;
-                    }
+            .await;
+
+            let message = match result {
+                Ok(()) => AsyncMessage::WorkflowRerun {
+                    run_id,
+                    failed_only,
+                },
+                Err(e) => AsyncMessage::WorkflowRerunError(e.to_string()),
+            };
+            let _ = tx.send(message).await;
+        });
+    }
+
+    /// Ask GitHub to cancel a queued or running workflow run in the background
+    fn cancel_workflow(&mut self, run_id: u64) {
+        let repo = match &self.repository {
+            Some(r) => r.clone(),
+            None => return,
+        };
+
+        self.workflow_cancelling = true;
+        self.status_message = Some("Requesting cancellation...".to_string());
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
+                WorkflowHandler::new(&client).cancel(run_id).await
+            }
+            .await;
+
+            let message = match result {
+                Ok(()) => AsyncMessage::WorkflowCancelled(run_id),
+                Err(e) => AsyncMessage::WorkflowCancelError(e.to_string()),
+            };
+            let _ = tx.send(message).await;
+        });
+    }
+
+    fn handle_workflow_logs_key(&mut self, key: KeyEvent) {
+        let Screen::WorkflowLogs(run_id) = self.current_screen else {
+            return;
+        };
+        let max_scroll = self.workflow_logs.len().saturating_sub(1);
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => {
+                self.workflow_logs_scroll = (self.workflow_logs_scroll + 1).min(max_scroll);
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.workflow_logs_scroll = self.workflow_logs_scroll.saturating_sub(1);
+            }
+            KeyCode::PageDown => {
+                self.workflow_logs_scroll = (self.workflow_logs_scroll + 20).min(max_scroll);
+            }
+            KeyCode::PageUp => {
+                self.workflow_logs_scroll = self.workflow_logs_scroll.saturating_sub(20);
+            }
+            KeyCode::Home => self.workflow_logs_scroll = 0,
+            KeyCode::End => self.workflow_logs_scroll = max_scroll,
+            KeyCode::Char('r') => self.fetch_workflow_logs(run_id),
+            KeyCode::Char('o') => {
+                if let Some(run) = self.workflow_runs.iter().find(|r| r.id == run_id) {
+                    crate::github::open_browser(&run.html_url);
                 }
             }
+            _ => {}
         }
-
-        // Keep legacy selection in sync for toggle_file_staging
-        self.sync_legacy_selection();
-        // Adjust scroll to keep selection visible
-        self.adjust_commit_scroll_to_selection();
     }
 
-    /// Navigate to previous item in commit screen
-    fn commit_navigate_prev(&mut self) {
-        if self.file_groups.is_empty() {
+    /// Cycle to the next Gemini model and save
+    ///
+    /// Cycling onto `Custom` opens a name input instead of saving right away.
+    fn cycle_gemini_model(&mut self) {
+        // Other providers take any model name, so they are set from the CLI
+        if self.ai_provider != AiBackend::Gemini {
+            self.status_message = Some(format!(
+                "Run: gr config set {}-model NAME",
+                self.ai_provider.as_str()
+            ));
             return;
         }
 
-        match self.selected_file_in_group {
-            None => {
-                // On folder header, move to previous folder's last file (if expanded) or header
-                if self.selected_group_idx == 0 {
-                    self.selected_group_idx = self.file_groups.len() - 1;
-                } else {
-                    self.selected_group_idx -= 1;
-                }
+        let models = GeminiModel::all();
+        let current_idx = models
+            .iter()
+            .position(|m| *m == self.gemini_model)
+            .unwrap_or(0);
+        let next_idx = (current_idx + 1) % models.len();
 
-                // If previous folder is expanded, go to its last file
-                if let Some(prev_group) = self.file_groups.get(self.selected_group_idx) {
-                    if prev_group.expanded && !prev_group.files.is_empty() {
-                        self.selected_file_in_group = Some(prev_group.files.len() - 1);
+        if models[next_idx] == GeminiModel::Custom {
+            self.settings_input_mode = true;
+            self.settings_model_input = self.gemini_custom_model.clone().unwrap_or_default();
+            self.status_message =
+                Some("Enter a Gemini model name (empty skips Custom)".to_string());
+            return;
+        }
+        self.set_gemini_model(models[next_idx]);
+    }
+
+    /// Handle key events while typing a custom model name
+    fn handle_custom_model_input(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.settings_input_mode = false;
+                self.settings_model_input.clear();
+                self.status_message = Some("Cancelled".to_string());
+            }
+            KeyCode::Enter => {
+                let input = std::mem::take(&mut self.settings_model_input);
+                if input.trim().is_empty() {
+                    // Nothing typed: move past Custom to the first built-in model
+                    self.settings_input_mode = false;
+                    self.set_gemini_model(GeminiModel::all()[0]);
+                    return;
+                }
+                let Some(name) = GeminiModel::normalize_custom_name(&input) else {
+                    self.settings_model_input = input;
+                    self.status_message = Some(
+                        "Model names may only contain letters, digits, '-', '.' and '_'"
+                            .to_string(),
+                    );
+                    return;
+                };
+                self.settings_input_mode = false;
+                match Config::load() {
+                    Ok(mut config) => {
+                        config.set_gemini_custom_model(name.clone());
+                        if let Err(e) = config.save() {
+                            self.status_message = Some(format!("Error saving config: {}", e));
+                        } else {
+                            self.gemini_model = GeminiModel::Custom;
+                            self.gemini_custom_model = Some(name.clone());
+                            self.ai_cache = AiResultCache::default();
+                            self.status_message = Some(format!("Model: {}", name));
+                        }
+                    }
+                    Err(e) => {
+                        self.status_message = Some(format!("Error loading config: {}", e));
                     }
                 }
             }
-            Some(file_idx) => {
-                if file_idx > 0 {
-                    // Move to previous file in same folder
-                    self.selected_file_in_group = Some(file_idx - 1);
+            KeyCode::Backspace => {
+                self.settings_model_input.pop();
+            }
+            KeyCode::Char(c) if self.settings_model_input.len() < 100 => {
+                self.settings_model_input.push(c);
+            }
+            _ => {}
+        }
+    }
+
+    /// Select a built-in Gemini model and save
+    fn set_gemini_model(&mut self, model: GeminiModel) {
+        self.gemini_model = model;
+        // Results from the previous model shouldn't be reused
+        self.ai_cache = AiResultCache::default();
+
+        // Save to config
+        match Config::load() {
+            Ok(mut config) => {
+                config.set_gemini_model(self.gemini_model);
+                if let Err(e) = config.save() {
+                    self.status_message = Some(format!("Error saving config: {}", e));
                 } else {
-                    // Move to folder header
-                    self.selected_file_in_group = None;
+                    self.status_message =
+                        Some(format!("Model: {}", self.gemini_model.display_name()));
                 }
             }
+            Err(e) => {
+                self.status_message = Some(format!("Error loading config: {}", e));
+            }
         }
-
-        // Keep legacy selection in sync for toggle_file_staging
-        self.sync_legacy_selection();
-        // Adjust scroll to keep selection visible
-        self.adjust_commit_scroll_to_selection();
     }
 
-    /// Sync the legacy flat selection with the grouped selection
-    fn sync_legacy_selection(&mut self) {
-        if let Some(file_idx) = self.selected_file_in_group {
-            if let Some(group) = self.file_groups.get(self.selected_group_idx) {
-                if let Some(file) = group.files.get(file_idx) {
-                    // Find this file's index in the flat list
-                    if let Some(flat_idx) =
-                        self.changed_files.iter().position(|f| f.path == file.path)
-                    {
-                        self.commit_file_selection.selected = flat_idx;
-                    }
-                }
+    /// Open the first `#123` reference in text as a PR detail screen
+    fn open_referenced_pr(&mut self, text: &str) {
+        let current = match self.current_screen {
+            Screen::PrDetail(number) => Some(number),
+            _ => None,
+        };
+        match crate::tui::issue_references(text)
+            .into_iter()
+            .find(|&n| Some(n) != current)
+        {
+            Some(number) => {
+                self.pr_comment_expanded = false;
+                self.pr_comment_scroll = 0;
+                self.pr_description_expanded = false;
+                self.pr_description_scroll = 0;
+                self.navigate_to(Screen::PrDetail(number));
+            }
+            None => {
+                self.status_message = Some("No #references found".to_string());
             }
         }
     }
 
-    /// Adjust scroll offset to ensure current selection is visible in the viewport
-    fn adjust_commit_scroll_to_selection(&mut self) {
-        // Calculate the flat visual index of currently selected item
-        let mut flat_idx: usize = 0;
-        for (group_idx, group) in self.file_groups.iter().enumerate() {
-            if group_idx == self.selected_group_idx {
-                // Found our group
-                if self.selected_file_in_group.is_none() {
-                    // On folder header
-                    break;
-                }
-                // Count folder header
-                flat_idx += 1;
-                // Add file index within folder
-                if let Some(file_idx) = self.selected_file_in_group {
-                    flat_idx += file_idx;
+    /// Toggle between rendering and stripping HTML in markdown and save
+    fn toggle_render_html(&mut self) {
+        self.render_html = !self.render_html;
+
+        match Config::load() {
+            Ok(mut config) => {
+                config.set_render_html(self.render_html);
+                if let Err(e) = config.save() {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                } else {
+                    let mode = if self.render_html { "render" } else { "strip" };
+                    self.status_message = Some(format!("HTML: {}", mode));
                 }
-                break;
             }
-            // Count folder header
-            flat_idx += 1;
-            // Count files if expanded
-            if group.expanded {
-                flat_idx += group.files.len();
+            Err(e) => {
+                self.status_message = Some(format!("Error loading config: {}", e));
             }
         }
+    }
 
-        let viewport_height = self.commit_viewport_height.get();
-        if viewport_height == 0 {
-            return; // Not rendered yet
-        }
+    /// Copy the error popup's title, message and version info to the clipboard
+    fn copy_error_details(&mut self) {
+        let Some(popup) = &self.error_popup else {
+            return;
+        };
 
-        // Scroll down if selection is below viewport
-        if flat_idx >= self.commit_file_scroll + viewport_height {
-            self.commit_file_scroll = flat_idx.saturating_sub(viewport_height) + 1;
-        }
-        // Scroll up if selection is above viewport
-        if flat_idx < self.commit_file_scroll {
-            self.commit_file_scroll = flat_idx;
-        }
+        let details = format!(
+            "{}\n\n{}\n\nargo v{} ({}/{})",
+            popup.title,
+            popup.message,
+            env!("CARGO_PKG_VERSION"),
+            std::env::consts::OS,
+            std::env::consts::ARCH
+        );
+
+        self.status_message = Some(match crate::core::clipboard::copy_to_clipboard(&details) {
+            Ok(()) => "Error details copied to clipboard".to_string(),
+            Err(e) => format!("Copy failed: {}", e),
+        });
     }
 
-    /// Unstage all files
-    fn unstage_all_files(&mut self) {
-        if let Ok(repo) = GitRepository::open_current_dir() {
-            for file in &self.changed_files {
-                if file.is_staged {
-                    let _ = repo.unstage_file(&file.path);
+    /// Switch the AI commit prompt between the built-in and custom template and save
+    fn toggle_commit_prompt_template(&mut self) {
+        self.use_commit_prompt_template = !self.use_commit_prompt_template;
+        // A message generated with the other prompt must not be reused
+        self.ai_cache.invalidate_commit();
+
+        match Config::load() {
+            Ok(mut config) => {
+                config.set_use_commit_prompt_template(self.use_commit_prompt_template);
+                if let Err(e) = config.save() {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                } else if !self.use_commit_prompt_template {
+                    self.status_message = Some("Commit prompt: default".to_string());
+                } else if config.active_commit_prompt_template().is_none() {
+                    self.status_message = Some(
+                        "Commit prompt: custom (no template set, using default; run: gr config set commit-prompt-template)"
+                            .to_string(),
+                    );
+                } else {
+                    self.status_message = Some("Commit prompt: custom".to_string());
                 }
             }
+            Err(e) => {
+                self.status_message = Some(format!("Error loading config: {}", e));
+            }
         }
-        self.refresh_changed_files();
-        self.status_message = Some("Unstaged all files".to_string());
     }
 
-    fn handle_settings_key(&mut self, key: KeyEvent) {
-        // If in input mode, handle text input
-        if self.settings_input_mode {
-            match key.code {
-                KeyCode::Esc => {
-                    // Cancel input
-                    self.settings_input_mode = false;
-                    self.settings_api_key_input.clear();
-                    self.status_message = Some("Cancelled".to_string());
-                }
-                KeyCode::Enter => {
-                    // Save the API key
-                    if !self.settings_api_key_input.is_empty() {
-                        match CredentialStore::store_gemini_key(&self.settings_api_key_input) {
-                            Ok(()) => {
-                                self.gemini_configured = true;
-                                self.status_message = Some("Gemini API key saved".to_string());
-                            }
-                            Err(e) => {
-                                self.status_message = Some(format!("Error saving key: {}", e));
-                            }
-                        }
-                    }
-                    self.settings_input_mode = false;
-                    self.settings_api_key_input.clear();
-                }
-                KeyCode::Backspace => {
-                    self.settings_api_key_input.pop();
-                }
-                KeyCode::Char(c) => {
-                    // Only allow printable characters, limit length
-                    if self.settings_api_key_input.len() < 100 {
-                        self.settings_api_key_input.push(c);
-                    }
-                }
-                _ => {}
-            }
-            return;
-        }
+    /// Step the PR list page size through the common presets and save
+    fn cycle_pr_list_page_size(&mut self, forward: bool) {
+        let current = self.pr_list_page_size;
+        let next = if forward {
+            PR_LIST_PAGE_SIZES
+                .iter()
+                .copied()
+                .find(|&size| size > current)
+                .unwrap_or(PR_LIST_PAGE_SIZES[0])
+        } else {
+            PR_LIST_PAGE_SIZES
+                .iter()
+                .rev()
+                .copied()
+                .find(|&size| size < current)
+                .unwrap_or(PR_LIST_PAGE_SIZES[PR_LIST_PAGE_SIZES.len() - 1])
+        };
 
-        // Normal navigation mode
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.settings_selection.next(),
-            KeyCode::Char('k') | KeyCode::Up => self.settings_selection.previous(),
-            KeyCode::Enter => {
-                match self.settings_selection.selected {
-                    0 => {
-                        // GitHub auth - show hint
-                        let msg = if self.github_authenticated {
-                            "Run: gr auth logout"
-                        } else {
-                            "Run: gr auth login"
-                        };
-                        self.status_message = Some(msg.to_string());
-                    }
-                    1 => {
-                        // Gemini API key - enter input mode
-                        self.settings_input_mode = true;
-                        self.settings_api_key_input.clear();
-                        self.status_message =
-                            Some("Enter API key (hidden) then press Enter".to_string());
-                    }
-                    2 => {
-                        // Cycle through models
-                        self.cycle_gemini_model();
-                    }
-                    _ => {}
+        match Config::load() {
+            Ok(mut config) => {
+                config.set_pr_list_page_size(next);
+                if let Err(e) = config.save() {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                } else {
+                    self.pr_list_page_size = next;
+                    // Refetch with the new size the next time the list is opened
+                    self.pr_list_fetched = false;
+                    self.status_message = Some(format!("PR list page size: {}", next));
                 }
             }
-            KeyCode::Char(' ') => {
-                // Space also cycles model when on model row
-                if self.settings_selection.selected == 2 {
-                    self.cycle_gemini_model();
-                }
+            Err(e) => {
+                self.status_message = Some(format!("Error loading config: {}", e));
             }
-            _ => {}
         }
     }
 
-    /// Handle key events for workflow runs screen
-    fn handle_workflow_runs_key(&mut self, key: KeyEvent) {
-        match key.code {
-            KeyCode::Char('j') | KeyCode::Down => self.workflow_runs_selection.next(),
-            KeyCode::Char('k') | KeyCode::Up => self.workflow_runs_selection.previous(),
-            KeyCode::Char('r') => {
-                // Reset poll timer to prevent immediate auto-poll after manual refresh
-                self.workflow_runs_last_poll_tick = self.tick_counter;
+    /// Step the workflow poll interval through the presets and save
+    fn cycle_workflow_poll_seconds(&mut self, forward: bool) {
+        let current = self.workflow_poll_seconds;
+        let next = if forward {
+            WORKFLOW_POLL_SECONDS
+                .iter()
+                .copied()
+                .find(|&seconds| seconds > current)
+                .unwrap_or(WORKFLOW_POLL_SECONDS[0])
+        } else {
+            WORKFLOW_POLL_SECONDS
+                .iter()
+                .rev()
+                .copied()
+                .find(|&seconds| seconds < current)
+                .unwrap_or(WORKFLOW_POLL_SECONDS[WORKFLOW_POLL_SECONDS.len() - 1])
+        };
 
-                // Force refresh
-                self.workflow_runs.clear();
-                self.workflow_runs_fetched = false;
-                self.fetch_workflow_runs();
-            }
-            KeyCode::Char('o') => {
-                // Open workflow run in browser
-                if let Some(run) = self
-                    .workflow_runs
-                    .get(self.workflow_runs_selection.selected)
-                {
-                    crate::github::open_browser(&run.html_url);
+        match Config::load() {
+            Ok(mut config) => {
+                config.set_workflow_poll_seconds(next);
+                if let Err(e) = config.save() {
+                    self.status_message = Some(format!("Error saving config: {}", e));
+                } else {
+                    self.workflow_poll_seconds = next;
+                    self.status_message = Some(format!("Workflow poll interval: {}s", next));
                 }
             }
-            _ => {}
+            Err(e) => {
+                self.status_message = Some(format!("Error loading config: {}", e));
+            }
         }
     }
 
-    /// Cycle to the next Gemini model and save
-    fn cycle_gemini_model(&mut self) {
-        let models = GeminiModel::all();
-        let current_idx = models
-            .iter()
-            .position(|m| *m == self.gemini_model)
-            .unwrap_or(0);
-        let next_idx = (current_idx + 1) % models.len();
-        self.gemini_model = models[next_idx];
+    /// Toggle dry-run mode for merges, pushes and deletions and save
+    fn toggle_dry_run(&mut self) {
+        self.dry_run = !self.dry_run;
 
-        // Save to config
         match Config::load() {
             Ok(mut config) => {
-                config.set_gemini_model(self.gemini_model);
+                config.set_dry_run(self.dry_run);
                 if let Err(e) = config.save() {
                     self.status_message = Some(format!("Error saving config: {}", e));
                 } else {
-                    self.status_message =
-                        Some(format!("Model: {}", self.gemini_model.display_name()));
+                    let mode = if self.dry_run { "on" } else { "off" };
+                    self.status_message = Some(format!("Dry run: {}", mode));
                 }
             }
             Err(e) => {
                 self.status_message = Some(format!("Error loading config: {}", e));
             }
-        }
+        }
+    }
+
+    /// Invalidate all cached data, re-check auth, and reload the current screen
+    pub fn refresh_all(&mut self) {
+        self.pr_list_fetched = false;
+        self.workflow_runs_fetched = false;
+        self.tags_fetched = false;
+
+        self.check_auth_status();
+
+        match self.current_screen {
+            Screen::PrList => self.fetch_pr_list(),
+            Screen::PrDetail(number) => {
+                self.fetch_pr_detail(number);
+                self.fetch_pr_comments(number);
+                self.fetch_pr_reviews(number);
+            }
+            Screen::Commit => self.refresh_changed_files(),
+            Screen::PrCreate => {
+                self.fetch_branches();
+                self.update_pr_commits();
+            }
+            Screen::WorkflowRuns => {
+                self.workflow_runs_last_poll_tick = self.tick_counter;
+                self.fetch_workflow_runs();
+            }
+            Screen::Tags => self.fetch_tags(),
+            Screen::Branches => self.refresh_local_branches(),
+            _ => {
+                self.status_message = Some("Refreshed".to_string());
+            }
+        }
+    }
+
+    /// Re-check stored credentials in the background
+    fn check_auth_status(&mut self) {
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = tokio::task::spawn_blocking(|| {
+                let github = CredentialStore::has_github_token().unwrap_or(false);
+                let provider = Config::load().map(|c| c.ai_provider).unwrap_or_default();
+                let ai = CredentialStore::has_ai_key(provider).unwrap_or(false);
+                (github, ai)
+            })
+            .await;
+
+            if let Ok((github, ai)) = result {
+                let _ = tx.send(AsyncMessage::AuthStatus { github, ai }).await;
+            }
+        });
     }
 
     /// Navigate to a new screen
     pub fn navigate_to(&mut self, screen: Screen) {
+        // Pull requests and workflows need a GitHub remote; local screens always work
+        let needs_github = matches!(
+            screen,
+            Screen::PrList
+                | Screen::PrDetail(_)
+                | Screen::PrDiff(_)
+                | Screen::PrCreate
+                | Screen::WorkflowRuns
+                | Screen::WorkflowLogs(_)
+        );
+        if needs_github && self.repository.is_none() {
+            self.status_message =
+                Some("No GitHub remote: pull requests and workflows are unavailable".to_string());
+            return;
+        }
+
+        self.save_selection();
         self.navigation_stack.push(self.current_screen);
         self.current_screen = screen;
         self.status_message = None; // Clear stale messages on screen change
@@ -2740,6 +6213,8 @@ impl App {
                 // to handle edge cases where pr_list_fetched is true but list is empty)
                 if (!self.pr_list_fetched || self.pr_list.is_empty()) && !self.pr_list_loading {
                     self.fetch_pr_list();
+                } else {
+                    self.restore_selection(Screen::PrList);
                 }
             }
             Screen::PrDetail(number) => {
@@ -2752,10 +6227,20 @@ impl App {
                 self.pr_comment_text.clear();
                 self.pr_comment_scroll = 0;
                 self.pr_workflow_runs.clear();
+                self.pr_reviews.clear();
+                self.pr_reviews_error = None;
                 self.fetch_pr_comments(number);
+                self.fetch_pr_reviews(number);
                 // PR workflow runs will be fetched after PR details load (in handle_async_message)
             }
+            Screen::PrDiff(number) => {
+                self.fetch_pr_diff(number);
+            }
+            Screen::WorkflowLogs(run_id) => {
+                self.fetch_workflow_logs(run_id);
+            }
             Screen::Commit => {
+                self.commit_diff_focused = false;
                 self.refresh_changed_files();
             }
             Screen::PrCreate => {
@@ -2763,6 +6248,11 @@ impl App {
                 self.fetch_branches();
             }
             Screen::WorkflowRuns => {
+                // A branch-filtered list doesn't line up with the remembered selection
+                if self.pr_workflow_branch.is_some() {
+                    self.selection_memory.remove(&Screen::WorkflowRuns);
+                }
+
                 // Clear branch filter if coming from Dashboard (not from PR detail)
                 if self.current_screen == Screen::Dashboard {
                     self.pr_workflow_branch = None;
@@ -2782,8 +6272,15 @@ impl App {
                 let tags_empty = self.tags_local.is_empty() && self.tags_remote.is_empty();
                 if (!self.tags_fetched || tags_empty) && !self.tags_loading {
                     self.fetch_tags();
+                } else {
+                    self.restore_selection(Screen::Tags);
                 }
             }
+            Screen::Branches => {
+                // Local branches are cheap to list, so always reload
+                self.refresh_local_branches();
+                self.restore_selection(Screen::Branches);
+            }
             _ => {}
         }
     }
@@ -2793,11 +6290,17 @@ impl App {
         self.pr_create_title = String::new();
         self.pr_create_body = String::new();
         self.pr_create_draft = false;
+        self.pr_create_reviewers = String::new();
+        self.pr_create_assignees = String::new();
         self.pr_create_error = None;
         self.pr_create_field = 0;
         self.pr_create_body_cursor = (0, 0);
         self.pr_create_body_scroll = 0;
+        self.pr_create_body_preview = false;
+        self.pr_create_body_history.clear();
         self.pr_create_ai_loading = false;
+        self.pr_create_ai_feedback.clear();
+        self.pr_create_feedback_open = false;
 
         // Set default branches from repository context
         if let Some(repo) = &self.repository {
@@ -2811,18 +6314,48 @@ impl App {
 
     /// Update the list of commits between head and base branches
     fn update_pr_commits(&mut self) {
+        self.pr_create_commits_resolved = false;
+        self.ai_cache.invalidate_pr();
+
         if self.pr_create_head.is_empty() || self.pr_create_base.is_empty() {
             self.pr_create_commits = Vec::new();
             return;
         }
 
         if let Ok(git) = GitRepository::open_current_dir() {
-            self.pr_create_commits = git
-                .get_commits_between(&self.pr_create_base, &self.pr_create_head)
-                .unwrap_or_default();
+            match git.get_commits_between(&self.pr_create_base, &self.pr_create_head) {
+                Ok(commits) => {
+                    self.pr_create_commits = commits;
+                    self.pr_create_commits_resolved = true;
+                }
+                Err(_) => self.pr_create_commits = Vec::new(),
+            }
+        }
+    }
+
+    /// Live validation message for the head/base branch selection, if any
+    ///
+    /// Identical branches are an error; an empty commit range is only a warning
+    /// since the local view of the remote branches may be stale.
+    pub fn pr_create_branch_warning(&self) -> Option<&'static str> {
+        if self.pr_create_head.is_empty() || self.pr_create_base.is_empty() {
+            return None;
+        }
+
+        if self.pr_create_head == self.pr_create_base {
+            Some("head and base must differ")
+        } else if self.pr_create_commits_resolved && self.pr_create_commits.is_empty() {
+            Some("nothing to compare")
+        } else {
+            None
         }
     }
 
+    /// Whether the head/base selection is invalid (identical branches)
+    pub fn pr_create_branches_conflict(&self) -> bool {
+        !self.pr_create_head.is_empty() && self.pr_create_head == self.pr_create_base
+    }
+
     /// Fetch branches for PR creation
     fn fetch_branches(&mut self) {
         if self.pr_create_loading {
@@ -2906,6 +6439,8 @@ impl App {
                 Some(self.pr_create_body.clone())
             },
             draft: self.pr_create_draft,
+            reviewers: crate::tui::parse_usernames(&self.pr_create_reviewers),
+            assignees: crate::tui::parse_usernames(&self.pr_create_assignees),
         };
 
         tokio::spawn(async move {
@@ -2917,8 +6452,13 @@ impl App {
             .await;
 
             match result {
-                Ok(pr) => {
-                    let _ = tx.send(AsyncMessage::PrCreated(Box::new(pr))).await;
+                Ok(created) => {
+                    let _ = tx
+                        .send(AsyncMessage::PrCreated {
+                            pr: Box::new(created.pr),
+                            warnings: created.warnings,
+                        })
+                        .await;
                 }
                 Err(e) => {
                     tracing::error!("PR creation failed: {:?}", e);
@@ -2929,14 +6469,20 @@ impl App {
     }
 
     /// Generate PR title and body using AI
-    fn generate_ai_pr_content(&mut self) {
+    ///
+    /// With `feedback`, the current title and body are revised instead of
+    /// generated from scratch.
+    fn generate_ai_pr_content(&mut self, feedback: Option<String>) {
         if self.pr_create_ai_loading {
             return;
         }
 
-        if !self.gemini_configured {
-            self.pr_create_error = Some("Gemini API key not configured".to_string());
-            self.status_message = Some("Configure Gemini key in Settings first".to_string());
+        if !self.ai_configured {
+            self.pr_create_error = Some(format!(
+                "{} API key not configured",
+                self.ai_provider.display_name()
+            ));
+            self.status_message = Some("Configure an AI API key in Settings first".to_string());
             return;
         }
 
@@ -2948,41 +6494,110 @@ impl App {
         // Get diff and commits for context
         let base = self.pr_create_base.clone();
         let head = self.pr_create_head.clone();
+        let previous = PrContent {
+            title: self.pr_create_title.clone(),
+            body: self.pr_create_body.clone(),
+        };
+        // Feedback always asks the model again
+        let last = match feedback {
+            Some(_) => None,
+            None => self.ai_cache.pr.clone(),
+        };
+        self.ai_cache.pending_pr = true;
 
         self.pr_create_ai_loading = true;
         self.pr_create_error = None;
         self.status_message = Some("Generating with AI...".to_string());
 
+        self.ai_request += 1;
+        let request = self.ai_request;
         let tx = self.async_tx.clone();
 
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             let result = async {
-                // Get diff between branches (only the diff, no commit messages)
+                // Diff between branches (only the diff, no commit messages)
                 let git = GitRepository::open_current_dir()?;
                 let diff = git
                     .branch_diff(&base, &head)
-                    .or_else(|_| git.all_changes_diff())?;
+                    .or_else(|_| git.all_changes_diff().map(|d| d.text))?;
+
+                let client = AiClient::new()?;
+
+                // Same diff, branches and prompt without feedback: reuse the last result
+                let key = AiResultCache::key(&[&diff, &head, &base, &client.prompt_settings()]);
+                if let Some((_, content)) = last.filter(|(k, _)| *k == key) {
+                    return Ok((content, key, true));
+                }
 
                 // Generate with AI using only the diff content
-                let client = GeminiClient::new()?;
-                client.generate_pr_content(&diff, &head).await
+                let content = match feedback {
+                    Some(feedback) => {
+                        client
+                            .regenerate_pr_content(&diff, &head, &previous, &feedback)
+                            .await
+                    }
+                    None => client.generate_pr_content(&diff, &head).await,
+                }?;
+                Ok::<_, GhrustError>((content, key, false))
             }
             .await;
 
             match result {
-                Ok(content) => {
+                Ok((content, key, cached)) => {
                     let _ = tx
                         .send(AsyncMessage::AiContentGenerated {
-                            title: content.title,
-                            body: content.body,
+                            request,
+                            content,
+                            key,
+                            cached,
                         })
                         .await;
                 }
                 Err(e) => {
-                    let _ = tx.send(AsyncMessage::AiContentError(e.to_string())).await;
+                    let _ = tx
+                        .send(AsyncMessage::AiContentError {
+                            request,
+                            error: e.to_string(),
+                        })
+                        .await;
                 }
             }
         });
+        self.ai_task = Some(task.abort_handle());
+    }
+
+    /// Enter commit message mode, pre-filling from the git commit template
+    ///
+    /// Non-comment template lines become the initial message; `#` comment
+    /// lines are kept as hints and never end up in the commit.
+    fn enter_commit_message_mode(&mut self) {
+        if !self.commit_conflicts.is_empty() {
+            self.status_message = Some("Resolve merge conflicts before committing".to_string());
+            return;
+        }
+
+        self.commit_message_mode = true;
+        self.commit_message.clear();
+        self.commit_message_history.clear();
+        self.commit_template_hints.clear();
+        self.protected_branch_confirmed = None;
+        self.status_message = Some("Enter commit message...".to_string());
+
+        let template = GitRepository::open_current_dir()
+            .and_then(|git| git.commit_template())
+            .ok()
+            .flatten();
+        if let Some(template) = template {
+            let (hints, text): (Vec<&str>, Vec<&str>) =
+                template.lines().partition(|l| l.starts_with('#'));
+            self.commit_message = text.join("\n").trim_end().to_string();
+            self.commit_template_hints = hints
+                .into_iter()
+                .map(|l| l.trim_start_matches('#').trim().to_string())
+                .filter(|l| !l.is_empty())
+                .collect();
+            self.status_message = Some("Enter commit message (from template)...".to_string());
+        }
     }
 
     /// Refresh the list of changed files
@@ -2992,6 +6607,7 @@ impl App {
         match GitRepository::open_current_dir() {
             Ok(repo) => match repo.changed_files() {
                 Ok(files) => {
+                    self.commit_conflicts = repo.conflicted_files().unwrap_or_default();
                     self.changed_files = files;
                     self.commit_file_selection = ListState::new(self.changed_files.len());
                     // Restore selection, clamped to valid range
@@ -3007,6 +6623,9 @@ impl App {
                     self.build_file_groups();
                     // Ensure scroll is valid after refresh
                     self.adjust_commit_scroll_to_selection();
+                    // Staging changes what each diff shows
+                    self.commit_diff_cache.clear();
+                    self.update_commit_diff_preview();
                 }
                 Err(e) => {
                     self.status_message = Some(format!("Error: {}", e));
@@ -3020,41 +6639,7 @@ impl App {
 
     /// Build file groups from the flat file list
     fn build_file_groups(&mut self) {
-        use std::collections::BTreeMap;
-        use std::path::Path;
-
-        let mut groups: BTreeMap<String, Vec<FileStatus>> = BTreeMap::new();
-
-        for file in &self.changed_files {
-            let dir = Path::new(&file.path)
-                .parent()
-                .map(|p| {
-                    let s = p.to_string_lossy().to_string();
-                    if s.is_empty() {
-                        ".".to_string()
-                    } else {
-                        s
-                    }
-                })
-                .unwrap_or_else(|| ".".to_string());
-            groups.entry(dir).or_default().push(file.clone());
-        }
-
-        // Preserve expansion state from previous groups
-        let was_expanded: HashMap<String, bool> = self
-            .file_groups
-            .iter()
-            .map(|g| (g.directory.clone(), g.expanded))
-            .collect();
-
-        self.file_groups = groups
-            .into_iter()
-            .map(|(directory, files)| FileGroup {
-                expanded: *was_expanded.get(&directory).unwrap_or(&true),
-                directory,
-                files,
-            })
-            .collect();
+        self.file_groups = group_files(&self.changed_files, &self.file_groups);
 
         // Reset selection if out of bounds
         if self.selected_group_idx >= self.file_groups.len() {
@@ -3063,6 +6648,11 @@ impl App {
         }
     }
 
+    /// Directories of the staged files in the commit screen's file groups
+    fn staged_directories(&self) -> Vec<String> {
+        git::staged_directories(self.file_groups.iter().flat_map(|g| &g.files))
+    }
+
     /// Toggle staging for a folder (all files in the group)
     fn toggle_folder_staging(&mut self, group_idx: usize) {
         if let Some(group) = self.file_groups.get(group_idx) {
@@ -3078,6 +6668,7 @@ impl App {
                     }
                 }
             }
+            self.ai_cache.invalidate_commit();
             self.refresh_changed_files();
         }
     }
@@ -3102,6 +6693,7 @@ impl App {
 
                 match result {
                     Ok(()) => {
+                        self.ai_cache.invalidate_commit();
                         self.refresh_changed_files();
                     }
                     Err(e) => {
@@ -3112,11 +6704,127 @@ impl App {
         }
     }
 
+    /// Show the hunks of a file for hunk-level staging
+    fn open_hunk_staging(&mut self, path: String) {
+        let is_untracked = self
+            .changed_files
+            .iter()
+            .any(|f| f.path == path && f.is_new && !f.is_staged);
+        if is_untracked {
+            self.status_message =
+                Some("New files have no hunks yet; stage the whole file with Space".to_string());
+            return;
+        }
+
+        self.commit_hunk_path = Some(path);
+        self.commit_hunk_selection = ListState::default();
+        self.reload_hunks();
+    }
+
+    /// Reload the hunk list for the current file, keeping the selection in range
+    fn reload_hunks(&mut self) {
+        let Some(path) = self.commit_hunk_path.clone() else {
+            return;
+        };
+
+        match GitRepository::open_current_dir().and_then(|repo| repo.file_hunks(&path)) {
+            Ok(hunks) => {
+                let selected = self.commit_hunk_selection.selected;
+                self.commit_hunks = hunks;
+                self.commit_hunk_selection = ListState::new(self.commit_hunks.len());
+                self.commit_hunk_selection.selected =
+                    selected.min(self.commit_hunks.len().saturating_sub(1));
+                if self.commit_hunks.is_empty() {
+                    self.status_message = Some(format!("No changes left in {}", path));
+                }
+            }
+            Err(e) => {
+                self.commit_hunk_path = None;
+                self.error_popup = Some(ErrorPopup {
+                    title: "Cannot Load Hunks".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Handle key events in the hunk staging list
+    fn handle_commit_hunk_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('h') | KeyCode::Char('q') => {
+                self.commit_hunk_path = None;
+                self.commit_hunks.clear();
+                self.refresh_changed_files();
+            }
+            KeyCode::Char('j') | KeyCode::Down => self.commit_hunk_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.commit_hunk_selection.previous(),
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                self.commit_hunk_selection.handle_jump_key(key.code);
+            }
+            KeyCode::Char(' ') | KeyCode::Enter => self.toggle_hunk_staging(),
+            KeyCode::Char('r') => self.reload_hunks(),
+            _ => {}
+        }
+    }
+
+    /// Stage the selected hunk if unstaged, or unstage it if staged
+    fn toggle_hunk_staging(&mut self) {
+        let Some(path) = self.commit_hunk_path.clone() else {
+            return;
+        };
+        let Some(hunk) = self.commit_hunks.get(self.commit_hunk_selection.selected) else {
+            return;
+        };
+        let (staged, index) = (hunk.staged, hunk.index);
+
+        let result = GitRepository::open_current_dir().and_then(|repo| {
+            if staged {
+                repo.unstage_hunk(&path, index)
+            } else {
+                repo.stage_hunk(&path, index)
+            }
+        });
+
+        match result {
+            Ok(()) => {
+                self.ai_cache.invalidate_commit();
+                self.status_message = Some(if staged {
+                    "Hunk unstaged".to_string()
+                } else {
+                    "Hunk staged".to_string()
+                });
+                self.reload_hunks();
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Error: {}", e));
+            }
+        }
+    }
+
+    /// Discard a file's unstaged changes (deletes untracked files) and refresh
+    fn discard_file_changes(&mut self, path: &str) {
+        let result = GitRepository::open_current_dir().and_then(|repo| repo.discard_file(path));
+
+        match result {
+            Ok(()) => {
+                self.refresh_changed_files();
+                self.status_message = Some(format!("Discarded changes to {}", path));
+            }
+            Err(e) => {
+                self.error_popup = Some(ErrorPopup {
+                    title: "Discard Failed".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
     /// Stage all files
     fn stage_all_files(&mut self) {
         if let Ok(repo) = GitRepository::open_current_dir() {
             match repo.stage_all() {
                 Ok(()) => {
+                    self.ai_cache.invalidate_commit();
                     self.refresh_changed_files();
                     self.status_message = Some("All files staged".to_string());
                 }
@@ -3129,48 +6837,139 @@ impl App {
 
     /// Generate AI commit message from staged changes
     fn generate_ai_commit_message(&mut self) {
+        if !self.commit_conflicts.is_empty() {
+            self.status_message = Some("Resolve merge conflicts before committing".to_string());
+            return;
+        }
+
         if self.commit_ai_loading {
             return;
         }
 
-        if !self.gemini_configured {
-            self.status_message = Some("Configure Gemini key in Settings first".to_string());
+        if !self.ai_configured {
+            self.status_message = Some("Configure an AI API key in Settings first".to_string());
+            return;
+        }
+
+        if !self.changed_files.iter().any(|f| f.is_staged) {
+            self.status_message = Some("No staged changes to generate message from".to_string());
             return;
         }
 
+        let last = self.ai_cache.commit.clone();
+        self.ai_cache.pending_commit = true;
+
         self.commit_ai_loading = true;
         self.status_message = Some("Generating commit message with AI...".to_string());
 
+        self.ai_request += 1;
+        let request = self.ai_request;
         let tx = self.async_tx.clone();
+        let directories = self.staged_directories();
 
-        tokio::spawn(async move {
+        let task = tokio::spawn(async move {
             let result = async {
                 let git = GitRepository::open_current_dir()?;
                 let diff = git.staged_diff()?;
                 if diff.is_empty() {
-                    return Err(crate::error::GhrustError::InvalidInput(
+                    return Err(GhrustError::InvalidInput(
                         "No staged changes to generate message from".to_string(),
                     ));
                 }
+                let branch = git.current_branch().unwrap_or_default();
+
+                let client = AiClient::new()?;
+
+                // Same staged diff, branch and prompt: reuse the last message
+                let key = AiResultCache::key(&[&diff.text, &branch, &client.prompt_settings()]);
+                if let Some((_, message)) = last.filter(|(k, _)| *k == key) {
+                    return Ok((message, key, true));
+                }
 
-                let client = GeminiClient::new()?;
-                client.generate_commit_message(&diff).await
+                // Partial text is best-effort; the final message replaces it
+                let chunk_tx = tx.clone();
+                let mut on_chunk = move |chunk: &str| {
+                    let _ = chunk_tx.try_send(AsyncMessage::AiCommitMessageChunk {
+                        request,
+                        chunk: chunk.to_string(),
+                    });
+                };
+                let message = client
+                    .generate_commit_message_stream(&diff.text, &directories, &mut on_chunk)
+                    .await?;
+                Ok((message, key, false))
             }
             .await;
 
             match result {
-                Ok(message) => {
+                Ok((message, key, cached)) => {
                     let _ = tx
-                        .send(AsyncMessage::AiCommitMessageGenerated(message))
+                        .send(AsyncMessage::AiCommitMessageGenerated {
+                            request,
+                            message,
+                            key,
+                            cached,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::AiCommitMessageError {
+                            request,
+                            error: e.to_string(),
+                        })
+                        .await;
+                }
+            }
+        });
+        self.ai_task = Some(task.abort_handle());
+    }
+
+    /// Suggest a name for the new push branch from the last commit using AI
+    fn suggest_ai_branch_name(&mut self) {
+        if self.commit_ai_loading {
+            return;
+        }
+
+        if !self.ai_configured {
+            self.status_message = Some("Configure an AI API key in Settings first".to_string());
+            return;
+        }
+
+        self.commit_ai_loading = true;
+        self.status_message = Some("Suggesting branch name with AI...".to_string());
+
+        self.ai_request += 1;
+        let request = self.ai_request;
+        let tx = self.async_tx.clone();
+
+        let task = tokio::spawn(async move {
+            let result = async {
+                let git = GitRepository::open_current_dir()?;
+                let diff = git.head_commit_diff()?;
+
+                let client = AiClient::new()?;
+                client.suggest_branch_name(&diff).await
+            }
+            .await;
+
+            match result {
+                Ok(name) => {
+                    let _ = tx
+                        .send(AsyncMessage::AiBranchNameGenerated { request, name })
                         .await;
                 }
                 Err(e) => {
                     let _ = tx
-                        .send(AsyncMessage::AiCommitMessageError(e.to_string()))
+                        .send(AsyncMessage::AiBranchNameError {
+                            request,
+                            error: e.to_string(),
+                        })
                         .await;
                 }
             }
         });
+        self.ai_task = Some(task.abort_handle());
     }
 
     /// Commit staged changes with the current commit message
@@ -3190,7 +6989,14 @@ impl App {
             return;
         }
 
+        if let Some(branch) = self.current_local_branch() {
+            if !self.confirm_protected_branch(&branch, "commit", "Enter") {
+                return;
+            }
+        }
+
         if let Ok(repo) = GitRepository::open_current_dir() {
+            let repo = repo.with_dry_run(self.dry_run);
             match repo.commit(message) {
                 Ok(sha) => {
                     let first_line = message.lines().next().unwrap_or("");
@@ -3206,6 +7012,7 @@ impl App {
 
                     // Store state and show push prompt
                     self.last_commit_hash = Some(sha);
+                    self.branch_status_last_tick = None;
                     self.commit_tracking_branch = Some(tracking);
                     self.commit_push_prompt = true;
                     self.commit_message_mode = false;
@@ -3213,6 +7020,9 @@ impl App {
                     self.status_message = Some(format!("✓ {}: {}", short_sha, first_line));
                     self.refresh_changed_files();
                 }
+                Err(GhrustError::DryRun(action)) => {
+                    self.handle_async_message(AsyncMessage::DryRun(action));
+                }
                 Err(e) => {
                     self.status_message = Some(format!("Commit failed: {}", e));
                 }
@@ -3220,6 +7030,111 @@ impl App {
         }
     }
 
+    /// Open the message input prefilled with HEAD's message, amending on Enter
+    fn enter_amend_message_mode(&mut self) {
+        match GitRepository::open_current_dir().and_then(|repo| repo.head_commit_message()) {
+            Ok(message) => {
+                self.commit_message = message.trim_end().to_string();
+                self.commit_message_history.clear();
+                self.commit_template_hints.clear();
+                self.commit_amend_mode = true;
+                self.commit_message_mode = true;
+                self.status_message = Some("Edit the message, then Enter to amend".to_string());
+            }
+            Err(e) => {
+                self.error_popup = Some(ErrorPopup {
+                    title: "Cannot Amend".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Amend the last commit with the staged changes (`None` keeps its message)
+    fn do_amend(&mut self, message: Option<&str>) {
+        if let Some(branch) = self.current_local_branch() {
+            // The prompt confirms with y, the message editor with Enter
+            let key = if message.is_some() { "Enter" } else { "y" };
+            if !self.confirm_protected_branch(&branch, "amend", key) {
+                // Keep the prompt open so the next y reaches this check again
+                self.commit_amend_confirm = message.is_none();
+                return;
+            }
+        }
+
+        let dry_run = self.dry_run;
+        let result = GitRepository::open_current_dir().and_then(|repo| {
+            let repo = repo.with_dry_run(dry_run);
+            let sha = repo.commit_amend(message)?;
+            let summary = repo.head_commit_summary()?.unwrap_or_default();
+            Ok((sha, summary))
+        });
+
+        match result {
+            Ok((sha, summary)) => {
+                let short_sha = sha[..7.min(sha.len())].to_string();
+                self.branch_status_last_tick = None;
+                self.commit_amend_mode = false;
+                self.commit_message_mode = false;
+                self.commit_message.clear();
+                self.status_message = Some(format!("✓ Amended {}: {}", short_sha, summary));
+                self.refresh_changed_files();
+            }
+            Err(GhrustError::DryRun(action)) => {
+                self.handle_async_message(AsyncMessage::DryRun(action));
+            }
+            Err(e) => {
+                self.error_popup = Some(ErrorPopup {
+                    title: "Amend Failed".to_string(),
+                    message: e.to_string(),
+                });
+            }
+        }
+    }
+
+    /// Name of the currently checked out branch
+    fn current_local_branch(&self) -> Option<String> {
+        GitRepository::open_current_dir()
+            .ok()?
+            .current_branch()
+            .ok()
+    }
+
+    /// Whether a branch is protected by config or by GitHub branch protection
+    fn is_branch_protected(&self, branch: &str) -> bool {
+        let default_branch = self
+            .repository
+            .as_ref()
+            .map(|r| r.default_branch.as_str())
+            .unwrap_or("main");
+        let github_protected = self
+            .pr_create_branches
+            .iter()
+            .any(|b| b.name == branch && b.protected);
+
+        github_protected || is_protected_branch(&self.protected_branches, branch, default_branch)
+    }
+
+    /// Require a second press of `key` before committing/pushing to a protected branch
+    ///
+    /// Returns true when the action may proceed.
+    fn confirm_protected_branch(&mut self, branch: &str, action: &str, key: &str) -> bool {
+        if !self.is_branch_protected(branch) {
+            return true;
+        }
+
+        if self.protected_branch_confirmed.take().as_deref() == Some(branch) {
+            return true;
+        }
+
+        self.protected_branch_confirmed = Some(branch.to_string());
+        self.status_message = Some(format!(
+            "⚠ '{}' is protected. Press {} again to {} anyway",
+            branch, key, action
+        ));
+        false
+    }
+
     /// Push to origin after commit
     fn do_push(&mut self) {
         let tracking = self
@@ -3234,11 +7149,12 @@ impl App {
         // Clone for async task
         let sender = self.async_tx.clone();
         let tracking_clone = tracking.clone();
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             // Run push in blocking task since git2 is sync
             let result = tokio::task::spawn_blocking(move || {
-                let repo = GitRepository::open_current_dir()?;
+                let repo = GitRepository::open_current_dir()?.with_dry_run(dry_run);
                 repo.push(false)?;
                 Ok::<_, crate::error::GhrustError>(())
             })
@@ -3246,6 +7162,7 @@ impl App {
 
             let message = match result {
                 Ok(Ok(())) => AsyncMessage::PushCompleted(tracking_clone),
+                Ok(Err(GhrustError::DryRun(action))) => AsyncMessage::DryRun(action),
                 Ok(Err(e)) => AsyncMessage::PushError(e.to_string()),
                 Err(e) => AsyncMessage::PushError(format!("Task failed: {}", e)),
             };
@@ -3286,20 +7203,23 @@ impl App {
 
         let sender = self.async_tx.clone();
         let branch_clone = target_branch.clone();
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             let result = tokio::task::spawn_blocking(move || {
-                let repo = GitRepository::open_current_dir()?;
+                let repo = GitRepository::open_current_dir()?.with_dry_run(dry_run);
+                let mut plan = DryRunPlan::default();
                 // Checkout the target branch
-                repo.checkout(&target_branch)?;
+                plan.step(repo.checkout(&target_branch))?;
                 // Push it
-                repo.push(false)?;
-                Ok::<_, crate::error::GhrustError>(())
+                plan.step(repo.push_branch(&target_branch, "origin", false))?;
+                plan.finish()
             })
             .await;
 
             let message = match result {
                 Ok(Ok(())) => AsyncMessage::PushCompleted(branch_clone),
+                Ok(Err(GhrustError::DryRun(action))) => AsyncMessage::DryRun(action),
                 Ok(Err(e)) => AsyncMessage::PushError(e.to_string()),
                 Err(e) => AsyncMessage::PushError(format!("Task failed: {}", e)),
             };
@@ -3321,20 +7241,23 @@ impl App {
 
         let sender = self.async_tx.clone();
         let branch_clone = new_branch.clone();
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             let result = tokio::task::spawn_blocking(move || {
-                let repo = GitRepository::open_current_dir()?;
+                let repo = GitRepository::open_current_dir()?.with_dry_run(dry_run);
+                let mut plan = DryRunPlan::default();
                 // Create new branch and switch to it
-                repo.create_branch(&new_branch)?;
+                plan.step(repo.create_branch(&new_branch))?;
                 // Push with upstream tracking
-                repo.set_upstream(&format!("origin/{}", new_branch))?;
-                Ok::<_, crate::error::GhrustError>(())
+                plan.step(repo.push_with_upstream(&new_branch, "origin"))?;
+                plan.finish()
             })
             .await;
 
             let message = match result {
                 Ok(Ok(())) => AsyncMessage::PushCompleted(branch_clone),
+                Ok(Err(GhrustError::DryRun(action))) => AsyncMessage::DryRun(action),
                 Ok(Err(e)) => AsyncMessage::PushError(e.to_string()),
                 Err(e) => AsyncMessage::PushError(format!("Task failed: {}", e)),
             };
@@ -3350,23 +7273,136 @@ impl App {
             self.pr_workflow_branch = None;
         }
 
-        if let Some(screen) = self.navigation_stack.pop() {
-            self.current_screen = screen;
-            self.status_message = None; // Clear stale messages on screen change
+        self.save_selection();
+        if let Some(screen) = self.navigation_stack.pop() {
+            self.current_screen = screen;
+            self.status_message = None; // Clear stale messages on screen change
+            self.restore_selection(screen);
+        }
+    }
+
+    /// List selection state for screens that show a selectable list
+    fn list_selection_mut(&mut self, screen: Screen) -> Option<&mut ListState> {
+        match screen {
+            Screen::PrList => Some(&mut self.pr_list_selection),
+            Screen::WorkflowRuns => Some(&mut self.workflow_runs_selection),
+            Screen::Tags => Some(&mut self.tags_selection),
+            Screen::Branches => Some(&mut self.branches_selection),
+            _ => None,
+        }
+    }
+
+    /// Remember the current screen's list selection before leaving it
+    fn save_selection(&mut self) {
+        if !self.remember_selection {
+            return;
+        }
+        let screen = self.current_screen;
+        if let Some(selected) = self.list_selection_mut(screen).map(|s| s.selected) {
+            self.selection_memory.insert(screen, selected);
+        }
+    }
+
+    /// Restore a remembered list selection once the screen's list has items
+    ///
+    /// The remembered index is consumed so later in-place refreshes start fresh.
+    fn restore_selection(&mut self, screen: Screen) {
+        if !self.remember_selection {
+            return;
+        }
+        let Some(&remembered) = self.selection_memory.get(&screen) else {
+            return;
+        };
+        let Some(state) = self.list_selection_mut(screen) else {
+            return;
+        };
+        // Still loading: keep the index for when the list arrives
+        if state.total == 0 {
+            return;
+        }
+        state.selected = remembered.min(state.total - 1);
+        self.selection_memory.remove(&screen);
+    }
+
+    /// Quit the application
+    pub fn quit(&mut self) {
+        // If update is downloading, mark it as partial for cleanup on next launch
+        if matches!(self.update_state, crate::core::UpdateState::Downloading(_)) {
+            if let Ok(mut state) = crate::core::update::UpdatePersistentState::load() {
+                state.partial_download = true;
+                state.clear_pending();
+                let _ = state.save();
+            }
+        }
+        self.save_session();
+        self.running = false;
+    }
+
+    /// Persist the current screen and list selections for the next launch
+    fn save_session(&mut self) {
+        // Record the current screen's selection alongside the remembered ones
+        self.save_selection();
+        let selected = |app: &Self, screen: Screen, list: &ListState| {
+            app.selection_memory
+                .get(&screen)
+                .copied()
+                .unwrap_or(list.selected)
+        };
+        let session = SessionState {
+            repository: self.repository.as_ref().map(|r| r.full_name()),
+            screen: self.current_screen,
+            dashboard_selected: self.dashboard_selection.selected,
+            pr_list_selected: selected(self, Screen::PrList, &self.pr_list_selection),
+            workflow_runs_selected: selected(
+                self,
+                Screen::WorkflowRuns,
+                &self.workflow_runs_selection,
+            ),
+            tags_selected: selected(self, Screen::Tags, &self.tags_selection),
+            branches_selected: selected(self, Screen::Branches, &self.branches_selection),
+        };
+        if let Err(e) = session.save() {
+            tracing::debug!("Failed to save session state: {}", e);
+        }
+    }
+
+    /// Reopen the screen and selections saved by the previous session
+    ///
+    /// Only applies when the session was recorded in the same repository.
+    fn restore_session(&mut self) {
+        let Some(session) = self.pending_session.take() else {
+            return;
+        };
+        if session.repository != self.repository.as_ref().map(|r| r.full_name()) {
+            return;
+        }
+
+        self.dashboard_selection.selected = session
+            .dashboard_selected
+            .min(self.dashboard_selection.total.saturating_sub(1));
+        if self.remember_selection {
+            // Applied by restore_selection once each list loads
+            self.selection_memory
+                .insert(Screen::PrList, session.pr_list_selected);
+            self.selection_memory
+                .insert(Screen::WorkflowRuns, session.workflow_runs_selected);
+            self.selection_memory
+                .insert(Screen::Tags, session.tags_selected);
+            self.selection_memory
+                .insert(Screen::Branches, session.branches_selected);
         }
-    }
 
-    /// Quit the application
-    pub fn quit(&mut self) {
-        // If update is downloading, mark it as partial for cleanup on next launch
-        if matches!(self.update_state, crate::core::UpdateState::Downloading(_)) {
-            if let Ok(mut state) = crate::core::update::UpdatePersistentState::load() {
-                state.partial_download = true;
-                state.clear_pending();
-                let _ = state.save();
-            }
+        let screen = match session.screen {
+            // The diff reopens as its PR; forms start fresh
+            Screen::PrDiff(number) => Screen::PrDetail(number),
+            Screen::WorkflowLogs(_) => Screen::WorkflowRuns,
+            Screen::Dashboard | Screen::PrCreate | Screen::Auth => return,
+            screen => screen,
+        };
+        if let Screen::PrDetail(number) = screen {
+            self.restored_pr = Some(number);
         }
-        self.running = false;
+        self.navigate_to(screen);
     }
 
     // ─────────────────────────────────────────────────────────────────────────
@@ -3379,13 +7415,8 @@ impl App {
             return;
         }
 
-        let repo = match &self.repository {
-            Some(r) => r.clone(),
-            None => {
-                self.tags_error = Some("No repository context".to_string());
-                return;
-            }
-        };
+        // Without a GitHub remote only local tags are shown
+        let repo = self.repository.clone();
 
         self.tags_loading = true;
         self.tags_error = None;
@@ -3403,13 +7434,15 @@ impl App {
                 let local_tags = git.list_tags()?;
 
                 // Get remote tags
-                let client = GitHubClient::new(repo.owner.clone(), repo.name.clone()).await?;
-                let handler = TagHandler::new(&client);
-                let remote_tags = handler.list().await?;
-                let remote_tag_names: Vec<String> =
-                    remote_tags.into_iter().map(|t| t.name).collect();
+                let remote_tags = match repo {
+                    Some(repo) => {
+                        let client = GitHubClient::new(repo.owner, repo.name).await?;
+                        TagHandler::new(&client).list().await?
+                    }
+                    None => Vec::new(),
+                };
 
-                Ok::<_, crate::error::GhrustError>((local_tags, remote_tag_names))
+                Ok::<_, crate::error::GhrustError>((local_tags, remote_tags))
             }
             .await;
 
@@ -3429,6 +7462,31 @@ impl App {
         });
     }
 
+    /// Remote tags with no local tag of the same name, in list order
+    pub fn tags_remote_only(&self) -> Vec<&crate::github::TagInfo> {
+        self.tags_remote
+            .iter()
+            .filter(|remote| {
+                !self
+                    .tags_local
+                    .iter()
+                    .any(|local| local.name == remote.name)
+            })
+            .collect()
+    }
+
+    /// Name of the selected tag and whether it exists locally
+    fn selected_tag(&self) -> Option<(String, bool)> {
+        let selected = self.tags_selection.selected;
+        match self.tags_local.get(selected) {
+            Some(tag) => Some((tag.name.clone(), true)),
+            None => self
+                .tags_remote_only()
+                .get(selected - self.tags_local.len())
+                .map(|tag| (tag.name.clone(), false)),
+        }
+    }
+
     /// Handle key events on the tags screen
     fn handle_tags_key(&mut self, key: KeyEvent) {
         match key.code {
@@ -3451,30 +7509,89 @@ impl App {
                 // Push all tags
                 self.push_all_tags();
             }
+            KeyCode::Enter => {
+                // Show annotation and target commit of the selected local tag
+                if let Some(tag) = self.tags_local.get(self.tags_selection.selected).cloned() {
+                    self.open_tag_detail(tag);
+                }
+            }
+            KeyCode::Char('d') => {
+                // Delete selected local tag
+                match self.selected_tag() {
+                    Some((name, true)) => self.tag_delete_confirm = Some((name, false)),
+                    Some((_, false)) => {
+                        self.status_message =
+                            Some("Tag only exists on origin, press D to delete it".to_string());
+                    }
+                    None => {}
+                }
+            }
+            KeyCode::Char('D') => {
+                // Delete selected tag locally and from origin
+                if let Some((name, _)) = self.selected_tag() {
+                    let on_remote = self.tags_remote.iter().any(|t| t.name == name);
+                    self.tag_delete_confirm = Some((name, on_remote));
+                }
+            }
             KeyCode::Char('n') => {
                 // Enter tag creation mode
-                self.tag_create_mode = true;
-                self.tag_create_name.clear();
-                self.tag_create_message.clear();
-                self.tag_create_message_cursor = (0, 0);
-                self.tag_create_field = 0;
+                self.open_tag_create();
             }
             _ => {}
         }
     }
 
+    /// Delete a tag locally (if present) and optionally from origin, in the background
+    fn delete_tag(&mut self, name: String, remote: bool) {
+        let tx = self.async_tx.clone();
+
+        self.status_message = Some(format!("Deleting tag {}...", name));
+        let dry_run = self.dry_run;
+
+        tokio::spawn(async move {
+            use crate::core::git::GitRepository;
+
+            let result = async {
+                let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
+                // Collect both deletes so a dry run reports the remote one too
+                let mut plan = crate::error::DryRunPlan::default();
+                if git.tag_exists(&name)? {
+                    plan.step(git.delete_tag(&name))?;
+                }
+                if remote {
+                    plan.step(git.delete_remote_tag(&name))?;
+                }
+                plan.finish()
+            }
+            .await;
+
+            match result {
+                Ok(()) => {
+                    let _ = tx.send(AsyncMessage::TagDeleted { name }).await;
+                }
+                Err(crate::error::GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
+                Err(e) => {
+                    let _ = tx.send(AsyncMessage::TagDeleteError(e.to_string())).await;
+                }
+            }
+        });
+    }
+
     /// Push a single tag to remote
     fn push_tag(&mut self, name: &str) {
         let tag_name = name.to_string();
         let tx = self.async_tx.clone();
 
         self.status_message = Some(format!("Pushing tag {}...", tag_name));
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             use crate::core::git::GitRepository;
 
             let result = async {
-                let git = GitRepository::open_current_dir()?;
+                let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
                 git.push_tag(&tag_name)?;
                 Ok::<_, crate::error::GhrustError>(())
             }
@@ -3484,6 +7601,9 @@ impl App {
                 Ok(()) => {
                     let _ = tx.send(AsyncMessage::TagPushed(tag_name)).await;
                 }
+                Err(crate::error::GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
                 Err(e) => {
                     let _ = tx.send(AsyncMessage::TagPushError(e.to_string())).await;
                 }
@@ -3496,12 +7616,13 @@ impl App {
         let tx = self.async_tx.clone();
 
         self.status_message = Some("Pushing all tags...".to_string());
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             use crate::core::git::GitRepository;
 
             let result = async {
-                let git = GitRepository::open_current_dir()?;
+                let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
                 git.push_tags()?;
                 Ok::<_, crate::error::GhrustError>(())
             }
@@ -3511,6 +7632,9 @@ impl App {
                 Ok(()) => {
                     let _ = tx.send(AsyncMessage::TagPushed("all".to_string())).await;
                 }
+                Err(crate::error::GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
                 Err(e) => {
                     let _ = tx.send(AsyncMessage::TagPushError(e.to_string())).await;
                 }
@@ -3518,8 +7642,78 @@ impl App {
         });
     }
 
+    /// Look up the tag's target commit and open the detail popup
+    fn open_tag_detail(&mut self, tag: crate::core::git::LocalTagInfo) {
+        use crate::core::git::GitRepository;
+
+        match GitRepository::open_current_dir().and_then(|git| git.tag_target_summary(&tag.name)) {
+            Ok(target) => {
+                self.tag_detail = Some((tag, target));
+                self.tag_detail_scroll = 0;
+            }
+            Err(e) => {
+                self.status_message = Some(format!("Cannot show tag {}: {}", tag.name, e));
+            }
+        }
+    }
+
+    /// Handle key events while the tag detail popup is open
+    fn handle_tag_detail_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') | KeyCode::Enter => {
+                self.tag_detail = None;
+                self.tag_detail_scroll = 0;
+            }
+            KeyCode::Char('j') | KeyCode::Down
+                if self.tag_detail_scroll < self.tag_detail_max_scroll.get() =>
+            {
+                self.tag_detail_scroll += 1;
+            }
+            KeyCode::Char('k') | KeyCode::Up => {
+                self.tag_detail_scroll = self.tag_detail_scroll.saturating_sub(1);
+            }
+            _ => {}
+        }
+    }
+
+    /// Open tag creation with the name seeded from the next patch version
+    fn open_tag_create(&mut self) {
+        use crate::core::git::{suggest_version_tag, GitRepository, VersionBump};
+
+        self.tag_create_latest = GitRepository::open_current_dir()
+            .and_then(|git| git.latest_version_tag())
+            .ok()
+            .flatten();
+        self.tag_create_bump = VersionBump::default();
+        self.tag_create_name =
+            suggest_version_tag(self.tag_create_latest.as_deref(), self.tag_create_bump);
+
+        self.tag_create_mode = true;
+        self.tag_create_message.clear();
+        self.tag_create_message_cursor = (0, 0);
+        self.tag_create_field = 0;
+    }
+
+    /// Replace the tag name with the suggestion for another bump level
+    fn cycle_tag_create_bump(&mut self, bump: crate::core::git::VersionBump) {
+        self.tag_create_bump = bump;
+        self.tag_create_name =
+            crate::core::git::suggest_version_tag(self.tag_create_latest.as_deref(), bump);
+    }
+
     /// Handle key events when in tag creation mode
     fn handle_tag_create_key(&mut self, key: KeyEvent) {
+        // Message field: plain editing keys go to the multi-line editor
+        if self.tag_create_field == 1
+            && text_input::handle_body_key(
+                &mut self.tag_create_message,
+                &mut self.tag_create_message_cursor,
+                key.code,
+            )
+        {
+            return;
+        }
+
         match key.code {
             KeyCode::Esc => {
                 self.tag_create_mode = false;
@@ -3542,32 +7736,6 @@ impl App {
                         // Move to message field
                         self.tag_create_field = 1;
                     }
-                    1 => {
-                        // Insert newline in message field
-                        let lines = split_lines_preserve_trailing(&self.tag_create_message);
-                        let (row, col) = self.tag_create_message_cursor;
-
-                        let mut new_message = String::new();
-                        for (i, line) in lines.iter().enumerate() {
-                            if i == row {
-                                let col = col.min(line.len());
-                                new_message.push_str(&line[..col]);
-                                new_message.push('\n');
-                                new_message.push_str(&line[col..]);
-                            } else {
-                                new_message.push_str(line);
-                            }
-                            if i < lines.len() - 1 {
-                                new_message.push('\n');
-                            }
-                        }
-                        // Handle empty message or cursor at end
-                        if lines.is_empty() || row >= lines.len() {
-                            new_message.push('\n');
-                        }
-                        self.tag_create_message = new_message;
-                        self.tag_create_message_cursor = (row + 1, 0);
-                    }
                     2 => {
                         // On confirm field, create the tag
                         self.create_tag_from_input();
@@ -3575,124 +7743,21 @@ impl App {
                     _ => {}
                 }
             }
-            // Up/Down: navigate within message body
-            KeyCode::Up => {
-                if self.tag_create_field == 1 && self.tag_create_message_cursor.0 > 0 {
-                    self.tag_create_message_cursor.0 -= 1;
-                }
-            }
-            KeyCode::Down => {
-                if self.tag_create_field == 1 {
-                    let line_count = split_lines_preserve_trailing(&self.tag_create_message).len();
-                    if self.tag_create_message_cursor.0 < line_count.saturating_sub(1) {
-                        self.tag_create_message_cursor.0 += 1;
-                    }
-                }
+            // Up/Down: cycle the suggested version in the name field
+            KeyCode::Up if self.tag_create_field == 0 => {
+                self.cycle_tag_create_bump(self.tag_create_bump.next());
             }
-            // Left/Right: move cursor in text fields
-            KeyCode::Left => {
-                if self.tag_create_field == 1 && self.tag_create_message_cursor.1 > 0 {
-                    self.tag_create_message_cursor.1 -= 1;
-                }
+            KeyCode::Down if self.tag_create_field == 0 => {
+                self.cycle_tag_create_bump(self.tag_create_bump.previous());
             }
-            KeyCode::Right => {
-                if self.tag_create_field == 1 {
-                    let lines = split_lines_preserve_trailing(&self.tag_create_message);
-                    let (row, col) = self.tag_create_message_cursor;
-                    if let Some(line) = lines.get(row) {
-                        if col < line.len() {
-                            self.tag_create_message_cursor.1 = col + 1;
-                        }
-                    }
-                }
+            KeyCode::Char(c) if self.tag_create_field == 0 => self.tag_create_name.push(c),
+            KeyCode::Backspace if self.tag_create_field == 0 => {
+                self.tag_create_name.pop();
             }
-            KeyCode::Char(c) => match self.tag_create_field {
-                0 => self.tag_create_name.push(c),
-                1 => self.insert_char_at_tag_message_cursor(c),
-                _ => {}
-            },
-            KeyCode::Backspace => match self.tag_create_field {
-                0 => {
-                    self.tag_create_name.pop();
-                }
-                1 => {
-                    // Delete character in message at cursor
-                    if !self.tag_create_message.is_empty() {
-                        let lines = split_lines_preserve_trailing(&self.tag_create_message);
-                        let (row, col) = self.tag_create_message_cursor;
-
-                        if col > 0 {
-                            // Delete character before cursor
-                            let mut new_message = String::new();
-                            for (i, line) in lines.iter().enumerate() {
-                                if i == row {
-                                    let col = col.min(line.len());
-                                    if col > 0 {
-                                        new_message.push_str(&line[..col - 1]);
-                                        new_message.push_str(&line[col..]);
-                                    } else {
-                                        new_message.push_str(line);
-                                    }
-                                } else {
-                                    new_message.push_str(line);
-                                }
-                                if i < lines.len() - 1 {
-                                    new_message.push('\n');
-                                }
-                            }
-                            self.tag_create_message = new_message;
-                            self.tag_create_message_cursor.1 = col.saturating_sub(1);
-                        } else if row > 0 {
-                            // Join with previous line (remove the newline between them)
-                            let mut new_message = String::new();
-                            let prev_line_len = lines.get(row - 1).map(|l| l.len()).unwrap_or(0);
-                            for (i, line) in lines.iter().enumerate() {
-                                new_message.push_str(line);
-                                // Add newline after every line except:
-                                // - The last line (i == lines.len() - 1)
-                                // - The line before the one being joined (row - 1)
-                                if i < lines.len() - 1 && i != row - 1 {
-                                    new_message.push('\n');
-                                }
-                            }
-                            self.tag_create_message = new_message;
-                            self.tag_create_message_cursor = (row - 1, prev_line_len);
-                        }
-                    }
-                }
-                _ => {}
-            },
             _ => {}
         }
     }
 
-    /// Insert a character at the current tag message cursor position
-    fn insert_char_at_tag_message_cursor(&mut self, c: char) {
-        let lines = split_lines_preserve_trailing(&self.tag_create_message);
-        let (row, col) = self.tag_create_message_cursor;
-
-        let mut new_message = String::new();
-        if lines.is_empty() {
-            new_message.push(c);
-        } else {
-            for (i, line) in lines.iter().enumerate() {
-                if i == row {
-                    let col = col.min(line.len());
-                    new_message.push_str(&line[..col]);
-                    new_message.push(c);
-                    new_message.push_str(&line[col..]);
-                } else {
-                    new_message.push_str(line);
-                }
-                if i < lines.len() - 1 {
-                    new_message.push('\n');
-                }
-            }
-        }
-        self.tag_create_message = new_message;
-        self.tag_create_message_cursor.1 = col + 1;
-    }
-
     /// Create a tag from the input fields and push it
     fn create_tag_from_input(&mut self) {
         let name = self.tag_create_name.trim().to_string();
@@ -3716,29 +7781,32 @@ impl App {
         self.status_message = Some(format!("Creating tag {}...", name));
 
         let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
 
         tokio::spawn(async move {
             use crate::core::git::GitRepository;
 
             let result = async {
-                let git = GitRepository::open_current_dir()?;
+                let git = GitRepository::open_current_dir()?.with_dry_run(dry_run);
 
                 // Check if tag already exists
                 if git.tag_exists(&name)? {
                     return Err(crate::error::GhrustError::TagAlreadyExists(name.clone()));
                 }
 
+                let mut plan = DryRunPlan::default();
+
                 // Create the tag (annotated or lightweight)
                 if let Some(ref msg) = message {
-                    git.create_annotated_tag(&name, msg)?;
+                    plan.step(git.create_annotated_tag(&name, msg))?;
                 } else {
-                    git.create_tag(&name)?;
+                    plan.step(git.create_tag(&name))?;
                 }
 
                 // Push the tag
-                git.push_tag(&name)?;
+                plan.step(git.push_tag(&name))?;
 
-                Ok::<_, crate::error::GhrustError>(())
+                plan.finish()
             }
             .await;
 
@@ -3748,6 +7816,9 @@ impl App {
                         .send(AsyncMessage::TagCreated { name, pushed: true })
                         .await;
                 }
+                Err(crate::error::GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
                 Err(e) => {
                     let _ = tx.send(AsyncMessage::TagCreateError(e.to_string())).await;
                 }
@@ -3755,6 +7826,190 @@ impl App {
         });
     }
 
+    // ─────────────────────────────────────────────────────────────────────────
+    // Branch methods
+    // ─────────────────────────────────────────────────────────────────────────
+
+    /// Reload local branches from git, keeping the selected branch when it still exists
+    fn refresh_local_branches(&mut self) {
+        let selected_name = self
+            .branches_local
+            .get(self.branches_selection.selected)
+            .cloned();
+
+        let result = GitRepository::open_current_dir()
+            .and_then(|git| Ok((git.local_branches()?, git.current_branch().ok())));
+
+        match result {
+            Ok((branches, current)) => {
+                self.branches_local = branches;
+                self.branches_current = current;
+                self.branches_error = None;
+                self.branches_selection = ListState::new(self.branches_local.len());
+                if let Some(index) = selected_name
+                    .and_then(|name| self.branches_local.iter().position(|b| *b == name))
+                {
+                    self.branches_selection.selected = index;
+                }
+            }
+            Err(e) => {
+                self.branches_error = Some(e.to_string());
+            }
+        }
+    }
+
+    /// Handle key events on the branches screen
+    fn handle_branches_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Char('j') | KeyCode::Down => self.branches_selection.next(),
+            KeyCode::Char('k') | KeyCode::Up => self.branches_selection.previous(),
+            KeyCode::PageUp | KeyCode::PageDown | KeyCode::Home | KeyCode::End => {
+                self.branches_selection.handle_jump_key(key.code);
+            }
+            KeyCode::Char('r') => {
+                self.refresh_local_branches();
+                self.status_message = Some(format!(
+                    "Loaded {} local branches",
+                    self.branches_local.len()
+                ));
+            }
+            KeyCode::Enter => {
+                if let Some(name) = self
+                    .branches_local
+                    .get(self.branches_selection.selected)
+                    .cloned()
+                {
+                    if self.branches_current.as_deref() == Some(name.as_str()) {
+                        self.status_message = Some(format!("Already on {}", name));
+                    } else {
+                        self.switch_branch(name, false);
+                    }
+                }
+            }
+            KeyCode::Char('n') => {
+                self.branch_create_mode = true;
+                self.branch_create_name.clear();
+            }
+            KeyCode::Char('d') => {
+                if let Some(name) = self
+                    .branches_local
+                    .get(self.branches_selection.selected)
+                    .cloned()
+                {
+                    if self.branches_current.as_deref() == Some(name.as_str()) {
+                        self.status_message =
+                            Some("Cannot delete the checked out branch".to_string());
+                    } else {
+                        self.branch_delete_confirm = Some(name);
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    /// Handle key events while typing a new branch name
+    fn handle_branch_create_key(&mut self, key: KeyEvent) {
+        match key.code {
+            KeyCode::Esc => {
+                self.branch_create_mode = false;
+            }
+            KeyCode::Enter => {
+                let name = self.branch_create_name.trim().to_string();
+                if !crate::core::branch_name::is_valid(&name) {
+                    self.status_message = Some(format!("'{}' is not a valid branch name", name));
+                    return;
+                }
+                self.branch_create_mode = false;
+                self.switch_branch(name, true);
+            }
+            KeyCode::Backspace => {
+                self.branch_create_name.pop();
+            }
+            KeyCode::Char(c) => self.branch_create_name.push(c),
+            _ => {}
+        }
+    }
+
+    /// Check out an existing branch, or create one from HEAD, in the background
+    fn switch_branch(&mut self, name: String, create: bool) {
+        if self.branch_switching {
+            return;
+        }
+
+        self.branch_switching = true;
+        self.status_message = Some(if create {
+            format!("Creating branch {}...", name)
+        } else {
+            format!("Switching to {}...", name)
+        });
+
+        let tx = self.async_tx.clone();
+
+        tokio::spawn(async move {
+            let result = async {
+                let git = GitRepository::open_current_dir()?;
+                if create {
+                    git.create_branch(&name)
+                } else {
+                    git.checkout(&name)
+                }
+            }
+            .await;
+
+            match result {
+                Ok(()) => {
+                    let _ = tx
+                        .send(AsyncMessage::BranchCheckedOut {
+                            name,
+                            created: create,
+                        })
+                        .await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::BranchCheckoutError {
+                            error: e.to_string(),
+                            created: create,
+                        })
+                        .await;
+                }
+            }
+        });
+    }
+
+    /// Delete a local branch in the background
+    fn delete_local_branch(&mut self, name: String) {
+        if self.branch_switching {
+            return;
+        }
+
+        self.branch_switching = true;
+        self.status_message = Some(format!("Deleting branch {}...", name));
+
+        let tx = self.async_tx.clone();
+        let dry_run = self.dry_run;
+
+        tokio::spawn(async move {
+            let result = GitRepository::open_current_dir()
+                .and_then(|git| git.with_dry_run(dry_run).delete_local_branch(&name, false));
+
+            match result {
+                Ok(()) => {
+                    let _ = tx.send(AsyncMessage::BranchDeleted(name)).await;
+                }
+                Err(crate::error::GhrustError::DryRun(action)) => {
+                    let _ = tx.send(AsyncMessage::DryRun(action)).await;
+                }
+                Err(e) => {
+                    let _ = tx
+                        .send(AsyncMessage::BranchDeleteError(e.to_string()))
+                        .await;
+                }
+            }
+        });
+    }
+
     // ─────────────────────────────────────────────────────────────────────────
     // Update methods
     // ─────────────────────────────────────────────────────────────────────────
@@ -3869,3 +8124,131 @@ impl Default for App {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_list_state_next_by_wraps() {
+        let mut list = ListState::new(5);
+        list.next_by(3);
+        assert_eq!(list.selected, 3);
+        list.next_by(3);
+        assert_eq!(list.selected, 1);
+        // Steps larger than the list wrap around it
+        list.next_by(12);
+        assert_eq!(list.selected, 3);
+    }
+
+    #[test]
+    fn test_list_state_previous_by_wraps() {
+        let mut list = ListState::new(5);
+        list.previous_by(2);
+        assert_eq!(list.selected, 3);
+        list.previous_by(3);
+        assert_eq!(list.selected, 0);
+        list.previous_by(11);
+        assert_eq!(list.selected, 4);
+    }
+
+    #[test]
+    fn test_list_state_jumps_on_empty_list() {
+        let mut list = ListState::new(0);
+        list.next_by(10);
+        list.previous_by(10);
+        list.last();
+        assert_eq!(list.selected, 0);
+
+        let mut list = ListState::new(4);
+        list.last();
+        assert_eq!(list.selected, 3);
+        list.first();
+        assert_eq!(list.selected, 0);
+    }
+
+    fn file(path: &str) -> FileStatus {
+        FileStatus {
+            path: path.to_string(),
+            is_staged: false,
+            is_modified: true,
+            is_new: false,
+            is_deleted: false,
+        }
+    }
+
+    #[test]
+    fn test_grouped_file_resolves_across_groups() {
+        // Flat order differs from the sorted, grouped order shown on screen
+        let files = vec![file("src/b.rs"), file("README.md"), file("src/a.rs")];
+        let groups = group_files(&files, &[]);
+
+        assert_eq!(groups.len(), 2);
+        assert_eq!(groups[0].directory, ".");
+        assert_eq!(
+            grouped_file(&groups, 1, Some(1)).map(|f| f.path.as_str()),
+            Some("src/a.rs")
+        );
+        assert_eq!(
+            grouped_file(&groups, 0, Some(0)).map(|f| f.path.as_str()),
+            Some("README.md")
+        );
+        // A folder row or an out-of-range selection has no file
+        assert!(grouped_file(&groups, 1, None).is_none());
+        assert!(grouped_file(&groups, 2, Some(0)).is_none());
+    }
+
+    #[test]
+    fn test_group_files_keeps_expansion() {
+        let files = vec![file("src/a.rs"), file("docs/x.md")];
+        let mut groups = group_files(&files, &[]);
+        groups[0].expanded = false;
+
+        let regrouped = group_files(&files, &groups);
+        assert_eq!(regrouped[0].directory, "docs");
+        assert!(!regrouped[0].expanded);
+        assert!(regrouped[1].expanded);
+    }
+
+    #[test]
+    fn test_push_reaction_skips_known_ids() {
+        let reaction = |id| Reaction {
+            id,
+            user: None,
+            content: "+1".to_string(),
+        };
+        let mut reactions = vec![reaction(1)];
+        push_reaction(&mut reactions, reaction(1));
+        push_reaction(&mut reactions, reaction(2));
+        let ids: Vec<u64> = reactions.iter().map(|r| r.id).collect();
+        assert_eq!(ids, vec![1, 2]);
+    }
+
+    #[test]
+    fn test_stale_ai_result_ignored_after_cancel() {
+        let mut app = App::new();
+        app.commit_message = "draft".to_string();
+        app.commit_ai_loading = true;
+        let request = app.ai_request;
+
+        app.cancel_ai_task();
+        // Results queued before Esc must not overwrite or report anything
+        app.handle_async_message(AsyncMessage::AiCommitMessageGenerated {
+            request,
+            message: "feat: stale".to_string(),
+            key: 0,
+            cached: false,
+        });
+        app.handle_async_message(AsyncMessage::AiContentError {
+            request,
+            error: "boom".to_string(),
+        });
+
+        assert_eq!(app.commit_message, "draft");
+        assert!(app.pr_create_error.is_none());
+        assert_eq!(
+            app.status_message.as_deref(),
+            Some("AI generation cancelled")
+        );
+    }
+}
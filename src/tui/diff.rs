@@ -0,0 +1,171 @@
+//! Diff navigation helpers
+//!
+//! Indexes the `diff --git` file headers and `@@` hunk headers of a unified
+//! diff so diff viewers can jump between them by adjusting their scroll offset.
+
+/// Line offsets of file and hunk headers within a unified diff
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DiffIndex {
+    /// Line numbers of `diff --git` headers
+    pub files: Vec<usize>,
+    /// Line numbers of `@@` hunk headers
+    pub hunks: Vec<usize>,
+}
+
+impl DiffIndex {
+    /// Build an index from diff text
+    pub fn parse(diff: &str) -> Self {
+        let mut index = Self::default();
+
+        for (line_no, line) in diff.lines().enumerate() {
+            if line.starts_with("diff --git ") {
+                index.files.push(line_no);
+            } else if line.starts_with("@@") {
+                index.hunks.push(line_no);
+            }
+        }
+
+        index
+    }
+
+    /// Scroll offset of the next hunk after `scroll`, if any
+    pub fn next_hunk(&self, scroll: usize) -> Option<usize> {
+        next_after(&self.hunks, scroll)
+    }
+
+    /// Scroll offset of the previous hunk before `scroll`, if any
+    pub fn prev_hunk(&self, scroll: usize) -> Option<usize> {
+        prev_before(&self.hunks, scroll)
+    }
+
+    /// Scroll offset of the next file after `scroll`, if any
+    pub fn next_file(&self, scroll: usize) -> Option<usize> {
+        next_after(&self.files, scroll)
+    }
+
+    /// Scroll offset of the previous file before `scroll`, if any
+    pub fn prev_file(&self, scroll: usize) -> Option<usize> {
+        prev_before(&self.files, scroll)
+    }
+
+    /// Scroll offset after a jump, or `scroll` unchanged when there is nothing to jump to
+    pub fn jump(&self, scroll: usize, jump: DiffJump) -> usize {
+        let target = match jump {
+            DiffJump::NextHunk => self.next_hunk(scroll),
+            DiffJump::PrevHunk => self.prev_hunk(scroll),
+            DiffJump::NextFile => self.next_file(scroll),
+            DiffJump::PrevFile => self.prev_file(scroll),
+        };
+        target.unwrap_or(scroll)
+    }
+
+    /// 1-based position of the hunk containing `scroll` and the total hunk count
+    pub fn hunk_position(&self, scroll: usize) -> (usize, usize) {
+        let current = self.hunks.iter().take_while(|&&h| h <= scroll).count();
+        (current, self.hunks.len())
+    }
+
+    /// 1-based position of the file containing `scroll` and the total file count
+    pub fn file_position(&self, scroll: usize) -> (usize, usize) {
+        let current = self.files.iter().take_while(|&&f| f <= scroll).count();
+        (current, self.files.len())
+    }
+}
+
+/// Jump targets in a diff viewer
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffJump {
+    /// Next `@@` hunk header (`]`)
+    NextHunk,
+    /// Previous `@@` hunk header (`[`)
+    PrevHunk,
+    /// Next `diff --git` file header (`}`)
+    NextFile,
+    /// Previous `diff --git` file header (`{`)
+    PrevFile,
+}
+
+impl DiffJump {
+    /// Jump bound to a key, if any
+    pub fn from_key(c: char) -> Option<Self> {
+        match c {
+            ']' => Some(Self::NextHunk),
+            '[' => Some(Self::PrevHunk),
+            '}' => Some(Self::NextFile),
+            '{' => Some(Self::PrevFile),
+            _ => None,
+        }
+    }
+}
+
+fn next_after(offsets: &[usize], scroll: usize) -> Option<usize> {
+    offsets.iter().copied().find(|&o| o > scroll)
+}
+
+fn prev_before(offsets: &[usize], scroll: usize) -> Option<usize> {
+    offsets.iter().copied().rev().find(|&o| o < scroll)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIFF: &str = "\
+diff --git a/a.rs b/a.rs
+--- a/a.rs
++++ b/a.rs
+@@ -1,2 +1,2 @@
+-old
++new
+@@ -10,1 +10,1 @@
+-x
++y
+diff --git a/b.rs b/b.rs
+--- a/b.rs
++++ b/b.rs
+@@ -1 +1 @@
+-1
++2
+";
+
+    #[test]
+    fn test_parse_offsets() {
+        let index = DiffIndex::parse(DIFF);
+        assert_eq!(index.files, vec![0, 9]);
+        assert_eq!(index.hunks, vec![3, 6, 12]);
+    }
+
+    #[test]
+    fn test_hunk_navigation() {
+        let index = DiffIndex::parse(DIFF);
+        assert_eq!(index.next_hunk(0), Some(3));
+        assert_eq!(index.next_hunk(3), Some(6));
+        assert_eq!(index.next_hunk(12), None);
+        assert_eq!(index.prev_hunk(6), Some(3));
+        assert_eq!(index.prev_hunk(3), None);
+        assert_eq!(index.hunk_position(7), (2, 3));
+    }
+
+    #[test]
+    fn test_jump_keys() {
+        let index = DiffIndex::parse(DIFF);
+        let jump = |scroll, key| index.jump(scroll, DiffJump::from_key(key).unwrap());
+        assert_eq!(jump(0, ']'), 3);
+        assert_eq!(jump(4, ']'), 6);
+        assert_eq!(jump(12, ']'), 12);
+        assert_eq!(jump(7, '['), 6);
+        assert_eq!(jump(3, '['), 3);
+        assert_eq!(jump(0, '}'), 9);
+        assert_eq!(jump(10, '{'), 9);
+        assert_eq!(DiffJump::from_key('x'), None);
+    }
+
+    #[test]
+    fn test_file_navigation() {
+        let index = DiffIndex::parse(DIFF);
+        assert_eq!(index.next_file(0), Some(9));
+        assert_eq!(index.prev_file(9), Some(0));
+        assert_eq!(index.next_file(9), None);
+        assert_eq!(index.file_position(10), (2, 2));
+    }
+}
@@ -0,0 +1,77 @@
+//! External editor handoff
+//!
+//! Opens the user's editor on a temp file so long PR bodies and commit
+//! messages can be written outside the TUI. The caller is responsible for
+//! leaving raw mode and the alternate screen around the call.
+
+use std::io::Write;
+use std::process::Command;
+
+use crate::core::config::Config;
+use crate::error::{GhrustError, Result};
+
+/// What the editor is being opened for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EditorTarget {
+    /// PR create body field
+    PrBody,
+    /// PR edit body field
+    PrEditBody,
+    /// Commit message input
+    CommitMessage,
+}
+
+impl EditorTarget {
+    /// Temp file suffix, so editors pick a sensible file type
+    fn suffix(&self) -> &'static str {
+        match self {
+            EditorTarget::PrBody | EditorTarget::PrEditBody => ".md",
+            EditorTarget::CommitMessage => ".gitcommit",
+        }
+    }
+}
+
+/// Resolve the editor command: config, then `$VISUAL`, `$EDITOR`, then `vi`
+pub fn editor_command() -> String {
+    Config::load()
+        .ok()
+        .and_then(|c| c.editor)
+        .or_else(|| std::env::var("VISUAL").ok())
+        .or_else(|| std::env::var("EDITOR").ok())
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Open `initial` in the editor and return the saved content
+///
+/// Blocks until the editor exits. A trailing newline added by the editor
+/// is removed.
+pub fn edit_text(initial: &str, target: EditorTarget) -> Result<String> {
+    let mut file = tempfile::Builder::new()
+        .prefix("argo-")
+        .suffix(target.suffix())
+        .tempfile()?;
+    file.write_all(initial.as_bytes())?;
+    file.flush()?;
+
+    // Editor commands may carry arguments, e.g. "code --wait"
+    let command = editor_command();
+    let mut parts = command.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+
+    let status = Command::new(program)
+        .args(parts)
+        .arg(file.path())
+        .status()
+        .map_err(|e| GhrustError::Custom(format!("Failed to start editor '{}': {}", program, e)))?;
+
+    if !status.success() {
+        return Err(GhrustError::Custom(format!(
+            "Editor '{}' exited with {}",
+            program, status
+        )));
+    }
+
+    let edited = std::fs::read_to_string(file.path())?;
+    Ok(edited.trim_end_matches('\n').to_string())
+}
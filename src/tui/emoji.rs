@@ -0,0 +1,160 @@
+//! Emoji shortcode rendering
+//!
+//! Maps GitHub-style shortcodes such as `:rocket:` to emoji. Only a common
+//! subset is included; unknown shortcodes are left as-is.
+
+use std::borrow::Cow;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// Shortcode pattern (`:name:`)
+static SHORTCODE_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r":([a-z0-9_+\-]+):").unwrap());
+
+/// Shortcode to emoji table, sorted by shortcode for binary search
+const EMOJI: &[(&str, &str)] = &[
+    ("+1", "👍"),
+    ("-1", "👎"),
+    ("100", "💯"),
+    ("arrow_down", "⬇️"),
+    ("arrow_left", "⬅️"),
+    ("arrow_right", "➡️"),
+    ("arrow_up", "⬆️"),
+    ("art", "🎨"),
+    ("bell", "🔔"),
+    ("book", "📖"),
+    ("bookmark", "🔖"),
+    ("boom", "💥"),
+    ("bug", "🐛"),
+    ("bulb", "💡"),
+    ("calendar", "📆"),
+    ("chart_with_upwards_trend", "📈"),
+    ("check", "✔️"),
+    ("clap", "👏"),
+    ("clipboard", "📋"),
+    ("confused", "😕"),
+    ("construction", "🚧"),
+    ("cry", "😢"),
+    ("dart", "🎯"),
+    ("eyes", "👀"),
+    ("fire", "🔥"),
+    ("gear", "⚙️"),
+    ("ghost", "👻"),
+    ("green_heart", "💚"),
+    ("hammer", "🔨"),
+    ("heart", "❤️"),
+    ("heavy_check_mark", "✔️"),
+    ("heavy_minus_sign", "➖"),
+    ("heavy_plus_sign", "➕"),
+    ("hourglass", "⌛"),
+    ("information_source", "ℹ️"),
+    ("joy", "😂"),
+    ("key", "🔑"),
+    ("laughing", "😆"),
+    ("link", "🔗"),
+    ("lipstick", "💄"),
+    ("lock", "🔒"),
+    ("loud_sound", "🔊"),
+    ("mag", "🔍"),
+    ("memo", "📝"),
+    ("muscle", "💪"),
+    ("no_entry", "⛔"),
+    ("ok_hand", "👌"),
+    ("package", "📦"),
+    ("pencil", "📝"),
+    ("pencil2", "✏️"),
+    ("point_right", "👉"),
+    ("pray", "🙏"),
+    ("pushpin", "📌"),
+    ("question", "❓"),
+    ("raised_hands", "🙌"),
+    ("recycle", "♻️"),
+    ("red_circle", "🔴"),
+    ("rocket", "🚀"),
+    ("rotating_light", "🚨"),
+    ("see_no_evil", "🙈"),
+    ("shipit", "🐿️"),
+    ("smile", "😄"),
+    ("smiley", "😃"),
+    ("sparkles", "✨"),
+    ("star", "⭐"),
+    ("tada", "🎉"),
+    ("test_tube", "🧪"),
+    ("thinking", "🤔"),
+    ("thumbsdown", "👎"),
+    ("thumbsup", "👍"),
+    ("trophy", "🏆"),
+    ("truck", "🚚"),
+    ("warning", "⚠️"),
+    ("wastebasket", "🗑️"),
+    ("wave", "👋"),
+    ("white_check_mark", "✅"),
+    ("wink", "😉"),
+    ("wrench", "🔧"),
+    ("x", "❌"),
+    ("zap", "⚡"),
+];
+
+/// Look up the emoji for a shortcode name (without colons)
+pub fn lookup(name: &str) -> Option<&'static str> {
+    EMOJI
+        .binary_search_by(|(code, _)| code.cmp(&name))
+        .ok()
+        .map(|idx| EMOJI[idx].1)
+}
+
+/// Replace known `:shortcode:`s in text, leaving unknown ones untouched
+///
+/// Text inside inline code (between backticks) is not changed.
+pub fn replace_shortcodes(text: &str) -> Cow<'_, str> {
+    if !text.contains(':') {
+        return Cow::Borrowed(text);
+    }
+
+    let mut result = String::with_capacity(text.len());
+    for (i, segment) in text.split('`').enumerate() {
+        if i > 0 {
+            result.push('`');
+        }
+        // Odd segments are inside backticks
+        if i % 2 == 1 {
+            result.push_str(segment);
+        } else {
+            let replaced = SHORTCODE_REGEX.replace_all(segment, |caps: &regex::Captures| {
+                lookup(&caps[1])
+                    .map(str::to_string)
+                    .unwrap_or_else(|| caps[0].to_string())
+            });
+            result.push_str(&replaced);
+        }
+    }
+    Cow::Owned(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_table_is_sorted() {
+        assert!(EMOJI.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_replace_shortcodes() {
+        assert_eq!(
+            replace_shortcodes("Ship it :rocket: :tada:"),
+            "Ship it 🚀 🎉"
+        );
+        assert_eq!(
+            replace_shortcodes(":not_an_emoji: stays"),
+            ":not_an_emoji: stays"
+        );
+        assert_eq!(replace_shortcodes("time 10:30:00"), "time 10:30:00");
+    }
+
+    #[test]
+    fn test_inline_code_untouched() {
+        assert_eq!(replace_shortcodes("`:tada:` :tada:"), "`:tada:` 🎉");
+    }
+}
@@ -2,7 +2,9 @@
 
 use std::time::Duration;
 
-use crossterm::event::{self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::event::{
+    self, Event as CrosstermEvent, KeyCode, KeyEvent, KeyModifiers, MouseEvent, MouseEventKind,
+};
 use tokio::sync::mpsc;
 use tokio::time::interval;
 
@@ -11,6 +13,8 @@ use tokio::time::interval;
 pub enum AppEvent {
     /// Keyboard event
     Key(KeyEvent),
+    /// Mouse click or wheel event (only when mouse capture is enabled)
+    Mouse(MouseEvent),
     /// Terminal resize
     Resize(u16, u16),
     /// Tick event for periodic updates
@@ -21,7 +25,7 @@ pub enum AppEvent {
 pub struct EventHandler {
     rx: mpsc::Receiver<AppEvent>,
     /// Handle to the event task for cleanup
-    _task: tokio::task::JoinHandle<()>,
+    task: tokio::task::JoinHandle<()>,
 }
 
 impl EventHandler {
@@ -49,6 +53,12 @@ impl EventHandler {
                             if let Ok(evt) = event::read() {
                                 let app_event = match evt {
                                     CrosstermEvent::Key(key) => Some(AppEvent::Key(key)),
+                                    // Plain pointer motion would only trigger redraws
+                                    CrosstermEvent::Mouse(mouse)
+                                        if mouse.kind != MouseEventKind::Moved =>
+                                    {
+                                        Some(AppEvent::Mouse(mouse))
+                                    }
                                     CrosstermEvent::Resize(w, h) => Some(AppEvent::Resize(w, h)),
                                     _ => None,
                                 };
@@ -65,7 +75,7 @@ impl EventHandler {
             }
         });
 
-        Self { rx, _task: task }
+        Self { rx, task }
     }
 
     /// Get the next event
@@ -74,6 +84,13 @@ impl EventHandler {
     }
 }
 
+impl Drop for EventHandler {
+    /// Stop polling so another process (e.g. an editor) can read the terminal
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
 /// Helper to check for quit key combinations
 pub fn is_quit_key(key: &KeyEvent) -> bool {
     matches!(
@@ -4,8 +4,13 @@
 //! Will be fully implemented in Phases 4-5.
 
 pub mod app;
+pub mod diff;
+pub mod editor;
+pub mod emoji;
 pub mod event;
 pub mod screens;
+pub mod session;
+pub mod text_input;
 pub mod theme;
 pub mod ui;
 pub mod widgets;
@@ -30,3 +35,51 @@ pub use app::App;
 pub fn split_lines_preserve_trailing(text: &str) -> Vec<&str> {
     text.split('\n').collect()
 }
+
+/// Extract `#123` issue/PR references from text, in order of appearance.
+///
+/// References inside words (e.g. `abc#1`) and inline code are ignored.
+///
+/// # Examples
+/// ```
+/// # use argo_rs::tui::issue_references;
+/// assert_eq!(issue_references("Fixes #12, see #7 and `#9`"), vec![12, 7]);
+/// ```
+pub fn issue_references(text: &str) -> Vec<u64> {
+    let mut refs = Vec::new();
+    for (i, segment) in text.split('`').enumerate() {
+        // Odd segments are inside backticks
+        if i % 2 == 1 {
+            continue;
+        }
+        let mut prev: Option<char> = None;
+        for (idx, c) in segment.char_indices() {
+            if c == '#' && prev.is_none_or(|p| !p.is_alphanumeric()) {
+                let digits: String = segment[idx + 1..]
+                    .chars()
+                    .take_while(|d| d.is_ascii_digit())
+                    .collect();
+                if let Ok(number) = digits.parse::<u64>() {
+                    refs.push(number);
+                }
+            }
+            prev = Some(c);
+        }
+    }
+    refs
+}
+
+/// Parse a comma-separated list of GitHub usernames, dropping `@` and blanks.
+///
+/// # Examples
+/// ```
+/// # use argo_rs::tui::parse_usernames;
+/// assert_eq!(parse_usernames(" @alice, bob,,"), vec!["alice", "bob"]);
+/// ```
+pub fn parse_usernames(text: &str) -> Vec<String> {
+    text.split(',')
+        .map(|name| name.trim().trim_start_matches('@'))
+        .filter(|name| !name.is_empty())
+        .map(str::to_string)
+        .collect()
+}
@@ -0,0 +1,93 @@
+//! TUI session persistence
+//!
+//! Remembers the last open screen and list selections so relaunching the
+//! TUI in the same repository picks up where the previous session left off.
+
+use std::fs;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::core::config::Config;
+use crate::error::Result;
+use crate::tui::app::Screen;
+
+/// Session state stored between TUI launches
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SessionState {
+    /// Repository (`owner/name`) the session was recorded in
+    pub repository: Option<String>,
+    /// Screen open when the TUI was closed
+    pub screen: Screen,
+    /// Selected dashboard menu item
+    #[serde(default)]
+    pub dashboard_selected: usize,
+    /// Selected row in the PR list
+    #[serde(default)]
+    pub pr_list_selected: usize,
+    /// Selected row in the workflow runs list
+    #[serde(default)]
+    pub workflow_runs_selected: usize,
+    /// Selected row in the tags list
+    #[serde(default)]
+    pub tags_selected: usize,
+    /// Selected row in the branches list
+    #[serde(default)]
+    pub branches_selected: usize,
+}
+
+impl SessionState {
+    /// Load state from the session file
+    pub fn load() -> Result<Self> {
+        let path = Self::state_path()?;
+        if path.exists() {
+            let contents = fs::read_to_string(&path)?;
+            let state: Self = serde_json::from_str(&contents)?;
+            Ok(state)
+        } else {
+            Ok(Self::default())
+        }
+    }
+
+    /// Save state to the session file
+    pub fn save(&self) -> Result<()> {
+        let path = Self::state_path()?;
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        let contents = serde_json::to_string_pretty(self)?;
+        fs::write(&path, contents)?;
+        Ok(())
+    }
+
+    /// Get path to the session file
+    fn state_path() -> Result<PathBuf> {
+        let config_dir = Config::config_dir()?;
+        Ok(config_dir.join("session-state.json"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_round_trip() {
+        let state = SessionState {
+            repository: Some("owner/repo".to_string()),
+            screen: Screen::PrDetail(42),
+            pr_list_selected: 3,
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&state).unwrap();
+        assert_eq!(serde_json::from_str::<SessionState>(&json).unwrap(), state);
+    }
+
+    #[test]
+    fn test_missing_selections_default_to_zero() {
+        let state: SessionState =
+            serde_json::from_str(r#"{"repository":null,"screen":"Tags"}"#).unwrap();
+        assert_eq!(state.screen, Screen::Tags);
+        assert_eq!(state.tags_selected, 0);
+    }
+}
@@ -0,0 +1,647 @@
+//! Multi-line text editing
+//!
+//! Cursor movement and editing for the PR body fields. The cursor is a
+//! `(row, col)` pair into the lines produced by `split_lines_preserve_trailing`,
+//! where `col` counts characters, not bytes, so multibyte text is safe to edit.
+
+use crossterm::event::KeyCode;
+use unicode_width::UnicodeWidthChar;
+
+use super::split_lines_preserve_trailing;
+
+/// Apply an editing key to a multi-line body, returning true if it was handled
+pub fn handle_body_key(body: &mut String, cursor: &mut (usize, usize), code: KeyCode) -> bool {
+    match code {
+        KeyCode::Enter => insert_newline(body, cursor),
+        KeyCode::Up => {
+            if cursor.0 > 0 {
+                cursor.0 -= 1;
+            }
+        }
+        KeyCode::Down => {
+            let line_count = split_lines_preserve_trailing(body).len();
+            if cursor.0 < line_count.saturating_sub(1) {
+                cursor.0 += 1;
+            }
+        }
+        KeyCode::Left => {
+            if cursor.1 > 0 {
+                cursor.1 -= 1;
+            } else if cursor.0 > 0 {
+                // Wrap to the end of the previous line
+                let lines = split_lines_preserve_trailing(body);
+                cursor.0 -= 1;
+                cursor.1 = lines.get(cursor.0).map_or(0, |l| l.chars().count());
+            }
+        }
+        KeyCode::Right => {
+            let lines = split_lines_preserve_trailing(body);
+            let (row, col) = *cursor;
+            if let Some(line) = lines.get(row) {
+                if col < line.chars().count() {
+                    cursor.1 = col + 1;
+                } else if row + 1 < lines.len() {
+                    // Wrap to the start of the next line
+                    *cursor = (row + 1, 0);
+                }
+            }
+        }
+        KeyCode::Backspace => backspace(body, cursor),
+        KeyCode::Char(c) => insert_char(body, cursor, c),
+        _ => return false,
+    }
+    true
+}
+
+/// Like [`handle_body_key`], but Up/Down move by displayed line when the body
+/// is word-wrapped to `width` columns (0 means unknown: move by logical line)
+pub fn handle_wrapped_body_key(
+    body: &mut String,
+    cursor: &mut (usize, usize),
+    code: KeyCode,
+    width: usize,
+) -> bool {
+    match code {
+        KeyCode::Up if width > 0 => move_visual_line(body, cursor, width, false),
+        KeyCode::Down if width > 0 => move_visual_line(body, cursor, width, true),
+        _ => return handle_body_key(body, cursor, code),
+    }
+    true
+}
+
+/// Terminal columns taken by `c` (wide CJK and emoji take two)
+fn char_width(c: char) -> usize {
+    c.width().unwrap_or(0)
+}
+
+/// Character ranges `[start, end)` of the displayed rows of `line` when
+/// word-wrapped to `width` terminal columns; an empty line is a single empty row
+///
+/// Rows break after the last space that fits, or mid-word when none does.
+pub fn wrap_ranges(line: &str, width: usize) -> Vec<(usize, usize)> {
+    let chars: Vec<char> = line.chars().collect();
+    let width = width.max(1);
+    let mut ranges = Vec::new();
+    let mut start = 0;
+    let mut row_width = 0;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let w = char_width(chars[i]);
+        if row_width + w > width && i > start {
+            let end = chars[start..i]
+                .iter()
+                .rposition(|c| *c == ' ')
+                .map_or(i, |space| start + space + 1);
+            ranges.push((start, end));
+            start = end;
+            row_width = chars[start..i].iter().map(|c| char_width(*c)).sum();
+            continue;
+        }
+        row_width += w;
+        i += 1;
+    }
+    ranges.push((start, chars.len()));
+    ranges
+}
+
+/// Displayed row of character column `col` among the `ranges` of `line`, and
+/// the terminal column of the cursor within that row
+///
+/// A column on a row boundary belongs to the row it starts, except at the end
+/// of the line.
+pub fn visual_position(line: &str, ranges: &[(usize, usize)], col: usize) -> (usize, usize) {
+    let row = ranges
+        .iter()
+        .rposition(|(start, _)| *start <= col)
+        .unwrap_or(0);
+    let start = ranges[row].0;
+    let x = line
+        .chars()
+        .skip(start)
+        .take(col.saturating_sub(start))
+        .map(char_width)
+        .sum();
+    (row, x)
+}
+
+/// Character column reached by moving `x` terminal columns into the row of
+/// `line` that starts at `start`, taking at most `max_len` characters
+fn column_at_width(line: &str, start: usize, max_len: usize, x: usize) -> usize {
+    let mut used = 0;
+    let mut len = 0;
+    for c in line.chars().skip(start).take(max_len) {
+        used += char_width(c);
+        if used > x {
+            break;
+        }
+        len += 1;
+    }
+    start + len
+}
+
+/// Move the cursor one displayed row up or down, keeping its column where possible
+fn move_visual_line(body: &str, cursor: &mut (usize, usize), width: usize, down: bool) {
+    let lines = split_lines_preserve_trailing(body);
+    let Some(line) = lines.get(cursor.0) else {
+        return;
+    };
+    let ranges = wrap_ranges(line, width);
+    let (visual_row, x) = visual_position(line, &ranges, cursor.1.min(line.chars().count()));
+
+    // Target row: another row of this line, or the nearest row of the adjacent line
+    let (row, target_ranges, target) = if down {
+        if visual_row + 1 < ranges.len() {
+            (cursor.0, ranges, visual_row + 1)
+        } else if cursor.0 + 1 < lines.len() {
+            (cursor.0 + 1, wrap_ranges(lines[cursor.0 + 1], width), 0)
+        } else {
+            return;
+        }
+    } else if visual_row > 0 {
+        (cursor.0, ranges, visual_row - 1)
+    } else if cursor.0 > 0 {
+        let prev = wrap_ranges(lines[cursor.0 - 1], width);
+        let last = prev.len() - 1;
+        (cursor.0 - 1, prev, last)
+    } else {
+        return;
+    };
+
+    // Stay on the target row: only its last row may hold the end-of-line column
+    let (start, end) = target_ranges[target];
+    let max_x = if target + 1 < target_ranges.len() {
+        (end - start).saturating_sub(1)
+    } else {
+        end - start
+    };
+    *cursor = (row, column_at_width(lines[row], start, max_x, x));
+}
+
+/// Place the cursor at the end of the body
+pub fn cursor_at_end(body: &str) -> (usize, usize) {
+    let lines = split_lines_preserve_trailing(body);
+    let row = lines.len().saturating_sub(1);
+    let col = lines.last().map_or(0, |l| l.chars().count());
+    (row, col)
+}
+
+/// Byte offset of character column `col` in `line`, clamped to the line end
+pub fn byte_offset(line: &str, col: usize) -> usize {
+    line.char_indices()
+        .nth(col)
+        .map_or(line.len(), |(offset, _)| offset)
+}
+
+/// Insert a character at the cursor
+pub fn insert_char(body: &mut String, cursor: &mut (usize, usize), c: char) {
+    let mut lines = owned_lines(body);
+    let row = cursor.0.min(lines.len() - 1);
+    let col = cursor.1.min(lines[row].chars().count());
+
+    let offset = byte_offset(&lines[row], col);
+    lines[row].insert(offset, c);
+
+    *body = lines.join("\n");
+    *cursor = (row, col + 1);
+}
+
+/// Split the current line at the cursor
+fn insert_newline(body: &mut String, cursor: &mut (usize, usize)) {
+    let mut lines = owned_lines(body);
+    let row = cursor.0.min(lines.len() - 1);
+
+    let offset = byte_offset(&lines[row], cursor.1);
+    let rest = lines[row].split_off(offset);
+    lines.insert(row + 1, rest);
+
+    *body = lines.join("\n");
+    *cursor = (row + 1, 0);
+}
+
+/// Delete the character before the cursor, joining lines at column 0
+fn backspace(body: &mut String, cursor: &mut (usize, usize)) {
+    if body.is_empty() {
+        return;
+    }
+    let mut lines = owned_lines(body);
+    let row = cursor.0.min(lines.len() - 1);
+    let col = cursor.1.min(lines[row].chars().count());
+
+    if col > 0 {
+        // Delete character before cursor
+        let offset = byte_offset(&lines[row], col - 1);
+        lines[row].remove(offset);
+        *cursor = (row, col - 1);
+    } else if row > 0 {
+        // Join with previous line
+        let current = lines.remove(row);
+        let prev_len = lines[row - 1].chars().count();
+        lines[row - 1].push_str(&current);
+        *cursor = (row - 1, prev_len);
+    } else {
+        return;
+    }
+
+    *body = lines.join("\n");
+}
+
+/// Maximum number of undo snapshots kept per field
+const UNDO_LIMIT: usize = 100;
+
+/// Undo/redo snapshots of a text field
+///
+/// Snapshots are taken at edit boundaries (a space or newline, switching
+/// between typing and deleting) and before content is replaced wholesale,
+/// e.g. by AI generation, so an undo restores a word or a change at a time.
+#[derive(Debug, Default)]
+pub struct UndoHistory {
+    undo: Vec<String>,
+    redo: Vec<String>,
+    /// Whether the last edit deleted text
+    deleting: bool,
+}
+
+impl UndoHistory {
+    /// Record `text` as a state to return to; a new edit discards the redo stack
+    pub fn record(&mut self, text: &str) {
+        if self.undo.last().map(String::as_str) != Some(text) {
+            self.push_undo(text.to_string());
+        }
+        self.redo.clear();
+    }
+
+    /// Push onto the undo stack, dropping the oldest snapshot past `UNDO_LIMIT`
+    fn push_undo(&mut self, text: String) {
+        self.undo.push(text);
+        if self.undo.len() > UNDO_LIMIT {
+            self.undo.remove(0);
+        }
+    }
+
+    /// Take a snapshot of `text` if the key about to be applied starts a new edit
+    pub fn before_key(&mut self, text: &str, code: KeyCode) {
+        let boundary = match code {
+            KeyCode::Char(' ') | KeyCode::Enter => true,
+            KeyCode::Backspace => !self.deleting,
+            KeyCode::Char(_) => self.deleting || self.undo.is_empty(),
+            _ => return,
+        };
+        self.deleting = code == KeyCode::Backspace;
+
+        // Any edit after an undo also starts a new history, dropping the redo stack
+        if boundary || !self.redo.is_empty() {
+            self.record(text);
+        }
+    }
+
+    /// Restore the previous snapshot into `text`, returning true if there was one
+    pub fn undo(&mut self, text: &mut String) -> bool {
+        while let Some(previous) = self.undo.pop() {
+            if previous != *text {
+                self.redo.push(std::mem::replace(text, previous));
+                self.deleting = false;
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Re-apply the last undone change, returning true if there was one
+    pub fn redo(&mut self, text: &mut String) -> bool {
+        match self.redo.pop() {
+            Some(next) => {
+                let previous = std::mem::replace(text, next);
+                self.push_undo(previous);
+                self.deleting = false;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Forget all snapshots
+    pub fn clear(&mut self) {
+        *self = Self::default();
+    }
+}
+
+/// Lines of the body as owned strings (always at least one)
+fn owned_lines(body: &str) -> Vec<String> {
+    split_lines_preserve_trailing(body)
+        .into_iter()
+        .map(str::to_string)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_str(body: &mut String, cursor: &mut (usize, usize), text: &str) {
+        for c in text.chars() {
+            let code = if c == '\n' {
+                KeyCode::Enter
+            } else {
+                KeyCode::Char(c)
+            };
+            handle_body_key(body, cursor, code);
+        }
+    }
+
+    #[test]
+    fn test_type_multibyte_characters() {
+        let mut body = String::new();
+        let mut cursor = (0, 0);
+        type_str(&mut body, &mut cursor, "café 🚀\nnaïve");
+
+        assert_eq!(body, "café 🚀\nnaïve");
+        assert_eq!(cursor, (1, 5));
+        assert_eq!(cursor_at_end(&body), (1, 5));
+    }
+
+    #[test]
+    fn test_move_and_edit_across_multibyte_characters() {
+        let mut body = "añb🎉c".to_string();
+        let mut cursor = cursor_at_end(&body);
+        assert_eq!(cursor, (0, 5));
+
+        // Step back over the emoji and the ñ, inserting between characters
+        handle_body_key(&mut body, &mut cursor, KeyCode::Left);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Left);
+        type_str(&mut body, &mut cursor, "é");
+        assert_eq!(body, "añbé🎉c");
+
+        // Right stops at the end of the line
+        for _ in 0..10 {
+            handle_body_key(&mut body, &mut cursor, KeyCode::Right);
+        }
+        assert_eq!(cursor, (0, 6));
+
+        handle_body_key(&mut body, &mut cursor, KeyCode::Left);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Backspace);
+        assert_eq!(body, "añbéc");
+        assert_eq!(cursor, (0, 4));
+    }
+
+    #[test]
+    fn test_newline_and_join_with_multibyte_characters() {
+        let mut body = "日本語".to_string();
+        let mut cursor = (0, 1);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Enter);
+        assert_eq!(body, "日\n本語");
+        assert_eq!(cursor, (1, 0));
+
+        // Backspace at column 0 joins the lines back
+        handle_body_key(&mut body, &mut cursor, KeyCode::Backspace);
+        assert_eq!(body, "日本語");
+        assert_eq!(cursor, (0, 1));
+    }
+
+    #[test]
+    fn test_join_keeps_other_lines_intact() {
+        let mut body = "one\ntwo\nthree\nfour".to_string();
+        let mut cursor = (2, 0);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Backspace);
+        assert_eq!(body, "one\ntwothree\nfour");
+        assert_eq!(cursor, (1, 3));
+    }
+
+    #[test]
+    fn test_cursor_past_line_end_is_clamped() {
+        let mut body = "é\nlonger line".to_string();
+        let mut cursor = (1, 11);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Up);
+        type_str(&mut body, &mut cursor, "!");
+        assert_eq!(body, "é!\nlonger line");
+        assert_eq!(cursor, (0, 2));
+    }
+
+    #[test]
+    fn test_left_right_wrap_between_lines() {
+        let mut body = "ab\ncd".to_string();
+        let mut cursor = (1, 0);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Left);
+        assert_eq!(cursor, (0, 2));
+        handle_body_key(&mut body, &mut cursor, KeyCode::Right);
+        assert_eq!(cursor, (1, 0));
+
+        // No wrapping past the start or end of the body
+        let mut cursor = (0, 0);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Left);
+        assert_eq!(cursor, (0, 0));
+        let mut cursor = (1, 2);
+        handle_body_key(&mut body, &mut cursor, KeyCode::Right);
+        assert_eq!(cursor, (1, 2));
+    }
+
+    #[test]
+    fn test_wrap_ranges() {
+        assert_eq!(wrap_ranges("", 10), vec![(0, 0)]);
+        assert_eq!(wrap_ranges("short", 10), vec![(0, 5)]);
+        // Breaks after the last space that fits
+        assert_eq!(
+            wrap_ranges("hello world again", 8),
+            vec![(0, 6), (6, 12), (12, 17)]
+        );
+        // Long words are split
+        assert_eq!(wrap_ranges("abcdefghij", 4), vec![(0, 4), (4, 8), (8, 10)]);
+        // Wide characters take two columns each
+        assert_eq!(wrap_ranges("日本語です", 4), vec![(0, 2), (2, 4), (4, 5)]);
+        assert_eq!(wrap_ranges("ab 日本", 4), vec![(0, 3), (3, 5)]);
+        assert_eq!(wrap_ranges("a日", 2), vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn test_visual_position_counts_display_width() {
+        let line = "日本語です";
+        let ranges = wrap_ranges(line, 4);
+        assert_eq!(visual_position(line, &ranges, 0), (0, 0));
+        assert_eq!(visual_position(line, &ranges, 1), (0, 2));
+        assert_eq!(visual_position(line, &ranges, 3), (1, 2));
+        assert_eq!(visual_position(line, &ranges, 5), (2, 2));
+    }
+
+    #[test]
+    fn test_visual_down_keeps_display_column() {
+        let mut body = "ab日本\n日本ab".to_string();
+        let mut cursor = (0, 2);
+        assert!(handle_wrapped_body_key(
+            &mut body,
+            &mut cursor,
+            KeyCode::Down,
+            20
+        ));
+        assert_eq!(cursor, (1, 1));
+    }
+
+    #[test]
+    fn test_visual_up_down_within_wrapped_line() {
+        // Rows: "hello " | "world " | "again"
+        let mut body = "hello world again".to_string();
+        let mut cursor = (0, 2);
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Down, 8);
+        assert_eq!(cursor, (0, 8));
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Down, 8);
+        assert_eq!(cursor, (0, 14));
+        // Already on the last row of the last line
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Down, 8);
+        assert_eq!(cursor, (0, 14));
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Up, 8);
+        assert_eq!(cursor, (0, 8));
+    }
+
+    #[test]
+    fn test_visual_up_down_across_lines() {
+        // Line 0 rows: "hello " | "world"; line 1: "x"
+        let mut body = "hello world\nx".to_string();
+
+        // From the last row of line 0 down to line 1, clamped to its length
+        let mut cursor = (0, 10);
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Down, 8);
+        assert_eq!(cursor, (1, 1));
+
+        // Up from line 1 lands on the last displayed row of line 0
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Up, 8);
+        assert_eq!(cursor, (0, 7));
+
+        // A column past a non-final row's end stays on that row
+        let mut cursor = (0, 11);
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Up, 8);
+        assert_eq!(cursor, (0, 5));
+    }
+
+    #[test]
+    fn test_unknown_width_moves_by_logical_line() {
+        let mut body = "hello world\nx".to_string();
+        let mut cursor = (0, 2);
+        handle_wrapped_body_key(&mut body, &mut cursor, KeyCode::Down, 0);
+        assert_eq!(cursor, (1, 2));
+    }
+
+    /// Type keys into a body, taking undo snapshots like the editors do
+    fn type_with_history(
+        body: &mut String,
+        cursor: &mut (usize, usize),
+        history: &mut UndoHistory,
+        codes: &[KeyCode],
+    ) {
+        for &code in codes {
+            history.before_key(body, code);
+            handle_body_key(body, cursor, code);
+        }
+    }
+
+    fn chars(text: &str) -> Vec<KeyCode> {
+        text.chars().map(KeyCode::Char).collect()
+    }
+
+    #[test]
+    fn test_undo_restores_word_at_a_time() {
+        let mut body = String::new();
+        let mut cursor = (0, 0);
+        let mut history = UndoHistory::default();
+        type_with_history(&mut body, &mut cursor, &mut history, &chars("fix the bug"));
+
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "fix the");
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "fix");
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "");
+        assert!(!history.undo(&mut body));
+
+        assert!(history.redo(&mut body));
+        assert_eq!(body, "fix");
+        assert!(history.redo(&mut body));
+        assert!(history.redo(&mut body));
+        assert_eq!(body, "fix the bug");
+        assert!(!history.redo(&mut body));
+    }
+
+    #[test]
+    fn test_undo_recovers_deleted_text() {
+        let mut body = String::new();
+        let mut cursor = (0, 0);
+        let mut history = UndoHistory::default();
+        type_with_history(&mut body, &mut cursor, &mut history, &chars("keep this"));
+        type_with_history(
+            &mut body,
+            &mut cursor,
+            &mut history,
+            &[KeyCode::Backspace; 9],
+        );
+        assert_eq!(body, "");
+
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "keep this");
+    }
+
+    #[test]
+    fn test_new_edit_clears_redo() {
+        let mut history = UndoHistory::default();
+        let mut body = "old".to_string();
+        history.record(&body);
+        body = "generated".to_string();
+
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "old");
+        history.record(&body);
+        assert!(!history.redo(&mut body));
+    }
+
+    #[test]
+    fn test_typing_after_undo_clears_redo() {
+        let mut body = String::new();
+        let mut cursor = (0, 0);
+        let mut history = UndoHistory::default();
+        type_with_history(&mut body, &mut cursor, &mut history, &chars("one two"));
+
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "one");
+        cursor = cursor_at_end(&body);
+        type_with_history(&mut body, &mut cursor, &mut history, &chars("s"));
+        assert_eq!(body, "ones");
+        assert!(!history.redo(&mut body));
+
+        assert!(history.undo(&mut body));
+        assert_eq!(body, "one");
+    }
+
+    #[test]
+    fn test_undo_history_is_capped() {
+        let mut history = UndoHistory::default();
+        for i in 0..UNDO_LIMIT + 10 {
+            history.record(&i.to_string());
+        }
+        let mut body = "current".to_string();
+        let mut undone = 0;
+        while history.undo(&mut body) {
+            undone += 1;
+        }
+        assert_eq!(undone, UNDO_LIMIT);
+        assert_eq!(body, "10");
+    }
+
+    #[test]
+    fn test_redo_keeps_undo_history_capped() {
+        let mut history = UndoHistory::default();
+        for i in 0..UNDO_LIMIT {
+            history.record(&i.to_string());
+        }
+        let mut body = "current".to_string();
+        assert!(history.undo(&mut body));
+        history.redo.push("extra".to_string());
+        assert!(history.redo(&mut body));
+        assert!(history.redo(&mut body));
+        assert_eq!(history.undo.len(), UNDO_LIMIT);
+    }
+
+    #[test]
+    fn test_byte_offset() {
+        assert_eq!(byte_offset("a🚀b", 0), 0);
+        assert_eq!(byte_offset("a🚀b", 1), 1);
+        assert_eq!(byte_offset("a🚀b", 2), 5);
+        assert_eq!(byte_offset("a🚀b", 9), 6);
+    }
+}
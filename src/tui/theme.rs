@@ -1,53 +1,336 @@
 //! TUI theme and styles
 //!
-//! Will be fully implemented in Phase 4.
+//! Colors come from a built-in preset, optionally overridden by the
+//! `[theme]` section of the config file. In no-color mode (`--no-color` or
+//! `NO_COLOR`) every color is stripped from the rendered frame and status
+//! is conveyed with text markers and reverse video instead.
 
-use ratatui::style::{Color, Style};
+use std::str::FromStr;
 
-/// Application color theme
-pub struct Theme;
+use ratatui::buffer::Buffer;
+use ratatui::style::{Color, Modifier, Style};
 
-impl Theme {
-    /// Primary accent color
-    pub const PRIMARY: Color = Color::Cyan;
+use crate::core::config::Config;
 
-    /// Secondary accent color
-    pub const SECONDARY: Color = Color::Yellow;
+/// Application color theme
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Theme {
+    /// Header text color
+    pub header: Color,
+    /// Background of the selected list row
+    pub selection: Color,
+    /// Status bar background
+    pub status_bar: Color,
+    /// Accent color for highlighted elements
+    pub accent: Color,
+    /// Muted text color
+    pub muted: Color,
+    /// Focused inputs, list markers and work in progress
+    pub highlight: Color,
+    /// Background of popups and dialogs
+    pub popup: Color,
+    /// Render without color, using ASCII markers and reverse video
+    pub no_color: bool,
+    /// Whether the terminal shows 24-bit color; label colors are mapped to
+    /// the nearest ANSI color otherwise
+    pub truecolor: bool,
+}
 
+impl Default for Theme {
+    fn default() -> Self {
+        Self::dark()
+    }
+}
+
+impl Theme {
     /// Success color
     pub const SUCCESS: Color = Color::Green;
 
     /// Error color
     pub const ERROR: Color = Color::Red;
 
-    /// Warning color
-    pub const WARNING: Color = Color::Yellow;
+    /// Default preset for dark terminals
+    pub fn dark() -> Self {
+        Self {
+            header: Color::Cyan,
+            selection: Color::Cyan,
+            status_bar: Color::DarkGray,
+            accent: Color::Cyan,
+            muted: Color::DarkGray,
+            highlight: Color::Yellow,
+            popup: Color::Black,
+            no_color: false,
+            truecolor: false,
+        }
+    }
+
+    /// Preset for light terminal backgrounds
+    pub fn light() -> Self {
+        Self {
+            header: Color::Blue,
+            selection: Color::Blue,
+            status_bar: Color::Gray,
+            accent: Color::Blue,
+            muted: Color::DarkGray,
+            highlight: Color::Magenta,
+            popup: Color::Reset,
+            no_color: false,
+            truecolor: false,
+        }
+    }
 
-    /// Muted text color
-    pub const MUTED: Color = Color::DarkGray;
+    /// Preset with maximum contrast between text and highlights
+    pub fn high_contrast() -> Self {
+        Self {
+            header: Color::Yellow,
+            selection: Color::White,
+            status_bar: Color::White,
+            accent: Color::Yellow,
+            muted: Color::Gray,
+            highlight: Color::LightYellow,
+            popup: Color::Black,
+            no_color: false,
+            truecolor: false,
+        }
+    }
+
+    /// Look up a built-in preset by name
+    pub fn preset(name: &str) -> Option<Self> {
+        match name.trim().to_lowercase().as_str() {
+            "dark" => Some(Self::dark()),
+            "light" => Some(Self::light()),
+            "high-contrast" => Some(Self::high_contrast()),
+            _ => None,
+        }
+    }
+
+    /// Build the theme from the `[theme]` config section
+    ///
+    /// Starts from the configured preset (dark when unset or unknown) and
+    /// applies any color overrides. An `accent` override also recolors the
+    /// header and selection unless those are set explicitly. Colors that
+    /// can't be parsed are ignored.
+    pub fn from_config(config: &Config) -> Self {
+        let settings = &config.theme;
+        let mut theme = settings
+            .preset
+            .as_deref()
+            .and_then(Self::preset)
+            .unwrap_or_default();
+
+        if let Some(accent) = settings.accent.as_deref().and_then(parse_color) {
+            theme.accent = accent;
+            theme.header = accent;
+            theme.selection = accent;
+        }
+        if let Some(header) = settings.header.as_deref().and_then(parse_color) {
+            theme.header = header;
+        }
+        if let Some(selection) = settings.selection.as_deref().and_then(parse_color) {
+            theme.selection = selection;
+        }
+        if let Some(status_bar) = settings.status_bar.as_deref().and_then(parse_color) {
+            theme.status_bar = status_bar;
+        }
+        theme.truecolor = truecolor_supported();
+        theme
+    }
+
+    /// The same theme with colors turned off
+    pub fn without_color(self) -> Self {
+        Self {
+            no_color: true,
+            ..self
+        }
+    }
 
     /// Header style
-    pub fn header() -> Style {
-        Style::default().fg(Self::PRIMARY)
+    pub fn header(&self) -> Style {
+        Style::default().fg(self.header)
     }
 
     /// Status bar style
-    pub fn status_bar() -> Style {
-        Style::default().bg(Color::DarkGray)
+    pub fn status_bar(&self) -> Style {
+        Style::default()
+            .bg(self.status_bar)
+            .fg(contrast_fg(self.status_bar))
+    }
+
+    /// Selected item style (reverse video in no-color mode)
+    pub fn selected(&self) -> Style {
+        if self.no_color {
+            return Style::default().add_modifier(Modifier::REVERSED);
+        }
+        Style::default()
+            .bg(self.selection)
+            .fg(contrast_fg(self.selection))
     }
 
-    /// Selected item style
-    pub fn selected() -> Style {
-        Style::default().bg(Self::PRIMARY).fg(Color::Black)
+    /// Accent text style
+    pub fn accent(&self) -> Style {
+        Style::default().fg(self.accent)
     }
 
     /// Normal text style
-    pub fn normal() -> Style {
+    pub fn normal(&self) -> Style {
         Style::default()
     }
 
     /// Muted text style
-    pub fn muted() -> Style {
-        Style::default().fg(Self::MUTED)
+    pub fn muted(&self) -> Style {
+        Style::default().fg(self.muted)
+    }
+
+    /// Highlighted text style
+    pub fn highlight(&self) -> Style {
+        Style::default().fg(self.highlight)
+    }
+
+    /// Popup and dialog background style
+    pub fn popup(&self) -> Style {
+        Style::default().bg(self.popup)
+    }
+
+    /// Frame of the activity spinner for the given tick
+    pub fn spinner(&self, tick: u64) -> &'static str {
+        const SPINNER: &[&str] = &["\u{25d0}", "\u{25d3}", "\u{25d1}", "\u{25d2}"]; // ◐ ◓ ◑ ◒
+        const ASCII_SPINNER: &[&str] = &["|", "/", "-", "\\"];
+
+        let frames = if self.no_color {
+            ASCII_SPINNER
+        } else {
+            SPINNER
+        };
+        frames[tick as usize % frames.len()]
+    }
+
+    /// Check mark for a staged file or completed item
+    pub fn check_mark(&self) -> &'static str {
+        if self.no_color {
+            "x"
+        } else {
+            "\u{2713}" // ✓
+        }
+    }
+
+    /// Remove all color from a rendered frame when in no-color mode
+    ///
+    /// Cells drawn on a colored background (selections, chips, the status
+    /// bar) switch to reverse video so they stay distinguishable.
+    pub fn apply(&self, buf: &mut Buffer) {
+        if !self.no_color {
+            return;
+        }
+        for cell in buf.content.iter_mut() {
+            if !matches!(cell.bg, Color::Reset | Color::Black) {
+                cell.modifier.insert(Modifier::REVERSED);
+            }
+            cell.fg = Color::Reset;
+            cell.bg = Color::Reset;
+        }
+    }
+
+    /// Map a GitHub hex color (e.g. "d73a4a" or "#d73a4a") to a terminal color,
+    /// falling back to the muted color when it can't be parsed
+    ///
+    /// Without truecolor support the nearest ANSI named color is used.
+    pub fn hex_color(&self, hex: &str) -> Color {
+        let hex = hex.trim_start_matches('#');
+        if hex.len() != 6 || !hex.is_ascii() {
+            return self.muted;
+        }
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).ok();
+        match (channel(0), channel(2), channel(4)) {
+            (Some(r), Some(g), Some(b)) if self.truecolor => Color::Rgb(r, g, b),
+            (Some(r), Some(g), Some(b)) => nearest_ansi_color(r, g, b),
+            _ => self.muted,
+        }
+    }
+
+    /// Chip style for a PR label: label color as background with readable text
+    ///
+    /// In no-color mode the chip is shown in reverse video.
+    pub fn label(&self, hex: &str) -> Style {
+        if self.no_color {
+            return Style::default().add_modifier(Modifier::REVERSED);
+        }
+        let bg = self.hex_color(hex);
+        Style::default().bg(bg).fg(contrast_fg(bg))
+    }
+}
+
+/// Whether the `NO_COLOR` environment variable asks for colorless output
+///
+/// Follows <https://no-color.org>: any non-empty value disables color.
+pub fn no_color_requested() -> bool {
+    std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty())
+}
+
+/// Whether `COLORTERM` reports 24-bit color support
+pub fn truecolor_supported() -> bool {
+    std::env::var("COLORTERM").is_ok_and(|value| matches!(value.as_str(), "truecolor" | "24bit"))
+}
+
+/// The 16 ANSI named colors with their usual (xterm) RGB values
+const ANSI_COLORS: [(Color, (u8, u8, u8)); 16] = [
+    (Color::Black, (0, 0, 0)),
+    (Color::Red, (205, 0, 0)),
+    (Color::Green, (0, 205, 0)),
+    (Color::Yellow, (205, 205, 0)),
+    (Color::Blue, (0, 0, 238)),
+    (Color::Magenta, (205, 0, 205)),
+    (Color::Cyan, (0, 205, 205)),
+    (Color::Gray, (229, 229, 229)),
+    (Color::DarkGray, (127, 127, 127)),
+    (Color::LightRed, (255, 0, 0)),
+    (Color::LightGreen, (0, 255, 0)),
+    (Color::LightYellow, (255, 255, 0)),
+    (Color::LightBlue, (92, 92, 255)),
+    (Color::LightMagenta, (255, 0, 255)),
+    (Color::LightCyan, (0, 255, 255)),
+    (Color::White, (255, 255, 255)),
+];
+
+/// ANSI named color closest to an RGB color
+fn nearest_ansi_color(r: u8, g: u8, b: u8) -> Color {
+    let distance = |(cr, cg, cb): (u8, u8, u8)| {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2);
+        d(r, cr) + d(g, cg) + d(b, cb)
+    };
+    ANSI_COLORS
+        .iter()
+        .min_by_key(|(_, rgb)| distance(*rgb))
+        .map_or(Color::Reset, |(color, _)| *color)
+}
+
+/// Parse a color given by name ("cyan", "dark-gray"), ANSI index ("208")
+/// or hex ("#1e90ff" or "1e90ff")
+pub fn parse_color(value: &str) -> Option<Color> {
+    let value = value.trim();
+    Color::from_str(value)
+        .or_else(|_| Color::from_str(&format!("#{}", value)))
+        .ok()
+}
+
+/// Black or white text, whichever reads better on the given background
+fn contrast_fg(bg: Color) -> Color {
+    match bg {
+        Color::Rgb(r, g, b) => {
+            // Perceived brightness decides between dark and light text
+            let luma = (299 * r as u32 + 587 * g as u32 + 114 * b as u32) / 1000;
+            if luma > 128 {
+                Color::Black
+            } else {
+                Color::White
+            }
+        }
+        Color::Black
+        | Color::Red
+        | Color::Blue
+        | Color::Magenta
+        | Color::DarkGray
+        | Color::Indexed(_)
+        | Color::Reset => Color::White,
+        _ => Color::Black,
     }
 }
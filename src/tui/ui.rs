@@ -5,12 +5,66 @@ use ratatui::prelude::*;
 use ratatui::widgets::{Block, Borders, Clear, List, ListItem, Paragraph, Wrap};
 use regex::Regex;
 
-use crate::tui::split_lines_preserve_trailing;
+use crate::tui::{emoji, split_lines_preserve_trailing, text_input};
 
 /// Regex patterns for stripping HTML from markdown
 static HTML_TAG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<[^>]+>").unwrap());
 static HTML_COMMENT_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"<!--[\s\S]*?-->").unwrap());
 
+/// Regex patterns for converting common HTML to text when rendering HTML
+static HTML_SUMMARY_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<summary[^>]*>(.*?)</summary>").unwrap());
+static HTML_DETAILS_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)</?details[^>]*>").unwrap());
+static HTML_IMG_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<img\b[^>]*>").unwrap());
+static HTML_ALT_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r#"(?i)\balt\s*=\s*["']([^"']*)["']"#).unwrap());
+static HTML_BR_REGEX: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?i)<br\s*/?>").unwrap());
+
+/// Regex pattern for a whole `<details>` block
+static DETAILS_BLOCK_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"(?is)<details[^>]*>(.*?)</details>").unwrap());
+
+/// Regex pattern for a placeholder left by `mask_code_lines`
+static CODE_LINE_PLACEHOLDER_REGEX: Lazy<Regex> =
+    Lazy::new(|| Regex::new("\u{E000}(\\d+)\u{E000}").unwrap());
+
+/// Replace each line inside a fenced code block with a numbered placeholder
+///
+/// HTML processing then can't touch code; the placeholders hold no markup and
+/// keep one line per code line, so hidden-line counts stay right.
+fn mask_code_lines(input: &str) -> (String, Vec<&str>) {
+    let mut code = Vec::new();
+    let mut in_code_block = false;
+    let masked: Vec<String> = input
+        .lines()
+        .map(|line| {
+            let is_fence = line.trim_start().starts_with("```");
+            let masked = if in_code_block && !is_fence {
+                code.push(line);
+                format!("\u{E000}{}\u{E000}", code.len() - 1)
+            } else {
+                line.to_string()
+            };
+            in_code_block = in_code_block != is_fence;
+            masked
+        })
+        .collect();
+    (masked.join("\n"), code)
+}
+
+/// Put back the code lines replaced by `mask_code_lines`
+fn restore_code_lines(input: &str, code: &[&str]) -> String {
+    CODE_LINE_PLACEHOLDER_REGEX
+        .replace_all(input, |caps: &regex::Captures| {
+            caps[1]
+                .parse::<usize>()
+                .ok()
+                .and_then(|i| code.get(i))
+                .map_or_else(String::new, |line| line.to_string())
+        })
+        .to_string()
+}
+
 /// Strip HTML tags and comments from markdown content
 /// GitHub PR descriptions often contain HTML that tui-markdown can't render
 fn strip_html(input: &str) -> String {
@@ -20,24 +74,110 @@ fn strip_html(input: &str) -> String {
         .to_string()
 }
 
+/// Convert common HTML to readable text before stripping the remaining tags
+///
+/// `<summary>` becomes a `▸` line, `<img>` shows its alt text and `<br>`
+/// becomes a line break, so collapsibles and images don't just disappear.
+fn render_html(input: &str) -> String {
+    let text = HTML_COMMENT_REGEX.replace_all(input, "");
+    let text = HTML_SUMMARY_REGEX.replace_all(&text, |caps: &regex::Captures| {
+        let summary = HTML_TAG_REGEX.replace_all(&caps[1], "");
+        format!("\n▸ {}\n", summary.trim())
+    });
+    let text = HTML_DETAILS_REGEX.replace_all(&text, "\n");
+    let text = HTML_IMG_REGEX.replace_all(&text, |caps: &regex::Captures| {
+        match HTML_ALT_REGEX.captures(&caps[0]) {
+            Some(alt) if !alt[1].trim().is_empty() => format!("(image: {})", alt[1].trim()),
+            _ => "(image)".to_string(),
+        }
+    });
+    let text = HTML_BR_REGEX.replace_all(&text, "\n");
+    strip_html(&text)
+}
+
+/// Replace `<details>` blocks with a `▸ summary` line, or `▾ summary` plus
+/// the content when expanded
+fn collapse_details(input: &str, expanded: bool) -> String {
+    DETAILS_BLOCK_REGEX
+        .replace_all(input, |caps: &regex::Captures| {
+            let inner = &caps[1];
+            let (summary, content) = match HTML_SUMMARY_REGEX.captures(inner) {
+                Some(s) => {
+                    let whole = s.get(0).unwrap();
+                    let summary = HTML_TAG_REGEX.replace_all(&s[1], "").trim().to_string();
+                    let content = format!("{}{}", &inner[..whole.start()], &inner[whole.end()..]);
+                    (summary, content)
+                }
+                None => (String::new(), inner.to_string()),
+            };
+            let summary = if summary.is_empty() {
+                "Details".to_string()
+            } else {
+                summary
+            };
+            let content = content.trim_matches('\n');
+
+            if expanded {
+                format!("\n▾ {}\n{}\n", summary, content)
+            } else {
+                let hidden = content.lines().filter(|l| !l.trim().is_empty()).count();
+                format!("\n▸ {} ({} lines hidden)\n", summary, hidden)
+            }
+        })
+        .to_string()
+}
+
 /// Convert markdown string to styled ratatui Text
 /// Custom implementation since tui_markdown doesn't render styles correctly
-fn markdown_to_text(input: &str) -> Text<'static> {
-    // Strip HTML before parsing markdown
-    let cleaned = strip_html(input);
+fn markdown_to_text(
+    input: &str,
+    render_html_tags: bool,
+    expand_details: bool,
+    theme: &Theme,
+) -> Text<'static> {
+    // Collapse <details> blocks, then strip (or convert) the remaining HTML,
+    // leaving fenced code as written
+    let (masked, code) = mask_code_lines(input);
+    let collapsed = collapse_details(&masked, expand_details);
+    let cleaned = if render_html_tags {
+        render_html(&collapsed)
+    } else {
+        strip_html(&collapsed)
+    };
+    let cleaned = restore_code_lines(&cleaned, &code);
 
     // If input is empty, return empty text
     if cleaned.trim().is_empty() {
         return Text::raw("(no content)");
     }
 
-    let lines: Vec<Line<'static>> = cleaned.lines().map(parse_markdown_line).collect();
+    // Lines inside a fenced code block are shown verbatim, so track the fence state
+    let (lines, _) = cleaned.lines().fold(
+        (Vec::new(), false),
+        |(mut lines, in_code_block): (Vec<Line<'static>>, bool), line| {
+            let is_fence = line.trim_start().starts_with("```");
+            if in_code_block && !is_fence {
+                lines.push(code_block_line(line));
+            } else {
+                lines.push(parse_markdown_line(line, theme));
+            }
+            (lines, in_code_block != is_fence)
+        },
+    );
 
     Text::from(lines)
 }
 
+/// Render a line inside a fenced code block, without inline formatting
+fn code_block_line(line: &str) -> Line<'static> {
+    Line::from(Span::styled(
+        format!("  {}", line.trim_end()),
+        Style::default().fg(Color::Green).bg(Color::Black),
+    ))
+}
+
 /// Parse a single line of markdown into a styled Line
-fn parse_markdown_line(line: &str) -> Line<'static> {
+fn parse_markdown_line(line: &str, theme: &Theme) -> Line<'static> {
     let trimmed = line.trim();
 
     // Empty line
@@ -47,21 +187,16 @@ fn parse_markdown_line(line: &str) -> Line<'static> {
 
     // Horizontal rule (---, ___, ***)
     if is_horizontal_rule(trimmed) {
-        return Line::from(Span::styled(
-            "─".repeat(40),
-            Style::default().fg(Color::DarkGray),
-        ));
+        return Line::from(Span::styled("─".repeat(40), theme.muted()));
     }
 
     // Headers (# ## ### etc.)
     if let Some((level, content)) = parse_header(trimmed) {
         let style = match level {
-            1 => Style::default()
-                .fg(Color::Cyan)
+            1 => theme
+                .accent()
                 .add_modifier(Modifier::BOLD | Modifier::UNDERLINED),
-            2 => Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD),
+            2 => theme.accent().add_modifier(Modifier::BOLD),
             _ => Style::default()
                 .fg(Color::Blue)
                 .add_modifier(Modifier::BOLD),
@@ -69,10 +204,42 @@ fn parse_markdown_line(line: &str) -> Line<'static> {
         return Line::from(Span::styled(content.to_string(), style));
     }
 
-    // List items (- or * or numbered)
-    if let Some(content) = parse_list_item(trimmed) {
-        let mut spans = vec![Span::styled("  • ", Style::default().fg(Color::Yellow))];
-        spans.extend(parse_inline_spans(content));
+    // Collapsible section summary (from <details>)
+    if let Some(rest) = trimmed
+        .strip_prefix("▸ ")
+        .or_else(|| trimmed.strip_prefix("▾ "))
+    {
+        let marker = &trimmed[..trimmed.len() - rest.len()];
+        return Line::from(vec![
+            Span::styled(marker.to_string(), theme.highlight()),
+            Span::styled(
+                rest.to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]);
+    }
+
+    // List items (- or * or numbered), indented by nesting depth
+    if let Some(item) = parse_list_item(line) {
+        let indent = "  ".repeat(item.depth + 1);
+        let (marker, marker_style, content_style) = match item.marker {
+            ListMarker::Bullet => ("• ".to_string(), theme.highlight(), Style::default()),
+            ListMarker::Ordered(number) => {
+                (format!("{} ", number), theme.highlight(), Style::default())
+            }
+            ListMarker::Task(false) => ("☐ ".to_string(), theme.highlight(), Style::default()),
+            ListMarker::Task(true) => (
+                "☑ ".to_string(),
+                Style::default().fg(Color::Green),
+                theme.muted(),
+            ),
+        };
+        let mut spans = vec![Span::styled(format!("{}{}", indent, marker), marker_style)];
+        spans.extend(
+            parse_inline_spans(item.content)
+                .into_iter()
+                .map(|span| span.patch_style(content_style)),
+        );
         return Line::from(spans);
     }
 
@@ -80,15 +247,9 @@ fn parse_markdown_line(line: &str) -> Line<'static> {
     if trimmed.starts_with("```") {
         let lang = trimmed.trim_start_matches('`').trim();
         if lang.is_empty() {
-            return Line::from(Span::styled(
-                "───── code ─────",
-                Style::default().fg(Color::DarkGray),
-            ));
+            return Line::from(Span::styled("───── code ─────", theme.muted()));
         } else {
-            return Line::from(Span::styled(
-                format!("───── {} ─────", lang),
-                Style::default().fg(Color::DarkGray),
-            ));
+            return Line::from(Span::styled(format!("───── {} ─────", lang), theme.muted()));
         }
     }
 
@@ -96,7 +257,7 @@ fn parse_markdown_line(line: &str) -> Line<'static> {
     if trimmed.starts_with('>') {
         let content = trimmed.trim_start_matches('>').trim();
         return Line::from(vec![
-            Span::styled("│ ", Style::default().fg(Color::DarkGray)),
+            Span::styled("│ ", theme.muted()),
             Span::styled(
                 content.to_string(),
                 Style::default()
@@ -144,22 +305,61 @@ fn parse_header(line: &str) -> Option<(usize, &str)> {
     Some((level, content))
 }
 
-/// Parse a list item, returns the content without the marker
-fn parse_list_item(line: &str) -> Option<&str> {
-    // Unordered list (- or *)
-    if line.starts_with("- ") || line.starts_with("* ") {
-        return Some(&line[2..]);
+/// Marker of a markdown list item
+#[derive(Debug, PartialEq)]
+enum ListMarker<'a> {
+    /// `-` or `*`
+    Bullet,
+    /// `1.`, `2.`, ... (kept as written)
+    Ordered(&'a str),
+    /// GitHub task list item, `- [ ]` or `- [x]`
+    Task(bool),
+}
+
+/// A markdown list item
+#[derive(Debug, PartialEq)]
+struct MarkdownListItem<'a> {
+    /// Nesting level: every two leading spaces (or a tab) is one level
+    depth: usize,
+    marker: ListMarker<'a>,
+    /// Content without the marker
+    content: &'a str,
+}
+
+/// Parse a list item, keeping its nesting depth and marker
+fn parse_list_item(line: &str) -> Option<MarkdownListItem<'_>> {
+    let rest = line.trim_start();
+    let indent: usize = line[..line.len() - rest.len()]
+        .chars()
+        .map(|c| if c == '\t' { 4 } else { 1 })
+        .sum();
+    let depth = indent / 2;
+    let rest = rest.trim_end();
+
+    // Unordered list (- or *), possibly a task item
+    if let Some(content) = rest.strip_prefix("- ").or_else(|| rest.strip_prefix("* ")) {
+        let task = [("[ ] ", false), ("[x] ", true), ("[X] ", true)]
+            .into_iter()
+            .find_map(|(checkbox, checked)| content.strip_prefix(checkbox).map(|c| (c, checked)));
+        let (marker, content) = match task {
+            Some((content, checked)) => (ListMarker::Task(checked), content),
+            None => (ListMarker::Bullet, content),
+        };
+        return Some(MarkdownListItem {
+            depth,
+            marker,
+            content,
+        });
     }
 
     // Numbered list (1. 2. etc.)
-    let mut chars = line.chars().peekable();
-    let mut num_len = 0;
-    while chars.peek().map(|c| c.is_ascii_digit()).unwrap_or(false) {
-        chars.next();
-        num_len += 1;
-    }
-    if num_len > 0 && chars.next() == Some('.') && chars.next() == Some(' ') {
-        return Some(&line[num_len + 2..]);
+    let num_len = rest.chars().take_while(|c| c.is_ascii_digit()).count();
+    if num_len > 0 && rest[num_len..].starts_with(". ") {
+        return Some(MarkdownListItem {
+            depth,
+            marker: ListMarker::Ordered(&rest[..num_len + 1]),
+            content: &rest[num_len + 2..],
+        });
     }
 
     None
@@ -167,6 +367,7 @@ fn parse_list_item(line: &str) -> Option<&str> {
 
 /// Parse inline formatting (bold, italic, code, links)
 fn parse_inline_spans(text: &str) -> Vec<Span<'static>> {
+    let text = emoji::replace_shortcodes(text);
     let mut spans = Vec::new();
     let mut current = String::new();
     let mut chars = text.chars().peekable();
@@ -226,6 +427,27 @@ fn parse_inline_spans(text: &str) -> Vec<Span<'static>> {
                         .add_modifier(Modifier::UNDERLINED),
                 ));
             }
+            // @mention or #123 reference at a word boundary
+            '@' | '#' if current.chars().last().is_none_or(|p| !p.is_alphanumeric()) => {
+                let word = consume_reference(&mut chars, c);
+                if word.is_empty() {
+                    current.push(c);
+                    continue;
+                }
+                if !current.is_empty() {
+                    spans.push(Span::raw(std::mem::take(&mut current)));
+                }
+                let style = if c == '@' {
+                    Style::default()
+                        .fg(Color::Magenta)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default()
+                        .fg(Color::Blue)
+                        .add_modifier(Modifier::UNDERLINED)
+                };
+                spans.push(Span::styled(format!("{}{}", c, word), style));
+            }
             // Regular character
             _ => {
                 current.push(c);
@@ -244,6 +466,24 @@ fn parse_inline_spans(text: &str) -> Vec<Span<'static>> {
     spans
 }
 
+/// Consume the name after `@` (login characters) or number after `#` (digits)
+fn consume_reference(chars: &mut std::iter::Peekable<std::str::Chars>, prefix: char) -> String {
+    let mut result = String::new();
+    while let Some(&c) = chars.peek() {
+        let valid = if prefix == '@' {
+            c.is_ascii_alphanumeric() || c == '-'
+        } else {
+            c.is_ascii_digit()
+        };
+        if !valid {
+            break;
+        }
+        result.push(c);
+        chars.next();
+    }
+    result
+}
+
 /// Consume characters until we hit the delimiter string
 fn consume_until(chars: &mut std::iter::Peekable<std::str::Chars>, delimiter: &str) -> String {
     let mut result = String::new();
@@ -285,6 +525,7 @@ fn consume_until_char(chars: &mut std::iter::Peekable<std::str::Chars>, delimite
 
 use octocrab::models::IssueState;
 
+use crate::core::config::{AiBackend, GeminiModel};
 use crate::github::workflow::{WorkflowConclusion, WorkflowRunStatus};
 use crate::tui::app::{App, ErrorPopup, Screen};
 use crate::tui::theme::Theme;
@@ -309,10 +550,28 @@ pub fn render(frame: &mut Frame, app: &App) {
         render_help_overlay(frame, app);
     }
 
+    // Render quit confirmation on top of the screen
+    if app.quit_prompt_open {
+        render_quit_prompt(frame, &app.theme);
+    }
+
+    // Render discard confirmation on top of the commit screen
+    if let Some((path, untracked)) = &app.commit_discard_confirm {
+        render_discard_prompt(frame, path, *untracked, &app.theme);
+    }
+
+    // Render amend confirmation on top of the commit screen
+    if app.commit_amend_confirm {
+        render_amend_prompt(frame, &app.theme);
+    }
+
     // Render error popup overlay (highest priority, always on top)
     if let Some(popup) = &app.error_popup {
-        render_error_popup(frame, popup);
+        render_error_popup(frame, popup, &app.theme);
     }
+
+    // Strip colors last so every widget above is covered
+    app.theme.apply(frame.buffer_mut());
 }
 
 /// Render the header
@@ -326,28 +585,31 @@ fn render_header(frame: &mut Frame, area: Rect, app: &App) {
     let screen_name = match app.current_screen {
         Screen::Dashboard => "Dashboard",
         Screen::PrList => "Pull Requests",
-        Screen::PrDetail(n) => return render_pr_detail_header(frame, area, n),
+        Screen::PrDetail(n) => return render_pr_detail_header(frame, area, app, n),
+        Screen::PrDiff(n) => return render_pr_detail_header(frame, area, app, n),
         Screen::PrCreate => "Create Pull Request",
         Screen::Commit => "Create Commit",
         Screen::Tags => "Tags",
+        Screen::Branches => "Branches",
         Screen::Settings => "Settings",
         Screen::Auth => "Authentication",
         Screen::WorkflowRuns => "Workflow Runs",
+        Screen::WorkflowLogs(_) => "Workflow Logs",
     };
 
     let title = format!(" argo-rs │ {} │ {} ", repo_name, screen_name);
 
     let header = Paragraph::new(title)
-        .style(Theme::header())
+        .style(app.theme.header())
         .block(Block::default().borders(Borders::BOTTOM));
 
     frame.render_widget(header, area);
 }
 
-fn render_pr_detail_header(frame: &mut Frame, area: Rect, pr_number: u64) {
+fn render_pr_detail_header(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
     let title = format!(" argo-rs │ Pull Request #{} ", pr_number);
     let header = Paragraph::new(title)
-        .style(Theme::header())
+        .style(app.theme.header())
         .block(Block::default().borders(Borders::BOTTOM));
     frame.render_widget(header, area);
 }
@@ -359,11 +621,14 @@ fn render_content(frame: &mut Frame, area: Rect, app: &App) {
         Screen::PrList => render_pr_list(frame, area, app),
         Screen::PrCreate => render_pr_create(frame, area, app),
         Screen::PrDetail(number) => render_pr_detail(frame, area, app, number),
+        Screen::PrDiff(number) => render_pr_diff(frame, area, app, number),
         Screen::Commit => render_commit_screen(frame, area, app),
         Screen::Tags => render_tags(frame, area, app),
+        Screen::Branches => render_branches(frame, area, app),
         Screen::Settings => render_settings(frame, area, app),
         Screen::Auth => render_placeholder(frame, area, "Authentication", "Coming soon..."),
         Screen::WorkflowRuns => render_workflow_runs(frame, area, app),
+        Screen::WorkflowLogs(run_id) => render_workflow_logs(frame, area, app, run_id),
     }
 }
 
@@ -379,6 +644,7 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
         ListItem::new("  [p] Pull Requests"),
         ListItem::new("  [c] Create Commit"),
         ListItem::new("  [t] Tags"),
+        ListItem::new("  [b] Branches"),
         ListItem::new("  [w] Workflow Runs"),
         ListItem::new("  [s] Settings"),
         ListItem::new("  [q] Quit"),
@@ -389,7 +655,7 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
         .enumerate()
         .map(|(i, item)| {
             if i == app.dashboard_selection.selected {
-                item.style(Theme::selected())
+                item.style(app.theme.selected())
             } else {
                 item
             }
@@ -401,9 +667,9 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
             Block::default()
                 .title(" Menu ")
                 .borders(Borders::ALL)
-                .border_style(Theme::normal()),
+                .border_style(app.theme.normal()),
         )
-        .highlight_style(Theme::selected());
+        .highlight_style(app.theme.selected());
 
     frame.render_widget(list, chunks[0]);
 
@@ -417,10 +683,10 @@ fn render_dashboard(frame: &mut Frame, area: Rect, app: &App) {
         )
     };
 
-    let gemini_indicator = if app.gemini_configured {
+    let gemini_indicator = if app.ai_configured {
         Span::styled("  AI ✓", Style::default().fg(Color::Green))
     } else {
-        Span::styled("  AI ✗", Style::default().fg(Color::DarkGray))
+        Span::styled("  AI ✗", app.theme.muted())
     };
 
     let status_line = Line::from(vec![Span::raw("  "), github_indicator, gemini_indicator]);
@@ -451,23 +717,30 @@ fn render_pr_list(frame: &mut Frame, area: Rect, app: &App) {
         vec![ListItem::new("  Press [r] to load pull requests")]
     } else if app.pr_list.is_empty() {
         vec![
-            ListItem::new("  No open pull requests"),
+            ListItem::new(format!(
+                "  No {} pull requests",
+                app.pr_list_state_filter.label().to_lowercase()
+            )),
             ListItem::new(""),
             ListItem::new("  Press [n] to create a new PR"),
         ]
+    } else if app.filtered_pr_list().is_empty() {
+        vec![ListItem::new(format!(
+            "  No pull requests match \"{}\"",
+            app.pr_list_search_query
+        ))]
     } else {
-        app.pr_list
-            .iter()
+        app.filtered_pr_list()
+            .into_iter()
             .enumerate()
             .map(|(i, pr)| {
-                let state_icon = if pr.draft == Some(true) {
-                    "◇"
-                } else {
-                    match &pr.state {
-                        Some(IssueState::Open) => "○",
-                        Some(IssueState::Closed) => "●",
-                        None | Some(_) => "○",
-                    }
+                let state_icon = match (pr.draft == Some(true), &pr.state) {
+                    (true, _) if app.theme.no_color => "[DRAFT]",
+                    (true, _) => "◇",
+                    (false, Some(IssueState::Closed)) if app.theme.no_color => "[CLOSED]",
+                    (false, Some(IssueState::Closed)) => "●",
+                    _ if app.theme.no_color => "[OPEN]",
+                    _ => "○",
                 };
 
                 let title = pr.title.as_deref().unwrap_or("(no title)");
@@ -477,11 +750,25 @@ fn render_pr_list(frame: &mut Frame, area: Rect, app: &App) {
                     .map(|u| u.login.as_str())
                     .unwrap_or("unknown");
 
-                let text = format!("  {} #{} {} ({})", state_icon, pr.number, title, author);
-                let item = ListItem::new(text);
+                let mut spans = vec![Span::raw(format!(
+                    "  {} #{} {} ({})",
+                    state_icon, pr.number, title, author
+                ))];
+                let labels = pr.labels.as_deref().unwrap_or_default();
+                for label in labels.iter().take(3) {
+                    spans.push(Span::raw(" "));
+                    spans.push(label_chip(label, &app.theme));
+                }
+                if labels.len() > 3 {
+                    spans.push(Span::styled(
+                        format!(" +{}", labels.len() - 3),
+                        app.theme.muted(),
+                    ));
+                }
+                let item = ListItem::new(Line::from(spans));
 
                 if i == app.pr_list_selection.selected {
-                    item.style(Theme::selected())
+                    item.style(app.theme.selected())
                 } else {
                     item
                 }
@@ -492,19 +779,53 @@ fn render_pr_list(frame: &mut Frame, area: Rect, app: &App) {
     let list = List::new(items)
         .block(
             Block::default()
-                .title(format!(" Pull Requests ({}) ", app.pr_list.len()))
+                .title(pr_list_title(app))
                 .borders(Borders::ALL)
-                .border_style(Theme::normal()),
+                .border_style(app.theme.normal()),
         )
-        .highlight_style(Theme::selected());
+        .highlight_style(app.theme.selected());
 
     frame.render_widget(list, chunks[0]);
+    app.pr_list_area.set(chunks[0].inner(Margin::new(1, 1)));
 
-    let help = Paragraph::new(" [n] New PR  [r] Refresh  [o] Open  [Enter] View  [Esc] Back")
-        .style(Theme::muted());
+    let help_text = if app.pr_list_search_mode {
+        " Type to search  [Enter] Done  [Esc] Clear"
+    } else {
+        " [n] New PR  [/] Search  [f] Filter  [L] Load more  [r] Refresh  [o] Open  [Enter] View  [Esc] Back"
+    };
+    let help = Paragraph::new(help_text).style(app.theme.muted());
     frame.render_widget(help, chunks[1]);
 }
 
+/// Render a PR label as a colored chip
+fn label_chip(label: &octocrab::models::Label, theme: &Theme) -> Span<'static> {
+    Span::styled(format!(" {} ", label.name), theme.label(&label.color))
+}
+
+/// Title for the PR list block: state filter, count and any search query
+fn pr_list_title(app: &App) -> String {
+    let state = app.pr_list_state_filter.label();
+    if app.pr_list_search_mode || !app.pr_list_search_query.is_empty() {
+        let cursor = if app.pr_list_search_mode { "_" } else { "" };
+        format!(
+            " Pull Requests [{}] ({}/{}) /{}{} ",
+            state,
+            app.filtered_pr_list().len(),
+            app.pr_list.len(),
+            app.pr_list_search_query,
+            cursor
+        )
+    } else {
+        let more = if app.pr_list_has_more { "+" } else { "" };
+        format!(
+            " Pull Requests [{}] ({}{}) ",
+            state,
+            app.pr_list.len(),
+            more
+        )
+    }
+}
+
 /// Render the PR detail screen
 fn render_pr_detail(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
     // Main vertical layout: content area + help bar
@@ -530,10 +851,12 @@ fn render_pr_detail(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
         " [j/k] Scroll  [Esc/Enter/q] Close"
     } else if app.pr_comment_input_mode {
         " [Enter] Submit  [Esc] Cancel"
+    } else if app.review_dialog_open {
+        " [Tab/↑↓] Verdict  [Enter] Submit  [Esc] Cancel"
     } else {
-        " [j/k] Navigate  [Enter] Expand  [d] Description  [c] Comment  [m] Merge  [o] Open  [r] Refresh  [Esc] Back"
+        " [j/k] Navigate  [Enter] Expand  [d] Description  [e] Edit  [c] Comment  [v] Review  [m] Merge  [b] Base  [o] Open  [r] Refresh  [Esc] Back"
     };
-    let help = Paragraph::new(help_text).style(Theme::muted());
+    let help = Paragraph::new(help_text).style(app.theme.muted());
     frame.render_widget(help, main_chunks[1]);
 
     // Render expanded comment overlay if active
@@ -555,23 +878,216 @@ fn render_pr_detail(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
     if app.merge_dialog_open {
         render_merge_dialog(frame, app);
     }
+
+    // Render base branch picker overlay if active
+    if app.base_picker_open {
+        render_base_picker(frame, app);
+    }
+
+    // Render review dialog overlay if active
+    if app.review_dialog_open {
+        render_review_dialog(frame, app, pr_number);
+    }
+
+    // Render PR edit form overlay if active
+    if app.pr_edit_open {
+        render_pr_edit(frame, app, pr_number);
+    }
+}
+
+/// Render the PR diff viewer
+fn render_pr_diff(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let visible = chunks[0].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = if app.pr_diff_loading.is_some() {
+        vec![Line::from("  Fetching diff...")]
+    } else if let Some(err) = &app.pr_diff_error {
+        vec![
+            Line::from(Span::styled(
+                format!("  Error: {}", err),
+                Style::default().fg(Color::Red),
+            )),
+            Line::from(""),
+            Line::from("  Press [r] to retry"),
+        ]
+    } else if let Some(diff) = &app.pr_diff {
+        if diff.is_empty() {
+            vec![Line::from("  No changes")]
+        } else {
+            // Only style the lines that are on screen
+            diff.lines()
+                .skip(app.pr_diff_scroll)
+                .take(visible)
+                .map(|line| diff_line(line, &app.theme))
+                .collect()
+        }
+    } else {
+        vec![Line::from("  Press [r] to load the diff")]
+    };
+
+    let (file, files) = app.pr_diff_index.file_position(app.pr_diff_scroll);
+    let (hunk, hunks) = app.pr_diff_index.hunk_position(app.pr_diff_scroll);
+    let title = if files > 0 {
+        format!(
+            " Diff - PR #{} (file {}/{}, hunk {}/{}) ",
+            pr_number,
+            file.max(1),
+            files,
+            hunk,
+            hunks
+        )
+    } else {
+        format!(" Diff - PR #{} ", pr_number)
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(app.theme.normal()),
+    );
+    frame.render_widget(paragraph, chunks[0]);
+
+    let help = Paragraph::new(
+        " [j/k] Scroll  [PgUp/PgDn] Page  []]/[[] Next/prev hunk  [}/{] Next/prev file  [r] Refresh  [Esc] Back",
+    )
+    .style(app.theme.muted());
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Render the downloaded logs of a workflow run
+fn render_workflow_logs(frame: &mut Frame, area: Rect, app: &App, run_id: u64) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let visible = chunks[0].height.saturating_sub(2) as usize;
+    let lines: Vec<Line> = if app.workflow_logs_loading.is_some() {
+        vec![Line::from("  Downloading logs...")]
+    } else if let Some(err) = &app.workflow_logs_error {
+        vec![
+            Line::from(Span::styled(
+                format!("  Error: {}", err),
+                Style::default().fg(Color::Red),
+            )),
+            Line::from(""),
+            Line::from("  Press [r] to retry"),
+        ]
+    } else if app.workflow_logs.is_empty() {
+        vec![Line::from("  No logs available yet")]
+    } else {
+        let mut lines = Vec::new();
+        if app.workflow_logs_truncated > 0 && app.workflow_logs_scroll == 0 {
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "  … {} earlier lines not shown; press [o] for the full logs on GitHub",
+                    app.workflow_logs_truncated
+                ),
+                app.theme.highlight(),
+            )));
+        }
+        // Only style the lines that are on screen
+        let remaining = visible.saturating_sub(lines.len());
+        lines.extend(
+            app.workflow_logs
+                .iter()
+                .skip(app.workflow_logs_scroll)
+                .take(remaining)
+                .map(|line| workflow_log_line(line, &app.theme)),
+        );
+        lines
+    };
+
+    let title = match app.workflow_runs.iter().find(|r| r.id == run_id) {
+        Some(run) => format!(" Logs - {} #{} ", run.name, run.run_number),
+        None => format!(" Logs - run {} ", run_id),
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(app.theme.normal()),
+    );
+    frame.render_widget(paragraph, chunks[0]);
+
+    let help = Paragraph::new(
+        " [j/k] Scroll  [PgUp/PgDn] Page  [Home/End] Top/Bottom  [r] Refresh  [o] Open  [Esc] Back",
+    )
+    .style(app.theme.muted());
+    frame.render_widget(help, chunks[1]);
+}
+
+/// Colorize a single workflow log line by its GitHub Actions marker
+fn workflow_log_line<'a>(line: &'a str, theme: &Theme) -> Line<'a> {
+    let style = if line.starts_with("── ") {
+        theme.highlight().add_modifier(Modifier::BOLD)
+    } else if line.contains("##[error]") {
+        Style::default().fg(Color::Red)
+    } else if line.contains("##[warning]") {
+        theme.highlight()
+    } else if line.contains("##[group]") || line.contains("##[section]") {
+        theme.accent()
+    } else {
+        Style::default()
+    };
+    Line::from(Span::styled(line, style))
+}
+
+/// Colorize a single unified diff line
+fn diff_line<'a>(line: &'a str, theme: &Theme) -> Line<'a> {
+    let style = if line.starts_with("diff --git ") {
+        theme.highlight().add_modifier(Modifier::BOLD)
+    } else if line.starts_with("+++") || line.starts_with("---") {
+        theme.highlight()
+    } else if line.starts_with("@@") {
+        theme.accent()
+    } else if line.starts_with('+') {
+        Style::default().fg(Color::Green)
+    } else if line.starts_with('-') {
+        Style::default().fg(Color::Red)
+    } else {
+        Style::default()
+    };
+    Line::from(Span::styled(line, style))
 }
 
 /// Render the left panel with PR info, description, and comments
 fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64) {
+    // PR info grows by one line each for labels and assignees
+    let pr = app.selected_pr.as_ref();
+    let has_labels = pr
+        .and_then(|pr| pr.labels.as_ref())
+        .is_some_and(|labels| !labels.is_empty());
+    let has_assignees = pr
+        .and_then(|pr| pr.assignees.as_ref())
+        .is_some_and(|assignees| !assignees.is_empty());
+    let info_height = 6 + has_labels as u16 + has_assignees as u16;
+
+    // One line per reviewer, at least one for the loading/empty line
+    const MAX_REVIEW_LINES: usize = 4;
+    let reviews_height = app.pr_reviews.len().clamp(1, MAX_REVIEW_LINES) as u16 + 2;
+
     // Determine layout based on comment input mode
     let constraints = if app.pr_comment_input_mode {
         vec![
-            Constraint::Length(5), // PR info (compact)
-            Constraint::Length(8), // Description preview
-            Constraint::Min(5),    // Comments
-            Constraint::Length(3), // Comment input
+            Constraint::Length(info_height),    // PR info (compact)
+            Constraint::Length(8),              // Description preview
+            Constraint::Length(reviews_height), // Reviews
+            Constraint::Min(5),                 // Comments
+            Constraint::Length(3),              // Comment input
         ]
     } else {
         vec![
-            Constraint::Length(5), // PR info (compact)
-            Constraint::Length(8), // Description preview
-            Constraint::Min(5),    // Comments
+            Constraint::Length(info_height),    // PR info (compact)
+            Constraint::Length(8),              // Description preview
+            Constraint::Length(reviews_height), // Reviews
+            Constraint::Min(5),                 // Comments
         ]
     };
 
@@ -608,13 +1124,13 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
         let head_branch = pr.head.ref_field.as_str();
         let base_branch = pr.base.ref_field.as_str();
 
-        let lines: Vec<Line> = vec![
+        let mut lines: Vec<Line> = vec![
             Line::from(vec![
-                Span::styled("Title: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Title: ", app.theme.accent()),
                 Span::raw(truncate(title, 50)),
             ]),
             Line::from(vec![
-                Span::styled("State: ", Style::default().fg(Color::Cyan)),
+                Span::styled("State: ", app.theme.accent()),
                 Span::styled(
                     format!("{}{}", state_str, draft_str),
                     if state_str == "Open" {
@@ -624,11 +1140,11 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
                     },
                 ),
                 Span::raw("  "),
-                Span::styled("Author: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Author: ", app.theme.accent()),
                 Span::raw(format!("@{}", author)),
             ]),
             Line::from(vec![
-                Span::styled("Branches: ", Style::default().fg(Color::Cyan)),
+                Span::styled("Branches: ", app.theme.accent()),
                 Span::raw(format!(
                     "{} → {}",
                     truncate(head_branch, 20),
@@ -636,12 +1152,65 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
                 )),
             ]),
         ];
+        if let Some(labels) = pr.labels.as_ref().filter(|l| !l.is_empty()) {
+            let mut spans = vec![Span::styled("Labels: ", app.theme.accent())];
+            for (i, label) in labels.iter().enumerate() {
+                if i > 0 {
+                    spans.push(Span::raw(" "));
+                }
+                spans.push(label_chip(label, &app.theme));
+            }
+            lines.push(Line::from(spans));
+        }
+        if let Some(assignees) = pr.assignees.as_ref().filter(|a| !a.is_empty()) {
+            let names: Vec<String> = assignees.iter().map(|a| format!("@{}", a.login)).collect();
+            lines.push(Line::from(vec![
+                Span::styled("Assignees: ", app.theme.accent()),
+                Span::raw(names.join(", ")),
+            ]));
+        }
+
+        // CI status of the head commit and GitHub's mergeability verdict
+        let checks_color = pr_checks_color(app);
+        let checks = app
+            .pr_combined_status
+            .as_ref()
+            .map(|s| s.summary())
+            .unwrap_or_else(|| "-".to_string());
+        let mut status_spans = vec![
+            Span::styled("Checks: ", app.theme.accent()),
+            Span::styled(checks, Style::default().fg(checks_color)),
+        ];
+        // GitHub computes mergeability in the background, and only for open PRs
+        if pr.state == Some(IssueState::Open) {
+            let (mergeable, mergeable_color) = match pr.mergeable {
+                Some(true) => ("yes", Color::Green),
+                Some(false) => ("conflicts", Color::Red),
+                None => ("checking (press r)", app.theme.muted),
+            };
+            status_spans.push(Span::raw("  "));
+            status_spans.push(Span::styled("Mergeable: ", app.theme.accent()));
+            status_spans.push(Span::styled(
+                mergeable,
+                Style::default().fg(mergeable_color),
+            ));
+        }
+        lines.push(Line::from(status_spans));
+
+        let mut title = vec![Span::raw(format!(" PR #{} ", pr_number))];
+        // The dot only carries color; the checks line spells the status out
+        if !app.theme.no_color {
+            title.push(Span::styled("● ", Style::default().fg(checks_color)));
+        }
+        if app.pr_description_edited {
+            title.push(Span::styled("(description edited) ", app.theme.muted()));
+        }
 
         let content = Paragraph::new(lines).block(
             Block::default()
-                .title(format!(" PR #{} ", pr_number))
+                .title(Line::from(title))
                 .borders(Borders::ALL)
-                .border_style(Theme::normal()),
+                .border_style(app.theme.normal()),
         );
         frame.render_widget(content, chunks[0]);
     } else {
@@ -656,12 +1225,15 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
     // PR Description section (chunks[1])
     render_pr_description_preview(frame, chunks[1], app);
 
-    // Comments section (chunks[2])
-    render_pr_comments(frame, chunks[2], app);
+    // Reviews section (chunks[2])
+    render_pr_reviews(frame, chunks[2], app, MAX_REVIEW_LINES);
 
-    // Comment input box (if in input mode) - chunks[3]
+    // Comments section (chunks[3])
+    render_pr_comments(frame, chunks[3], app);
+
+    // Comment input box (if in input mode) - chunks[4]
     if app.pr_comment_input_mode {
-        let input_area = chunks[3];
+        let input_area = chunks[4];
         let display_text = if app.pr_comment_submitting {
             "Posting comment...".to_string()
         } else {
@@ -669,7 +1241,7 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
         };
 
         let input_style = if app.pr_comment_submitting {
-            Style::default().fg(Color::Yellow)
+            app.theme.highlight()
         } else {
             Style::default().fg(Color::White)
         };
@@ -678,12 +1250,109 @@ fn render_pr_left_panel(frame: &mut Frame, area: Rect, app: &App, pr_number: u64
             Block::default()
                 .title(" New Comment ")
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(app.theme.accent()),
         );
         frame.render_widget(input, input_area);
     }
 }
 
+/// Traffic-light color for the selected PR's CI status and mergeability
+fn pr_checks_color(app: &App) -> Color {
+    use crate::github::CommitState;
+
+    let conflicts = app
+        .selected_pr
+        .as_ref()
+        .is_some_and(|pr| pr.mergeable == Some(false));
+    match &app.pr_combined_status {
+        _ if conflicts => Color::Red,
+        Some(status) if status.total_count == 0 => app.theme.muted,
+        Some(status) => match status.state {
+            CommitState::Success => Color::Green,
+            CommitState::Pending => app.theme.highlight,
+            CommitState::Failure | CommitState::Error => Color::Red,
+            CommitState::Unknown => app.theme.muted,
+        },
+        None => app.theme.muted,
+    }
+}
+
+/// Render the latest review of each reviewer with a colored state
+fn render_pr_reviews(frame: &mut Frame, area: Rect, app: &App, max_lines: usize) {
+    use crate::github::ReviewState;
+
+    let lines: Vec<Line> = if app.pr_reviews_loading.is_some() && app.pr_reviews.is_empty() {
+        vec![Line::from(Span::styled(
+            "  Loading reviews...",
+            app.theme.highlight(),
+        ))]
+    } else if let Some(err) = &app.pr_reviews_error {
+        vec![Line::from(Span::styled(
+            format!("  Error: {}", err),
+            Style::default().fg(Color::Red),
+        ))]
+    } else if app.pr_reviews.is_empty() {
+        vec![Line::from(Span::styled(
+            "  No reviews yet",
+            app.theme.muted(),
+        ))]
+    } else {
+        let mut lines: Vec<Line> = app
+            .pr_reviews
+            .iter()
+            .take(max_lines)
+            .map(|review| {
+                let (symbol, color) = match review.state {
+                    ReviewState::Approved => ("✓", Color::Green),
+                    ReviewState::ChangesRequested => ("✗", Color::Red),
+                    ReviewState::Commented => ("•", app.theme.accent),
+                    _ => ("-", app.theme.muted),
+                };
+                let time = review
+                    .submitted_at
+                    .map(|t| format!("  {}", format_relative_time(t)))
+                    .unwrap_or_default();
+                Line::from(vec![
+                    Span::styled(format!("  {} ", symbol), Style::default().fg(color)),
+                    Span::raw(format!("@{} ", review.reviewer())),
+                    Span::styled(review.state.label(), Style::default().fg(color)),
+                    Span::styled(time, app.theme.muted()),
+                ])
+            })
+            .collect();
+
+        // Replace the last line with a count when reviewers don't fit
+        let hidden = app.pr_reviews.len().saturating_sub(max_lines);
+        if hidden > 0 {
+            lines.pop();
+            lines.push(Line::from(Span::styled(
+                format!("  +{} more", hidden + 1),
+                app.theme.muted(),
+            )));
+        }
+        lines
+    };
+
+    let approvals = app
+        .pr_reviews
+        .iter()
+        .filter(|r| r.state == ReviewState::Approved)
+        .count();
+    let title = if app.pr_reviews.is_empty() {
+        " Reviews ".to_string()
+    } else {
+        format!(" Reviews ({} approved) ", approvals)
+    };
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(app.theme.normal()),
+    );
+    frame.render_widget(paragraph, area);
+}
+
 /// Render the PR description preview in the main view
 fn render_pr_description_preview(frame: &mut Frame, area: Rect, app: &App) {
     let pr = match &app.selected_pr {
@@ -702,14 +1371,14 @@ fn render_pr_description_preview(frame: &mut Frame, area: Rect, app: &App) {
     let body = pr.body.as_deref().unwrap_or("(no description)");
 
     // Use markdown rendering for the description
-    let markdown_text = markdown_to_text(body);
+    let markdown_text = markdown_to_text(body, app.render_html, false, &app.theme);
 
     let description = Paragraph::new(markdown_text)
         .block(
             Block::default()
                 .title(" Description (d for full) ")
                 .borders(Borders::ALL)
-                .border_style(Theme::normal()),
+                .border_style(app.theme.normal()),
         )
         .wrap(Wrap { trim: false });
 
@@ -762,7 +1431,7 @@ fn render_pr_comments(frame: &mut Frame, area: Rect, app: &App) {
 
                 // Highlight selected comment
                 if i == app.pr_comments_selection.selected && !app.pr_comments.is_empty() {
-                    item.style(Theme::selected())
+                    item.style(app.theme.selected())
                 } else {
                     item
                 }
@@ -774,7 +1443,7 @@ fn render_pr_comments(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
 
     frame.render_widget(list, area);
@@ -785,10 +1454,14 @@ fn format_reactions_summary(
     reactions_map: &std::collections::HashMap<u64, Vec<crate::github::pull_request::Reaction>>,
     comment_id: u64,
 ) -> String {
-    let Some(reactions) = reactions_map.get(&comment_id) else {
-        return String::new();
-    };
+    reactions_map
+        .get(&comment_id)
+        .map(|reactions| summarize_reactions(reactions))
+        .unwrap_or_default()
+}
 
+/// Format a list of reactions as "👍2 ❤️1"
+fn summarize_reactions(reactions: &[crate::github::pull_request::Reaction]) -> String {
     if reactions.is_empty() {
         return String::new();
     }
@@ -801,8 +1474,8 @@ fn format_reactions_summary(
 
     // Format as "👍2 ❤️1" etc.
     let mut parts: Vec<String> = Vec::new();
-    // Order: thumbs up, thumbs down, heart, hooray (matching picker order)
-    for emoji in &["👍", "👎", "❤️", "🎉", "😄", "😕", "🚀", "👀"] {
+    // Same order as the reaction picker
+    for emoji in crate::github::pull_request::ReactionType::all().map(|r| r.emoji()) {
         if let Some(&count) = counts.get(emoji) {
             parts.push(format!("{}{}", emoji, count));
         }
@@ -823,8 +1496,12 @@ fn render_pr_workflows_panel(frame: &mut Frame, area: Rect, app: &App) {
         app.pr_workflow_runs
             .iter()
             .map(|run| {
-                let (icon, icon_color) =
-                    workflow_status_display(run.status, run.conclusion, app.tick_counter);
+                let (icon, icon_color) = workflow_status_display(
+                    run.status,
+                    run.conclusion,
+                    app.tick_counter,
+                    &app.theme,
+                );
                 let text = format!(
                     " {} {} {}",
                     icon,
@@ -840,7 +1517,7 @@ fn render_pr_workflows_panel(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
 
     frame.render_widget(list, area);
@@ -896,31 +1573,31 @@ fn render_expanded_comment(frame: &mut Frame, app: &App) {
     let outer_block = Block::default()
         .title(" Comment ")
         .borders(Borders::ALL)
-        .border_style(Style::default().fg(Color::Yellow))
-        .style(Style::default().bg(Color::Black));
+        .border_style(app.theme.highlight())
+        .style(app.theme.popup());
     frame.render_widget(outer_block, popup_area);
 
     // Render header with author and time
     let mut header_lines: Vec<Line> = vec![Line::from(vec![
-        Span::styled("Author: ", Style::default().fg(Color::Cyan)),
+        Span::styled("Author: ", app.theme.accent()),
         Span::raw(format!("@{}", author)),
         Span::raw("  "),
-        Span::styled("Time: ", Style::default().fg(Color::Cyan)),
+        Span::styled("Time: ", app.theme.accent()),
         Span::raw(time),
     ])];
     if !reactions_str.is_empty() {
         header_lines.push(Line::from(vec![
-            Span::styled("Reactions: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Reactions: ", app.theme.accent()),
             Span::raw(reactions_str),
         ]));
     }
     header_lines.push(Line::from("─".repeat(chunks[0].width as usize)));
 
-    let header = Paragraph::new(header_lines).style(Style::default().bg(Color::Black));
+    let header = Paragraph::new(header_lines).style(app.theme.popup());
     frame.render_widget(header, chunks[0]);
 
     // Render markdown body with scroll support
-    let markdown_text = markdown_to_text(body);
+    let markdown_text = markdown_to_text(body, app.render_html, false, &app.theme);
     // Estimate wrapped line count (rough: chars / width * 1.5 for wrapping overhead)
     let total_chars: usize = markdown_text.lines.iter().map(|l| l.width()).sum();
     let estimated_lines =
@@ -931,7 +1608,7 @@ fn render_expanded_comment(frame: &mut Frame, app: &App) {
     let scroll = app.pr_comment_scroll.min(max_scroll);
 
     let body_paragraph = Paragraph::new(markdown_text)
-        .style(Style::default().bg(Color::Black))
+        .style(app.theme.popup())
         .wrap(Wrap { trim: false })
         .scroll((scroll as u16, 0));
     frame.render_widget(body_paragraph, chunks[1]);
@@ -941,15 +1618,15 @@ fn render_expanded_comment(frame: &mut Frame, app: &App) {
     if max_scroll > 0 {
         footer_lines.push(Line::from(Span::styled(
             format!("[{}/{}] j/k to scroll", scroll + 1, max_scroll + 1),
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted(),
         )));
     }
     footer_lines.push(Line::from(Span::styled(
-        "[e] Add reaction  [Esc] Close",
-        Style::default().fg(Color::DarkGray),
+        "[e] Toggle reaction  [g] Go to #ref  [Esc] Close",
+        app.theme.muted(),
     )));
 
-    let footer = Paragraph::new(footer_lines).style(Style::default().bg(Color::Black));
+    let footer = Paragraph::new(footer_lines).style(app.theme.popup());
     frame.render_widget(footer, chunks[2]);
 }
 
@@ -982,9 +1659,11 @@ fn render_expanded_description(frame: &mut Frame, app: &App) {
         .unwrap_or("unknown");
     let body = pr.body.as_deref().unwrap_or("(no description)");
 
+    let reactions_str = summarize_reactions(&app.pr_reactions);
+
     // Split popup into header, body, and footer
     let inner_area = popup_area.inner(Margin::new(1, 1)); // Account for border
-    let header_height = 3;
+    let header_height = if reactions_str.is_empty() { 3 } else { 4 };
     let footer_height = 1;
     let body_height = inner_area
         .height
@@ -1004,27 +1683,38 @@ fn render_expanded_description(frame: &mut Frame, app: &App) {
         .title(" PR Description ")
         .borders(Borders::ALL)
         .border_style(Style::default().fg(Color::Green))
-        .style(Style::default().bg(Color::Black));
+        .style(app.theme.popup());
     frame.render_widget(outer_block, popup_area);
 
     // Render header with title and author
-    let header_lines: Vec<Line> = vec![
+    let mut header_lines: Vec<Line> = vec![
         Line::from(vec![
-            Span::styled("Title: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Title: ", app.theme.accent()),
             Span::raw(title),
         ]),
         Line::from(vec![
-            Span::styled("Author: ", Style::default().fg(Color::Cyan)),
+            Span::styled("Author: ", app.theme.accent()),
             Span::raw(format!("@{}", author)),
         ]),
-        Line::from("─".repeat(chunks[0].width as usize)),
     ];
+    if !reactions_str.is_empty() {
+        header_lines.push(Line::from(vec![
+            Span::styled("Reactions: ", app.theme.accent()),
+            Span::raw(reactions_str),
+        ]));
+    }
+    header_lines.push(Line::from("─".repeat(chunks[0].width as usize)));
 
-    let header = Paragraph::new(header_lines).style(Style::default().bg(Color::Black));
+    let header = Paragraph::new(header_lines).style(app.theme.popup());
     frame.render_widget(header, chunks[0]);
 
     // Render markdown body with scroll support
-    let markdown_text = markdown_to_text(body);
+    let markdown_text = markdown_to_text(
+        body,
+        app.render_html,
+        app.pr_description_details_expanded,
+        &app.theme,
+    );
     // Estimate wrapped line count (rough: chars / width for wrapping)
     let total_chars: usize = markdown_text.lines.iter().map(|l| l.width()).sum();
     let estimated_lines =
@@ -1035,27 +1725,34 @@ fn render_expanded_description(frame: &mut Frame, app: &App) {
     let scroll = app.pr_description_scroll.min(max_scroll);
 
     let body_paragraph = Paragraph::new(markdown_text)
-        .style(Style::default().bg(Color::Black))
+        .style(app.theme.popup())
         .wrap(Wrap { trim: false })
         .scroll((scroll as u16, 0));
     frame.render_widget(body_paragraph, chunks[1]);
 
     // Render footer with scroll indicator
+    let details_hint = if DETAILS_BLOCK_REGEX.is_match(body) {
+        if app.pr_description_details_expanded {
+            "[z] Collapse  "
+        } else {
+            "[z] Expand  "
+        }
+    } else {
+        ""
+    };
     let footer_text = if max_scroll > 0 {
         format!(
-            "[{}/{}] j/k to scroll  [Esc] Close",
+            "[{}/{}] j/k to scroll  [e] Add reaction  {}[Esc] Close",
             scroll + 1,
-            max_scroll + 1
+            max_scroll + 1,
+            details_hint
         )
     } else {
-        "[Esc] Close".to_string()
+        format!("[e] Add reaction  {}[Esc] Close", details_hint)
     };
 
-    let footer = Paragraph::new(Span::styled(
-        footer_text,
-        Style::default().fg(Color::DarkGray),
-    ))
-    .style(Style::default().bg(Color::Black));
+    let footer =
+        Paragraph::new(Span::styled(footer_text, app.theme.muted())).style(app.theme.popup());
     frame.render_widget(footer, chunks[2]);
 }
 
@@ -1067,7 +1764,7 @@ fn render_reaction_picker(frame: &mut Frame, app: &App) {
 
     // Small centered popup for reaction picker
     let popup_width = 36_u16;
-    let popup_height = 5_u16;
+    let popup_height = 6_u16;
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -1077,28 +1774,33 @@ fn render_reaction_picker(frame: &mut Frame, app: &App) {
     frame.render_widget(Clear, popup_area);
 
     // Build reaction options with selection highlighting
+    // Two rows of four
     let reactions = ReactionType::all();
-    let mut spans: Vec<Span> = Vec::new();
+    let mut lines = vec![Line::from("")];
 
-    for (i, reaction) in reactions.iter().enumerate() {
-        let label = format!(" [{}] {} ", i + 1, reaction.emoji());
-        let style = if i == app.reaction_picker_selection {
-            Style::default().bg(Color::Yellow).fg(Color::Black)
-        } else {
-            Style::default()
-        };
-        spans.push(Span::styled(label, style));
+    for (row, chunk) in reactions.chunks(4).enumerate() {
+        let spans: Vec<Span> = chunk
+            .iter()
+            .enumerate()
+            .map(|(col, reaction)| {
+                let i = row * 4 + col;
+                let label = format!(" [{}] {} ", i + 1, reaction.emoji());
+                let style = if i == app.reaction_picker_selection {
+                    Style::default().bg(app.theme.highlight).fg(Color::Black)
+                } else {
+                    Style::default()
+                };
+                Span::styled(label, style)
+            })
+            .collect();
+        lines.push(Line::from(spans));
     }
 
-    let lines = vec![
-        Line::from(""),
-        Line::from(spans),
-        Line::from(""),
-        Line::from(Span::styled(
-            "  [1-4] Select  [Esc] Cancel",
-            Style::default().fg(Color::DarkGray),
-        )),
-    ];
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        "  [1-8] Select  [Esc] Cancel",
+        app.theme.muted(),
+    )));
 
     let paragraph = Paragraph::new(lines)
         .block(
@@ -1107,12 +1809,259 @@ fn render_reaction_picker(frame: &mut Frame, app: &App) {
                 .borders(Borders::ALL)
                 .border_style(Style::default().fg(Color::Magenta)),
         )
-        .style(Style::default().bg(Color::Black))
+        .style(app.theme.popup())
         .alignment(ratatui::layout::Alignment::Center);
 
     frame.render_widget(paragraph, popup_area);
 }
 
+/// Render the base branch picker overlay
+fn render_base_picker(frame: &mut Frame, app: &App) {
+    let pr = match &app.selected_pr {
+        Some(pr) => pr,
+        None => return,
+    };
+
+    let area = frame.area();
+
+    const MAX_VISIBLE: usize = 10;
+    let visible = app.base_picker_branches.len().clamp(1, MAX_VISIBLE);
+
+    let popup_width = 48_u16;
+    let popup_height = visible as u16 + 6;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = vec![
+        Line::from(vec![
+            Span::styled("  Current base: ", app.theme.accent()),
+            Span::raw(pr.base.ref_field.clone()),
+        ]),
+        Line::from(""),
+    ];
+
+    if app.base_picker_loading && app.base_picker_branches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Loading branches...",
+            app.theme.highlight(),
+        )));
+    } else if app.base_picker_branches.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No other branches available",
+            app.theme.muted(),
+        )));
+    } else {
+        // Keep the selection in view
+        let selected = app.base_picker_selection.selected;
+        let start = selected.saturating_sub(MAX_VISIBLE - 1);
+        let inner_width = popup_width.saturating_sub(8) as usize;
+
+        for (i, name) in app
+            .base_picker_branches
+            .iter()
+            .enumerate()
+            .skip(start)
+            .take(MAX_VISIBLE)
+        {
+            let (prefix, style) = if i == selected {
+                ("  > ", app.theme.highlight().add_modifier(Modifier::BOLD))
+            } else {
+                ("    ", Style::default())
+            };
+            lines.push(Line::from(Span::styled(
+                format!("{}{}", prefix, truncate(name, inner_width)),
+                style,
+            )));
+        }
+    }
+
+    lines.push(Line::from(""));
+    let footer = if app.base_picker_loading && !app.base_picker_branches.is_empty() {
+        Span::styled("  Changing base...", app.theme.highlight())
+    } else {
+        Span::styled("  [Enter] Retarget  [Esc] Cancel", app.theme.muted())
+    };
+    lines.push(Line::from(footer));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Change Base of PR #{} ", pr.number))
+            .borders(Borders::ALL)
+            .border_style(app.theme.accent()),
+    );
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Render the review dialog (verdict picker and optional body)
+fn render_review_dialog(frame: &mut Frame, app: &App, pr_number: u64) {
+    use crate::github::ReviewEvent;
+
+    let area = frame.area();
+
+    let popup_width = 60_u16.min(area.width);
+    let popup_height = 12_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let mut lines: Vec<Line> = vec![Line::from("")];
+
+    for (i, event) in ReviewEvent::all().iter().enumerate() {
+        let (prefix, style) = if i == app.review_event_selection {
+            ("  > ", app.theme.highlight().add_modifier(Modifier::BOLD))
+        } else {
+            ("    ", Style::default())
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{}{}", prefix, event.label()),
+            style,
+        )));
+    }
+
+    lines.push(Line::from(""));
+
+    let event = ReviewEvent::all()[app.review_event_selection];
+    let label = if event.requires_body() {
+        "  Comment: "
+    } else {
+        "  Comment (optional): "
+    };
+    // Show the end of long bodies so the cursor stays visible
+    let max_body = (popup_width as usize).saturating_sub(label.len() + 4);
+    let body_chars = app.review_body.chars().count();
+    let body: String = app
+        .review_body
+        .chars()
+        .skip(body_chars.saturating_sub(max_body))
+        .collect();
+    lines.push(Line::from(vec![
+        Span::styled(label, app.theme.accent()),
+        Span::styled(body, app.theme.highlight()),
+        Span::styled("█", app.theme.highlight()),
+    ]));
+
+    lines.push(Line::from(""));
+    let footer = if app.review_submitting {
+        Span::styled("  Submitting review...", app.theme.highlight())
+    } else {
+        Span::styled(
+            "  [Tab] Verdict  [Enter] Submit  [Esc] Cancel",
+            app.theme.muted(),
+        )
+    };
+    lines.push(Line::from(footer));
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(format!(" Review PR #{} ", pr_number))
+            .borders(Borders::ALL)
+            .border_style(app.theme.accent()),
+    );
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Render the PR edit form overlay (title and body)
+fn render_pr_edit(frame: &mut Frame, app: &App, pr_number: u64) {
+    let area = frame.area();
+    let popup_width = (area.width * 80 / 100).max(40).min(area.width);
+    let popup_height = (area.height * 80 / 100).max(12).min(area.height);
+    let popup_area = Rect::new(
+        area.x + (area.width.saturating_sub(popup_width)) / 2,
+        area.y + (area.height.saturating_sub(popup_height)) / 2,
+        popup_width,
+        popup_height,
+    );
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let block = Block::default()
+        .title(format!(" Edit PR #{} ", pr_number))
+        .borders(Borders::ALL)
+        .border_style(app.theme.accent());
+    let inner = block.inner(popup_area);
+    frame.render_widget(block, popup_area);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(3), // Title
+            Constraint::Min(3),    // Body
+            Constraint::Length(1), // Help
+        ])
+        .split(inner);
+
+    let focused = |field: usize| {
+        if app.pr_edit_field == field {
+            app.theme.highlight()
+        } else {
+            app.theme.normal()
+        }
+    };
+
+    // Title field
+    let title_block = Block::default()
+        .title(" Title ")
+        .borders(Borders::ALL)
+        .border_style(focused(0));
+    frame.render_widget(
+        Paragraph::new(app.pr_edit_title.as_str()).block(title_block),
+        chunks[0],
+    );
+
+    // Body field, scrolled so the cursor row stays visible
+    let (row, col) = app.pr_edit_body_cursor;
+    let body_height = chunks[1].height.saturating_sub(2) as usize;
+    let scroll = (row + 1).saturating_sub(body_height);
+    let body_block = Block::default()
+        .title(" Description ")
+        .borders(Borders::ALL)
+        .border_style(focused(1));
+    frame.render_widget(
+        Paragraph::new(app.pr_edit_body.as_str())
+            .block(body_block)
+            .scroll((scroll as u16, 0)),
+        chunks[1],
+    );
+
+    let help = if app.pr_edit_submitting {
+        Span::styled(" Saving...", app.theme.highlight())
+    } else {
+        Span::styled(
+            " [Tab] Switch field  [Ctrl+e] Editor  [Ctrl+s] Save  [Esc] Cancel",
+            app.theme.muted(),
+        )
+    };
+    frame.render_widget(Paragraph::new(Line::from(help)), chunks[2]);
+
+    // Terminal cursor in the focused field
+    if !app.pr_edit_submitting {
+        let (x, y) = if app.pr_edit_field == 0 {
+            (
+                chunks[0].x + 1 + app.pr_edit_title.chars().count() as u16,
+                chunks[0].y + 1,
+            )
+        } else {
+            (
+                chunks[1].x + 1 + col as u16,
+                chunks[1].y + 1 + row.saturating_sub(scroll) as u16,
+            )
+        };
+        frame.set_cursor_position((x, y));
+    }
+}
+
 /// Render the merge dialog overlay
 fn render_merge_dialog(frame: &mut Frame, app: &App) {
     let pr = match &app.selected_pr {
@@ -1122,9 +2071,24 @@ fn render_merge_dialog(frame: &mut Frame, app: &App) {
 
     let area = frame.area();
 
+    // Merge and squash merges get commit title/message fields; rebase has no merge commit
+    let is_squash = app.merge_method_selection == 1;
+    let has_commit_fields = app.merge_method_selection != 2;
+    const MESSAGE_LINES: usize = 4;
+
+    // Failing or pending checks add a warning section
+    let unsettled_checks = app
+        .pr_combined_status
+        .as_ref()
+        .filter(|status| status.is_unsettled());
+
     // Centered popup
-    let popup_width = 48_u16;
-    let popup_height = 13_u16;
+    let popup_width = if has_commit_fields { 76_u16 } else { 48_u16 };
+    let popup_height = if has_commit_fields {
+        13 + 3 + MESSAGE_LINES as u16
+    } else {
+        13_u16
+    } + if unsettled_checks.is_some() { 4 } else { 0 };
     let popup_x = (area.width.saturating_sub(popup_width)) / 2;
     let popup_y = (area.height.saturating_sub(popup_height)) / 2;
 
@@ -1140,7 +2104,7 @@ fn render_merge_dialog(frame: &mut Frame, app: &App) {
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "  Select merge method:",
-        Style::default().fg(Color::Cyan),
+        app.theme.accent(),
     )));
     lines.push(Line::from(""));
 
@@ -1151,9 +2115,7 @@ fn render_merge_dialog(frame: &mut Frame, app: &App) {
             "    "
         };
         let style = if i == app.merge_method_selection {
-            Style::default()
-                .fg(Color::Yellow)
-                .add_modifier(Modifier::BOLD)
+            app.theme.highlight().add_modifier(Modifier::BOLD)
         } else {
             Style::default()
         };
@@ -1177,6 +2139,107 @@ fn render_merge_dialog(frame: &mut Frame, app: &App) {
         Span::raw(" Delete branch after merge"),
     ]));
 
+    if let Some(status) = unsettled_checks {
+        lines.push(Line::from(""));
+        lines.push(Line::from(Span::styled(
+            format!("  ⚠ Checks {}", status.summary()),
+            Style::default().fg(Color::Red).add_modifier(Modifier::BOLD),
+        )));
+        let key = app.merge_confirm_key();
+        let confirm = if app.merge_force_confirmed {
+            format!("  Press {} again to merge anyway", key)
+        } else {
+            format!("  {} twice to merge anyway", key)
+        };
+        lines.push(Line::from(Span::styled(confirm, app.theme.highlight())));
+        lines.push(Line::from(Span::styled(
+            "  [a] Merge automatically once requirements pass",
+            app.theme.highlight(),
+        )));
+    }
+
+    if has_commit_fields {
+        let field_style = |field: usize| {
+            if app.merge_dialog_field == field {
+                app.theme.highlight()
+            } else {
+                Style::default()
+            }
+        };
+        let field_width = popup_width.saturating_sub(8) as usize;
+
+        lines.push(Line::from(""));
+        let title_cursor = if app.merge_dialog_field == 1 {
+            "█"
+        } else {
+            ""
+        };
+        let title = if app.merge_commit_title.is_empty() && app.merge_dialog_field != 1 {
+            Span::styled("GitHub default", app.theme.muted())
+        } else {
+            // Keep the end of long titles (and the cursor) in view
+            let chars = app.merge_commit_title.chars().count();
+            let visible: String = app
+                .merge_commit_title
+                .chars()
+                .skip(chars.saturating_sub(field_width.saturating_sub(10)))
+                .collect();
+            Span::styled(visible, field_style(1))
+        };
+        lines.push(Line::from(vec![
+            Span::styled("  Title:   ", app.theme.accent()),
+            title,
+            Span::styled(title_cursor, app.theme.highlight()),
+        ]));
+
+        lines.push(Line::from(Span::styled("  Message:", app.theme.accent())));
+
+        let message: Vec<Line> = if app.merge_ai_loading {
+            vec![Line::from(Span::styled(
+                "    Generating with AI... (Esc to cancel)",
+                app.theme.highlight(),
+            ))]
+        } else if app.merge_commit_message.is_empty() && app.merge_dialog_field != 2 {
+            let default = if is_squash {
+                "    GitHub default (concatenated commits)"
+            } else {
+                "    GitHub default (PR title)"
+            };
+            vec![Line::from(Span::styled(default, app.theme.muted()))]
+        } else {
+            // Scroll so the cursor row stays visible
+            let (row, col) = app.merge_commit_message_cursor;
+            let scroll = (row + 1).saturating_sub(MESSAGE_LINES);
+            split_lines_preserve_trailing(&app.merge_commit_message)
+                .into_iter()
+                .enumerate()
+                .skip(scroll)
+                .take(MESSAGE_LINES)
+                .map(|(i, line)| {
+                    if app.merge_dialog_field == 2 && i == row {
+                        let (before, after) = line.split_at(text_input::byte_offset(line, col));
+                        Line::from(vec![
+                            Span::raw("    "),
+                            Span::styled(truncate(before, field_width), field_style(2)),
+                            Span::styled("█", app.theme.highlight()),
+                            Span::styled(after.to_string(), field_style(2)),
+                        ])
+                    } else {
+                        Line::from(Span::styled(
+                            format!("    {}", truncate(line, field_width)),
+                            field_style(2),
+                        ))
+                    }
+                })
+                .collect()
+        };
+        let message_len = message.len();
+        lines.extend(message);
+        for _ in message_len..MESSAGE_LINES {
+            lines.push(Line::from(""));
+        }
+    }
+
     lines.push(Line::from(""));
     lines.push(Line::from(
         "─".repeat(popup_width.saturating_sub(2) as usize),
@@ -1184,11 +2247,26 @@ fn render_merge_dialog(frame: &mut Frame, app: &App) {
 
     // Footer with loading state or actions
     let footer_text = if app.merge_in_progress {
-        Span::styled("  Merging...", Style::default().fg(Color::Yellow))
+        Span::styled("  Merging...", app.theme.highlight())
+    } else if app.merge_dialog_field != 0 {
+        Span::styled(
+            "  [Tab] Next field  [Ctrl+s] Merge  [Esc] Cancel",
+            app.theme.muted(),
+        )
+    } else if is_squash && app.ai_configured {
+        Span::styled(
+            "  [Enter] Merge  [Tab] Edit  [d] Delete  [g] AI  [x] Clear  [Esc] Cancel",
+            app.theme.muted(),
+        )
+    } else if has_commit_fields {
+        Span::styled(
+            "  [Enter] Merge  [Tab] Edit  [d] Toggle delete  [x] Clear  [Esc] Cancel",
+            app.theme.muted(),
+        )
     } else {
         Span::styled(
             "  [Enter] Merge  [d] Toggle delete  [Esc] Cancel",
-            Style::default().fg(Color::DarkGray),
+            app.theme.muted(),
         )
     };
     lines.push(Line::from(footer_text));
@@ -1240,17 +2318,18 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
             Constraint::Length(8), // Branches (side by side)
             Constraint::Min(5),    // Body
             Constraint::Length(3), // Draft + Submit
+            Constraint::Length(3), // Reviewers + Assignees
         ])
         .split(chunks[0]);
 
     // Title field (field 0)
     let title_style = if app.pr_create_field == 0 {
-        Style::default().fg(Color::Yellow)
+        app.theme.highlight()
     } else {
-        Theme::normal()
+        app.theme.normal()
     };
     let title_text = if app.pr_create_title.is_empty() && app.pr_create_field != 0 {
-        Span::styled("Enter PR title...", Style::default().fg(Color::DarkGray))
+        Span::styled("Enter PR title...", app.theme.muted())
     } else {
         Span::raw(&app.pr_create_title)
     };
@@ -1261,7 +2340,8 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
     let title_paragraph = Paragraph::new(title_text).block(title_block);
     frame.render_widget(title_paragraph, form_chunks[0]);
 
-    // Branch selectors (side by side)
+    // Branch selectors (side by side), flagged red when head and base are identical
+    let branches_conflict = app.pr_create_branches_conflict();
     let branch_chunks = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -1277,6 +2357,8 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
         app.pr_create_head_selection.selected,
         app.pr_create_field == 1,
         app.pr_create_loading,
+        branches_conflict,
+        &app.theme,
     );
 
     // Base branch (field 2)
@@ -1289,6 +2371,8 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
         app.pr_create_base_selection.selected,
         app.pr_create_field == 2,
         app.pr_create_loading,
+        branches_conflict,
+        &app.theme,
     );
 
     // Split body area into description and commits panels
@@ -1299,32 +2383,84 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
 
     // Body/Description field (field 3) - left panel
     let body_style = if app.pr_create_field == 3 {
-        Style::default().fg(Color::Yellow)
+        app.theme.highlight()
     } else {
-        Theme::normal()
+        app.theme.normal()
     };
+    let body_area = body_commits_chunks[0];
+    let body_width = body_area.width.saturating_sub(2) as usize;
+    app.pr_create_body_width.set(body_width);
+
+    // Word-wrap the body ourselves so displayed rows match cursor movement
+    let (cursor_row, cursor_col) = app.pr_create_body_cursor;
+    let mut body_rows: Vec<String> = Vec::new();
+    let mut cursor_pos = (0, 0);
+    for (i, line) in split_lines_preserve_trailing(&app.pr_create_body)
+        .into_iter()
+        .enumerate()
+    {
+        let ranges = text_input::wrap_ranges(line, body_width);
+        if i == cursor_row {
+            let (row, x) =
+                text_input::visual_position(line, &ranges, cursor_col.min(line.chars().count()));
+            cursor_pos = (body_rows.len() + row, x);
+        }
+        body_rows.extend(
+            ranges
+                .iter()
+                .map(|&(start, end)| line.chars().skip(start).take(end - start).collect()),
+        );
+    }
+    let body_height = body_area.height.saturating_sub(2) as usize;
+    let body_scroll = (cursor_pos.0 + 1).saturating_sub(body_height);
+
     let body_text = if app.pr_create_body.is_empty() && app.pr_create_field != 3 {
-        "Enter PR description (optional)..."
+        Text::raw("Enter PR description (optional)...")
     } else {
-        &app.pr_create_body
+        Text::from(body_rows.into_iter().map(Line::from).collect::<Vec<_>>())
     };
     let body_block = Block::default()
-        .title(" Description ")
+        .title(if app.pr_create_body_preview {
+            " Description (preview) "
+        } else {
+            " Description "
+        })
         .borders(Borders::ALL)
         .border_style(body_style);
-    let body_paragraph = Paragraph::new(body_text).block(body_block).style(
-        if app.pr_create_body.is_empty() && app.pr_create_field != 3 {
-            Style::default().fg(Color::DarkGray)
-        } else {
-            Style::default()
-        },
-    );
-    frame.render_widget(body_paragraph, body_commits_chunks[0]);
+    let body_paragraph = if app.pr_create_body_preview {
+        Paragraph::new(markdown_to_text(
+            &app.pr_create_body,
+            app.render_html,
+            false,
+            &app.theme,
+        ))
+        .block(body_block)
+        .wrap(Wrap { trim: false })
+    } else {
+        Paragraph::new(body_text)
+            .block(body_block)
+            .scroll((body_scroll as u16, 0))
+            .style(
+                if app.pr_create_body.is_empty() && app.pr_create_field != 3 {
+                    app.theme.muted()
+                } else {
+                    Style::default()
+                },
+            )
+    };
+    frame.render_widget(body_paragraph, body_area);
+
+    // Terminal cursor while editing the body
+    if app.pr_create_field == 3 && !app.pr_create_body_preview && !app.pr_create_feedback_open {
+        frame.set_cursor_position((
+            body_area.x + 1 + cursor_pos.1.min(body_width.saturating_sub(1)) as u16,
+            body_area.y + 1 + (cursor_pos.0 - body_scroll) as u16,
+        ));
+    }
 
     // Commits list - right panel
     let commits_items: Vec<ListItem> = if app.pr_create_commits.is_empty() {
-        vec![ListItem::new("  No commits between branches")
-            .style(Style::default().fg(Color::DarkGray))]
+        vec![ListItem::new("  No commits between branches").style(app.theme.muted())]
     } else {
         app.pr_create_commits
             .iter()
@@ -1336,7 +2472,7 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(format!(" Commits ({}) ", app.pr_create_commits.len()))
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
     frame.render_widget(commits_list, body_commits_chunks[1]);
 
@@ -1348,9 +2484,9 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
 
     // Draft toggle
     let draft_style = if app.pr_create_field == 4 {
-        Style::default().fg(Color::Yellow)
+        app.theme.highlight()
     } else {
-        Theme::normal()
+        app.theme.normal()
     };
     let draft_indicator = if app.pr_create_draft { "[x]" } else { "[ ]" };
     let draft_block = Block::default()
@@ -1367,26 +2503,70 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
             .fg(Color::Green)
             .add_modifier(ratatui::style::Modifier::BOLD)
     } else {
-        Theme::normal()
+        app.theme.normal()
     };
     let submit_text = if app.pr_create_submitting {
         " Creating PR..."
     } else {
         " [ Create Pull Request ]"
     };
-    let submit_block = Block::default()
+    let mut submit_block = Block::default()
         .borders(Borders::ALL)
         .border_style(submit_style);
+    if let Some(warning) = app.pr_create_branch_warning() {
+        let warning_color = if branches_conflict {
+            Color::Red
+        } else {
+            app.theme.highlight
+        };
+        submit_block = submit_block.title(Span::styled(
+            format!(" {} ", warning),
+            Style::default().fg(warning_color),
+        ));
+    }
     let submit_paragraph = Paragraph::new(submit_text)
         .block(submit_block)
         .alignment(Alignment::Center);
     frame.render_widget(submit_paragraph, bottom_chunks[1]);
 
-    // Show AI loading indicator or error
-    if app.pr_create_ai_loading {
+    // Reviewers (field 6) and assignees (field 7), side by side
+    let people_chunks = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(form_chunks[4]);
+    for (field, title, value, chunk) in [
+        (6, " Reviewers ", &app.pr_create_reviewers, people_chunks[0]),
+        (7, " Assignees ", &app.pr_create_assignees, people_chunks[1]),
+    ] {
+        let style = if app.pr_create_field == field {
+            app.theme.highlight()
+        } else {
+            app.theme.normal()
+        };
+        let text = if value.is_empty() && app.pr_create_field != field {
+            Span::styled("Comma-separated usernames (optional)...", app.theme.muted())
+        } else {
+            Span::raw(value.as_str())
+        };
+        let block = Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(style);
+        frame.render_widget(Paragraph::new(text).block(block), chunk);
+    }
+
+    // Show AI feedback prompt, loading indicator or error
+    if app.pr_create_feedback_open {
+        let prompt_area = Rect::new(area.x + 2, area.y + area.height - 3, area.width - 4, 1);
+        let prompt = Paragraph::new(Line::from(vec![
+            Span::styled("AI feedback: ", app.theme.accent()),
+            Span::raw(format!("{}▌", app.pr_create_ai_feedback)),
+        ]));
+        frame.render_widget(prompt, prompt_area);
+    } else if app.pr_create_ai_loading {
         let loading_area = Rect::new(area.x + 2, area.y + area.height - 3, area.width - 4, 1);
         let loading_text =
-            Paragraph::new("Generating with AI...").style(Style::default().fg(Color::Yellow));
+            Paragraph::new("Generating with AI... (Esc to cancel)").style(app.theme.highlight());
         frame.render_widget(loading_text, loading_area);
     } else if let Some(error) = &app.pr_create_error {
         let error_area = Rect::new(area.x + 2, area.y + area.height - 3, area.width - 4, 1);
@@ -1396,12 +2576,18 @@ fn render_pr_create(frame: &mut Frame, area: Rect, app: &App) {
     }
 
     // Help bar with AI hint if configured
-    let help_text = if app.gemini_configured {
-        " [Tab] Next  [Enter] Select  [Ctrl+g] AI Generate  [Esc] Cancel"
+    let help_text = if app.pr_create_feedback_open {
+        " [Enter] Regenerate with feedback  [Esc] Cancel"
+    } else if app.pr_create_body_preview && app.pr_create_field == 3 {
+        " [Tab] Next  [Ctrl+p] Edit  [Esc] Cancel"
+    } else if app.pr_create_field == 3 {
+        " [Tab] Next  [Enter] Newline  [Ctrl+p] Preview  [Ctrl+e] Open in editor  [Esc] Cancel"
+    } else if app.ai_configured {
+        " [Tab] Next  [Enter] Select  [Ctrl+g] AI Generate  [Ctrl+f] AI Feedback  [Esc] Cancel"
     } else {
         " [Tab] Next field  [Shift+Tab] Previous  [Enter] Select/Submit  [Esc] Cancel"
     };
-    let help = Paragraph::new(help_text).style(Theme::muted());
+    let help = Paragraph::new(help_text).style(app.theme.muted());
     frame.render_widget(help, chunks[1]);
 }
 
@@ -1416,11 +2602,15 @@ fn render_branch_selector(
     selection_index: usize,
     is_focused: bool,
     is_loading: bool,
+    is_invalid: bool,
+    theme: &Theme,
 ) {
-    let style = if is_focused {
-        Style::default().fg(Color::Yellow)
+    let style = if is_invalid {
+        Style::default().fg(Color::Red)
+    } else if is_focused {
+        theme.highlight()
     } else {
-        Theme::normal()
+        Style::default()
     };
 
     let block = Block::default()
@@ -1431,7 +2621,7 @@ fn render_branch_selector(
     if is_loading {
         let loading = Paragraph::new("  Loading branches...")
             .block(block)
-            .style(Style::default().fg(Color::DarkGray));
+            .style(theme.muted());
         frame.render_widget(loading, area);
         return;
     }
@@ -1455,8 +2645,8 @@ fn render_branch_selector(
                 let prefix = if i == selection_index { "› " } else { "  " };
                 let suffix = if branch.is_default { " (default)" } else { "" };
                 let style = if i == selection_index {
-                    Style::default()
-                        .fg(Color::Yellow)
+                    theme
+                        .highlight()
                         .add_modifier(ratatui::style::Modifier::BOLD)
                 } else if branch.name == selected_branch {
                     Style::default().fg(Color::Green)
@@ -1500,15 +2690,13 @@ fn build_grouped_file_items(app: &App) -> Vec<ListItem<'static>> {
         );
 
         let header_style = if is_group_selected {
-            Theme::selected()
+            app.theme.selected()
         } else if group.all_staged() {
             Style::default()
                 .fg(Color::Green)
                 .add_modifier(Modifier::BOLD)
         } else {
-            Style::default()
-                .fg(Color::Cyan)
-                .add_modifier(Modifier::BOLD)
+            app.theme.accent().add_modifier(Modifier::BOLD)
         };
 
         items.push(ListItem::new(header_text).style(header_style));
@@ -1519,7 +2707,11 @@ fn build_grouped_file_items(app: &App) -> Vec<ListItem<'static>> {
                 let is_file_selected = app.selected_group_idx == group_idx
                     && app.selected_file_in_group == Some(file_idx);
 
-                let checkbox = if file.is_staged { "[✓]" } else { "[ ]" };
+                let checkbox = if file.is_staged {
+                    format!("[{}]", app.theme.check_mark())
+                } else {
+                    "[ ]".to_string()
+                };
                 let status = file.status_char();
                 // Show just the filename, not the full path (since directory is in header)
                 let filename = std::path::Path::new(&file.path)
@@ -1529,11 +2721,11 @@ fn build_grouped_file_items(app: &App) -> Vec<ListItem<'static>> {
                 let file_text = format!("   {} {} {}", checkbox, status, filename);
 
                 let file_style = if is_file_selected {
-                    Theme::selected()
+                    app.theme.selected()
                 } else if file.is_staged {
                     Style::default().fg(Color::Green)
                 } else if file.is_new {
-                    Style::default().fg(Color::Yellow)
+                    app.theme.highlight()
                 } else if file.is_deleted {
                     Style::default().fg(Color::Red)
                 } else {
@@ -1550,12 +2742,21 @@ fn build_grouped_file_items(app: &App) -> Vec<ListItem<'static>> {
 
 /// Render the commit screen
 fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
+    // Message box grows for multi-line messages and template hints
+    let message_height =
+        if app.commit_message_mode && (!app.commit_ai_loading || app.commit_ai_streaming) {
+            let lines = app.commit_message.lines().count().max(1) + app.commit_template_hints.len();
+            lines.min(10) as u16 + 2
+        } else {
+            3
+        };
+
     // Split into file list, optional message input/push prompt, and help bar
     let constraints = if app.commit_message_mode || app.commit_push_prompt {
         vec![
-            Constraint::Min(0),    // File list
-            Constraint::Length(3), // Message input box or push prompt
-            Constraint::Length(1), // Help bar
+            Constraint::Min(0),                 // File list
+            Constraint::Length(message_height), // Message input box or push prompt
+            Constraint::Length(1),              // Help bar
         ]
     } else {
         vec![
@@ -1569,7 +2770,56 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
         .constraints(constraints)
         .split(area);
 
-    if app.changed_files.is_empty() {
+    // Unresolved conflicts get a read-only panel above the file list
+    let list_area = if app.commit_conflicts.is_empty() {
+        chunks[0]
+    } else {
+        const MAX_CONFLICT_LINES: usize = 6;
+        let shown = app.commit_conflicts.len().min(MAX_CONFLICT_LINES);
+        let panel_height = shown as u16 + 4;
+        let split = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Length(panel_height), Constraint::Min(0)])
+            .split(chunks[0]);
+
+        let mut lines: Vec<Line> = app
+            .commit_conflicts
+            .iter()
+            .take(shown)
+            .map(|path| {
+                Line::from(Span::styled(
+                    format!("  ✗ {}", path),
+                    Style::default().fg(Color::Red),
+                ))
+            })
+            .collect();
+        if app.commit_conflicts.len() > shown {
+            lines.push(Line::from(Span::styled(
+                format!("  … and {} more", app.commit_conflicts.len() - shown),
+                app.theme.muted(),
+            )));
+        }
+        lines.push(Line::from(Span::styled(
+            "  Resolve conflicts in your editor, stage the files, then commit.",
+            app.theme.muted(),
+        )));
+
+        let panel = Paragraph::new(lines).block(
+            Block::default()
+                .title(format!(
+                    " Merge Conflicts ({}) ",
+                    app.commit_conflicts.len()
+                ))
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        );
+        frame.render_widget(panel, split[0]);
+        split[1]
+    };
+
+    if let Some(path) = &app.commit_hunk_path {
+        render_commit_hunks(frame, list_area, app, path);
+    } else if app.changed_files.is_empty() {
         let text = vec![
             Line::from(""),
             Line::from("  No changes to commit."),
@@ -1581,10 +2831,18 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
             Block::default()
                 .title(" Create Commit ")
                 .borders(Borders::ALL)
-                .border_style(Theme::normal()),
+                .border_style(app.theme.normal()),
         );
-        frame.render_widget(paragraph, chunks[0]);
+        frame.render_widget(paragraph, list_area);
     } else {
+        // File list on the left, diff of the selected file on the right
+        let halves = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .split(list_area);
+        let list_area = halves[0];
+        render_commit_diff_preview(frame, halves[1], app);
+
         // Count staged files
         let staged_count = app.changed_files.iter().filter(|f| f.is_staged).count();
 
@@ -1592,7 +2850,7 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
         let items: Vec<ListItem> = build_grouped_file_items(app);
 
         // Calculate viewport height (inner area minus borders)
-        let inner_height = chunks[0].height.saturating_sub(2) as usize;
+        let inner_height = list_area.height.saturating_sub(2) as usize;
         app.commit_viewport_height.set(inner_height);
 
         // Apply scroll offset - show only visible items
@@ -1614,33 +2872,49 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
                 Block::default()
                     .title(title)
                     .borders(Borders::ALL)
-                    .border_style(Theme::normal()),
+                    .border_style(app.theme.normal()),
             )
-            .highlight_style(Theme::selected());
+            .highlight_style(app.theme.selected());
 
-        frame.render_widget(list, chunks[0]);
+        frame.render_widget(list, list_area);
+        app.commit_files_area
+            .set(list_area.inner(Margin::new(1, 1)));
     }
 
     // Render message input box if in message mode
     if app.commit_message_mode {
         let message_area = chunks[1];
-        let display_text = if app.commit_ai_loading {
-            "Generating with AI...".to_string()
+        let display_text = if app.commit_ai_loading && !app.commit_ai_streaming {
+            Text::raw("Generating with AI... (Esc to cancel)")
         } else {
-            format!("{}▌", &app.commit_message) // Show cursor
+            let mut text = Text::raw(format!("{}▌", &app.commit_message)); // Show cursor
+            for hint in &app.commit_template_hints {
+                text.lines.push(Line::from(Span::styled(
+                    format!("# {}", hint),
+                    app.theme.muted(),
+                )));
+            }
+            text
         };
 
         let input_style = if app.commit_ai_loading {
-            Style::default().fg(Color::Yellow)
+            app.theme.highlight()
         } else {
             Style::default().fg(Color::White)
         };
 
+        // Amending rewrites the last commit, so make the mode stand out
+        let (title, border_color) = if app.commit_amend_mode {
+            (" Amend Last Commit ", app.theme.highlight)
+        } else {
+            (" Commit Message ", app.theme.accent)
+        };
+
         let input = Paragraph::new(display_text).style(input_style).block(
             Block::default()
-                .title(" Commit Message ")
+                .title(title)
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Cyan)),
+                .border_style(Style::default().fg(border_color)),
         );
         frame.render_widget(input, message_area);
     }
@@ -1654,7 +2928,7 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
                 let tracking = app.commit_tracking_branch.as_deref().unwrap_or("origin");
 
                 let (display_text, border_color) = if app.commit_push_loading {
-                    (format!("Pushing to {}...", tracking), Color::Yellow)
+                    (format!("Pushing to {}...", tracking), app.theme.highlight)
                 } else {
                     let hash = app
                         .last_commit_hash
@@ -1669,7 +2943,7 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
 
                 let prompt = Paragraph::new(display_text)
                     .style(Style::default().fg(if app.commit_push_loading {
-                        Color::Yellow
+                        app.theme.highlight
                     } else {
                         Color::Green
                     }))
@@ -1684,12 +2958,12 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
             crate::tui::app::PushMode::BranchSelect => {
                 if app.push_branches_loading {
                     let loading = Paragraph::new("  Loading branches...")
-                        .style(Style::default().fg(Color::Yellow))
+                        .style(app.theme.highlight())
                         .block(
                             Block::default()
                                 .title(" Select Branch ")
                                 .borders(Borders::ALL)
-                                .border_style(Style::default().fg(Color::Yellow)),
+                                .border_style(app.theme.highlight()),
                         );
                     frame.render_widget(loading, prompt_area);
                 } else {
@@ -1715,12 +2989,12 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
                     };
 
                     let prompt = Paragraph::new(display_text)
-                        .style(Style::default().fg(Color::Cyan))
+                        .style(app.theme.accent())
                         .block(
                             Block::default()
                                 .title(" Select Branch ")
                                 .borders(Borders::ALL)
-                                .border_style(Style::default().fg(Color::Cyan)),
+                                .border_style(app.theme.accent()),
                         );
                     frame.render_widget(prompt, prompt_area);
                 }
@@ -1747,7 +3021,11 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         chunks[1]
     };
-    let help_text = if app.commit_push_prompt {
+    let help_text = if app.commit_diff_focused {
+        " [j/k] Scroll diff  [PgUp/PgDn] Page  [Tab] Back to files  [Esc] Back"
+    } else if app.commit_hunk_path.is_some() {
+        " [Space] Stage/unstage hunk  [j/k] Navigate  [r] Refresh  [Esc/h] Back to files"
+    } else if app.commit_push_prompt {
         if app.commit_push_loading || app.push_branches_loading {
             "" // No help text during loading
         } else {
@@ -1758,24 +3036,136 @@ fn render_commit_screen(frame: &mut Frame, area: Rect, app: &App) {
                 crate::tui::app::PushMode::BranchSelect => {
                     " [j/k] Navigate  [Enter] Push  [Esc] Back"
                 }
-                crate::tui::app::PushMode::NewBranch => " [Enter] Create & Push  [Esc] Back",
+                crate::tui::app::PushMode::NewBranch => {
+                    " [Enter] Create & Push  [Ctrl+g] AI Suggest  [Esc] Back"
+                }
             }
         }
+    } else if app.commit_amend_mode {
+        " [Enter] Amend  [Esc] Cancel  [Ctrl+e] Editor"
     } else if app.commit_message_mode {
-        " [Enter] Commit  [Esc] Cancel  [Ctrl+g] Regenerate AI"
+        " [Enter] Commit  [Esc] Cancel  [Ctrl+e] Editor  [Ctrl+g] Regenerate AI"
     } else {
-        " [Space] Toggle  [a] Stage all  [r] Refresh  [c/Ctrl+Enter] Commit  [g] AI  [Esc] Back"
+        " [Space] Toggle  [Tab] Diff  [h] Hunks  [a] Stage all  [x] Discard  [r] Refresh  [c/Ctrl+Enter] Commit  [A] Amend  [g] AI  [Esc] Back"
     };
-    let help = Paragraph::new(help_text).style(Theme::muted());
+    let help = Paragraph::new(help_text).style(app.theme.muted());
     frame.render_widget(help, help_area);
 }
 
-/// Render the settings screen
-fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
-    // Split into main content and help bar
-    let chunks = Layout::default()
-        .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(1)])
+/// Render the diff of the selected file next to the commit file list
+fn render_commit_diff_preview(frame: &mut Frame, area: Rect, app: &App) {
+    let diff = app
+        .commit_diff_path
+        .as_ref()
+        .and_then(|path| app.commit_diff_cache.get(path));
+
+    let lines: Vec<Line> = match diff {
+        Some(diff) if diff.is_empty() => vec![Line::from(Span::styled(
+            "  No textual changes (binary or mode change)",
+            app.theme.muted(),
+        ))],
+        Some(diff) => diff
+            .lines()
+            .map(|line| diff_line(line, &app.theme))
+            .collect(),
+        None => vec![Line::from(Span::styled(
+            "  Select a file to preview its diff",
+            app.theme.muted(),
+        ))],
+    };
+
+    let title = match &app.commit_diff_path {
+        Some(path) => format!(" Diff: {} ", path),
+        None => " Diff ".to_string(),
+    };
+    let border_style = if app.commit_diff_focused {
+        app.theme.accent()
+    } else {
+        app.theme.normal()
+    };
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(border_style),
+        )
+        .scroll((app.commit_diff_scroll, 0));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Render a file's hunks for hunk-level staging, selected hunk at the top
+fn render_commit_hunks(frame: &mut Frame, area: Rect, app: &App, path: &str) {
+    let mut lines: Vec<Line> = Vec::new();
+    let mut selected_line = 0;
+
+    if app.commit_hunks.is_empty() {
+        lines.push(Line::from(""));
+        lines.push(Line::from("  No changes left in this file."));
+    }
+
+    for (i, hunk) in app.commit_hunks.iter().enumerate() {
+        let is_selected = i == app.commit_hunk_selection.selected;
+        if is_selected {
+            selected_line = lines.len();
+        }
+
+        let (marker, marker_color) = if hunk.staged {
+            (
+                format!("[{}] staged  ", app.theme.check_mark()),
+                Color::Green,
+            )
+        } else {
+            ("[ ] unstaged".to_string(), app.theme.highlight)
+        };
+        let header_style = if is_selected {
+            app.theme.selected()
+        } else {
+            app.theme.accent()
+        };
+        lines.push(Line::from(vec![
+            Span::raw(if is_selected { " ▶ " } else { "   " }),
+            Span::styled(marker, Style::default().fg(marker_color)),
+            Span::raw(" "),
+            Span::styled(hunk.header.as_str(), header_style),
+        ]));
+
+        for line in &hunk.lines {
+            let mut styled = diff_line(line, &app.theme);
+            styled.spans.insert(0, Span::raw("     "));
+            lines.push(styled);
+        }
+        lines.push(Line::from(""));
+    }
+
+    let staged = app.commit_hunks.iter().filter(|h| h.staged).count();
+    let title = format!(
+        " Hunks: {} ({}/{} staged) ",
+        path,
+        staged,
+        app.commit_hunks.len()
+    );
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(app.theme.normal()),
+        )
+        .scroll((selected_line as u16, 0));
+
+    frame.render_widget(paragraph, area);
+}
+
+/// Render the settings screen
+fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
+    // Split into main content and help bar
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
         .split(area);
 
     let (github_text, github_color) = if app.github_authenticated {
@@ -1789,56 +3179,150 @@ fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
     // GitHub line
     let github_line = Line::from(vec![
         Span::raw(if sel == 0 { " ▶ " } else { "   " }),
-        Span::styled("GitHub:      ", Style::default().fg(Color::Cyan)),
+        Span::styled("GitHub:      ", app.theme.accent()),
         Span::styled(github_text, Style::default().fg(github_color)),
     ]);
 
-    // Gemini API key line - show input field when editing
+    // AI provider API key line - show input field when editing
     let gemini_line = if app.settings_input_mode && sel == 1 {
         // Input mode: show masked input with cursor
         let masked_input = "•".repeat(app.settings_api_key_input.len());
         Line::from(vec![
             Span::raw(" ▶ "),
-            Span::styled("Gemini API:  ", Style::default().fg(Color::Cyan)),
-            Span::styled("[", Style::default().fg(Color::Yellow)),
+            Span::styled("API key:     ", app.theme.accent()),
+            Span::styled("[", app.theme.highlight()),
             Span::styled(masked_input, Style::default().fg(Color::White)),
-            Span::styled("█", Style::default().fg(Color::Yellow)), // cursor
-            Span::styled("]", Style::default().fg(Color::Yellow)),
+            Span::styled("█", app.theme.highlight()), // cursor
+            Span::styled("]", app.theme.highlight()),
         ])
     } else {
-        let (gemini_text, gemini_color) = if app.gemini_configured {
+        let (gemini_text, gemini_color) = if app.ai_configured {
             ("Configured ✓", Color::Green)
         } else {
-            ("Not configured ✗", Color::Yellow)
+            ("Not configured ✗", app.theme.highlight)
         };
         Line::from(vec![
             Span::raw(if sel == 1 { " ▶ " } else { "   " }),
-            Span::styled("Gemini API:  ", Style::default().fg(Color::Cyan)),
+            Span::styled("API key:     ", app.theme.accent()),
+            Span::styled(
+                format!("{} ", app.ai_provider.display_name()),
+                Style::default().fg(Color::White),
+            ),
             Span::styled(gemini_text, Style::default().fg(gemini_color)),
         ])
     };
 
-    // Model line - show current model from app state
-    let model_line = Line::from(vec![
-        Span::raw(if sel == 2 { " ▶ " } else { "   " }),
-        Span::styled("AI Model:    ", Style::default().fg(Color::Cyan)),
+    // Model line - show current model from app state, or the custom name input
+    let model_line = if app.settings_input_mode && sel == 2 {
+        Line::from(vec![
+            Span::raw(" ▶ "),
+            Span::styled("AI Model:    ", app.theme.accent()),
+            Span::styled("Custom [", app.theme.highlight()),
+            Span::styled(
+                app.settings_model_input.as_str(),
+                Style::default().fg(Color::White),
+            ),
+            Span::styled("█", app.theme.highlight()), // cursor
+            Span::styled("]", app.theme.highlight()),
+        ])
+    } else {
+        let model_text = match (app.ai_provider, app.gemini_model, &app.gemini_custom_model) {
+            (AiBackend::Gemini, GeminiModel::Custom, Some(name)) => format!("Custom ({})", name),
+            (AiBackend::Gemini, model, _) => model.display_name().to_string(),
+            _ => app.ai_model_name.clone(),
+        };
+        Line::from(vec![
+            Span::raw(if sel == 2 { " ▶ " } else { "   " }),
+            Span::styled("AI Model:    ", app.theme.accent()),
+            Span::styled(model_text, Style::default().fg(Color::White)),
+            Span::styled(" (j/k to cycle)", app.theme.muted()),
+        ])
+    };
+
+    // Commit prompt line
+    let commit_prompt_line = Line::from(vec![
+        Span::raw(if sel == 3 { " ▶ " } else { "   " }),
+        Span::styled("Commit AI:   ", app.theme.accent()),
+        Span::styled(
+            if app.use_commit_prompt_template {
+                "Custom prompt"
+            } else {
+                "Default prompt"
+            },
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" (commit-prompt-template)", app.theme.muted()),
+    ]);
+
+    // HTML handling line
+    let html_line = Line::from(vec![
+        Span::raw(if sel == 4 { " ▶ " } else { "   " }),
+        Span::styled("HTML:        ", app.theme.accent()),
+        Span::styled(
+            if app.render_html { "Render" } else { "Strip" },
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" (in PR descriptions)", app.theme.muted()),
+    ]);
+
+    // PR list page size line
+    let page_size_line = Line::from(vec![
+        Span::raw(if sel == 5 { " ▶ " } else { "   " }),
+        Span::styled("Page size:   ", app.theme.accent()),
+        Span::styled(
+            app.pr_list_page_size.to_string(),
+            Style::default().fg(Color::White),
+        ),
+        Span::styled(" (pull requests per page)", app.theme.muted()),
+    ]);
+
+    // Workflow poll interval line
+    let poll_line = Line::from(vec![
+        Span::raw(if sel == 6 { " ▶ " } else { "   " }),
+        Span::styled("Poll every:  ", app.theme.accent()),
         Span::styled(
-            app.gemini_model.display_name(),
+            format!("{}s", app.workflow_poll_seconds),
             Style::default().fg(Color::White),
         ),
-        Span::styled(" (j/k to cycle)", Style::default().fg(Color::DarkGray)),
+        Span::styled(" (active workflow runs)", app.theme.muted()),
+    ]);
+
+    // Dry-run line
+    let dry_run_line = Line::from(vec![
+        Span::raw(if sel == 7 { " ▶ " } else { "   " }),
+        Span::styled("Dry run:     ", app.theme.accent()),
+        if app.dry_run {
+            Span::styled("On", app.theme.highlight())
+        } else {
+            Span::styled("Off", Style::default().fg(Color::White))
+        },
+        Span::styled(" (merge, push, delete)", app.theme.muted()),
     ]);
 
     // Build help text based on current selection and mode
-    let help_section = if app.settings_input_mode {
+    let help_section = if app.settings_input_mode && sel == 2 {
+        vec![
+            Line::from(""),
+            Line::from(Span::styled(
+                "  Entering custom model name:",
+                app.theme.highlight(),
+            )),
+            Line::from(""),
+            Line::from("  Type a Gemini model name (e.g. gemini-2.5-flash-preview), then Enter"),
+            Line::from("  Leave it empty and press Enter to skip Custom, or Esc to cancel"),
+        ]
+    } else if app.settings_input_mode {
         vec![
             Line::from(""),
             Line::from(Span::styled(
                 "  Entering API Key (hidden):",
-                Style::default().fg(Color::Yellow),
+                app.theme.highlight(),
             )),
             Line::from(""),
-            Line::from("  Type your Gemini API key, then press Enter to save"),
+            Line::from(format!(
+                "  Type your {} API key, then press Enter to save",
+                app.ai_provider.display_name()
+            )),
             Line::from("  Press Esc to cancel"),
         ]
     } else {
@@ -1851,12 +3335,17 @@ fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
                 }
             }
             1 => "  Press Enter to configure API key",
-            2 => "  Press j/k or Enter to cycle through models",
+            2 => "  Press Enter to cycle through models; Custom asks for a model name",
+            3 => "  Press Enter to switch between the built-in and your custom commit prompt",
+            4 => "  Press Enter to toggle rendering collapsibles and image alt text",
+            5 => "  Press Enter or h/l to cycle through 10, 30, 50 and 100",
+            6 => "  Press Enter or h/l to cycle through 2, 5, 7, 15, 30 and 60 seconds",
+            7 => "  Press Enter to only show what merges, pushes and deletions would do",
             _ => "",
         };
         vec![
             Line::from(""),
-            Line::from(Span::styled("  Actions:", Style::default().fg(Color::Cyan))),
+            Line::from(Span::styled("  Actions:", app.theme.accent())),
             Line::from(""),
             Line::from(help_text),
         ]
@@ -1872,6 +3361,23 @@ fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
         github_line,
         gemini_line,
         model_line,
+        commit_prompt_line,
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Display",
+            Style::default().add_modifier(ratatui::style::Modifier::BOLD),
+        )),
+        Line::from(""),
+        html_line,
+        page_size_line,
+        poll_line,
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Safety",
+            Style::default().add_modifier(ratatui::style::Modifier::BOLD),
+        )),
+        Line::from(""),
+        dry_run_line,
     ];
     all_lines.extend(help_section);
 
@@ -1879,7 +3385,7 @@ fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(" Settings ")
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
 
     frame.render_widget(paragraph, chunks[0]);
@@ -1890,7 +3396,7 @@ fn render_settings(frame: &mut Frame, area: Rect, app: &App) {
     } else {
         " [j/k] Navigate  [Enter] Edit  [Esc] Back"
     };
-    let help = Paragraph::new(help_bar).style(Theme::muted());
+    let help = Paragraph::new(help_bar).style(app.theme.muted());
     frame.render_widget(help, chunks[1]);
 }
 
@@ -1899,12 +3405,20 @@ fn workflow_status_display(
     status: WorkflowRunStatus,
     conclusion: Option<WorkflowConclusion>,
     tick_counter: u64,
+    theme: &Theme,
 ) -> (&'static str, Color) {
-    const SPINNER: &[&str] = &["\u{25d0}", "\u{25d3}", "\u{25d1}", "\u{25d2}"]; // ◐ ◓ ◑ ◒
-
     if status.is_active() {
-        let frame = SPINNER[tick_counter as usize % SPINNER.len()];
-        (frame, Color::Yellow)
+        (theme.spinner(tick_counter), theme.highlight)
+    } else if theme.no_color {
+        match conclusion {
+            Some(WorkflowConclusion::Success) => ("+", Color::Green),
+            Some(WorkflowConclusion::Failure) => ("x", Color::Red),
+            Some(WorkflowConclusion::Cancelled) => ("o", Color::Gray),
+            Some(WorkflowConclusion::Skipped) => ("-", Color::Gray),
+            Some(WorkflowConclusion::TimedOut) => ("t", Color::Red),
+            Some(WorkflowConclusion::ActionRequired) => ("!", theme.highlight),
+            _ => ("?", Color::Gray),
+        }
     } else {
         match conclusion {
             Some(WorkflowConclusion::Success) => ("\u{2713}", Color::Green), // ✓
@@ -1912,7 +3426,7 @@ fn workflow_status_display(
             Some(WorkflowConclusion::Cancelled) => ("\u{25cb}", Color::Gray), // ○
             Some(WorkflowConclusion::Skipped) => ("\u{2298}", Color::Gray),  // ⊘
             Some(WorkflowConclusion::TimedOut) => ("\u{23f1}", Color::Red),  // ⏱
-            Some(WorkflowConclusion::ActionRequired) => ("!", Color::Yellow),
+            Some(WorkflowConclusion::ActionRequired) => ("!", theme.highlight),
             _ => ("?", Color::Gray),
         }
     }
@@ -1920,10 +3434,11 @@ fn workflow_status_display(
 
 /// Truncate a string to max length with ellipsis
 fn truncate(s: &str, max_len: usize) -> String {
-    if s.len() <= max_len {
+    if s.chars().count() <= max_len {
         s.to_string()
     } else {
-        format!("{}...", &s[..max_len.saturating_sub(3)])
+        let kept: String = s.chars().take(max_len.saturating_sub(3)).collect();
+        format!("{}...", kept)
     }
 }
 
@@ -1935,11 +3450,14 @@ fn render_tags(frame: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Min(0), Constraint::Length(1)])
         .split(area);
 
-    // Build remote tags set for checking sync status
-    let remote_tags_set: std::collections::HashSet<&str> =
-        app.tags_remote.iter().map(|s| s.as_str()).collect();
+    // Index remote tags by name for sync status and release details
+    let remote_tags: std::collections::HashMap<&str, &crate::github::TagInfo> = app
+        .tags_remote
+        .iter()
+        .map(|t| (t.name.as_str(), t))
+        .collect();
 
-    let items: Vec<ListItem> = if app.tags_loading && app.tags_local.is_empty() {
+    let mut items: Vec<ListItem> = if app.tags_loading && app.tags_local.is_empty() {
         vec![ListItem::new("  Loading tags...")]
     } else if let Some(err) = &app.tags_error {
         vec![
@@ -1962,10 +3480,11 @@ fn render_tags(frame: &mut Frame, area: Rect, app: &App) {
                     "(lightweight)"
                 };
 
-                let sync_status = if remote_tags_set.contains(tag.name.as_str()) {
+                let remote = remote_tags.get(tag.name.as_str());
+                let sync_status = if remote.is_some() {
                     Span::styled("[pushed]", Style::default().fg(Color::Green))
                 } else {
-                    Span::styled("[local only]", Style::default().fg(Color::Yellow))
+                    Span::styled("[local only]", app.theme.highlight())
                 };
 
                 let message_preview = tag
@@ -1979,20 +3498,25 @@ fn render_tags(frame: &mut Frame, area: Rect, app: &App) {
                             format!("  {}", first_line)
                         }
                     })
+                    .or_else(|| {
+                        remote
+                            .and_then(|r| r.release_name.as_ref())
+                            .map(|r| format!("  release: {}", r))
+                    })
                     .unwrap_or_default();
 
                 let text = Line::from(vec![
                     Span::raw(format!("  {:<16} ", tag.name)),
-                    Span::styled(tag.sha.clone(), Style::default().fg(Color::DarkGray)),
+                    Span::styled(tag.sha.clone(), app.theme.muted()),
                     Span::raw(format!("  {:<12} ", type_indicator)),
                     sync_status,
-                    Span::styled(message_preview, Style::default().fg(Color::DarkGray)),
+                    Span::styled(message_preview, app.theme.muted()),
                 ]);
 
                 let item = ListItem::new(text);
 
                 if i == app.tags_selection.selected {
-                    item.style(Theme::selected())
+                    item.style(app.theme.selected())
                 } else {
                     item
                 }
@@ -2000,6 +3524,46 @@ fn render_tags(frame: &mut Frame, area: Rect, app: &App) {
             .collect()
     };
 
+    // Remote-only tags are listed after local ones
+    if app.tags_fetched && app.tags_error.is_none() {
+        let remote_only = app.tags_remote_only();
+
+        if !remote_only.is_empty() {
+            items.push(ListItem::new(""));
+            items.push(ListItem::new(Span::styled(
+                format!("  Remote only ({}):", remote_only.len()),
+                app.theme.accent(),
+            )));
+        }
+
+        for (i, tag) in remote_only.into_iter().enumerate() {
+            let type_indicator = if tag.is_annotated {
+                "(annotated)"
+            } else {
+                "(lightweight)"
+            };
+            let release = tag
+                .release_name
+                .as_ref()
+                .map(|r| format!("  release: {}", r))
+                .unwrap_or_default();
+
+            let item = ListItem::new(Line::from(vec![
+                Span::raw(format!("  {:<16} ", tag.name)),
+                Span::styled(tag.sha.clone(), app.theme.muted()),
+                Span::raw(format!("  {:<12} ", type_indicator)),
+                Span::styled("[remote only]", Style::default().fg(Color::Blue)),
+                Span::styled(release, app.theme.muted()),
+            ]));
+
+            items.push(if app.tags_local.len() + i == app.tags_selection.selected {
+                item.style(app.theme.selected())
+            } else {
+                item
+            });
+        }
+    }
+
     let title = if app.tags_local.is_empty() {
         " Tags ".to_string()
     } else {
@@ -2010,20 +3574,329 @@ fn render_tags(frame: &mut Frame, area: Rect, app: &App) {
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
 
     frame.render_widget(list, chunks[0]);
 
-    let help =
-        Paragraph::new(" [n] New  [r] Refresh  [p] Push  [P] Push all  [j/k] Navigate  [Esc] Back")
-            .style(Theme::muted());
+    let help = Paragraph::new(
+        " [Enter] Details  [n] New  [r] Refresh  [p] Push  [P] Push all  [d] Delete  [D] Delete + origin  [j/k] Navigate  [Esc] Back",
+    )
+    .style(app.theme.muted());
     frame.render_widget(help, chunks[1]);
 
     // Render tag creation popup if active
     if app.tag_create_mode {
         render_tag_create_popup(frame, app);
     }
+    if let Some((name, remote)) = &app.tag_delete_confirm {
+        render_tag_delete_prompt(frame, name, *remote, &app.theme);
+    }
+    if let Some((tag, target)) = &app.tag_detail {
+        render_tag_detail(frame, app, tag, target);
+    }
+}
+
+/// Render the tag detail overlay with the annotation and target commit
+fn render_tag_detail(
+    frame: &mut Frame,
+    app: &App,
+    tag: &crate::core::git::LocalTagInfo,
+    target: &crate::core::git::TagTarget,
+) {
+    let area = frame.area();
+
+    // Calculate centered popup area (80% width, 70% height)
+    let popup_width = (area.width * 80 / 100).max(60).min(area.width);
+    let popup_height = (area.height * 70 / 100).max(15).min(area.height);
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    // Split popup into header, message, and footer
+    let inner_area = popup_area.inner(Margin::new(1, 1)); // Account for border
+    let header_height = 4;
+    let footer_height = 2;
+    let body_height = inner_area
+        .height
+        .saturating_sub(header_height + footer_height);
+
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([
+            Constraint::Length(header_height),
+            Constraint::Length(body_height),
+            Constraint::Length(footer_height),
+        ])
+        .split(inner_area);
+
+    let outer_block = Block::default()
+        .title(format!(" Tag {} ", tag.name))
+        .borders(Borders::ALL)
+        .border_style(app.theme.highlight())
+        .style(app.theme.popup());
+    frame.render_widget(outer_block, popup_area);
+
+    let kind = if tag.is_annotated {
+        "annotated"
+    } else {
+        "lightweight"
+    };
+    let header_lines = vec![
+        Line::from(vec![
+            Span::styled("Type:   ", app.theme.accent()),
+            Span::raw(kind),
+        ]),
+        Line::from(vec![
+            Span::styled("Commit: ", app.theme.accent()),
+            Span::styled(target.sha.clone(), app.theme.muted()),
+            Span::raw(format!("  {}", target.author)),
+        ]),
+        Line::from(vec![
+            Span::styled("        ", Style::default()),
+            Span::raw(target.subject.clone()),
+        ]),
+        Line::from("─".repeat(chunks[0].width as usize)),
+    ];
+    let header = Paragraph::new(header_lines).style(app.theme.popup());
+    frame.render_widget(header, chunks[0]);
+
+    // Annotation message with scroll support
+    let message = match tag.message.as_deref() {
+        Some(message) if !message.is_empty() => Text::raw(message),
+        _ => Text::styled("(no annotation message)", app.theme.muted()),
+    };
+    let total_chars: usize = message.lines.iter().map(|l| l.width()).sum();
+    let estimated_lines =
+        (total_chars / chunks[1].width.max(1) as usize).max(message.lines.len()) + 1;
+    let max_scroll = estimated_lines.saturating_sub(chunks[1].height as usize);
+    app.tag_detail_max_scroll.set(max_scroll);
+    let scroll = app.tag_detail_scroll.min(max_scroll);
+
+    let body = Paragraph::new(message)
+        .style(app.theme.popup())
+        .wrap(Wrap { trim: false })
+        .scroll((scroll as u16, 0));
+    frame.render_widget(body, chunks[1]);
+
+    let mut footer_lines: Vec<Line> = Vec::new();
+    if max_scroll > 0 {
+        footer_lines.push(Line::from(Span::styled(
+            format!("[{}/{}] j/k to scroll", scroll + 1, max_scroll + 1),
+            app.theme.muted(),
+        )));
+    }
+    footer_lines.push(Line::from(Span::styled("[Esc] Close", app.theme.muted())));
+
+    let footer = Paragraph::new(footer_lines).style(app.theme.popup());
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Render the tag deletion confirmation popup
+fn render_tag_delete_prompt(frame: &mut Frame, name: &str, remote: bool, theme: &Theme) {
+    let area = frame.area();
+
+    let popup_width = 56_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let question = if remote {
+        "Delete this tag locally and from origin?"
+    } else {
+        "Delete this local tag?"
+    };
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate(name, popup_width.saturating_sub(4) as usize),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(question),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[y] Confirm  [any other key] Cancel",
+            theme.muted(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Delete Tag ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Render the local branches screen
+fn render_branches(frame: &mut Frame, area: Rect, app: &App) {
+    let chunks = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .split(area);
+
+    let items: Vec<ListItem> = if let Some(err) = &app.branches_error {
+        vec![
+            ListItem::new(format!("  Error: {}", err)).style(Style::default().fg(Color::Red)),
+            ListItem::new(""),
+            ListItem::new("  Press [r] to retry"),
+        ]
+    } else if app.branches_local.is_empty() {
+        vec![ListItem::new("  No local branches found")]
+    } else {
+        app.branches_local
+            .iter()
+            .enumerate()
+            .map(|(i, name)| {
+                let is_current = app.branches_current.as_deref() == Some(name.as_str());
+                let line = if is_current {
+                    Line::from(vec![
+                        Span::styled("* ", Style::default().fg(Color::Green)),
+                        Span::styled(
+                            name.clone(),
+                            Style::default()
+                                .fg(Color::Green)
+                                .add_modifier(Modifier::BOLD),
+                        ),
+                        Span::styled("  (current)", app.theme.muted()),
+                    ])
+                } else {
+                    Line::from(format!("  {}", name))
+                };
+
+                let item = ListItem::new(line);
+                if i == app.branches_selection.selected {
+                    item.style(app.theme.selected())
+                } else {
+                    item
+                }
+            })
+            .collect()
+    };
+
+    let title = if app.branches_local.is_empty() {
+        " Branches ".to_string()
+    } else {
+        format!(" Branches ({}) ", app.branches_local.len())
+    };
+
+    let list = List::new(items).block(
+        Block::default()
+            .title(title)
+            .borders(Borders::ALL)
+            .border_style(app.theme.normal()),
+    );
+
+    frame.render_widget(list, chunks[0]);
+
+    let help = Paragraph::new(
+        " [Enter] Checkout  [n] New  [d] Delete  [r] Refresh  [j/k] Navigate  [Esc] Back",
+    )
+    .style(app.theme.muted());
+    frame.render_widget(help, chunks[1]);
+
+    if app.branch_create_mode {
+        render_branch_create_popup(frame, app);
+    }
+    if let Some(name) = &app.branch_delete_confirm {
+        render_branch_delete_prompt(frame, name, &app.theme);
+    }
+}
+
+/// Render the new branch name input
+fn render_branch_create_popup(frame: &mut Frame, app: &App) {
+    let area = frame.area();
+
+    let popup_width = 60_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(vec![
+            Span::styled("  Branch name: ", app.theme.accent()),
+            Span::styled(&app.branch_create_name, app.theme.highlight()),
+            Span::styled("█", app.theme.highlight()),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Created from HEAD and checked out",
+            app.theme.muted(),
+        )),
+        Line::from(Span::styled(
+            "  [Enter] Create  [Esc] Cancel",
+            app.theme.muted(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines).block(
+        Block::default()
+            .title(" New Branch ")
+            .borders(Borders::ALL)
+            .border_style(app.theme.highlight()),
+    );
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Render the confirmation prompt for deleting a local branch
+fn render_branch_delete_prompt(frame: &mut Frame, name: &str, theme: &Theme) {
+    let area = frame.area();
+
+    let popup_width = 56_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate(name, popup_width.saturating_sub(4) as usize),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from("Delete this local branch? Unmerged branches are kept."),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[y] Confirm  [any other key] Cancel",
+            theme.muted(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Delete Branch ")
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
 }
 
 /// Render the tag creation popup
@@ -2050,31 +3923,41 @@ fn render_tag_create_popup(frame: &mut Frame, app: &App) {
 
     // Tag name field
     let name_style = if app.tag_create_field == 0 {
-        Style::default().fg(Color::Yellow)
+        app.theme.highlight()
     } else {
         Style::default().fg(Color::White)
     };
     let name_cursor = if app.tag_create_field == 0 { "█" } else { "" };
     lines.push(Line::from(vec![
-        Span::styled("  Tag name: ", Style::default().fg(Color::Cyan)),
+        Span::styled("  Tag name: ", app.theme.accent()),
         Span::styled(&app.tag_create_name, name_style),
-        Span::styled(name_cursor, Style::default().fg(Color::Yellow)),
+        Span::styled(name_cursor, app.theme.highlight()),
     ]));
 
-    lines.push(Line::from(""));
+    // Suggested version hint (replaces the spacer line while the name is focused)
+    if app.tag_create_field == 0 {
+        let hint = match &app.tag_create_latest {
+            Some(latest) => format!(
+                "  {} bump of {}  [↑/↓] patch/minor/major",
+                app.tag_create_bump.label(),
+                latest
+            ),
+            None => "  No version tags yet, starting at v0.1.0".to_string(),
+        };
+        lines.push(Line::from(Span::styled(hint, app.theme.muted())));
+    } else {
+        lines.push(Line::from(""));
+    }
 
     // Message field label
     lines.push(Line::from(vec![
-        Span::styled("  Message:  ", Style::default().fg(Color::Cyan)),
-        Span::styled(
-            "(optional, multiline with Enter)",
-            Style::default().fg(Color::DarkGray),
-        ),
+        Span::styled("  Message:  ", app.theme.accent()),
+        Span::styled("(optional, multiline with Enter)", app.theme.muted()),
     ]));
 
     // Message content area - render each line with cursor
     let msg_style = if app.tag_create_field == 1 {
-        Style::default().fg(Color::Yellow)
+        app.theme.highlight()
     } else {
         Style::default().fg(Color::White)
     };
@@ -2096,26 +3979,26 @@ fn render_tag_create_popup(frame: &mut Frame, app: &App) {
         let line_content = msg_lines.get(actual_line_idx).unwrap_or(&"");
 
         // Truncate line if too long (with indicator)
-        let truncated: String = if line_content.len() > max_line_width {
-            format!("{}…", &line_content[..max_line_width - 1])
+        let truncated: String = if line_content.chars().count() > max_line_width {
+            let kept: String = line_content
+                .chars()
+                .take(max_line_width.saturating_sub(1))
+                .collect();
+            format!("{}…", kept)
         } else {
             line_content.to_string()
         };
 
         // Add cursor if this is the active line and field is selected
         if app.tag_create_field == 1 && actual_line_idx == cursor_row {
-            let col = cursor_col.min(line_content.len());
-            let col_in_truncated = col.min(truncated.len());
-            let before_cursor = truncated[..col_in_truncated].to_string();
-            let after_cursor = if col_in_truncated < truncated.len() {
-                truncated[col_in_truncated..].to_string()
-            } else {
-                String::new()
-            };
+            let (before, after) =
+                truncated.split_at(text_input::byte_offset(&truncated, cursor_col));
+            let before_cursor = before.to_string();
+            let after_cursor = after.to_string();
             lines.push(Line::from(vec![
                 Span::raw("    "),
                 Span::styled(before_cursor, msg_style),
-                Span::styled("█", Style::default().fg(Color::Yellow)),
+                Span::styled("█", app.theme.highlight()),
                 Span::styled(after_cursor, msg_style),
             ]));
         } else {
@@ -2148,14 +4031,14 @@ fn render_tag_create_popup(frame: &mut Frame, app: &App) {
     ));
     lines.push(Line::from(Span::styled(
         "  [Tab] Next  [↑↓] Lines  [Enter] Newline  [Esc] Cancel",
-        Style::default().fg(Color::DarkGray),
+        app.theme.muted(),
     )));
 
     let paragraph = Paragraph::new(lines).block(
         Block::default()
             .title(" Create Tag ")
             .borders(Borders::ALL)
-            .border_style(Style::default().fg(Color::Cyan)),
+            .border_style(app.theme.accent()),
     );
 
     frame.render_widget(paragraph, popup_area);
@@ -2167,6 +4050,14 @@ fn render_workflow_runs(frame: &mut Frame, area: Rect, app: &App) {
         .constraints([Constraint::Min(0), Constraint::Length(1)])
         .split(area);
 
+    let visible_runs = app.filtered_workflow_runs();
+    let mut job_rows = 0;
+    let count = if visible_runs.len() == app.workflow_runs.len() {
+        app.workflow_runs.len().to_string()
+    } else {
+        format!("{}/{}", visible_runs.len(), app.workflow_runs.len())
+    };
+
     let items: Vec<ListItem> = if app.workflow_runs_loading && app.workflow_runs.is_empty() {
         vec![ListItem::new("  Loading workflow runs...")]
     } else if let Some(err) = &app.workflow_runs_error {
@@ -2179,64 +4070,211 @@ fn render_workflow_runs(frame: &mut Frame, area: Rect, app: &App) {
         vec![ListItem::new("  Press [r] to load workflow runs")]
     } else if app.workflow_runs.is_empty() {
         vec![ListItem::new("  No workflow runs found")]
+    } else if visible_runs.is_empty() {
+        vec![ListItem::new(
+            "  No workflow runs match the filter (F/E to change)",
+        )]
     } else {
-        app.workflow_runs
-            .iter()
-            .enumerate()
-            .map(|(i, run)| {
-                let (icon, icon_color) =
-                    workflow_status_display(run.status, run.conclusion, app.tick_counter);
+        let mut items = Vec::new();
+        for (i, run) in visible_runs.iter().enumerate() {
+            let (icon, icon_color) =
+                workflow_status_display(run.status, run.conclusion, app.tick_counter, &app.theme);
+
+            let text = format!(
+                "  {} #{:<4} {:<22} {:<10} {} {:<12} {}",
+                icon,
+                run.run_number,
+                truncate(&run.name, 22),
+                truncate(&run.head_branch, 10),
+                run.head_sha_short(),
+                run.event,
+                run.duration_string(),
+            );
 
-                let text = format!(
-                    "  {} #{:<4} {:<22} {:<10} {} {:<12} {}",
-                    icon,
-                    run.run_number,
-                    truncate(&run.name, 22),
-                    truncate(&run.head_branch, 10),
-                    run.head_sha_short,
-                    run.event,
-                    run.duration_string(),
-                );
+            let item = ListItem::new(text);
+            let selected = i == app.workflow_runs_selection.selected;
 
-                let item = ListItem::new(text);
+            items.push(if selected {
+                item.style(app.theme.selected())
+            } else {
+                item.style(Style::default().fg(icon_color))
+            });
 
-                if i == app.workflow_runs_selection.selected {
-                    item.style(Theme::selected())
-                } else {
-                    item.style(Style::default().fg(icon_color))
-                }
-            })
-            .collect()
+            if selected && app.workflow_jobs_shown {
+                let jobs = workflow_job_items(app, run.id);
+                job_rows = jobs.len();
+                items.extend(jobs);
+            }
+        }
+        items
     };
+    app.workflow_job_rows.set(job_rows);
 
     let title = if let Some(ref branch) = app.pr_workflow_branch {
         if app.workflow_runs.is_empty() {
             format!(" Workflow Runs (branch: {}) ", branch)
         } else {
-            format!(
-                " Workflow Runs ({}) - branch: {} ",
-                app.workflow_runs.len(),
-                branch
-            )
+            format!(" Workflow Runs ({}) - branch: {} ", count, branch)
         }
     } else if app.workflow_runs.is_empty() {
         " Workflow Runs ".to_string()
     } else {
-        format!(" Workflow Runs ({}) ", app.workflow_runs.len())
+        format!(" Workflow Runs ({}) ", count)
+    };
+    let title = if app.workflow_runs_mine_only {
+        let who = app
+            .current_user
+            .as_deref()
+            .map(|login| format!("@{}", login))
+            .unwrap_or_else(|| "me".to_string());
+        format!("{}- actor: {} ", title, who)
+    } else {
+        title
+    };
+    let title = if app.workflow_poll_backoff_multiplier > 1 {
+        format!(
+            "{}- slowed to {}s after errors ",
+            title,
+            app.workflow_poll_interval_secs()
+        )
+    } else {
+        title
+    };
+    let title = match app.workflow_runs_conclusion_filter {
+        Some(conclusion) => format!("{}- conclusion: {} ", title, conclusion),
+        None => title,
+    };
+    let title = match &app.workflow_runs_event_filter {
+        Some(event) => format!("{}- event: {} ", title, event),
+        None => title,
     };
 
     let list = List::new(items).block(
         Block::default()
             .title(title)
             .borders(Borders::ALL)
-            .border_style(Theme::normal()),
+            .border_style(app.theme.normal()),
     );
 
     frame.render_widget(list, chunks[0]);
+    app.workflow_runs_area
+        .set(chunks[0].inner(Margin::new(1, 1)));
 
-    let help =
-        Paragraph::new(" [r] Refresh  [o] Open  [j/k] Navigate  [Esc] Back").style(Theme::muted());
+    let help = Paragraph::new(
+        " [Enter] Logs  [Space] Jobs  [R] Re-run  [c] Cancel  [F/E] Filter  [m] My Runs  [o] Open  [r] Refresh  [j/k] Navigate  [Esc] Back",
+    )
+    .style(app.theme.muted());
     frame.render_widget(help, chunks[1]);
+
+    if let Some((_, run_number, has_failures)) = app.workflow_rerun_confirm {
+        let keys = if has_failures {
+            "[a] All jobs  [f] Failed jobs  [any other key] Cancel"
+        } else {
+            "[a] All jobs  [any other key] Cancel"
+        };
+        render_workflow_confirm_prompt(
+            frame,
+            " Re-run Workflow ",
+            run_number,
+            "Re-run this completed workflow run?",
+            keys,
+            app.theme.highlight(),
+            &app.theme,
+        );
+    }
+    if let Some((_, run_number)) = app.workflow_cancel_confirm {
+        render_workflow_confirm_prompt(
+            frame,
+            " Cancel Workflow ",
+            run_number,
+            "Cancel this workflow run?",
+            "[y] Confirm  [any other key] Keep running",
+            Style::default().fg(Color::Red),
+            &app.theme,
+        );
+    }
+}
+
+/// Render a confirmation popup asking `question` about workflow run `run_number`
+fn render_workflow_confirm_prompt(
+    frame: &mut Frame,
+    title: &str,
+    run_number: u64,
+    question: &str,
+    keys: &str,
+    border_style: Style,
+    theme: &Theme,
+) {
+    let area = frame.area();
+
+    let popup_width = 56_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Run #{}", run_number),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(question.to_string()),
+        Line::from(""),
+        Line::from(Span::styled(keys.to_string(), theme.muted())),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title.to_string())
+                .borders(Borders::ALL)
+                .border_style(border_style),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Indented rows listing the jobs of a workflow run
+fn workflow_job_items(app: &App, run_id: u64) -> Vec<ListItem<'static>> {
+    if let Some(jobs) = app.workflow_jobs.get(&run_id) {
+        if jobs.is_empty() {
+            return vec![ListItem::new("        No jobs").style(app.theme.muted())];
+        }
+        return jobs
+            .iter()
+            .map(|job| {
+                let (icon, icon_color) = workflow_status_display(
+                    job.status,
+                    job.conclusion,
+                    app.tick_counter,
+                    &app.theme,
+                );
+                ListItem::new(format!(
+                    "      {} {:<40} {}",
+                    icon,
+                    truncate(&job.name, 40),
+                    job.duration_string()
+                ))
+                .style(Style::default().fg(icon_color))
+            })
+            .collect();
+    }
+
+    match &app.workflow_jobs_error {
+        Some((id, err)) if *id == run_id => {
+            vec![
+                ListItem::new(format!("        Error loading jobs: {}", err))
+                    .style(Style::default().fg(Color::Red)),
+            ]
+        }
+        _ => vec![ListItem::new("        Loading jobs...").style(app.theme.muted())],
+    }
 }
 
 /// Render a placeholder screen
@@ -2263,35 +4301,50 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     let status_text = if let Some(msg) = &app.status_message {
         msg.clone()
     } else {
-        format!(" Branch: {} │ ? for help ", branch)
+        format!(
+            " Branch: {}{} │ ? for help ",
+            branch,
+            ahead_behind_label(app.branch_ahead_behind)
+        )
     };
 
-    // Spinner for update animations
-    const SPINNER: &[&str] = &["\u{25d0}", "\u{25d3}", "\u{25d1}", "\u{25d2}"]; // ◐ ◓ ◑ ◒
-
     // Build update indicator based on state
     let update_indicator: Option<(String, Color)> = match &app.update_state {
         UpdateState::Idle | UpdateState::UpToDate | UpdateState::Failed => None,
         UpdateState::Checking => {
-            let spinner = SPINNER[app.tick_counter as usize % SPINNER.len()];
-            Some((format!("{} Checking ", spinner), Color::Yellow))
+            let spinner = app.theme.spinner(app.tick_counter);
+            Some((format!("{} Checking ", spinner), app.theme.highlight))
         }
-        UpdateState::Available(v) => Some((format!(" v{} available ", v), Color::Yellow)),
+        UpdateState::Available(v) => Some((format!(" v{} available ", v), app.theme.highlight)),
         UpdateState::Downloading(progress) => {
-            let spinner = SPINNER[app.tick_counter as usize % SPINNER.len()];
+            let spinner = app.theme.spinner(app.tick_counter);
             Some((
                 format!("{} Updating {:.0}% ", spinner, progress * 100.0),
-                Color::Yellow,
+                app.theme.highlight,
             ))
         }
         UpdateState::Ready(v) => Some((format!(" v{} ready ", v), Color::Green)),
     };
 
+    // API quota indicator, red when running low
+    let rate_limit_indicator: Option<(String, Color)> = app.api_rate_limit.map(|rl| {
+        let color = if rl.is_low() {
+            Color::Red
+        } else {
+            app.theme.muted
+        };
+        (format!(" API {}/{} ", rl.remaining, rl.limit), color)
+    });
+
     // Calculate layout for status bar content
     let update_width = update_indicator
         .as_ref()
         .map(|(s, _)| s.len() as u16)
         .unwrap_or(0);
+    let rate_limit_width = rate_limit_indicator
+        .as_ref()
+        .map(|(s, _)| s.len() as u16)
+        .unwrap_or(0);
 
     // Create inner area (inside the top border)
     let inner_area = Rect {
@@ -2304,7 +4357,11 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     // Split into left (status) and right (update indicator)
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Min(0), Constraint::Length(update_width)])
+        .constraints([
+            Constraint::Min(0),
+            Constraint::Length(rate_limit_width),
+            Constraint::Length(update_width),
+        ])
         .split(inner_area);
 
     // Render the top border
@@ -2312,19 +4369,23 @@ fn render_status_bar(frame: &mut Frame, area: Rect, app: &App) {
     frame.render_widget(border_block, area);
 
     // Render status message (left side)
-    let status = Paragraph::new(status_text).style(Theme::status_bar());
+    let status = Paragraph::new(status_text).style(app.theme.status_bar());
     frame.render_widget(status, chunks[0]);
 
+    // Render API quota indicator if known
+    if let Some((text, color)) = rate_limit_indicator {
+        let rate_limit_widget = Paragraph::new(text)
+            .style(Style::default().fg(color).bg(app.theme.status_bar))
+            .alignment(Alignment::Right);
+        frame.render_widget(rate_limit_widget, chunks[1]);
+    }
+
     // Render update indicator (right side) if present
     if let Some((text, color)) = update_indicator {
         let update_widget = Paragraph::new(text)
-            .style(
-                Style::default()
-                    .fg(color)
-                    .bg(Theme::status_bar().bg.unwrap_or(Color::Reset)),
-            )
+            .style(Style::default().fg(color).bg(app.theme.status_bar))
             .alignment(Alignment::Right);
-        frame.render_widget(update_widget, chunks[1]);
+        frame.render_widget(update_widget, chunks[2]);
     }
 }
 
@@ -2350,7 +4411,7 @@ fn render_help_overlay(frame: &mut Frame, app: &App) {
         .into_iter()
         .map(|(key, desc)| {
             Line::from(vec![
-                Span::styled(format!("  {:12}", key), Style::default().fg(Color::Cyan)),
+                Span::styled(format!("  {:12}", key), app.theme.accent()),
                 Span::raw(desc),
             ])
         })
@@ -2361,15 +4422,152 @@ fn render_help_overlay(frame: &mut Frame, app: &App) {
             Block::default()
                 .title(format!(" {} ", title))
                 .borders(Borders::ALL)
-                .border_style(Style::default().fg(Color::Yellow)),
+                .border_style(app.theme.highlight()),
         )
-        .style(Style::default().bg(Color::Black));
+        .style(app.theme.popup());
 
     frame.render_widget(help, popup_area);
 }
 
+/// Render the quit confirmation prompt
+fn render_quit_prompt(frame: &mut Frame, theme: &Theme) {
+    let area = frame.area();
+
+    let popup_width = 30_u16;
+    let popup_height = 5_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![Line::from(""), Line::from("Quit argo-rs? [y/N]")];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Quit ")
+                .borders(Borders::ALL)
+                .border_style(theme.highlight()),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Render the confirmation prompt for discarding a file's changes
+fn render_discard_prompt(frame: &mut Frame, path: &str, untracked: bool, theme: &Theme) {
+    let area = frame.area();
+
+    let popup_width = 56_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    // Untracked files are deleted, which must be obvious before confirming
+    let (title, action) = if untracked {
+        (
+            " Delete File ",
+            "Delete untracked file? This cannot be undone.",
+        )
+    } else {
+        (
+            " Discard Changes ",
+            "Discard unstaged changes? This cannot be undone.",
+        )
+    };
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            truncate(path, popup_width.saturating_sub(4) as usize),
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from(action),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[y] Confirm  [any other key] Cancel",
+            theme.muted(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(title)
+                .borders(Borders::ALL)
+                .border_style(Style::default().fg(Color::Red)),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
+/// Arrows for commits ahead of / behind the tracking branch (empty when in sync)
+fn ahead_behind_label(counts: Option<(usize, usize)>) -> String {
+    let Some((ahead, behind)) = counts else {
+        return String::new();
+    };
+
+    let mut label = String::new();
+    if ahead > 0 {
+        label.push_str(&format!(" ↑{}", ahead));
+    }
+    if behind > 0 {
+        label.push_str(&format!(" ↓{}", behind));
+    }
+    label
+}
+
+/// Render the confirmation prompt for amending the last commit
+fn render_amend_prompt(frame: &mut Frame, theme: &Theme) {
+    let area = frame.area();
+
+    let popup_width = 60_u16.min(area.width);
+    let popup_height = 7_u16;
+    let popup_x = (area.width.saturating_sub(popup_width)) / 2;
+    let popup_y = (area.height.saturating_sub(popup_height)) / 2;
+
+    let popup_area = Rect::new(popup_x, popup_y, popup_width, popup_height);
+
+    // Clear the area behind the popup
+    frame.render_widget(Clear, popup_area);
+
+    let lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            "Amend last commit with the staged changes?",
+            Style::default().add_modifier(Modifier::BOLD),
+        )),
+        Line::from("This rewrites history; a pushed commit needs --force."),
+        Line::from(""),
+        Line::from(Span::styled(
+            "[y] Keep message  [e] Edit message  [other] Cancel",
+            theme.muted(),
+        )),
+    ];
+
+    let paragraph = Paragraph::new(lines)
+        .block(
+            Block::default()
+                .title(" Amend Commit ")
+                .borders(Borders::ALL)
+                .border_style(theme.highlight()),
+        )
+        .alignment(Alignment::Center);
+
+    frame.render_widget(paragraph, popup_area);
+}
+
 /// Render an error popup overlay
-fn render_error_popup(frame: &mut Frame, popup: &ErrorPopup) {
+fn render_error_popup(frame: &mut Frame, popup: &ErrorPopup, theme: &Theme) {
     let area = frame.area();
 
     // Calculate centered popup area (60% width, 40% height max)
@@ -2436,8 +4634,8 @@ fn render_error_popup(frame: &mut Frame, popup: &ErrorPopup) {
     // Add blank line and dismiss hint
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "Press Enter or Esc to dismiss",
-        Style::default().fg(Color::DarkGray),
+        "Press Enter or Esc to dismiss, y to copy details",
+        theme.muted(),
     )));
 
     let paragraph = Paragraph::new(lines).wrap(Wrap { trim: false });
@@ -2453,6 +4651,7 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
         ("j / ↓", "Move down"),
         ("k / ↑", "Move up"),
         ("Enter", "Select / Confirm"),
+        ("Ctrl+r", "Refresh all data"),
     ];
 
     match screen {
@@ -2463,8 +4662,10 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
                 ("n", "Create new Pull Request"),
                 ("c", "Create Commit"),
                 ("t", "Manage Tags"),
+                ("b", "Manage Branches"),
                 ("w", "Workflow Runs"),
                 ("s", "Settings"),
+                ("Ctrl+r", "Refresh all data"),
                 ("q", "Quit application"),
                 ("?", "Show this help"),
             ],
@@ -2474,8 +4675,14 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
             vec![
                 ("j / ↓", "Move down"),
                 ("k / ↑", "Move up"),
+                ("PgUp / PgDn", "Move by 10"),
+                ("Home / End", "First / last PR"),
                 ("Enter", "View PR details"),
                 ("n", "Create new PR"),
+                ("/", "Search by title or author"),
+                ("f", "Cycle filter (Open / Closed / All)"),
+                ("L", "Load the next page of PRs"),
+                ("o", "Open PR in browser"),
                 ("r", "Refresh list"),
                 ("Esc", "Go back"),
                 ("?", "Show this help"),
@@ -2487,8 +4694,18 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
                 ("j / ↓", "Scroll down"),
                 ("k / ↑", "Scroll up"),
                 ("c", "Add comment"),
+                ("v", "Review (approve / request changes / comment)"),
+                ("d", "Full description (z toggles collapsibles)"),
+                ("g", "Open first #ref (expanded views)"),
                 ("w", "View workflows"),
                 ("m", "Merge PR"),
+                ("b", "Change base branch"),
+                ("e", "Edit title and description"),
+                ("x / X", "Close / reopen PR"),
+                ("D", "View diff"),
+                ("o", "Open PR in browser"),
+                ("y", "Copy PR URL"),
+                ("Y", "Copy selected comment link"),
                 ("r", "Refresh"),
                 ("Esc", "Go back"),
                 ("?", "Show this help"),
@@ -2513,6 +4730,9 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
                 ("a", "Stage all files"),
                 ("u", "Unstage all files"),
                 ("c / Ctrl+Enter", "Enter commit message"),
+                ("Tab", "Focus the diff preview (j/k scroll)"),
+                ("h", "Stage/unstage individual hunks"),
+                ("A", "Amend last commit"),
                 ("g", "Generate AI commit message"),
                 ("Esc", "Go back"),
             ],
@@ -2529,12 +4749,47 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
             ],
         ),
         Screen::Auth => ("Help - Authentication", global_keys),
+        Screen::PrDiff(_) => (
+            "Help - PR Diff",
+            vec![
+                ("j / ↓", "Scroll down"),
+                ("k / ↑", "Scroll up"),
+                ("PgUp / PgDn", "Scroll by page"),
+                ("Home / End", "Top / bottom"),
+                ("] / [", "Next / previous hunk"),
+                ("} / {", "Next / previous file"),
+                ("r", "Refresh"),
+                ("Esc", "Go back"),
+                ("?", "Show this help"),
+            ],
+        ),
         Screen::WorkflowRuns => (
             "Help - Workflow Runs",
             vec![
                 ("j / ↓", "Move down"),
                 ("k / ↑", "Move up"),
+                ("Enter", "View logs"),
+                ("Space", "Show / hide the selected run's jobs"),
+                ("F", "Cycle conclusion filter (all, success, failure)"),
+                ("E", "Cycle event filter (push, pull_request, ...)"),
+                ("R", "Re-run a completed run"),
+                ("c", "Cancel a queued or running run"),
                 ("r", "Refresh"),
+                ("m", "Toggle only my runs"),
+                ("o", "Open in browser"),
+                ("Esc", "Go back"),
+                ("?", "Show this help"),
+            ],
+        ),
+        Screen::WorkflowLogs(_) => (
+            "Help - Workflow Logs",
+            vec![
+                ("j / ↓", "Scroll down"),
+                ("k / ↑", "Scroll up"),
+                ("PgUp / PgDn", "Scroll by page"),
+                ("Home / End", "Top / bottom"),
+                ("r", "Refresh"),
+                ("o", "Open in browser"),
                 ("Esc", "Go back"),
                 ("?", "Show this help"),
             ],
@@ -2544,10 +4799,25 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
             vec![
                 ("j / ↓", "Move down"),
                 ("k / ↑", "Move up"),
+                ("Enter", "Show tag details"),
                 ("n", "Create new tag"),
                 ("p", "Push selected tag"),
                 ("P", "Push all tags"),
-                ("d", "Delete tag"),
+                ("d", "Delete local tag"),
+                ("D", "Delete tag locally and from origin"),
+                ("r", "Refresh"),
+                ("Esc", "Go back"),
+                ("?", "Show this help"),
+            ],
+        ),
+        Screen::Branches => (
+            "Help - Branches",
+            vec![
+                ("j / ↓", "Move down"),
+                ("k / ↑", "Move up"),
+                ("Enter", "Checkout selected branch"),
+                ("n", "Create and switch to a new branch"),
+                ("d", "Delete local branch"),
                 ("r", "Refresh"),
                 ("Esc", "Go back"),
                 ("?", "Show this help"),
@@ -2555,3 +4825,204 @@ fn get_help_content(screen: Screen) -> (&'static str, Vec<(&'static str, &'stati
         ),
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn line_text(line: &Line) -> String {
+        line.spans.iter().map(|s| s.content.as_ref()).collect()
+    }
+
+    #[test]
+    fn test_parse_list_item_markers() {
+        assert_eq!(
+            parse_list_item("- item"),
+            Some(MarkdownListItem {
+                depth: 0,
+                marker: ListMarker::Bullet,
+                content: "item",
+            })
+        );
+        assert_eq!(
+            parse_list_item("12. twelfth").map(|i| i.marker),
+            Some(ListMarker::Ordered("12."))
+        );
+        assert_eq!(parse_list_item("plain text"), None);
+        assert_eq!(parse_list_item("1.5 is not a list"), None);
+    }
+
+    #[test]
+    fn test_parse_list_item_mixed_nesting() {
+        let items: Vec<_> = [
+            "1. first",
+            "  - nested bullet",
+            "    2. deeper",
+            "\t* tabbed",
+        ]
+        .into_iter()
+        .map(|l| parse_list_item(l).unwrap())
+        .map(|i| (i.depth, i.marker))
+        .collect();
+        assert_eq!(
+            items,
+            vec![
+                (0, ListMarker::Ordered("1.")),
+                (1, ListMarker::Bullet),
+                (2, ListMarker::Ordered("2.")),
+                (2, ListMarker::Bullet),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_list_item_tasks() {
+        let open = parse_list_item("- [ ] todo").unwrap();
+        assert_eq!(open.marker, ListMarker::Task(false));
+        assert_eq!(open.content, "todo");
+
+        let done = parse_list_item("  * [x] done").unwrap();
+        assert_eq!((done.depth, done.marker), (1, ListMarker::Task(true)));
+        assert_eq!(
+            parse_list_item("- [X] Done").unwrap().marker,
+            ListMarker::Task(true)
+        );
+
+        // A link at the start of an item is not a checkbox
+        assert_eq!(
+            parse_list_item("- [docs](url)").unwrap().marker,
+            ListMarker::Bullet
+        );
+    }
+
+    #[test]
+    fn test_fenced_code_block_is_verbatim() {
+        let text = markdown_to_text(
+            "```rust\nlet x = a * b * c;\nfn snake_case_name() {}\n  **not bold**\nlet v: Vec<String> = x<y>();\nif a < b && c > d {}\n<details>raw</details>\n```\n*after*",
+            false,
+            false,
+            &Theme::default(),
+        );
+        let lines: Vec<String> = text.lines.iter().map(line_text).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "───── rust ─────",
+                "  let x = a * b * c;",
+                "  fn snake_case_name() {}",
+                "    **not bold**",
+                "  let v: Vec<String> = x<y>();",
+                "  if a < b && c > d {}",
+                "  <details>raw</details>",
+                "───── code ─────",
+                "after",
+            ]
+        );
+
+        // Code lines are a single code-styled span; text after the fence is parsed again
+        assert_eq!(text.lines[1].spans.len(), 1);
+        assert_eq!(text.lines[1].spans[0].style.fg, Some(Color::Green));
+        assert!(text.lines[8].spans[0]
+            .style
+            .add_modifier
+            .contains(Modifier::ITALIC));
+    }
+
+    #[test]
+    fn test_unclosed_code_block_runs_to_the_end() {
+        let text = markdown_to_text(
+            "```\n- not a list\n# not a header",
+            false,
+            false,
+            &Theme::default(),
+        );
+        let lines: Vec<String> = text.lines.iter().map(line_text).collect();
+        assert_eq!(
+            lines,
+            vec!["───── code ─────", "  - not a list", "  # not a header"]
+        );
+    }
+
+    #[test]
+    fn test_multibyte_inline_and_truncate() {
+        let line = parse_markdown_line("**héllo** _wörld_ `日本` 🚀 @dév", &Theme::default());
+        assert_eq!(line_text(&line), "héllo wörld 日本 🚀 @dév");
+
+        assert_eq!(truncate("日本語テキスト", 5), "日本...");
+        assert_eq!(truncate("añb", 3), "añb");
+    }
+
+    #[test]
+    fn test_render_nested_list_lines() {
+        let theme = Theme::default();
+        assert_eq!(line_text(&parse_markdown_line("- top", &theme)), "  • top");
+        assert_eq!(
+            line_text(&parse_markdown_line("  - child", &theme)),
+            "    • child"
+        );
+        assert_eq!(
+            line_text(&parse_markdown_line("    3. third", &theme)),
+            "      3. third"
+        );
+        assert_eq!(
+            line_text(&parse_markdown_line("- [ ] open", &theme)),
+            "  ☐ open"
+        );
+        assert_eq!(
+            line_text(&parse_markdown_line("  - [x] closed", &theme)),
+            "    ☑ closed"
+        );
+    }
+
+    #[test]
+    fn test_no_color_status_markers() {
+        let theme = Theme::default().without_color();
+        let (icon, _) = workflow_status_display(
+            WorkflowRunStatus::Completed,
+            Some(WorkflowConclusion::Failure),
+            0,
+            &theme,
+        );
+        assert_eq!(icon, "x");
+        assert_eq!(theme.spinner(1), "/");
+        assert!(theme.selected().add_modifier.contains(Modifier::REVERSED));
+
+        let mut buf = Buffer::empty(Rect::new(0, 0, 2, 1));
+        buf[(0, 0)].set_style(Theme::default().label("d73a4a"));
+        buf[(1, 0)].set_style(Style::default().fg(Color::Red).bg(Color::Black));
+        theme.apply(&mut buf);
+        assert_eq!(buf[(0, 0)].bg, Color::Reset);
+        assert!(buf[(0, 0)].modifier.contains(Modifier::REVERSED));
+        assert_eq!(buf[(1, 0)].fg, Color::Reset);
+        assert!(!buf[(1, 0)].modifier.contains(Modifier::REVERSED));
+    }
+
+    #[test]
+    fn test_theme_colors_markdown_and_labels() {
+        let theme = Theme::light();
+        let header = parse_markdown_line("## Summary", &theme);
+        assert_eq!(header.spans[0].style.fg, Some(theme.accent));
+        let bullet = parse_markdown_line("- item", &theme);
+        assert_eq!(bullet.spans[0].style.fg, Some(theme.highlight));
+
+        // Unparsable label colors fall back to the theme's muted color
+        let theme = Theme::high_contrast();
+        assert_eq!(theme.hex_color("nope"), theme.muted);
+        // 24-bit colors only where the terminal reports truecolor support
+        assert_eq!(theme.label("d73a4a").bg, Some(Color::Red));
+        assert_eq!(theme.hex_color("#0e8a16"), Color::Green);
+        assert_eq!(theme.hex_color("ededed"), Color::Gray);
+        assert_eq!(theme.hex_color("1d76db"), Color::LightBlue);
+        let truecolor = Theme {
+            truecolor: true,
+            ..theme
+        };
+        assert_eq!(
+            truecolor.label("d73a4a").bg,
+            Some(Color::Rgb(0xd7, 0x3a, 0x4a))
+        );
+        let label = theme.without_color().label("d73a4a");
+        assert_eq!(label.bg, None);
+        assert!(label.add_modifier.contains(Modifier::REVERSED));
+    }
+}